@@ -73,6 +73,7 @@ class ConversationSearchController extends ChangeNotifier {
         final results = await Future.wait<dynamic>([
           account.message().searchGlobalMessages(
             query: normalized,
+            rankByRelevance: false,
             limit: 32,
           ),
           account.user().searchLocalUsers(
@@ -137,6 +137,7 @@ class _SearchMessagePageState extends State<SearchMessagePage> {
         query: query,
         senderId: selectedUser?.userId,
         categories: selectedCategories ?? const [],
+        rankByRelevance: false,
         anchorMessageId: append ? messages.last.id : null,
         limit: 60,
       );
@@ -10,6 +10,7 @@ import '../third_party/mixin_desktop_api/model.dart';
 import '../third_party/mixin_desktop_core/runtime/model.dart';
 import 'device_transfer.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'search.dart';
 
 // These functions are ignored because they are not marked as `pub`: `new`
 
@@ -46,6 +47,8 @@ abstract class AccountHandle implements RustOpaqueInterface {
 
   Stream<DeviceTransferEvent> deviceTransferEvents();
 
+  Future<List<AccountSession>> linkedSessions();
+
   String mediaDirectory();
 
   MessageAccess message();
@@ -62,8 +65,16 @@ abstract class AccountHandle implements RustOpaqueInterface {
 
   void retryConnection();
 
+  Future<void> revokeLinkedSession({required String sessionId});
+
   Future<SnapshotDetailItem> safeSnapshotById({required String snapshotId});
 
+  SearchAccess search();
+
+  SearchHandle searchSession();
+
+  Stream<SessionLinkedItem> sessionLinkedEvents();
+
   Future<void> shutdown();
 
   Future<void> signOut();
@@ -18,6 +18,10 @@ Future<DesktopHandle> openDesktop() =>
 abstract class DesktopHandle implements RustOpaqueInterface {
   Future<void> abortSavedLogin();
 
+  Future<List<AccountHandle>> accounts();
+
+  Future<AccountHandle?> activeAccount();
+
   Future<LoginHandle> beginLogin();
 
   Future<HttpResponseItem> httpRequest({
@@ -31,17 +35,35 @@ abstract class DesktopHandle implements RustOpaqueInterface {
 
   MediaHandle get media;
 
+  Stream<AccountNotificationItem> notificationEvents();
+
+  Future<void> performNotificationAction({
+    required String accountId,
+    required String conversationId,
+    required String messageId,
+    required NotificationActionKind kind,
+    String? replyText,
+  });
+
   Future<void> recreateAccountDatabase();
 
   Future<AccountHandle> restoreAccount();
 
+  Future<List<AccountHandle>> restoreAccounts();
+
   SettingsHandle get settings;
+
+  Future<AccountHandle> switchAccount({required String accountId});
+
+  Stream<List<AccountUnreadCountItem>> unreadCountChanges();
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>>
 abstract class SettingsHandle implements RustOpaqueInterface {
   Future<bool> fileAutoDownload();
 
+  Future<String?> mcpAccountId();
+
   Future<McpServerStatusItem> mcpServerStatus();
 
   Future<McpSettingsItem> mcpSettings();
@@ -52,6 +74,8 @@ abstract class SettingsHandle implements RustOpaqueInterface {
 
   Future<void> setFileAutoDownload({required bool value});
 
+  Future<McpServerStatusItem> setMcpAccountId({String? accountId});
+
   Future<void> setPhotoAutoDownload({required bool value});
 
   Future<void> setProxySettings({required ProxySettingsItem settings});
@@ -76,3 +100,5 @@ abstract class SettingsHandle implements RustOpaqueInterface {
 
   Future<bool> videoAutoDownload();
 }
+
+enum NotificationActionKind { reply, markRead, muteForOneHour }
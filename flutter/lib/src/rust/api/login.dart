@@ -7,8 +7,11 @@ import '../error.dart';
 import '../frb_generated.dart';
 import 'account.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'login.freezed.dart';
 
 // These functions are ignored because they are not marked as `pub`: `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>>
 abstract class LoginHandle implements RustOpaqueInterface {
@@ -16,5 +19,23 @@ abstract class LoginHandle implements RustOpaqueInterface {
 
   void cancel();
 
+  Stream<LoginStatus> statusChanges();
+
   Future<AccountHandle> wait();
 }
+
+@freezed
+sealed class LoginStatus with _$LoginStatus {
+  const LoginStatus._();
+
+  const factory LoginStatus.waitingForScan({
+    required String authUrl,
+  }) = LoginStatus_WaitingForScan;
+  const factory LoginStatus.verifying() = LoginStatus_Verifying;
+  const factory LoginStatus.syncingKeys() = LoginStatus_SyncingKeys;
+  const factory LoginStatus.ready() = LoginStatus_Ready;
+  const factory LoginStatus.expired() = LoginStatus_Expired;
+  const factory LoginStatus.failed({
+    required String message,
+  }) = LoginStatus_Failed;
+}
@@ -0,0 +1,458 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'login.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$LoginStatus {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'LoginStatus()';
+}
+
+
+}
+
+/// @nodoc
+class $LoginStatusCopyWith<$Res>  {
+$LoginStatusCopyWith(LoginStatus _, $Res Function(LoginStatus) __);
+}
+
+
+/// Adds pattern-matching-related methods to [LoginStatus].
+extension LoginStatusPatterns on LoginStatus {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( LoginStatus_WaitingForScan value)?  waitingForScan,TResult Function( LoginStatus_Verifying value)?  verifying,TResult Function( LoginStatus_SyncingKeys value)?  syncingKeys,TResult Function( LoginStatus_Ready value)?  ready,TResult Function( LoginStatus_Expired value)?  expired,TResult Function( LoginStatus_Failed value)?  failed,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case LoginStatus_WaitingForScan() when waitingForScan != null:
+return waitingForScan(_that);case LoginStatus_Verifying() when verifying != null:
+return verifying(_that);case LoginStatus_SyncingKeys() when syncingKeys != null:
+return syncingKeys(_that);case LoginStatus_Ready() when ready != null:
+return ready(_that);case LoginStatus_Expired() when expired != null:
+return expired(_that);case LoginStatus_Failed() when failed != null:
+return failed(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( LoginStatus_WaitingForScan value)  waitingForScan,required TResult Function( LoginStatus_Verifying value)  verifying,required TResult Function( LoginStatus_SyncingKeys value)  syncingKeys,required TResult Function( LoginStatus_Ready value)  ready,required TResult Function( LoginStatus_Expired value)  expired,required TResult Function( LoginStatus_Failed value)  failed,}){
+final _that = this;
+switch (_that) {
+case LoginStatus_WaitingForScan():
+return waitingForScan(_that);case LoginStatus_Verifying():
+return verifying(_that);case LoginStatus_SyncingKeys():
+return syncingKeys(_that);case LoginStatus_Ready():
+return ready(_that);case LoginStatus_Expired():
+return expired(_that);case LoginStatus_Failed():
+return failed(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( LoginStatus_WaitingForScan value)?  waitingForScan,TResult? Function( LoginStatus_Verifying value)?  verifying,TResult? Function( LoginStatus_SyncingKeys value)?  syncingKeys,TResult? Function( LoginStatus_Ready value)?  ready,TResult? Function( LoginStatus_Expired value)?  expired,TResult? Function( LoginStatus_Failed value)?  failed,}){
+final _that = this;
+switch (_that) {
+case LoginStatus_WaitingForScan() when waitingForScan != null:
+return waitingForScan(_that);case LoginStatus_Verifying() when verifying != null:
+return verifying(_that);case LoginStatus_SyncingKeys() when syncingKeys != null:
+return syncingKeys(_that);case LoginStatus_Ready() when ready != null:
+return ready(_that);case LoginStatus_Expired() when expired != null:
+return expired(_that);case LoginStatus_Failed() when failed != null:
+return failed(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String authUrl)?  waitingForScan,TResult Function()?  verifying,TResult Function()?  syncingKeys,TResult Function()?  ready,TResult Function()?  expired,TResult Function( String message)?  failed,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case LoginStatus_WaitingForScan() when waitingForScan != null:
+return waitingForScan(_that.authUrl);case LoginStatus_Verifying() when verifying != null:
+return verifying();case LoginStatus_SyncingKeys() when syncingKeys != null:
+return syncingKeys();case LoginStatus_Ready() when ready != null:
+return ready();case LoginStatus_Expired() when expired != null:
+return expired();case LoginStatus_Failed() when failed != null:
+return failed(_that.message);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String authUrl)  waitingForScan,required TResult Function()  verifying,required TResult Function()  syncingKeys,required TResult Function()  ready,required TResult Function()  expired,required TResult Function( String message)  failed,}) {final _that = this;
+switch (_that) {
+case LoginStatus_WaitingForScan():
+return waitingForScan(_that.authUrl);case LoginStatus_Verifying():
+return verifying();case LoginStatus_SyncingKeys():
+return syncingKeys();case LoginStatus_Ready():
+return ready();case LoginStatus_Expired():
+return expired();case LoginStatus_Failed():
+return failed(_that.message);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String authUrl)?  waitingForScan,TResult? Function()?  verifying,TResult? Function()?  syncingKeys,TResult? Function()?  ready,TResult? Function()?  expired,TResult? Function( String message)?  failed,}) {final _that = this;
+switch (_that) {
+case LoginStatus_WaitingForScan() when waitingForScan != null:
+return waitingForScan(_that.authUrl);case LoginStatus_Verifying() when verifying != null:
+return verifying();case LoginStatus_SyncingKeys() when syncingKeys != null:
+return syncingKeys();case LoginStatus_Ready() when ready != null:
+return ready();case LoginStatus_Expired() when expired != null:
+return expired();case LoginStatus_Failed() when failed != null:
+return failed(_that.message);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class LoginStatus_WaitingForScan extends LoginStatus {
+  const LoginStatus_WaitingForScan({required this.authUrl}): super._();
+
+
+ final  String authUrl;
+
+/// Create a copy of LoginStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$LoginStatus_WaitingForScanCopyWith<LoginStatus_WaitingForScan> get copyWith => _$LoginStatus_WaitingForScanCopyWithImpl<LoginStatus_WaitingForScan>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus_WaitingForScan&&(identical(other.authUrl, authUrl) || other.authUrl == authUrl));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,authUrl);
+
+@override
+String toString() {
+  return 'LoginStatus.waitingForScan(authUrl: $authUrl)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $LoginStatus_WaitingForScanCopyWith<$Res> implements $LoginStatusCopyWith<$Res> {
+  factory $LoginStatus_WaitingForScanCopyWith(LoginStatus_WaitingForScan value, $Res Function(LoginStatus_WaitingForScan) _then) = _$LoginStatus_WaitingForScanCopyWithImpl;
+@useResult
+$Res call({
+ String authUrl
+});
+
+
+
+
+}
+/// @nodoc
+class _$LoginStatus_WaitingForScanCopyWithImpl<$Res>
+    implements $LoginStatus_WaitingForScanCopyWith<$Res> {
+  _$LoginStatus_WaitingForScanCopyWithImpl(this._self, this._then);
+
+  final LoginStatus_WaitingForScan _self;
+  final $Res Function(LoginStatus_WaitingForScan) _then;
+
+/// Create a copy of LoginStatus
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? authUrl = null,}) {
+  return _then(LoginStatus_WaitingForScan(
+authUrl: null == authUrl ? _self.authUrl : authUrl // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class LoginStatus_Verifying extends LoginStatus {
+  const LoginStatus_Verifying(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus_Verifying);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'LoginStatus.verifying()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class LoginStatus_SyncingKeys extends LoginStatus {
+  const LoginStatus_SyncingKeys(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus_SyncingKeys);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'LoginStatus.syncingKeys()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class LoginStatus_Ready extends LoginStatus {
+  const LoginStatus_Ready(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus_Ready);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'LoginStatus.ready()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class LoginStatus_Expired extends LoginStatus {
+  const LoginStatus_Expired(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus_Expired);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'LoginStatus.expired()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class LoginStatus_Failed extends LoginStatus {
+  const LoginStatus_Failed({required this.message}): super._();
+
+
+ final  String message;
+
+/// Create a copy of LoginStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$LoginStatus_FailedCopyWith<LoginStatus_Failed> get copyWith => _$LoginStatus_FailedCopyWithImpl<LoginStatus_Failed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is LoginStatus_Failed&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'LoginStatus.failed(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $LoginStatus_FailedCopyWith<$Res> implements $LoginStatusCopyWith<$Res> {
+  factory $LoginStatus_FailedCopyWith(LoginStatus_Failed value, $Res Function(LoginStatus_Failed) _then) = _$LoginStatus_FailedCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$LoginStatus_FailedCopyWithImpl<$Res>
+    implements $LoginStatus_FailedCopyWith<$Res> {
+  _$LoginStatus_FailedCopyWithImpl(this._self, this._then);
+
+  final LoginStatus_Failed _self;
+  final $Res Function(LoginStatus_Failed) _then;
+
+/// Create a copy of LoginStatus
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(LoginStatus_Failed(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
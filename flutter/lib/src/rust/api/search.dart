@@ -0,0 +1,19 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../third_party/mixin_desktop_core/runtime/model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `new`
+
+/// Search-as-you-type: every query set replaces the search still running
+/// for the previous one.
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>
+abstract class SearchHandle implements RustOpaqueInterface {
+  Stream<GlobalSearchView> results({required int limit});
+
+  void setQuery({required String query});
+}
@@ -9,6 +9,7 @@ import 'api/device_transfer.dart';
 import 'api/logging.dart';
 import 'api/login.dart';
 import 'api/media.dart';
+import 'api/search.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'error.dart';
@@ -150,6 +151,10 @@ abstract class RustLibApi extends BaseApi {
     required AccountHandle that,
   });
 
+  Future<List<AccountSession>> crateApiAccountAccountHandleLinkedSessions({
+    required AccountHandle that,
+  });
+
   String crateApiAccountAccountHandleMediaDirectory({
     required AccountHandle that,
   });
@@ -182,11 +187,28 @@ abstract class RustLibApi extends BaseApi {
     required AccountHandle that,
   });
 
+  Future<void> crateApiAccountAccountHandleRevokeLinkedSession({
+    required AccountHandle that,
+    required String sessionId,
+  });
+
   Future<SnapshotDetailItem> crateApiAccountAccountHandleSafeSnapshotById({
     required AccountHandle that,
     required String snapshotId,
   });
 
+  SearchAccess crateApiAccountAccountHandleSearch({
+    required AccountHandle that,
+  });
+
+  SearchHandle crateApiAccountAccountHandleSearchSession({
+    required AccountHandle that,
+  });
+
+  Stream<SessionLinkedItem> crateApiAccountAccountHandleSessionLinkedEvents({
+    required AccountHandle that,
+  });
+
   Future<void> crateApiAccountAccountHandleShutdown({
     required AccountHandle that,
   });
@@ -261,6 +283,13 @@ abstract class RustLibApi extends BaseApi {
     required String messageId,
   });
 
+  Future<VideoRangeItem> mixinDesktopApiAccessAttachmentAccessReadVideoRange({
+    required AttachmentAccess that,
+    required String messageId,
+    required BigInt offset,
+    required BigInt length,
+  });
+
   Future<void> mixinDesktopApiAccessAttachmentAccessRetryAttachment({
     required AttachmentAccess that,
     required String messageId,
@@ -470,6 +499,14 @@ abstract class RustLibApi extends BaseApi {
     required DesktopHandle that,
   });
 
+  Future<List<AccountHandle>> crateApiDesktopDesktopHandleAccounts({
+    required DesktopHandle that,
+  });
+
+  Future<AccountHandle?> crateApiDesktopDesktopHandleActiveAccount({
+    required DesktopHandle that,
+  });
+
   Future<LoginHandle> crateApiDesktopDesktopHandleBeginLogin({
     required DesktopHandle that,
   });
@@ -486,6 +523,18 @@ abstract class RustLibApi extends BaseApi {
 
   MediaHandle crateApiDesktopDesktopHandleMedia({required DesktopHandle that});
 
+  Stream<AccountNotificationItem>
+  crateApiDesktopDesktopHandleNotificationEvents({required DesktopHandle that});
+
+  Future<void> crateApiDesktopDesktopHandlePerformNotificationAction({
+    required DesktopHandle that,
+    required String accountId,
+    required String conversationId,
+    required String messageId,
+    required NotificationActionKind kind,
+    String? replyText,
+  });
+
   Future<void> crateApiDesktopDesktopHandleRecreateAccountDatabase({
     required DesktopHandle that,
   });
@@ -494,14 +543,30 @@ abstract class RustLibApi extends BaseApi {
     required DesktopHandle that,
   });
 
+  Future<List<AccountHandle>> crateApiDesktopDesktopHandleRestoreAccounts({
+    required DesktopHandle that,
+  });
+
   SettingsHandle crateApiDesktopDesktopHandleSettings({
     required DesktopHandle that,
   });
 
+  Future<AccountHandle> crateApiDesktopDesktopHandleSwitchAccount({
+    required DesktopHandle that,
+    required String accountId,
+  });
+
+  Stream<List<AccountUnreadCountItem>>
+  crateApiDesktopDesktopHandleUnreadCountChanges({required DesktopHandle that});
+
   String crateApiLoginLoginHandleAuthUrl({required LoginHandle that});
 
   void crateApiLoginLoginHandleCancel({required LoginHandle that});
 
+  Stream<LoginStatus> crateApiLoginLoginHandleStatusChanges({
+    required LoginHandle that,
+  });
+
   Future<AccountHandle> crateApiLoginLoginHandleWait({
     required LoginHandle that,
   });
@@ -556,6 +621,11 @@ abstract class RustLibApi extends BaseApi {
     required MediaHandle that,
   });
 
+  Future<void> mixinDesktopApiAccessMessageAccessCancelOutgoingMessage({
+    required MessageAccess that,
+    required String messageId,
+  });
+
   Future<String> mixinDesktopApiAccessMessageAccessCombineForwardMessages({
     required MessageAccess that,
     required String targetConversationId,
@@ -573,6 +643,20 @@ abstract class RustLibApi extends BaseApi {
     required List<String> messageIds,
   });
 
+  Future<void> mixinDesktopApiAccessMessageAccessEditMessage({
+    required MessageAccess that,
+    required String conversationId,
+    required String messageId,
+    required String content,
+  });
+
+  Future<List<OutgoingMessageItem>>
+  mixinDesktopApiAccessMessageAccessFailedOutgoingMessages({
+    required MessageAccess that,
+    String? conversationId,
+    required PlatformInt64 limit,
+  });
+
   Future<List<String>> mixinDesktopApiAccessMessageAccessForwardMessages({
     required MessageAccess that,
     required String targetConversationId,
@@ -588,6 +672,11 @@ abstract class RustLibApi extends BaseApi {
     required PlatformInt64 after,
   });
 
+  Future<LinkPreviewView?> mixinDesktopApiAccessMessageAccessLoadLinkPreview({
+    required MessageAccess that,
+    required String messageId,
+  });
+
   Future<void> mixinDesktopApiAccessMessageAccessMarkConversationRead({
     required MessageAccess that,
     required String conversationId,
@@ -599,6 +688,12 @@ abstract class RustLibApi extends BaseApi {
     required String messageId,
   });
 
+  Future<List<MessageEditItem>>
+  mixinDesktopApiAccessMessageAccessMessageEditHistory({
+    required MessageAccess that,
+    required String messageId,
+  });
+
   Future<List<String>> mixinDesktopApiAccessMessageAccessMessageIdsAfter({
     required MessageAccess that,
     required String conversationId,
@@ -627,6 +722,12 @@ abstract class RustLibApi extends BaseApi {
     required String messageId,
   });
 
+  Future<List<MessageReactionItem>>
+  mixinDesktopApiAccessMessageAccessMessageReactions({
+    required MessageAccess that,
+    required String messageId,
+  });
+
   Future<List<MessageListView>> mixinDesktopApiAccessMessageAccessMessages({
     required MessageAccess that,
     required String conversationId,
@@ -661,16 +762,32 @@ abstract class RustLibApi extends BaseApi {
     required String conversationId,
   });
 
+  Future<void> mixinDesktopApiAccessMessageAccessReactToMessage({
+    required MessageAccess that,
+    required String conversationId,
+    required String messageId,
+    required String emoji,
+    required bool removed,
+  });
+
   Future<void> mixinDesktopApiAccessMessageAccessRecallMessages({
     required MessageAccess that,
     required String conversationId,
     required List<String> messageIds,
   });
 
+  Future<void> mixinDesktopApiAccessMessageAccessRetryOutgoingMessage({
+    required MessageAccess that,
+    required String messageId,
+  });
+
   Future<List<MessageListView>>
   mixinDesktopApiAccessMessageAccessSearchGlobalMessages({
     required MessageAccess that,
     required String query,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   });
@@ -682,6 +799,9 @@ abstract class RustLibApi extends BaseApi {
     required String query,
     String? senderId,
     required List<String> categories,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   });
@@ -725,6 +845,14 @@ abstract class RustLibApi extends BaseApi {
     required bool silent,
   });
 
+  Future<String> mixinDesktopApiAccessMessageAccessSendLocation({
+    required MessageAccess that,
+    required String conversationId,
+    required LocationView location,
+    String? quoteMessageId,
+    required bool silent,
+  });
+
   Future<String> mixinDesktopApiAccessMessageAccessSendPost({
     required MessageAccess that,
     required String conversationId,
@@ -784,10 +912,39 @@ abstract class RustLibApi extends BaseApi {
     required String conversationId,
   });
 
+  Future<GlobalSearchView> mixinDesktopApiAccessSearchAccessGlobalSearch({
+    required SearchAccess that,
+    required String query,
+    required int limit,
+  });
+
+  Future<GlobalSearchView>
+  mixinDesktopApiAccessSearchAccessGlobalSearchSection({
+    required SearchAccess that,
+    required String query,
+    required String section,
+    String? cursor,
+    required int limit,
+  });
+
+  Stream<GlobalSearchView> crateApiSearchSearchHandleResults({
+    required SearchHandle that,
+    required int limit,
+  });
+
+  void crateApiSearchSearchHandleSetQuery({
+    required SearchHandle that,
+    required String query,
+  });
+
   Future<bool> crateApiDesktopSettingsHandleFileAutoDownload({
     required SettingsHandle that,
   });
 
+  Future<String?> crateApiDesktopSettingsHandleMcpAccountId({
+    required SettingsHandle that,
+  });
+
   Future<McpServerStatusItem> crateApiDesktopSettingsHandleMcpServerStatus({
     required SettingsHandle that,
   });
@@ -809,6 +966,11 @@ abstract class RustLibApi extends BaseApi {
     required bool value,
   });
 
+  Future<McpServerStatusItem> crateApiDesktopSettingsHandleSetMcpAccountId({
+    required SettingsHandle that,
+    String? accountId,
+  });
+
   Future<void> crateApiDesktopSettingsHandleSetPhotoAutoDownload({
     required SettingsHandle that,
     required bool value,
@@ -940,6 +1102,10 @@ abstract class RustLibApi extends BaseApi {
     required String userId,
   });
 
+  Future<List<UserProfileItem>> mixinDesktopApiAccessUserAccessBlockedUsers({
+    required UserAccess that,
+  });
+
   Future<String?> mixinDesktopApiAccessUserAccessBotCreatorId({
     required UserAccess that,
     required String userId,
@@ -950,6 +1116,10 @@ abstract class RustLibApi extends BaseApi {
     required String appId,
   });
 
+  Future<List<ContactGroup>> mixinDesktopApiAccessUserAccessContacts({
+    required UserAccess that,
+  });
+
   Future<List<SharedAppItem>> mixinDesktopApiAccessUserAccessLocalSharedApps({
     required UserAccess that,
     required String userId,
@@ -1002,6 +1172,13 @@ abstract class RustLibApi extends BaseApi {
     required UserAccess that,
   });
 
+  Future<void> mixinDesktopApiAccessUserAccessSetUserAnnotation({
+    required UserAccess that,
+    required String userId,
+    String? alias,
+    String? note,
+  });
+
   Future<List<SharedAppItem>> mixinDesktopApiAccessUserAccessSharedApps({
     required UserAccess that,
     required String userId,
@@ -1012,6 +1189,11 @@ abstract class RustLibApi extends BaseApi {
     required String userId,
   });
 
+  Future<UserAnnotationItem?> mixinDesktopApiAccessUserAccessUserAnnotation({
+    required UserAccess that,
+    required String userId,
+  });
+
   Future<UserProfileItem?> mixinDesktopApiAccessUserAccessUserProfile({
     required UserAccess that,
     String? userId,
@@ -1110,6 +1292,23 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_MessageAccessPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SearchAccess;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SearchAccess;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SearchAccessPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SearchHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SearchHandle;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SearchHandlePtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_SettingsHandle;
 
@@ -1647,6 +1846,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "sink"],
       );
 
+  @override
+  Future<List<AccountSession>> crateApiAccountAccountHandleLinkedSessions({
+    required AccountHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account_session,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiAccountAccountHandleLinkedSessionsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountAccountHandleLinkedSessionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "AccountHandle_linked_sessions",
+        argNames: ["that"],
+      );
+
   @override
   String crateApiAccountAccountHandleMediaDirectory({
     required AccountHandle that,
@@ -1659,7 +1894,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1690,7 +1925,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -1728,7 +1963,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 16,
+              funcId: 17,
               port: port_,
             );
           },
@@ -1763,7 +1998,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_account_profile,
@@ -1800,7 +2035,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 18,
+              funcId: 19,
               port: port_,
             );
           },
@@ -1838,7 +2073,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 20,
             port: port_,
           );
         },
@@ -1875,7 +2110,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 21,
             port: port_,
           );
         },
@@ -1908,7 +2143,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -1928,9 +2163,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<SnapshotDetailItem> crateApiAccountAccountHandleSafeSnapshotById({
+  Future<void> crateApiAccountAccountHandleRevokeLinkedSession({
     required AccountHandle that,
-    required String snapshotId,
+    required String sessionId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -1940,34 +2175,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_String(snapshotId, serializer);
+          sse_encode_String(sessionId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_snapshot_detail_item,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiAccountAccountHandleSafeSnapshotByIdConstMeta,
-        argValues: [that, snapshotId],
+        constMeta: kCrateApiAccountAccountHandleRevokeLinkedSessionConstMeta,
+        argValues: [that, sessionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountAccountHandleSafeSnapshotByIdConstMeta =>
+  TaskConstMeta get kCrateApiAccountAccountHandleRevokeLinkedSessionConstMeta =>
       const TaskConstMeta(
-        debugName: "AccountHandle_safe_snapshot_by_id",
-        argNames: ["that", "snapshotId"],
+        debugName: "AccountHandle_revoke_linked_session",
+        argNames: ["that", "sessionId"],
       );
 
   @override
-  Future<void> crateApiAccountAccountHandleShutdown({
+  Future<SnapshotDetailItem> crateApiAccountAccountHandleSafeSnapshotById({
     required AccountHandle that,
+    required String snapshotId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -1977,126 +2213,268 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
+          sse_encode_String(snapshotId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_snapshot_detail_item,
+          decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiAccountAccountHandleShutdownConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiAccountAccountHandleSafeSnapshotByIdConstMeta,
+        argValues: [that, snapshotId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountAccountHandleShutdownConstMeta =>
+  TaskConstMeta get kCrateApiAccountAccountHandleSafeSnapshotByIdConstMeta =>
       const TaskConstMeta(
-        debugName: "AccountHandle_shutdown",
-        argNames: ["that"],
+        debugName: "AccountHandle_safe_snapshot_by_id",
+        argNames: ["that", "snapshotId"],
       );
 
   @override
-  Future<void> crateApiAccountAccountHandleSignOut({
+  SearchAccess crateApiAccountAccountHandleSearch({
     required AccountHandle that,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
             that,
             serializer,
           );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_core_error,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiAccountAccountHandleSignOutConstMeta,
+        constMeta: kCrateApiAccountAccountHandleSearchConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountAccountHandleSignOutConstMeta =>
+  TaskConstMeta get kCrateApiAccountAccountHandleSearchConstMeta =>
       const TaskConstMeta(
-        debugName: "AccountHandle_sign_out",
+        debugName: "AccountHandle_search",
         argNames: ["that"],
       );
 
   @override
-  Future<SnapshotDetailItem> crateApiAccountAccountHandleSnapshotById({
+  SearchHandle crateApiAccountAccountHandleSearchSession({
     required AccountHandle that,
-    required String snapshotId,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
             that,
             serializer,
           );
-          sse_encode_String(snapshotId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_snapshot_detail_item,
-          decodeErrorData: sse_decode_core_error,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiAccountAccountHandleSnapshotByIdConstMeta,
-        argValues: [that, snapshotId],
+        constMeta: kCrateApiAccountAccountHandleSearchSessionConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAccountAccountHandleSnapshotByIdConstMeta =>
+  TaskConstMeta get kCrateApiAccountAccountHandleSearchSessionConstMeta =>
       const TaskConstMeta(
-        debugName: "AccountHandle_snapshot_by_id",
-        argNames: ["that", "snapshotId"],
+        debugName: "AccountHandle_search_session",
+        argNames: ["that"],
       );
 
   @override
-  Future<SnapshotDetailItem> crateApiAccountAccountHandleSnapshotByTrace({
+  Stream<SessionLinkedItem> crateApiAccountAccountHandleSessionLinkedEvents({
     required AccountHandle that,
-    required String traceId,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
-            that,
-            serializer,
-          );
-          sse_encode_String(traceId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+    final sink = RustStreamSink<SessionLinkedItem>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_session_linked_item_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 27,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_core_error,
+          ),
+          constMeta: kCrateApiAccountAccountHandleSessionLinkedEventsConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAccountAccountHandleSessionLinkedEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "AccountHandle_session_linked_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<void> crateApiAccountAccountHandleShutdown({
+    required AccountHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAccountAccountHandleShutdownConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountAccountHandleShutdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "AccountHandle_shutdown",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiAccountAccountHandleSignOut({
+    required AccountHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiAccountAccountHandleSignOutConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountAccountHandleSignOutConstMeta =>
+      const TaskConstMeta(
+        debugName: "AccountHandle_sign_out",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<SnapshotDetailItem> crateApiAccountAccountHandleSnapshotById({
+    required AccountHandle that,
+    required String snapshotId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(snapshotId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_snapshot_detail_item,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiAccountAccountHandleSnapshotByIdConstMeta,
+        argValues: [that, snapshotId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAccountAccountHandleSnapshotByIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "AccountHandle_snapshot_by_id",
+        argNames: ["that", "snapshotId"],
+      );
+
+  @override
+  Future<SnapshotDetailItem> crateApiAccountAccountHandleSnapshotByTrace({
+    required AccountHandle that,
+    required String traceId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(traceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
           decodeSuccessData: sse_decode_snapshot_detail_item,
           decodeErrorData: sse_decode_core_error,
         ),
@@ -2125,7 +2503,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -2159,7 +2537,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 33,
             port: port_,
           );
         },
@@ -2202,7 +2580,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 29,
+              funcId: 34,
               port: port_,
             );
           },
@@ -2243,7 +2621,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 30,
+              funcId: 35,
               port: port_,
             );
           },
@@ -2287,7 +2665,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 36,
             port: port_,
           );
         },
@@ -2318,7 +2696,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -2355,7 +2733,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 38,
             port: port_,
           );
         },
@@ -2398,7 +2776,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 39,
             port: port_,
           );
         },
@@ -2439,7 +2817,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 40,
             port: port_,
           );
         },
@@ -2483,7 +2861,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 41,
             port: port_,
           );
         },
@@ -2524,7 +2902,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 37,
+            funcId: 42,
             port: port_,
           );
         },
@@ -2566,7 +2944,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 38,
+            funcId: 43,
             port: port_,
           );
         },
@@ -2590,6 +2968,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "transcriptId", "messageId"],
       );
 
+  @override
+  Future<VideoRangeItem> mixinDesktopApiAccessAttachmentAccessReadVideoRange({
+    required AttachmentAccess that,
+    required String messageId,
+    required BigInt offset,
+    required BigInt length,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAttachmentAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          sse_encode_u_64(offset, serializer);
+          sse_encode_u_64(length, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_video_range_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessAttachmentAccessReadVideoRangeConstMeta,
+        argValues: [that, messageId, offset, length],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessAttachmentAccessReadVideoRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "AttachmentAccess_read_video_range",
+        argNames: ["that", "messageId", "offset", "length"],
+      );
+
   @override
   Future<void> mixinDesktopApiAccessAttachmentAccessRetryAttachment({
     required AttachmentAccess that,
@@ -2607,7 +3030,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 39,
+            funcId: 45,
             port: port_,
           );
         },
@@ -2648,7 +3071,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 46,
             port: port_,
           );
         },
@@ -2687,7 +3110,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 47,
             port: port_,
           );
         },
@@ -2726,7 +3149,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 42,
+            funcId: 48,
             port: port_,
           );
         },
@@ -2774,7 +3197,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 43,
+            funcId: 49,
             port: port_,
           );
         },
@@ -2816,7 +3239,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 44,
+            funcId: 50,
             port: port_,
           );
         },
@@ -2856,7 +3279,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 45,
+            funcId: 51,
             port: port_,
           );
         },
@@ -2898,7 +3321,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 46,
+            funcId: 52,
             port: port_,
           );
         },
@@ -2940,7 +3363,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 53,
             port: port_,
           );
         },
@@ -2992,7 +3415,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 54,
             port: port_,
           );
         },
@@ -3049,7 +3472,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 55,
             port: port_,
           );
         },
@@ -3092,7 +3515,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 56,
             port: port_,
           );
         },
@@ -3132,7 +3555,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 57,
             port: port_,
           );
         },
@@ -3173,7 +3596,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 52,
+            funcId: 58,
             port: port_,
           );
         },
@@ -3214,7 +3637,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 59,
             port: port_,
           );
         },
@@ -3263,7 +3686,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 54,
+            funcId: 60,
             port: port_,
           );
         },
@@ -3315,7 +3738,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 55,
+            funcId: 61,
             port: port_,
           );
         },
@@ -3356,7 +3779,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 56,
+            funcId: 62,
             port: port_,
           );
         },
@@ -3397,7 +3820,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 57,
+            funcId: 63,
             port: port_,
           );
         },
@@ -3438,7 +3861,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 58,
+            funcId: 64,
             port: port_,
           );
         },
@@ -3478,7 +3901,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 59,
+            funcId: 65,
             port: port_,
           );
         },
@@ -3519,7 +3942,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 60,
+            funcId: 66,
             port: port_,
           );
         },
@@ -3560,7 +3983,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 61,
+            funcId: 67,
             port: port_,
           );
         },
@@ -3601,7 +4024,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 62,
+            funcId: 68,
             port: port_,
           );
         },
@@ -3642,7 +4065,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 63,
+            funcId: 69,
             port: port_,
           );
         },
@@ -3682,7 +4105,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 64,
+            funcId: 70,
             port: port_,
           );
         },
@@ -3726,7 +4149,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 65,
+            funcId: 71,
             port: port_,
           );
         },
@@ -3770,7 +4193,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 66,
+            funcId: 72,
             port: port_,
           );
         },
@@ -3813,7 +4236,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 67,
+            funcId: 73,
             port: port_,
           );
         },
@@ -3860,7 +4283,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 68,
+            funcId: 74,
             port: port_,
           );
         },
@@ -3907,7 +4330,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 69,
+            funcId: 75,
             port: port_,
           );
         },
@@ -3949,7 +4372,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 70,
+            funcId: 76,
             port: port_,
           );
         },
@@ -3992,7 +4415,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 71,
+            funcId: 77,
             port: port_,
           );
         },
@@ -4038,7 +4461,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 72,
+            funcId: 78,
             port: port_,
           );
         },
@@ -4077,7 +4500,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 73,
+            funcId: 79,
             port: port_,
           );
         },
@@ -4098,6 +4521,80 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<List<AccountHandle>> crateApiDesktopDesktopHandleAccounts({
+    required DesktopHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiDesktopDesktopHandleAccountsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDesktopDesktopHandleAccountsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DesktopHandle_accounts",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<AccountHandle?> crateApiDesktopDesktopHandleActiveAccount({
+    required DesktopHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiDesktopDesktopHandleActiveAccountConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDesktopDesktopHandleActiveAccountConstMeta =>
+      const TaskConstMeta(
+        debugName: "DesktopHandle_active_account",
+        argNames: ["that"],
+      );
+
   @override
   Future<LoginHandle> crateApiDesktopDesktopHandleBeginLogin({
     required DesktopHandle that,
@@ -4113,7 +4610,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 74,
+            funcId: 82,
             port: port_,
           );
         },
@@ -4162,7 +4659,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 75,
+            funcId: 83,
             port: port_,
           );
         },
@@ -4209,7 +4706,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -4230,8 +4727,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<void> crateApiDesktopDesktopHandleRecreateAccountDatabase({
+  Stream<AccountNotificationItem>
+  crateApiDesktopDesktopHandleNotificationEvents({
+    required DesktopHandle that,
+  }) {
+    final sink = RustStreamSink<AccountNotificationItem>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_account_notification_item_Sse(
+              sink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 85,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_core_error,
+          ),
+          constMeta: kCrateApiDesktopDesktopHandleNotificationEventsConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDesktopDesktopHandleNotificationEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DesktopHandle_notification_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<void> crateApiDesktopDesktopHandlePerformNotificationAction({
     required DesktopHandle that,
+    required String accountId,
+    required String conversationId,
+    required String messageId,
+    required NotificationActionKind kind,
+    String? replyText,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -4241,10 +4788,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
+          sse_encode_String(accountId, serializer);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_notification_action_kind(kind, serializer);
+          sse_encode_opt_String(replyText, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 77,
+            funcId: 86,
             port: port_,
           );
         },
@@ -4252,23 +4804,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_core_error,
         ),
-        constMeta:
-            kCrateApiDesktopDesktopHandleRecreateAccountDatabaseConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiDesktopDesktopHandlePerformNotificationActionConstMeta,
+        argValues: [
+          that,
+          accountId,
+          conversationId,
+          messageId,
+          kind,
+          replyText,
+        ],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kCrateApiDesktopDesktopHandleRecreateAccountDatabaseConstMeta =>
+  get kCrateApiDesktopDesktopHandlePerformNotificationActionConstMeta =>
       const TaskConstMeta(
-        debugName: "DesktopHandle_recreate_account_database",
-        argNames: ["that"],
+        debugName: "DesktopHandle_perform_notification_action",
+        argNames: [
+          "that",
+          "accountId",
+          "conversationId",
+          "messageId",
+          "kind",
+          "replyText",
+        ],
       );
 
   @override
-  Future<AccountHandle> crateApiDesktopDesktopHandleRestoreAccount({
+  Future<void> crateApiDesktopDesktopHandleRecreateAccountDatabase({
     required DesktopHandle that,
   }) {
     return handler.executeNormal(
@@ -4282,134 +4847,153 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 78,
+            funcId: 87,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiDesktopDesktopHandleRestoreAccountConstMeta,
+        constMeta:
+            kCrateApiDesktopDesktopHandleRecreateAccountDatabaseConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDesktopDesktopHandleRestoreAccountConstMeta =>
+  TaskConstMeta
+  get kCrateApiDesktopDesktopHandleRecreateAccountDatabaseConstMeta =>
       const TaskConstMeta(
-        debugName: "DesktopHandle_restore_account",
+        debugName: "DesktopHandle_recreate_account_database",
         argNames: ["that"],
       );
 
   @override
-  SettingsHandle crateApiDesktopDesktopHandleSettings({
+  Future<AccountHandle> crateApiDesktopDesktopHandleRestoreAccount({
     required DesktopHandle that,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+          decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiDesktopDesktopHandleSettingsConstMeta,
+        constMeta: kCrateApiDesktopDesktopHandleRestoreAccountConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDesktopDesktopHandleSettingsConstMeta =>
+  TaskConstMeta get kCrateApiDesktopDesktopHandleRestoreAccountConstMeta =>
       const TaskConstMeta(
-        debugName: "DesktopHandle_settings",
+        debugName: "DesktopHandle_restore_account",
         argNames: ["that"],
       );
 
   @override
-  String crateApiLoginLoginHandleAuthUrl({required LoginHandle that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<List<AccountHandle>> crateApiDesktopDesktopHandleRestoreAccounts({
+    required DesktopHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+          decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiLoginLoginHandleAuthUrlConstMeta,
+        constMeta: kCrateApiDesktopDesktopHandleRestoreAccountsConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoginLoginHandleAuthUrlConstMeta =>
+  TaskConstMeta get kCrateApiDesktopDesktopHandleRestoreAccountsConstMeta =>
       const TaskConstMeta(
-        debugName: "LoginHandle_auth_url",
+        debugName: "DesktopHandle_restore_accounts",
         argNames: ["that"],
       );
 
   @override
-  void crateApiLoginLoginHandleCancel({required LoginHandle that}) {
+  SettingsHandle crateApiDesktopDesktopHandleSettings({
+    required DesktopHandle that,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiLoginLoginHandleCancelConstMeta,
+        constMeta: kCrateApiDesktopDesktopHandleSettingsConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoginLoginHandleCancelConstMeta =>
+  TaskConstMeta get kCrateApiDesktopDesktopHandleSettingsConstMeta =>
       const TaskConstMeta(
-        debugName: "LoginHandle_cancel",
+        debugName: "DesktopHandle_settings",
         argNames: ["that"],
       );
 
   @override
-  Future<AccountHandle> crateApiLoginLoginHandleWait({
-    required LoginHandle that,
+  Future<AccountHandle> crateApiDesktopDesktopHandleSwitchAccount({
+    required DesktopHandle that,
+    required String accountId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
             that,
             serializer,
           );
+          sse_encode_String(accountId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 82,
+            funcId: 91,
             port: port_,
           );
         },
@@ -4418,38 +5002,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
           decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiLoginLoginHandleWaitConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiDesktopDesktopHandleSwitchAccountConstMeta,
+        argValues: [that, accountId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoginLoginHandleWaitConstMeta =>
+  TaskConstMeta get kCrateApiDesktopDesktopHandleSwitchAccountConstMeta =>
       const TaskConstMeta(
-        debugName: "LoginHandle_wait",
-        argNames: ["that"],
+        debugName: "DesktopHandle_switch_account",
+        argNames: ["that", "accountId"],
       );
 
   @override
-  Stream<MediaPlaybackEvent> crateApiMediaMediaHandleAudioPlaybackEvents({
-    required MediaHandle that,
+  Stream<List<AccountUnreadCountItem>>
+  crateApiDesktopDesktopHandleUnreadCountChanges({
+    required DesktopHandle that,
   }) {
-    final sink = RustStreamSink<MediaPlaybackEvent>();
+    final sink = RustStreamSink<List<AccountUnreadCountItem>>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaHandle(
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDesktopHandle(
               that,
               serializer,
             );
-            sse_encode_StreamSink_media_playback_event_Sse(sink, serializer);
+            sse_encode_StreamSink_list_account_unread_count_item_Sse(
+              sink,
+              serializer,
+            );
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 83,
+              funcId: 92,
               port: port_,
             );
           },
@@ -4457,7 +5045,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             decodeSuccessData: sse_decode_unit,
             decodeErrorData: sse_decode_core_error,
           ),
-          constMeta: kCrateApiMediaMediaHandleAudioPlaybackEventsConstMeta,
+          constMeta: kCrateApiDesktopDesktopHandleUnreadCountChangesConstMeta,
           argValues: [that, sink],
           apiImpl: this,
         ),
@@ -4466,25 +5054,202 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiMediaMediaHandleAudioPlaybackEventsConstMeta =>
+  TaskConstMeta get kCrateApiDesktopDesktopHandleUnreadCountChangesConstMeta =>
       const TaskConstMeta(
-        debugName: "MediaHandle_audio_playback_events",
+        debugName: "DesktopHandle_unread_count_changes",
         argNames: ["that", "sink"],
       );
 
   @override
-  MediaPlaybackSnapshot crateApiMediaMediaHandleAudioPlaybackSnapshot({
-    required MediaHandle that,
-  }) {
+  String crateApiLoginLoginHandleAuthUrl({required LoginHandle that}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaHandle(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoginLoginHandleAuthUrlConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoginLoginHandleAuthUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "LoginHandle_auth_url",
+        argNames: ["that"],
+      );
+
+  @override
+  void crateApiLoginLoginHandleCancel({required LoginHandle that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoginLoginHandleCancelConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoginLoginHandleCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: "LoginHandle_cancel",
+        argNames: ["that"],
+      );
+
+  @override
+  Stream<LoginStatus> crateApiLoginLoginHandleStatusChanges({
+    required LoginHandle that,
+  }) {
+    final sink = RustStreamSink<LoginStatus>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_login_status_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 95,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_core_error,
+          ),
+          constMeta: kCrateApiLoginLoginHandleStatusChangesConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLoginLoginHandleStatusChangesConstMeta =>
+      const TaskConstMeta(
+        debugName: "LoginHandle_status_changes",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<AccountHandle> crateApiLoginLoginHandleWait({
+    required LoginHandle that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLoginHandle(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiLoginLoginHandleWaitConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoginLoginHandleWaitConstMeta =>
+      const TaskConstMeta(
+        debugName: "LoginHandle_wait",
+        argNames: ["that"],
+      );
+
+  @override
+  Stream<MediaPlaybackEvent> crateApiMediaMediaHandleAudioPlaybackEvents({
+    required MediaHandle that,
+  }) {
+    final sink = RustStreamSink<MediaPlaybackEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_media_playback_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 97,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_core_error,
+          ),
+          constMeta: kCrateApiMediaMediaHandleAudioPlaybackEventsConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaMediaHandleAudioPlaybackEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "MediaHandle_audio_playback_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  MediaPlaybackSnapshot crateApiMediaMediaHandleAudioPlaybackSnapshot({
+    required MediaHandle that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaHandle(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_media_playback_snapshot,
@@ -4518,7 +5283,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 85,
+            funcId: 99,
             port: port_,
           );
         },
@@ -4552,7 +5317,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 86,
+            funcId: 100,
             port: port_,
           );
         },
@@ -4592,7 +5357,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 87,
+            funcId: 101,
             port: port_,
           );
         },
@@ -4628,7 +5393,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 88,
+            funcId: 102,
             port: port_,
           );
         },
@@ -4663,7 +5428,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_u_64(positionMillis, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -4699,7 +5468,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 90,
+            funcId: 104,
             port: port_,
           );
         },
@@ -4735,7 +5504,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 91,
+            funcId: 105,
             port: port_,
           );
         },
@@ -4766,7 +5535,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -4800,7 +5573,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 93,
+            funcId: 107,
             port: port_,
           );
         },
@@ -4839,7 +5612,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 94,
+              funcId: 108,
               port: port_,
             );
           },
@@ -4874,7 +5647,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+          )!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_media_recorder_snapshot,
@@ -4893,6 +5670,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<void> mixinDesktopApiAccessMessageAccessCancelOutgoingMessage({
+    required MessageAccess that,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessCancelOutgoingMessageConstMeta,
+        argValues: [that, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessCancelOutgoingMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_cancel_outgoing_message",
+        argNames: ["that", "messageId"],
+      );
+
   @override
   Future<String> mixinDesktopApiAccessMessageAccessCombineForwardMessages({
     required MessageAccess that,
@@ -4907,39 +5725,124 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_String(targetConversationId, serializer);
-          sse_encode_list_String(sourceMessageIds, serializer);
+          sse_encode_String(targetConversationId, serializer);
+          sse_encode_list_String(sourceMessageIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessCombineForwardMessagesConstMeta,
+        argValues: [that, targetConversationId, sourceMessageIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessCombineForwardMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_combine_forward_messages",
+        argNames: ["that", "targetConversationId", "sourceMessageIds"],
+      );
+
+  @override
+  Future<bool> mixinDesktopApiAccessMessageAccessConversationIsEncrypted({
+    required MessageAccess that,
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessConversationIsEncryptedConstMeta,
+        argValues: [that, conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessConversationIsEncryptedConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_conversation_is_encrypted",
+        argNames: ["that", "conversationId"],
+      );
+
+  @override
+  Future<void> mixinDesktopApiAccessMessageAccessDeleteMessages({
+    required MessageAccess that,
+    required String conversationId,
+    required List<String> messageIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(conversationId, serializer);
+          sse_encode_list_String(messageIds, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 96,
+            funcId: 113,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta:
-            kMixinDesktopApiAccessMessageAccessCombineForwardMessagesConstMeta,
-        argValues: [that, targetConversationId, sourceMessageIds],
+        constMeta: kMixinDesktopApiAccessMessageAccessDeleteMessagesConstMeta,
+        argValues: [that, conversationId, messageIds],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kMixinDesktopApiAccessMessageAccessCombineForwardMessagesConstMeta =>
+  get kMixinDesktopApiAccessMessageAccessDeleteMessagesConstMeta =>
       const TaskConstMeta(
-        debugName: "MessageAccess_combine_forward_messages",
-        argNames: ["that", "targetConversationId", "sourceMessageIds"],
+        debugName: "MessageAccess_delete_messages",
+        argNames: ["that", "conversationId", "messageIds"],
       );
 
   @override
-  Future<bool> mixinDesktopApiAccessMessageAccessConversationIsEncrypted({
+  Future<void> mixinDesktopApiAccessMessageAccessEditMessage({
     required MessageAccess that,
     required String conversationId,
+    required String messageId,
+    required String content,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -4950,38 +5853,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 97,
+            funcId: 114,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta:
-            kMixinDesktopApiAccessMessageAccessConversationIsEncryptedConstMeta,
-        argValues: [that, conversationId],
+        constMeta: kMixinDesktopApiAccessMessageAccessEditMessageConstMeta,
+        argValues: [that, conversationId, messageId, content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kMixinDesktopApiAccessMessageAccessConversationIsEncryptedConstMeta =>
+  TaskConstMeta get kMixinDesktopApiAccessMessageAccessEditMessageConstMeta =>
       const TaskConstMeta(
-        debugName: "MessageAccess_conversation_is_encrypted",
-        argNames: ["that", "conversationId"],
+        debugName: "MessageAccess_edit_message",
+        argNames: ["that", "conversationId", "messageId", "content"],
       );
 
   @override
-  Future<void> mixinDesktopApiAccessMessageAccessDeleteMessages({
+  Future<List<OutgoingMessageItem>>
+  mixinDesktopApiAccessMessageAccessFailedOutgoingMessages({
     required MessageAccess that,
-    required String conversationId,
-    required List<String> messageIds,
+    String? conversationId,
+    required PlatformInt64 limit,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -4991,32 +5895,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          sse_encode_String(conversationId, serializer);
-          sse_encode_list_String(messageIds, serializer);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_i_64(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 98,
+            funcId: 115,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_outgoing_message_item,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta: kMixinDesktopApiAccessMessageAccessDeleteMessagesConstMeta,
-        argValues: [that, conversationId, messageIds],
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessFailedOutgoingMessagesConstMeta,
+        argValues: [that, conversationId, limit],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kMixinDesktopApiAccessMessageAccessDeleteMessagesConstMeta =>
+  get kMixinDesktopApiAccessMessageAccessFailedOutgoingMessagesConstMeta =>
       const TaskConstMeta(
-        debugName: "MessageAccess_delete_messages",
-        argNames: ["that", "conversationId", "messageIds"],
+        debugName: "MessageAccess_failed_outgoing_messages",
+        argNames: ["that", "conversationId", "limit"],
       );
 
   @override
@@ -5038,7 +5943,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 99,
+            funcId: 116,
             port: port_,
           );
         },
@@ -5085,7 +5990,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 100,
+            funcId: 117,
             port: port_,
           );
         },
@@ -5115,6 +6020,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ],
       );
 
+  @override
+  Future<LinkPreviewView?> mixinDesktopApiAccessMessageAccessLoadLinkPreview({
+    required MessageAccess that,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_link_preview_view,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessMessageAccessLoadLinkPreviewConstMeta,
+        argValues: [that, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessLoadLinkPreviewConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_load_link_preview",
+        argNames: ["that", "messageId"],
+      );
+
   @override
   Future<void> mixinDesktopApiAccessMessageAccessMarkConversationRead({
     required MessageAccess that,
@@ -5132,7 +6077,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 101,
+            funcId: 119,
             port: port_,
           );
         },
@@ -5175,7 +6120,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 102,
+            funcId: 120,
             port: port_,
           );
         },
@@ -5198,6 +6143,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "conversationId", "messageId"],
       );
 
+  @override
+  Future<List<MessageEditItem>>
+  mixinDesktopApiAccessMessageAccessMessageEditHistory({
+    required MessageAccess that,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_message_edit_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessMessageEditHistoryConstMeta,
+        argValues: [that, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessMessageEditHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_message_edit_history",
+        argNames: ["that", "messageId"],
+      );
+
   @override
   Future<List<String>> mixinDesktopApiAccessMessageAccessMessageIdsAfter({
     required MessageAccess that,
@@ -5221,7 +6208,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 103,
+            funcId: 122,
             port: port_,
           );
         },
@@ -5279,7 +6266,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 104,
+            funcId: 123,
             port: port_,
           );
         },
@@ -5332,7 +6319,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 105,
+            funcId: 124,
             port: port_,
           );
         },
@@ -5374,7 +6361,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 106,
+            funcId: 125,
             port: port_,
           );
         },
@@ -5397,6 +6384,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "messageId"],
       );
 
+  @override
+  Future<List<MessageReactionItem>>
+  mixinDesktopApiAccessMessageAccessMessageReactions({
+    required MessageAccess that,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_message_reaction_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessMessageAccessMessageReactionsConstMeta,
+        argValues: [that, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessMessageReactionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_message_reactions",
+        argNames: ["that", "messageId"],
+      );
+
   @override
   Future<List<MessageListView>> mixinDesktopApiAccessMessageAccessMessages({
     required MessageAccess that,
@@ -5420,7 +6448,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 107,
+            funcId: 127,
             port: port_,
           );
         },
@@ -5478,7 +6506,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 108,
+            funcId: 128,
             port: port_,
           );
         },
@@ -5525,7 +6553,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 109,
+            funcId: 129,
             port: port_,
           );
         },
@@ -5567,7 +6595,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 110,
+            funcId: 130,
             port: port_,
           );
         },
@@ -5608,7 +6636,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 111,
+            funcId: 131,
             port: port_,
           );
         },
@@ -5631,6 +6659,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "conversationId"],
       );
 
+  @override
+  Future<void> mixinDesktopApiAccessMessageAccessReactToMessage({
+    required MessageAccess that,
+    required String conversationId,
+    required String messageId,
+    required String emoji,
+    required bool removed,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(emoji, serializer);
+          sse_encode_bool(removed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessMessageAccessReactToMessageConstMeta,
+        argValues: [that, conversationId, messageId, emoji, removed],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessReactToMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_react_to_message",
+        argNames: ["that", "conversationId", "messageId", "emoji", "removed"],
+      );
+
   @override
   Future<void> mixinDesktopApiAccessMessageAccessRecallMessages({
     required MessageAccess that,
@@ -5650,7 +6724,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 112,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessMessageAccessRecallMessagesConstMeta,
+        argValues: [that, conversationId, messageIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessRecallMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_recall_messages",
+        argNames: ["that", "conversationId", "messageIds"],
+      );
+
+  @override
+  Future<void> mixinDesktopApiAccessMessageAccessRetryOutgoingMessage({
+    required MessageAccess that,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
             port: port_,
           );
         },
@@ -5659,18 +6773,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta: kMixinDesktopApiAccessMessageAccessRecallMessagesConstMeta,
-        argValues: [that, conversationId, messageIds],
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessRetryOutgoingMessageConstMeta,
+        argValues: [that, messageId],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta
-  get kMixinDesktopApiAccessMessageAccessRecallMessagesConstMeta =>
+  get kMixinDesktopApiAccessMessageAccessRetryOutgoingMessageConstMeta =>
       const TaskConstMeta(
-        debugName: "MessageAccess_recall_messages",
-        argNames: ["that", "conversationId", "messageIds"],
+        debugName: "MessageAccess_retry_outgoing_message",
+        argNames: ["that", "messageId"],
       );
 
   @override
@@ -5678,6 +6793,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   mixinDesktopApiAccessMessageAccessSearchGlobalMessages({
     required MessageAccess that,
     required String query,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   }) {
@@ -5690,12 +6808,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             serializer,
           );
           sse_encode_String(query, serializer);
+          sse_encode_opt_box_autoadd_i_64(afterMicros, serializer);
+          sse_encode_opt_box_autoadd_i_64(beforeMicros, serializer);
+          sse_encode_bool(rankByRelevance, serializer);
           sse_encode_opt_String(anchorMessageId, serializer);
           sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 113,
+            funcId: 135,
             port: port_,
           );
         },
@@ -5706,7 +6827,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ),
         constMeta:
             kMixinDesktopApiAccessMessageAccessSearchGlobalMessagesConstMeta,
-        argValues: [that, query, anchorMessageId, limit],
+        argValues: [
+          that,
+          query,
+          afterMicros,
+          beforeMicros,
+          rankByRelevance,
+          anchorMessageId,
+          limit,
+        ],
         apiImpl: this,
       ),
     );
@@ -5716,7 +6845,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get kMixinDesktopApiAccessMessageAccessSearchGlobalMessagesConstMeta =>
       const TaskConstMeta(
         debugName: "MessageAccess_search_global_messages",
-        argNames: ["that", "query", "anchorMessageId", "limit"],
+        argNames: [
+          "that",
+          "query",
+          "afterMicros",
+          "beforeMicros",
+          "rankByRelevance",
+          "anchorMessageId",
+          "limit",
+        ],
       );
 
   @override
@@ -5727,6 +6864,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String query,
     String? senderId,
     required List<String> categories,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   }) {
@@ -5742,12 +6882,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(query, serializer);
           sse_encode_opt_String(senderId, serializer);
           sse_encode_list_String(categories, serializer);
+          sse_encode_opt_box_autoadd_i_64(afterMicros, serializer);
+          sse_encode_opt_box_autoadd_i_64(beforeMicros, serializer);
+          sse_encode_bool(rankByRelevance, serializer);
           sse_encode_opt_String(anchorMessageId, serializer);
           sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 114,
+            funcId: 136,
             port: port_,
           );
         },
@@ -5763,6 +6906,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           query,
           senderId,
           categories,
+          afterMicros,
+          beforeMicros,
+          rankByRelevance,
           anchorMessageId,
           limit,
         ],
@@ -5781,6 +6927,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           "query",
           "senderId",
           "categories",
+          "afterMicros",
+          "beforeMicros",
+          "rankByRelevance",
           "anchorMessageId",
           "limit",
         ],
@@ -5805,7 +6954,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 115,
+            funcId: 137,
             port: port_,
           );
         },
@@ -5866,7 +7015,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 116,
+            funcId: 138,
             port: port_,
           );
         },
@@ -5942,7 +7091,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 117,
+            funcId: 139,
             port: port_,
           );
         },
@@ -6001,7 +7150,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 118,
+            funcId: 140,
             port: port_,
           );
         },
@@ -6029,6 +7178,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         ],
       );
 
+  @override
+  Future<String> mixinDesktopApiAccessMessageAccessSendLocation({
+    required MessageAccess that,
+    required String conversationId,
+    required LocationView location,
+    String? quoteMessageId,
+    required bool silent,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_location_view(location, serializer);
+          sse_encode_opt_String(quoteMessageId, serializer);
+          sse_encode_bool(silent, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessMessageAccessSendLocationConstMeta,
+        argValues: [that, conversationId, location, quoteMessageId, silent],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kMixinDesktopApiAccessMessageAccessSendLocationConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_send_location",
+        argNames: [
+          "that",
+          "conversationId",
+          "location",
+          "quoteMessageId",
+          "silent",
+        ],
+      );
+
   @override
   Future<String> mixinDesktopApiAccessMessageAccessSendPost({
     required MessageAccess that,
@@ -6048,7 +7248,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 119,
+            funcId: 142,
             port: port_,
           );
         },
@@ -6099,7 +7299,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 120,
+            funcId: 143,
             port: port_,
           );
         },
@@ -6159,7 +7359,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 121,
+            funcId: 144,
             port: port_,
           );
         },
@@ -6204,7 +7404,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 122,
+            funcId: 145,
             port: port_,
           );
         },
@@ -6253,7 +7453,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 123,
+            funcId: 146,
             port: port_,
           );
         },
@@ -6300,7 +7500,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 124,
+            funcId: 147,
             port: port_,
           );
         },
@@ -6341,7 +7541,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 125,
+            funcId: 148,
             port: port_,
           );
         },
@@ -6361,54 +7561,259 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta
   get kMixinDesktopApiAccessMessageAccessTranscriptMessagesConstMeta =>
       const TaskConstMeta(
-        debugName: "MessageAccess_transcript_messages",
-        argNames: ["that", "transcriptId"],
+        debugName: "MessageAccess_transcript_messages",
+        argNames: ["that", "transcriptId"],
+      );
+
+  @override
+  Future<List<String>>
+  mixinDesktopApiAccessMessageAccessUnreadMentionMessageIds({
+    required MessageAccess that,
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessMessageAccessUnreadMentionMessageIdsConstMeta,
+        argValues: [that, conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessMessageAccessUnreadMentionMessageIdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "MessageAccess_unread_mention_message_ids",
+        argNames: ["that", "conversationId"],
+      );
+
+  @override
+  Future<GlobalSearchView> mixinDesktopApiAccessSearchAccessGlobalSearch({
+    required SearchAccess that,
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_global_search_view,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessSearchAccessGlobalSearchConstMeta,
+        argValues: [that, query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kMixinDesktopApiAccessSearchAccessGlobalSearchConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchAccess_global_search",
+        argNames: ["that", "query", "limit"],
+      );
+
+  @override
+  Future<GlobalSearchView>
+  mixinDesktopApiAccessSearchAccessGlobalSearchSection({
+    required SearchAccess that,
+    required String query,
+    required String section,
+    String? cursor,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          sse_encode_String(section, serializer);
+          sse_encode_opt_String(cursor, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_global_search_view,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta:
+            kMixinDesktopApiAccessSearchAccessGlobalSearchSectionConstMeta,
+        argValues: [that, query, section, cursor, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessSearchAccessGlobalSearchSectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchAccess_global_search_section",
+        argNames: ["that", "query", "section", "cursor", "limit"],
+      );
+
+  @override
+  Stream<GlobalSearchView> crateApiSearchSearchHandleResults({
+    required SearchHandle that,
+    required int limit,
+  }) {
+    final sink = RustStreamSink<GlobalSearchView>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+              that,
+              serializer,
+            );
+            sse_encode_u_32(limit, serializer);
+            sse_encode_StreamSink_global_search_view_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 152,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_core_error,
+          ),
+          constMeta: kCrateApiSearchSearchHandleResultsConstMeta,
+          argValues: [that, limit, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSearchSearchHandleResultsConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchHandle_results",
+        argNames: ["that", "limit", "sink"],
+      );
+
+  @override
+  void crateApiSearchSearchHandleSetQuery({
+    required SearchHandle that,
+    required String query,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(query, serializer);
+          return pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+          )!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchSearchHandleSetQueryConstMeta,
+        argValues: [that, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSearchHandleSetQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "SearchHandle_set_query",
+        argNames: ["that", "query"],
       );
 
   @override
-  Future<List<String>>
-  mixinDesktopApiAccessMessageAccessUnreadMentionMessageIds({
-    required MessageAccess that,
-    required String conversationId,
+  Future<bool> crateApiDesktopSettingsHandleFileAutoDownload({
+    required SettingsHandle that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess(
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
             that,
             serializer,
           );
-          sse_encode_String(conversationId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 126,
+            funcId: 154,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_core_error,
         ),
-        constMeta:
-            kMixinDesktopApiAccessMessageAccessUnreadMentionMessageIdsConstMeta,
-        argValues: [that, conversationId],
+        constMeta: kCrateApiDesktopSettingsHandleFileAutoDownloadConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta
-  get kMixinDesktopApiAccessMessageAccessUnreadMentionMessageIdsConstMeta =>
+  TaskConstMeta get kCrateApiDesktopSettingsHandleFileAutoDownloadConstMeta =>
       const TaskConstMeta(
-        debugName: "MessageAccess_unread_mention_message_ids",
-        argNames: ["that", "conversationId"],
+        debugName: "SettingsHandle_file_auto_download",
+        argNames: ["that"],
       );
 
   @override
-  Future<bool> crateApiDesktopSettingsHandleFileAutoDownload({
+  Future<String?> crateApiDesktopSettingsHandleMcpAccountId({
     required SettingsHandle that,
   }) {
     return handler.executeNormal(
@@ -6422,24 +7827,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 127,
+            funcId: 155,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: sse_decode_core_error,
         ),
-        constMeta: kCrateApiDesktopSettingsHandleFileAutoDownloadConstMeta,
+        constMeta: kCrateApiDesktopSettingsHandleMcpAccountIdConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDesktopSettingsHandleFileAutoDownloadConstMeta =>
+  TaskConstMeta get kCrateApiDesktopSettingsHandleMcpAccountIdConstMeta =>
       const TaskConstMeta(
-        debugName: "SettingsHandle_file_auto_download",
+        debugName: "SettingsHandle_mcp_account_id",
         argNames: ["that"],
       );
 
@@ -6458,7 +7863,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 128,
+            funcId: 156,
             port: port_,
           );
         },
@@ -6494,7 +7899,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 129,
+            funcId: 157,
             port: port_,
           );
         },
@@ -6530,7 +7935,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 130,
+            funcId: 158,
             port: port_,
           );
         },
@@ -6566,7 +7971,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 131,
+            funcId: 159,
             port: port_,
           );
         },
@@ -6604,7 +8009,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 132,
+            funcId: 160,
             port: port_,
           );
         },
@@ -6626,6 +8031,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "value"],
       );
 
+  @override
+  Future<McpServerStatusItem> crateApiDesktopSettingsHandleSetMcpAccountId({
+    required SettingsHandle that,
+    String? accountId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_String(accountId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mcp_server_status_item,
+          decodeErrorData: sse_decode_core_error,
+        ),
+        constMeta: kCrateApiDesktopSettingsHandleSetMcpAccountIdConstMeta,
+        argValues: [that, accountId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDesktopSettingsHandleSetMcpAccountIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "SettingsHandle_set_mcp_account_id",
+        argNames: ["that", "accountId"],
+      );
+
   @override
   Future<void> crateApiDesktopSettingsHandleSetPhotoAutoDownload({
     required SettingsHandle that,
@@ -6643,7 +8086,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 133,
+            funcId: 162,
             port: port_,
           );
         },
@@ -6682,7 +8125,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 134,
+            funcId: 163,
             port: port_,
           );
         },
@@ -6722,7 +8165,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 135,
+            funcId: 164,
             port: port_,
           );
         },
@@ -6760,7 +8203,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 136,
+            funcId: 165,
             port: port_,
           );
         },
@@ -6799,7 +8242,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 137,
+            funcId: 166,
             port: port_,
           );
         },
@@ -6838,7 +8281,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 138,
+              funcId: 167,
               port: port_,
             );
           },
@@ -6881,7 +8324,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 139,
+              funcId: 168,
               port: port_,
             );
           },
@@ -6926,7 +8369,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 140,
+              funcId: 169,
               port: port_,
             );
           },
@@ -6967,7 +8410,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 141,
+              funcId: 170,
               port: port_,
             );
           },
@@ -7009,7 +8452,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 142,
+            funcId: 171,
             port: port_,
           );
         },
@@ -7045,7 +8488,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 143,
+            funcId: 172,
             port: port_,
           );
         },
@@ -7083,7 +8526,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 144,
+            funcId: 173,
             port: port_,
           );
         },
@@ -7122,7 +8565,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 145,
+            funcId: 174,
             port: port_,
           );
         },
@@ -7163,7 +8606,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 146,
+            funcId: 175,
             port: port_,
           );
         },
@@ -7204,7 +8647,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 147,
+            funcId: 176,
             port: port_,
           );
         },
@@ -7241,7 +8684,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 148,
+            funcId: 177,
             port: port_,
           );
         },
@@ -7279,7 +8722,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 149,
+            funcId: 178,
             port: port_,
           );
         },
@@ -7319,7 +8762,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 150,
+            funcId: 179,
             port: port_,
           );
         },
@@ -7357,7 +8800,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 151,
+            funcId: 180,
             port: port_,
           );
         },
@@ -7397,7 +8840,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 152,
+            funcId: 181,
             port: port_,
           );
         },
@@ -7438,7 +8881,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 153,
+            funcId: 182,
             port: port_,
           );
         },
@@ -7479,7 +8922,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 154,
+            funcId: 183,
             port: port_,
           );
         },
@@ -7519,7 +8962,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 155,
+            funcId: 184,
             port: port_,
           );
         },
@@ -7558,7 +9001,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 156,
+            funcId: 185,
             port: port_,
           );
         },
@@ -7596,7 +9039,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 157,
+            funcId: 186,
             port: port_,
           );
         },
@@ -7639,7 +9082,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 158,
+            funcId: 187,
             port: port_,
           );
         },
@@ -7678,7 +9121,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 159,
+            funcId: 188,
             port: port_,
           );
         },
@@ -7700,6 +9143,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "userId"],
       );
 
+  @override
+  Future<List<UserProfileItem>> mixinDesktopApiAccessUserAccessBlockedUsers({
+    required UserAccess that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerUserAccess(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 189,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_user_profile_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessUserAccessBlockedUsersConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kMixinDesktopApiAccessUserAccessBlockedUsersConstMeta =>
+      const TaskConstMeta(
+        debugName: "UserAccess_blocked_users",
+        argNames: ["that"],
+      );
+
   @override
   Future<String?> mixinDesktopApiAccessUserAccessBotCreatorId({
     required UserAccess that,
@@ -7717,7 +9197,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 160,
+            funcId: 190,
             port: port_,
           );
         },
@@ -7756,7 +9236,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 161,
+            funcId: 191,
             port: port_,
           );
         },
@@ -7778,6 +9258,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "appId"],
       );
 
+  @override
+  Future<List<ContactGroup>> mixinDesktopApiAccessUserAccessContacts({
+    required UserAccess that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerUserAccess(
+            that,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 192,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_contact_group,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessUserAccessContactsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kMixinDesktopApiAccessUserAccessContactsConstMeta =>
+      const TaskConstMeta(
+        debugName: "UserAccess_contacts",
+        argNames: ["that"],
+      );
+
   @override
   Future<List<SharedAppItem>> mixinDesktopApiAccessUserAccessLocalSharedApps({
     required UserAccess that,
@@ -7795,7 +9312,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 162,
+            funcId: 193,
             port: port_,
           );
         },
@@ -7834,7 +9351,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 163,
+            funcId: 194,
             port: port_,
           );
         },
@@ -7873,7 +9390,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 164,
+            funcId: 195,
             port: port_,
           );
         },
@@ -7913,7 +9430,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 165,
+            funcId: 196,
             port: port_,
           );
         },
@@ -7952,7 +9469,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 166,
+            funcId: 197,
             port: port_,
           );
         },
@@ -7991,7 +9508,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 167,
+            funcId: 198,
             port: port_,
           );
         },
@@ -8035,7 +9552,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 168,
+            funcId: 199,
             port: port_,
           );
         },
@@ -8074,7 +9591,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 169,
+            funcId: 200,
             port: port_,
           );
         },
@@ -8113,7 +9630,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 170,
+            funcId: 201,
             port: port_,
           );
         },
@@ -8150,30 +9667,113 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 171,
+            funcId: 202,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_user_profile_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessUserAccessSelectableUsersConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kMixinDesktopApiAccessUserAccessSelectableUsersConstMeta =>
+      const TaskConstMeta(
+        debugName: "UserAccess_selectable_users",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> mixinDesktopApiAccessUserAccessSetUserAnnotation({
+    required UserAccess that,
+    required String userId,
+    String? alias,
+    String? note,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerUserAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(userId, serializer);
+          sse_encode_opt_String(alias, serializer);
+          sse_encode_opt_String(note, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 203,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
+        ),
+        constMeta: kMixinDesktopApiAccessUserAccessSetUserAnnotationConstMeta,
+        argValues: [that, userId, alias, note],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kMixinDesktopApiAccessUserAccessSetUserAnnotationConstMeta =>
+      const TaskConstMeta(
+        debugName: "UserAccess_set_user_annotation",
+        argNames: ["that", "userId", "alias", "note"],
+      );
+
+  @override
+  Future<List<SharedAppItem>> mixinDesktopApiAccessUserAccessSharedApps({
+    required UserAccess that,
+    required String userId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerUserAccess(
+            that,
+            serializer,
+          );
+          sse_encode_String(userId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 204,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_user_profile_item,
+          decodeSuccessData: sse_decode_list_shared_app_item,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta: kMixinDesktopApiAccessUserAccessSelectableUsersConstMeta,
-        argValues: [that],
+        constMeta: kMixinDesktopApiAccessUserAccessSharedAppsConstMeta,
+        argValues: [that, userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kMixinDesktopApiAccessUserAccessSelectableUsersConstMeta =>
+  TaskConstMeta get kMixinDesktopApiAccessUserAccessSharedAppsConstMeta =>
       const TaskConstMeta(
-        debugName: "UserAccess_selectable_users",
-        argNames: ["that"],
+        debugName: "UserAccess_shared_apps",
+        argNames: ["that", "userId"],
       );
 
   @override
-  Future<List<SharedAppItem>> mixinDesktopApiAccessUserAccessSharedApps({
+  Future<void> mixinDesktopApiAccessUserAccessUnblockUser({
     required UserAccess that,
     required String userId,
   }) {
@@ -8189,30 +9789,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 172,
+            funcId: 205,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_shared_app_item,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta: kMixinDesktopApiAccessUserAccessSharedAppsConstMeta,
+        constMeta: kMixinDesktopApiAccessUserAccessUnblockUserConstMeta,
         argValues: [that, userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kMixinDesktopApiAccessUserAccessSharedAppsConstMeta =>
+  TaskConstMeta get kMixinDesktopApiAccessUserAccessUnblockUserConstMeta =>
       const TaskConstMeta(
-        debugName: "UserAccess_shared_apps",
+        debugName: "UserAccess_unblock_user",
         argNames: ["that", "userId"],
       );
 
   @override
-  Future<void> mixinDesktopApiAccessUserAccessUnblockUser({
+  Future<UserAnnotationItem?> mixinDesktopApiAccessUserAccessUserAnnotation({
     required UserAccess that,
     required String userId,
   }) {
@@ -8228,25 +9828,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 173,
+            funcId: 206,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_user_annotation_item,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerClientError,
         ),
-        constMeta: kMixinDesktopApiAccessUserAccessUnblockUserConstMeta,
+        constMeta: kMixinDesktopApiAccessUserAccessUserAnnotationConstMeta,
         argValues: [that, userId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kMixinDesktopApiAccessUserAccessUnblockUserConstMeta =>
+  TaskConstMeta get kMixinDesktopApiAccessUserAccessUserAnnotationConstMeta =>
       const TaskConstMeta(
-        debugName: "UserAccess_unblock_user",
+        debugName: "UserAccess_user_annotation",
         argNames: ["that", "userId"],
       );
 
@@ -8269,7 +9869,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 174,
+            funcId: 207,
             port: port_,
           );
         },
@@ -8309,7 +9909,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 175,
+            funcId: 208,
             port: port_,
           );
         },
@@ -8342,7 +9942,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 176,
+            funcId: 209,
             port: port_,
           );
         },
@@ -8378,7 +9978,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 177,
+            funcId: 210,
             port: port_,
           );
         },
@@ -8407,7 +10007,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 178,
+            funcId: 211,
             port: port_,
           );
         },
@@ -8441,7 +10041,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           return pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 179,
+            funcId: 212,
           )!;
         },
         codec: SseCodec(
@@ -8469,7 +10069,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 180,
+            funcId: 213,
             port: port_,
           );
         },
@@ -8554,6 +10154,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_MessageAccess => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SearchAccess => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SearchAccess => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SearchHandle => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SearchHandle => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_SettingsHandle => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle;
@@ -8656,6 +10272,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return MessageAccessImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  SearchAccess
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchAccessImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SearchHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   SettingsHandle
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -8746,6 +10380,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return MessageAccessImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  SearchAccess
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchAccessImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SearchHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   SettingsHandle
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -8855,6 +10507,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return MessageAccessImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  SearchAccess
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchAccessImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SearchHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   SettingsHandle
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -8888,6 +10558,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<AccountNotificationItem>
+  dco_decode_StreamSink_account_notification_item_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<AccountProfile> dco_decode_StreamSink_account_profile_Sse(
     dynamic raw,
@@ -8916,12 +10593,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<GlobalSearchView> dco_decode_StreamSink_global_search_view_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<List<AccountUnreadCountItem>>
+  dco_decode_StreamSink_list_account_unread_count_item_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<List<CircleItem>> dco_decode_StreamSink_list_circle_item_Sse(
     dynamic raw,
@@ -8937,6 +10629,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<LoginStatus> dco_decode_StreamSink_login_status_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<MediaPlaybackEvent>
   dco_decode_StreamSink_media_playback_event_Sse(dynamic raw) {
@@ -8964,6 +10664,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<SessionLinkedItem>
+  dco_decode_StreamSink_session_linked_item_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<BigInt> dco_decode_StreamSink_u_64_Sse(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8976,6 +10683,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  AccountNotificationItem dco_decode_account_notification_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AccountNotificationItem(
+      accountId: dco_decode_String(arr[0]),
+      event: dco_decode_notification_event(arr[1]),
+    );
+  }
+
   @protected
   AccountProfile dco_decode_account_profile(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -8996,6 +10715,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AccountSession dco_decode_account_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AccountSession(
+      sessionId: dco_decode_String(arr[0]),
+      platform: dco_decode_opt_String(arr[1]),
+      appVersion: dco_decode_opt_String(arr[2]),
+      lastSeenAt: dco_decode_opt_String(arr[3]),
+      isCurrent: dco_decode_bool(arr[4]),
+      isPrimary: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  AccountUnreadCountItem dco_decode_account_unread_count_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AccountUnreadCountItem(
+      accountId: dco_decode_String(arr[0]),
+      unseenMessageCount: dco_decode_i_64(arr[1]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9026,6 +10773,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_i_64(raw);
   }
 
+  @protected
+  LinkPreviewView dco_decode_box_autoadd_link_preview_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_link_preview_view(raw);
+  }
+
+  @protected
+  LocationView dco_decode_box_autoadd_location_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_location_view(raw);
+  }
+
   @protected
   McpSettingsItem dco_decode_box_autoadd_mcp_settings_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9070,6 +10829,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_message_order_info_view(raw);
   }
 
+  @protected
+  MessageSnippetView dco_decode_box_autoadd_message_snippet_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_message_snippet_view(raw);
+  }
+
   @protected
   PinMessagePreviewItem dco_decode_box_autoadd_pin_message_preview_item(
     dynamic raw,
@@ -9096,6 +10861,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  UserAnnotationItem dco_decode_box_autoadd_user_annotation_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_user_annotation_item(raw);
+  }
+
   @protected
   UserProfileItem dco_decode_box_autoadd_user_profile_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9148,6 +10919,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ConnectionFailedReason.values[raw as int];
   }
 
+  @protected
+  ContactGroup dco_decode_contact_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ContactGroup(
+      index: dco_decode_String(arr[0]),
+      contacts: dco_decode_list_contact_item(arr[1]),
+    );
+  }
+
+  @protected
+  ContactItem dco_decode_contact_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ContactItem(
+      user: dco_decode_user_profile_item(arr[0]),
+      alias: dco_decode_opt_String(arr[1]),
+      displayName: dco_decode_String(arr[2]),
+      sortKey: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   ConversationChangeEvent dco_decode_conversation_change_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9242,6 +11039,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ConversationSearchHitView dco_decode_conversation_search_hit_view(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConversationSearchHitView(
+      conversation: dco_decode_conversation_list_data(arr[0]),
+      hitCount: dco_decode_i_64(arr[1]),
+      latestCreatedAtMillis: dco_decode_i_64(arr[2]),
+    );
+  }
+
   @protected
   ConversationStorageUsage dco_decode_conversation_storage_usage(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9352,6 +11164,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  GlobalSearchView dco_decode_global_search_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return GlobalSearchView(
+      query: dco_decode_String(arr[0]),
+      contacts: dco_decode_list_user_profile_item(arr[1]),
+      contactsCursor: dco_decode_opt_String(arr[2]),
+      groups: dco_decode_list_conversation_list_data(arr[3]),
+      groupsCursor: dco_decode_opt_String(arr[4]),
+      messageConversations: dco_decode_list_conversation_search_hit_view(
+        arr[5],
+      ),
+      messageConversationsCursor: dco_decode_opt_String(arr[6]),
+      files: dco_decode_list_message_list_view(arr[7]),
+      filesCursor: dco_decode_opt_String(arr[8]),
+    );
+  }
+
   @protected
   GroupAvatar dco_decode_group_avatar(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9424,18 +11257,74 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LinkPreviewView dco_decode_link_preview_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LinkPreviewView(
+      url: dco_decode_String(arr[0]),
+      siteName: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      description: dco_decode_opt_String(arr[3]),
+      imageUrl: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  List<AccountHandle>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle,
+        )
+        .toList();
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<AccountSession> dco_decode_list_account_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_account_session).toList();
+  }
+
+  @protected
+  List<AccountUnreadCountItem> dco_decode_list_account_unread_count_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_account_unread_count_item)
+        .toList();
+  }
+
   @protected
   List<CircleItem> dco_decode_list_circle_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_circle_item).toList();
   }
 
+  @protected
+  List<ContactGroup> dco_decode_list_contact_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_contact_group).toList();
+  }
+
+  @protected
+  List<ContactItem> dco_decode_list_contact_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_contact_item).toList();
+  }
+
   @protected
   List<ConversationListData> dco_decode_list_conversation_list_data(
     dynamic raw,
@@ -9455,6 +11344,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<ConversationSearchHitView> dco_decode_list_conversation_search_hit_view(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_conversation_search_hit_view)
+        .toList();
+  }
+
   @protected
   List<ConversationStorageUsage> dco_decode_list_conversation_storage_usage(
     dynamic raw,
@@ -9486,27 +11385,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     dynamic raw,
   ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_group_conversation_item)
-        .toList();
+    return (raw as List<dynamic>)
+        .map(dco_decode_group_conversation_item)
+        .toList();
+  }
+
+  @protected
+  List<ImageMessageView> dco_decode_list_image_message_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_image_message_view).toList();
+  }
+
+  @protected
+  List<MediaAudioItem> dco_decode_list_media_audio_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_audio_item).toList();
+  }
+
+  @protected
+  List<MessageEditItem> dco_decode_list_message_edit_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_edit_item).toList();
+  }
+
+  @protected
+  List<MessageListView> dco_decode_list_message_list_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_list_view).toList();
   }
 
   @protected
-  List<ImageMessageView> dco_decode_list_image_message_view(dynamic raw) {
+  List<MessageReactionItem> dco_decode_list_message_reaction_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_image_message_view).toList();
+    return (raw as List<dynamic>)
+        .map(dco_decode_message_reaction_item)
+        .toList();
   }
 
   @protected
-  List<MediaAudioItem> dco_decode_list_media_audio_item(dynamic raw) {
+  List<MessageReactionView> dco_decode_list_message_reaction_view(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_media_audio_item).toList();
+    return (raw as List<dynamic>)
+        .map(dco_decode_message_reaction_view)
+        .toList();
   }
 
   @protected
-  List<MessageListView> dco_decode_list_message_list_view(dynamic raw) {
+  List<OutgoingMessageItem> dco_decode_list_outgoing_message_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_message_list_view).toList();
+    return (raw as List<dynamic>)
+        .map(dco_decode_outgoing_message_item)
+        .toList();
   }
 
   @protected
@@ -9561,12 +11492,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<TextRangeView> dco_decode_list_text_range_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_text_range_view).toList();
+  }
+
   @protected
   List<UserProfileItem> dco_decode_list_user_profile_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_user_profile_item).toList();
   }
 
+  @protected
+  LocationView dco_decode_location_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LocationView(
+      latitude: dco_decode_f_64(arr[0]),
+      longitude: dco_decode_f_64(arr[1]),
+      name: dco_decode_opt_String(arr[2]),
+      address: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  LoginStatus dco_decode_login_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LoginStatus_WaitingForScan(
+          authUrl: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return LoginStatus_Verifying();
+      case 2:
+        return LoginStatus_SyncingKeys();
+      case 3:
+        return LoginStatus_Ready();
+      case 4:
+        return LoginStatus_Expired();
+      case 5:
+        return LoginStatus_Failed(
+          message: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   McpServerStatusItem dco_decode_mcp_server_status_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9698,12 +11674,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MessageEditItem dco_decode_message_edit_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MessageEditItem(
+      editId: dco_decode_String(arr[0]),
+      messageId: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      editedAtMicros: dco_decode_i_64(arr[3]),
+    );
+  }
+
   @protected
   MessageListView dco_decode_message_list_view(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 74)
-      throw Exception('unexpected arr length: expect 74 but see ${arr.length}');
+    if (arr.length != 79)
+      throw Exception('unexpected arr length: expect 79 but see ${arr.length}');
     return MessageListView(
       messageId: dco_decode_String(arr[0]),
       conversationId: dco_decode_String(arr[1]),
@@ -9779,6 +11769,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       mentionRead: dco_decode_opt_box_autoadd_bool(arr[71]),
       pinned: dco_decode_bool(arr[72]),
       expireIn: dco_decode_opt_box_autoadd_i_64(arr[73]),
+      editedAtMicros: dco_decode_opt_box_autoadd_i_64(arr[74]),
+      reactions: dco_decode_list_message_reaction_view(arr[75]),
+      location: dco_decode_opt_box_autoadd_location_view(arr[76]),
+      snippet: dco_decode_opt_box_autoadd_message_snippet_view(arr[77]),
+      linkPreview: dco_decode_opt_box_autoadd_link_preview_view(arr[78]),
     );
   }
 
@@ -9795,6 +11790,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MessageReactionItem dco_decode_message_reaction_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MessageReactionItem(
+      userId: dco_decode_String(arr[0]),
+      emoji: dco_decode_String(arr[1]),
+      createdAtMicros: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  MessageReactionView dco_decode_message_reaction_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MessageReactionView(
+      emoji: dco_decode_String(arr[0]),
+      count: dco_decode_i_64(arr[1]),
+      reacted: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  MessageSnippetView dco_decode_message_snippet_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MessageSnippetView(
+      text: dco_decode_String(arr[0]),
+      highlights: dco_decode_list_text_range_view(arr[1]),
+    );
+  }
+
+  @protected
+  NotificationActionKind dco_decode_notification_action_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NotificationActionKind.values[raw as int];
+  }
+
   @protected
   NotificationEvent dco_decode_notification_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9814,6 +11853,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AccountHandle?
+  dco_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+            raw,
+          );
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9844,6 +11896,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
   }
 
+  @protected
+  LinkPreviewView? dco_decode_opt_box_autoadd_link_preview_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_link_preview_view(raw);
+  }
+
+  @protected
+  LocationView? dco_decode_opt_box_autoadd_location_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_location_view(raw);
+  }
+
   @protected
   MediaAudioItem? dco_decode_opt_box_autoadd_media_audio_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9870,6 +11934,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_message_order_info_view(raw);
   }
 
+  @protected
+  MessageSnippetView? dco_decode_opt_box_autoadd_message_snippet_view(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_message_snippet_view(raw);
+  }
+
   @protected
   PinMessagePreviewItem? dco_decode_opt_box_autoadd_pin_message_preview_item(
     dynamic raw,
@@ -9892,6 +11966,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  UserAnnotationItem? dco_decode_opt_box_autoadd_user_annotation_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_user_annotation_item(raw);
+  }
+
   @protected
   UserProfileItem? dco_decode_opt_box_autoadd_user_profile_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9904,6 +11988,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
   }
 
+  @protected
+  OutgoingMessageItem dco_decode_outgoing_message_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return OutgoingMessageItem(
+      messageId: dco_decode_String(arr[0]),
+      conversationId: dco_decode_String(arr[1]),
+      category: dco_decode_String(arr[2]),
+      status: dco_decode_String(arr[3]),
+      createdAtMicros: dco_decode_i_64(arr[4]),
+      attemptCount: dco_decode_i_64(arr[5]),
+      lastError: dco_decode_opt_String(arr[6]),
+      nextAttemptAtMicros: dco_decode_opt_box_autoadd_i_64(arr[7]),
+    );
+  }
+
   @protected
   PinMessagePreviewItem dco_decode_pin_message_preview_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -9959,6 +12061,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SessionLinkedItem dco_decode_session_linked_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SessionLinkedItem(
+      sessionId: dco_decode_String(arr[0]),
+      linkedAtMicros: dco_decode_i_64(arr[1]),
+    );
+  }
+
   @protected
   SharedAppItem dco_decode_shared_app_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -10077,6 +12191,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TextRangeView dco_decode_text_range_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TextRangeView(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+    );
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -10107,6 +12233,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return;
   }
 
+  @protected
+  UserAnnotationItem dco_decode_user_annotation_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return UserAnnotationItem(
+      userId: dco_decode_String(arr[0]),
+      alias: dco_decode_opt_String(arr[1]),
+      note: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   UserProfileItem dco_decode_user_profile_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -10133,6 +12272,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  VideoRangeItem dco_decode_video_range_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return VideoRangeItem(
+      data: dco_decode_list_prim_u_8_strict(arr[0]),
+      offset: dco_decode_u_64(arr[1]),
+      totalSize: dco_decode_u_64(arr[2]),
+      mimeType: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10236,6 +12389,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SearchAccess
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchAccessImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SearchHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   SettingsHandle
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -10356,6 +12533,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SearchAccess
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchAccessImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SearchHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   SettingsHandle
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -10497,6 +12698,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SearchAccess
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchAccessImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SearchHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SearchHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   SettingsHandle
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -10541,6 +12766,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<AccountNotificationItem>
+  sse_decode_StreamSink_account_notification_item_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<AccountProfile> sse_decode_StreamSink_account_profile_Sse(
     SseDeserializer deserializer,
@@ -10575,6 +12809,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<GlobalSearchView> sse_decode_StreamSink_global_search_view_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
     SseDeserializer deserializer,
@@ -10583,6 +12825,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<List<AccountUnreadCountItem>>
+  sse_decode_StreamSink_list_account_unread_count_item_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<List<CircleItem>> sse_decode_StreamSink_list_circle_item_Sse(
     SseDeserializer deserializer,
@@ -10600,6 +12851,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<LoginStatus> sse_decode_StreamSink_login_status_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<MediaPlaybackEvent>
   sse_decode_StreamSink_media_playback_event_Sse(SseDeserializer deserializer) {
@@ -10629,6 +12888,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<SessionLinkedItem>
+  sse_decode_StreamSink_session_linked_item_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<BigInt> sse_decode_StreamSink_u_64_Sse(
     SseDeserializer deserializer,
@@ -10640,8 +12906,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AccountNotificationItem sse_decode_account_notification_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_accountId = sse_decode_String(deserializer);
+    var var_event = sse_decode_notification_event(deserializer);
+    return AccountNotificationItem(accountId: var_accountId, event: var_event);
   }
 
   @protected
@@ -10671,6 +12947,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AccountSession sse_decode_account_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionId = sse_decode_String(deserializer);
+    var var_platform = sse_decode_opt_String(deserializer);
+    var var_appVersion = sse_decode_opt_String(deserializer);
+    var var_lastSeenAt = sse_decode_opt_String(deserializer);
+    var var_isCurrent = sse_decode_bool(deserializer);
+    var var_isPrimary = sse_decode_bool(deserializer);
+    return AccountSession(
+      sessionId: var_sessionId,
+      platform: var_platform,
+      appVersion: var_appVersion,
+      lastSeenAt: var_lastSeenAt,
+      isCurrent: var_isCurrent,
+      isPrimary: var_isPrimary,
+    );
+  }
+
+  @protected
+  AccountUnreadCountItem sse_decode_account_unread_count_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_accountId = sse_decode_String(deserializer);
+    var var_unseenMessageCount = sse_decode_i_64(deserializer);
+    return AccountUnreadCountItem(
+      accountId: var_accountId,
+      unseenMessageCount: var_unseenMessageCount,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -10701,6 +13009,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_i_64(deserializer));
   }
 
+  @protected
+  LinkPreviewView sse_decode_box_autoadd_link_preview_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_link_preview_view(deserializer));
+  }
+
+  @protected
+  LocationView sse_decode_box_autoadd_location_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_location_view(deserializer));
+  }
+
   @protected
   McpSettingsItem sse_decode_box_autoadd_mcp_settings_item(
     SseDeserializer deserializer,
@@ -10749,6 +13073,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_message_order_info_view(deserializer));
   }
 
+  @protected
+  MessageSnippetView sse_decode_box_autoadd_message_snippet_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_message_snippet_view(deserializer));
+  }
+
   @protected
   PinMessagePreviewItem sse_decode_box_autoadd_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -10779,6 +13111,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  UserAnnotationItem sse_decode_box_autoadd_user_annotation_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_user_annotation_item(deserializer));
+  }
+
   @protected
   UserProfileItem sse_decode_box_autoadd_user_profile_item(
     SseDeserializer deserializer,
@@ -10850,6 +13190,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ConnectionFailedReason.values[inner];
   }
 
+  @protected
+  ContactGroup sse_decode_contact_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_String(deserializer);
+    var var_contacts = sse_decode_list_contact_item(deserializer);
+    return ContactGroup(index: var_index, contacts: var_contacts);
+  }
+
+  @protected
+  ContactItem sse_decode_contact_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_user = sse_decode_user_profile_item(deserializer);
+    var var_alias = sse_decode_opt_String(deserializer);
+    var var_displayName = sse_decode_String(deserializer);
+    var var_sortKey = sse_decode_String(deserializer);
+    return ContactItem(
+      user: var_user,
+      alias: var_alias,
+      displayName: var_displayName,
+      sortKey: var_sortKey,
+    );
+  }
+
   @protected
   ConversationChangeEvent sse_decode_conversation_change_event(
     SseDeserializer deserializer,
@@ -10990,6 +13353,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ConversationSearchHitView sse_decode_conversation_search_hit_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversation = sse_decode_conversation_list_data(deserializer);
+    var var_hitCount = sse_decode_i_64(deserializer);
+    var var_latestCreatedAtMillis = sse_decode_i_64(deserializer);
+    return ConversationSearchHitView(
+      conversation: var_conversation,
+      hitCount: var_hitCount,
+      latestCreatedAtMillis: var_latestCreatedAtMillis,
+    );
+  }
+
   @protected
   ConversationStorageUsage sse_decode_conversation_storage_usage(
     SseDeserializer deserializer,
@@ -11106,6 +13484,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  GlobalSearchView sse_decode_global_search_view(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_query = sse_decode_String(deserializer);
+    var var_contacts = sse_decode_list_user_profile_item(deserializer);
+    var var_contactsCursor = sse_decode_opt_String(deserializer);
+    var var_groups = sse_decode_list_conversation_list_data(deserializer);
+    var var_groupsCursor = sse_decode_opt_String(deserializer);
+    var var_messageConversations = sse_decode_list_conversation_search_hit_view(
+      deserializer,
+    );
+    var var_messageConversationsCursor = sse_decode_opt_String(deserializer);
+    var var_files = sse_decode_list_message_list_view(deserializer);
+    var var_filesCursor = sse_decode_opt_String(deserializer);
+    return GlobalSearchView(
+      query: var_query,
+      contacts: var_contacts,
+      contactsCursor: var_contactsCursor,
+      groups: var_groups,
+      groupsCursor: var_groupsCursor,
+      messageConversations: var_messageConversations,
+      messageConversationsCursor: var_messageConversationsCursor,
+      files: var_files,
+      filesCursor: var_filesCursor,
+    );
+  }
+
   @protected
   GroupAvatar sse_decode_group_avatar(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -11188,6 +13593,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LinkPreviewView sse_decode_link_preview_view(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_siteName = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_imageUrl = sse_decode_opt_String(deserializer);
+    return LinkPreviewView(
+      url: var_url,
+      siteName: var_siteName,
+      title: var_title,
+      description: var_description,
+      imageUrl: var_imageUrl,
+    );
+  }
+
+  @protected
+  List<AccountHandle>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountHandle>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+          deserializer,
+        ),
+      );
+    }
+    return ans_;
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -11200,6 +13641,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<AccountSession> sse_decode_list_account_session(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountSession>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account_session(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccountUnreadCountItem> sse_decode_list_account_unread_count_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountUnreadCountItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account_unread_count_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<CircleItem> sse_decode_list_circle_item(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -11212,6 +13681,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ContactGroup> sse_decode_list_contact_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ContactGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_contact_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ContactItem> sse_decode_list_contact_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ContactItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_contact_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ConversationListData> sse_decode_list_conversation_list_data(
     SseDeserializer deserializer,
@@ -11239,6 +13734,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ConversationSearchHitView> sse_decode_list_conversation_search_hit_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConversationSearchHitView>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_conversation_search_hit_view(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ConversationStorageUsage> sse_decode_list_conversation_storage_usage(
     SseDeserializer deserializer,
@@ -11321,6 +13830,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<MessageEditItem> sse_decode_list_message_edit_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageEditItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_edit_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<MessageListView> sse_decode_list_message_list_view(
     SseDeserializer deserializer,
@@ -11335,6 +13858,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<MessageReactionItem> sse_decode_list_message_reaction_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageReactionItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_reaction_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageReactionView> sse_decode_list_message_reaction_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageReactionView>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_reaction_view(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutgoingMessageItem> sse_decode_list_outgoing_message_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutgoingMessageItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_outgoing_message_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -11429,6 +13994,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<TextRangeView> sse_decode_list_text_range_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TextRangeView>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_text_range_view(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<UserProfileItem> sse_decode_list_user_profile_item(
     SseDeserializer deserializer,
@@ -11443,6 +14022,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  LocationView sse_decode_location_view(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_latitude = sse_decode_f_64(deserializer);
+    var var_longitude = sse_decode_f_64(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_address = sse_decode_opt_String(deserializer);
+    return LocationView(
+      latitude: var_latitude,
+      longitude: var_longitude,
+      name: var_name,
+      address: var_address,
+    );
+  }
+
+  @protected
+  LoginStatus sse_decode_login_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_authUrl = sse_decode_String(deserializer);
+        return LoginStatus_WaitingForScan(authUrl: var_authUrl);
+      case 1:
+        return LoginStatus_Verifying();
+      case 2:
+        return LoginStatus_SyncingKeys();
+      case 3:
+        return LoginStatus_Ready();
+      case 4:
+        return LoginStatus_Expired();
+      case 5:
+        var var_message = sse_decode_String(deserializer);
+        return LoginStatus_Failed(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   McpServerStatusItem sse_decode_mcp_server_status_item(
     SseDeserializer deserializer,
@@ -11596,6 +14215,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  MessageEditItem sse_decode_message_edit_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_editId = sse_decode_String(deserializer);
+    var var_messageId = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_editedAtMicros = sse_decode_i_64(deserializer);
+    return MessageEditItem(
+      editId: var_editId,
+      messageId: var_messageId,
+      content: var_content,
+      editedAtMicros: var_editedAtMicros,
+    );
+  }
+
   @protected
   MessageListView sse_decode_message_list_view(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -11673,6 +14307,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_mentionRead = sse_decode_opt_box_autoadd_bool(deserializer);
     var var_pinned = sse_decode_bool(deserializer);
     var var_expireIn = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_editedAtMicros = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_reactions = sse_decode_list_message_reaction_view(deserializer);
+    var var_location = sse_decode_opt_box_autoadd_location_view(deserializer);
+    var var_snippet = sse_decode_opt_box_autoadd_message_snippet_view(
+      deserializer,
+    );
+    var var_linkPreview = sse_decode_opt_box_autoadd_link_preview_view(
+      deserializer,
+    );
     return MessageListView(
       messageId: var_messageId,
       conversationId: var_conversationId,
@@ -11748,22 +14391,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       mentionRead: var_mentionRead,
       pinned: var_pinned,
       expireIn: var_expireIn,
+      editedAtMicros: var_editedAtMicros,
+      reactions: var_reactions,
+      location: var_location,
+      snippet: var_snippet,
+      linkPreview: var_linkPreview,
+    );
+  }
+
+  @protected
+  MessageOrderInfoView sse_decode_message_order_info_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messageId = sse_decode_String(deserializer);
+    var var_rowId = sse_decode_i_64(deserializer);
+    var var_createdAtMicros = sse_decode_i_64(deserializer);
+    return MessageOrderInfoView(
+      messageId: var_messageId,
+      rowId: var_rowId,
+      createdAtMicros: var_createdAtMicros,
+    );
+  }
+
+  @protected
+  MessageReactionItem sse_decode_message_reaction_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_emoji = sse_decode_String(deserializer);
+    var var_createdAtMicros = sse_decode_i_64(deserializer);
+    return MessageReactionItem(
+      userId: var_userId,
+      emoji: var_emoji,
+      createdAtMicros: var_createdAtMicros,
+    );
+  }
+
+  @protected
+  MessageReactionView sse_decode_message_reaction_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_emoji = sse_decode_String(deserializer);
+    var var_count = sse_decode_i_64(deserializer);
+    var var_reacted = sse_decode_bool(deserializer);
+    return MessageReactionView(
+      emoji: var_emoji,
+      count: var_count,
+      reacted: var_reacted,
     );
   }
 
   @protected
-  MessageOrderInfoView sse_decode_message_order_info_view(
+  MessageSnippetView sse_decode_message_snippet_view(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_messageId = sse_decode_String(deserializer);
-    var var_rowId = sse_decode_i_64(deserializer);
-    var var_createdAtMicros = sse_decode_i_64(deserializer);
-    return MessageOrderInfoView(
-      messageId: var_messageId,
-      rowId: var_rowId,
-      createdAtMicros: var_createdAtMicros,
-    );
+    var var_text = sse_decode_String(deserializer);
+    var var_highlights = sse_decode_list_text_range_view(deserializer);
+    return MessageSnippetView(text: var_text, highlights: var_highlights);
+  }
+
+  @protected
+  NotificationActionKind sse_decode_notification_action_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NotificationActionKind.values[inner];
   }
 
   @protected
@@ -11793,6 +14490,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AccountHandle?
+  sse_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -11848,6 +14561,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  LinkPreviewView? sse_decode_opt_box_autoadd_link_preview_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_link_preview_view(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LocationView? sse_decode_opt_box_autoadd_location_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_location_view(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   MediaAudioItem? sse_decode_opt_box_autoadd_media_audio_item(
     SseDeserializer deserializer,
@@ -11887,6 +14626,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  MessageSnippetView? sse_decode_opt_box_autoadd_message_snippet_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_message_snippet_view(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PinMessagePreviewItem? sse_decode_opt_box_autoadd_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -11924,6 +14676,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  UserAnnotationItem? sse_decode_opt_box_autoadd_user_annotation_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_user_annotation_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   UserProfileItem? sse_decode_opt_box_autoadd_user_profile_item(
     SseDeserializer deserializer,
@@ -11948,6 +14713,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  OutgoingMessageItem sse_decode_outgoing_message_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messageId = sse_decode_String(deserializer);
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_category = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_createdAtMicros = sse_decode_i_64(deserializer);
+    var var_attemptCount = sse_decode_i_64(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    var var_nextAttemptAtMicros = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return OutgoingMessageItem(
+      messageId: var_messageId,
+      conversationId: var_conversationId,
+      category: var_category,
+      status: var_status,
+      createdAtMicros: var_createdAtMicros,
+      attemptCount: var_attemptCount,
+      lastError: var_lastError,
+      nextAttemptAtMicros: var_nextAttemptAtMicros,
+    );
+  }
+
   @protected
   PinMessagePreviewItem sse_decode_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -12007,6 +14797,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  SessionLinkedItem sse_decode_session_linked_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionId = sse_decode_String(deserializer);
+    var var_linkedAtMicros = sse_decode_i_64(deserializer);
+    return SessionLinkedItem(
+      sessionId: var_sessionId,
+      linkedAtMicros: var_linkedAtMicros,
+    );
+  }
+
   @protected
   SharedAppItem sse_decode_shared_app_item(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -12171,6 +14974,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TextRangeView sse_decode_text_range_view(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return TextRangeView(start: var_start, end: var_end);
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -12200,6 +15011,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  UserAnnotationItem sse_decode_user_annotation_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_userId = sse_decode_String(deserializer);
+    var var_alias = sse_decode_opt_String(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    return UserAnnotationItem(
+      userId: var_userId,
+      alias: var_alias,
+      note: var_note,
+    );
+  }
+
   @protected
   UserProfileItem sse_decode_user_profile_item(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -12233,6 +15059,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  VideoRangeItem sse_decode_video_range_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_offset = sse_decode_u_64(deserializer);
+    var var_totalSize = sse_decode_u_64(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    return VideoRangeItem(
+      data: var_data,
+      offset: var_offset,
+      totalSize: var_totalSize,
+      mimeType: var_mimeType,
+    );
+  }
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -12346,6 +15187,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SearchAccessImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SearchHandleImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -12476,6 +15343,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SearchAccessImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SearchHandleImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -12631,6 +15524,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SearchAccessImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SearchHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -12687,6 +15606,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_account_notification_item_Sse(
+    RustStreamSink<AccountNotificationItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_account_notification_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_account_profile_Sse(
     RustStreamSink<AccountProfile> self,
@@ -12755,6 +15691,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_global_search_view_Sse(
+    RustStreamSink<GlobalSearchView> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_global_search_view,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_i_64_Sse(
     RustStreamSink<PlatformInt64> self,
@@ -12772,6 +15725,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_list_account_unread_count_item_Sse(
+    RustStreamSink<List<AccountUnreadCountItem>> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account_unread_count_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_list_circle_item_Sse(
     RustStreamSink<List<CircleItem>> self,
@@ -12806,6 +15776,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_login_status_Sse(
+    RustStreamSink<LoginStatus> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_login_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_media_playback_event_Sse(
     RustStreamSink<MediaPlaybackEvent> self,
@@ -12874,6 +15861,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_session_linked_item_Sse(
+    RustStreamSink<SessionLinkedItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_session_linked_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_u_64_Sse(
     RustStreamSink<BigInt> self,
@@ -12892,9 +15896,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_account_notification_item(
+    AccountNotificationItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_String(self.accountId, serializer);
+    sse_encode_notification_event(self.event, serializer);
   }
 
   @protected
@@ -12915,6 +15929,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.membership, serializer);
   }
 
+  @protected
+  void sse_encode_account_session(
+    AccountSession self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sessionId, serializer);
+    sse_encode_opt_String(self.platform, serializer);
+    sse_encode_opt_String(self.appVersion, serializer);
+    sse_encode_opt_String(self.lastSeenAt, serializer);
+    sse_encode_bool(self.isCurrent, serializer);
+    sse_encode_bool(self.isPrimary, serializer);
+  }
+
+  @protected
+  void sse_encode_account_unread_count_item(
+    AccountUnreadCountItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.accountId, serializer);
+    sse_encode_i_64(self.unseenMessageCount, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -12948,6 +15986,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_link_preview_view(
+    LinkPreviewView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_link_preview_view(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_location_view(
+    LocationView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_location_view(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_mcp_settings_item(
     McpSettingsItem self,
@@ -13002,6 +16058,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_message_order_info_view(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_message_snippet_view(
+    MessageSnippetView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_message_snippet_view(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_pin_message_preview_item(
     PinMessagePreviewItem self,
@@ -13035,6 +16100,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_user_annotation_item(
+    UserAnnotationItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_user_annotation_item(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_user_profile_item(
     UserProfileItem self,
@@ -13083,6 +16157,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_contact_group(ContactGroup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.index, serializer);
+    sse_encode_list_contact_item(self.contacts, serializer);
+  }
+
+  @protected
+  void sse_encode_contact_item(ContactItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_user_profile_item(self.user, serializer);
+    sse_encode_opt_String(self.alias, serializer);
+    sse_encode_String(self.displayName, serializer);
+    sse_encode_String(self.sortKey, serializer);
+  }
+
   @protected
   void sse_encode_conversation_change_event(
     ConversationChangeEvent self,
@@ -13167,6 +16257,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.membership, serializer);
   }
 
+  @protected
+  void sse_encode_conversation_search_hit_view(
+    ConversationSearchHitView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_conversation_list_data(self.conversation, serializer);
+    sse_encode_i_64(self.hitCount, serializer);
+    sse_encode_i_64(self.latestCreatedAtMillis, serializer);
+  }
+
   @protected
   void sse_encode_conversation_storage_usage(
     ConversationStorageUsage self,
@@ -13268,6 +16369,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_global_search_view(
+    GlobalSearchView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.query, serializer);
+    sse_encode_list_user_profile_item(self.contacts, serializer);
+    sse_encode_opt_String(self.contactsCursor, serializer);
+    sse_encode_list_conversation_list_data(self.groups, serializer);
+    sse_encode_opt_String(self.groupsCursor, serializer);
+    sse_encode_list_conversation_search_hit_view(
+      self.messageConversations,
+      serializer,
+    );
+    sse_encode_opt_String(self.messageConversationsCursor, serializer);
+    sse_encode_list_message_list_view(self.files, serializer);
+    sse_encode_opt_String(self.filesCursor, serializer);
+  }
+
   @protected
   void sse_encode_group_avatar(GroupAvatar self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -13329,6 +16450,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.avatarUrl, serializer);
   }
 
+  @protected
+  void sse_encode_link_preview_view(
+    LinkPreviewView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.siteName, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.imageUrl, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    List<AccountHandle> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+        item,
+        serializer,
+      );
+    }
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -13338,6 +16488,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_account_session(
+    List<AccountSession> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account_session(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_account_unread_count_item(
+    List<AccountUnreadCountItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account_unread_count_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_circle_item(
     List<CircleItem> self,
@@ -13350,6 +16524,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_contact_group(
+    List<ContactGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_contact_group(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_contact_item(
+    List<ContactItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_contact_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_conversation_list_data(
     List<ConversationListData> self,
@@ -13374,6 +16572,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_conversation_search_hit_view(
+    List<ConversationSearchHitView> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_conversation_search_hit_view(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_conversation_storage_usage(
     List<ConversationStorageUsage> self,
@@ -13446,6 +16656,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_message_edit_item(
+    List<MessageEditItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_edit_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_message_list_view(
     List<MessageListView> self,
@@ -13458,6 +16680,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_message_reaction_item(
+    List<MessageReactionItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_reaction_item(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_message_reaction_view(
+    List<MessageReactionView> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_reaction_view(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_outgoing_message_item(
+    List<OutgoingMessageItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_outgoing_message_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_loose(
     List<int> self,
@@ -13552,6 +16810,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_text_range_view(
+    List<TextRangeView> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_text_range_view(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_user_profile_item(
     List<UserProfileItem> self,
@@ -13564,6 +16834,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_location_view(LocationView self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.latitude, serializer);
+    sse_encode_f_64(self.longitude, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.address, serializer);
+  }
+
+  @protected
+  void sse_encode_login_status(LoginStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case LoginStatus_WaitingForScan(authUrl: final authUrl):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(authUrl, serializer);
+      case LoginStatus_Verifying():
+        sse_encode_i_32(1, serializer);
+      case LoginStatus_SyncingKeys():
+        sse_encode_i_32(2, serializer);
+      case LoginStatus_Ready():
+        sse_encode_i_32(3, serializer);
+      case LoginStatus_Expired():
+        sse_encode_i_32(4, serializer);
+      case LoginStatus_Failed(message: final message):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(message, serializer);
+    }
+  }
+
   @protected
   void sse_encode_mcp_server_status_item(
     McpServerStatusItem self,
@@ -13689,6 +16989,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(self.waveform, serializer);
   }
 
+  @protected
+  void sse_encode_message_edit_item(
+    MessageEditItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.editId, serializer);
+    sse_encode_String(self.messageId, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_i_64(self.editedAtMicros, serializer);
+  }
+
   @protected
   void sse_encode_message_list_view(
     MessageListView self,
@@ -13769,6 +17081,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_bool(self.mentionRead, serializer);
     sse_encode_bool(self.pinned, serializer);
     sse_encode_opt_box_autoadd_i_64(self.expireIn, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.editedAtMicros, serializer);
+    sse_encode_list_message_reaction_view(self.reactions, serializer);
+    sse_encode_opt_box_autoadd_location_view(self.location, serializer);
+    sse_encode_opt_box_autoadd_message_snippet_view(self.snippet, serializer);
+    sse_encode_opt_box_autoadd_link_preview_view(self.linkPreview, serializer);
   }
 
   @protected
@@ -13782,6 +17099,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.createdAtMicros, serializer);
   }
 
+  @protected
+  void sse_encode_message_reaction_item(
+    MessageReactionItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.userId, serializer);
+    sse_encode_String(self.emoji, serializer);
+    sse_encode_i_64(self.createdAtMicros, serializer);
+  }
+
+  @protected
+  void sse_encode_message_reaction_view(
+    MessageReactionView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.emoji, serializer);
+    sse_encode_i_64(self.count, serializer);
+    sse_encode_bool(self.reacted, serializer);
+  }
+
+  @protected
+  void sse_encode_message_snippet_view(
+    MessageSnippetView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_text_range_view(self.highlights, serializer);
+  }
+
+  @protected
+  void sse_encode_notification_action_kind(
+    NotificationActionKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_notification_event(
     NotificationEvent self,
@@ -13799,6 +17157,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.dismissMessageId, serializer);
   }
 
+  @protected
+  void
+  sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    AccountHandle? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+        self,
+        serializer,
+      );
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -13810,45 +17185,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
+      sse_encode_box_autoadd_i_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
+      sse_encode_box_autoadd_i_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_link_preview_view(
+    LinkPreviewView? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
+      sse_encode_box_autoadd_link_preview_view(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_opt_box_autoadd_location_view(
+    LocationView? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
+      sse_encode_box_autoadd_location_view(self, serializer);
     }
   }
 
@@ -13891,6 +17292,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_message_snippet_view(
+    MessageSnippetView? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_message_snippet_view(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_pin_message_preview_item(
     PinMessagePreviewItem? self,
@@ -13927,6 +17341,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_user_annotation_item(
+    UserAnnotationItem? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_user_annotation_item(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_user_profile_item(
     UserProfileItem? self,
@@ -13953,6 +17380,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_outgoing_message_item(
+    OutgoingMessageItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.messageId, serializer);
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_String(self.category, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_i_64(self.createdAtMicros, serializer);
+    sse_encode_i_64(self.attemptCount, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextAttemptAtMicros, serializer);
+  }
+
   @protected
   void sse_encode_pin_message_preview_item(
     PinMessagePreviewItem self,
@@ -13996,6 +17439,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_session_linked_item(
+    SessionLinkedItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sessionId, serializer);
+    sse_encode_i_64(self.linkedAtMicros, serializer);
+  }
+
   @protected
   void sse_encode_shared_app_item(
     SharedAppItem self,
@@ -14099,6 +17552,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_64(self.sizeBytes, serializer);
   }
 
+  @protected
+  void sse_encode_text_range_view(
+    TextRangeView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -14128,6 +17591,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_user_annotation_item(
+    UserAnnotationItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.userId, serializer);
+    sse_encode_opt_String(self.alias, serializer);
+    sse_encode_opt_String(self.note, serializer);
+  }
+
   @protected
   void sse_encode_user_profile_item(
     UserProfileItem self,
@@ -14151,6 +17625,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+
+  @protected
+  void sse_encode_video_range_item(
+    VideoRangeItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+    sse_encode_u_64(self.offset, serializer);
+    sse_encode_u_64(self.totalSize, serializer);
+    sse_encode_String(self.mimeType, serializer);
+  }
 }
 
 @sealed
@@ -14248,6 +17734,11 @@ class AccountHandleImpl extends RustOpaque implements AccountHandle {
         that: this,
       );
 
+  Future<List<AccountSession>> linkedSessions() =>
+      RustLib.instance.api.crateApiAccountAccountHandleLinkedSessions(
+        that: this,
+      );
+
   String mediaDirectory() =>
       RustLib.instance.api.crateApiAccountAccountHandleMediaDirectory(
         that: this,
@@ -14288,12 +17779,33 @@ class AccountHandleImpl extends RustOpaque implements AccountHandle {
         that: this,
       );
 
+  Future<void> revokeLinkedSession({required String sessionId}) =>
+      RustLib.instance.api.crateApiAccountAccountHandleRevokeLinkedSession(
+        that: this,
+        sessionId: sessionId,
+      );
+
   Future<SnapshotDetailItem> safeSnapshotById({required String snapshotId}) =>
       RustLib.instance.api.crateApiAccountAccountHandleSafeSnapshotById(
         that: this,
         snapshotId: snapshotId,
       );
 
+  SearchAccess search() =>
+      RustLib.instance.api.crateApiAccountAccountHandleSearch(
+        that: this,
+      );
+
+  SearchHandle searchSession() =>
+      RustLib.instance.api.crateApiAccountAccountHandleSearchSession(
+        that: this,
+      );
+
+  Stream<SessionLinkedItem> sessionLinkedEvents() =>
+      RustLib.instance.api.crateApiAccountAccountHandleSessionLinkedEvents(
+        that: this,
+      );
+
   Future<void> shutdown() =>
       RustLib.instance.api.crateApiAccountAccountHandleShutdown(
         that: this,
@@ -14425,6 +17937,18 @@ class AttachmentAccessImpl extends RustOpaque implements AttachmentAccess {
         messageId: messageId,
       );
 
+  Future<VideoRangeItem> readVideoRange({
+    required String messageId,
+    required BigInt offset,
+    required BigInt length,
+  }) =>
+      RustLib.instance.api.mixinDesktopApiAccessAttachmentAccessReadVideoRange(
+        that: this,
+        messageId: messageId,
+        offset: offset,
+        length: length,
+      );
+
   Future<void> retryAttachment({required String messageId}) =>
       RustLib.instance.api.mixinDesktopApiAccessAttachmentAccessRetryAttachment(
         that: this,
@@ -14789,6 +18313,16 @@ class DesktopHandleImpl extends RustOpaque implements DesktopHandle {
         that: this,
       );
 
+  Future<List<AccountHandle>> accounts() =>
+      RustLib.instance.api.crateApiDesktopDesktopHandleAccounts(
+        that: this,
+      );
+
+  Future<AccountHandle?> activeAccount() =>
+      RustLib.instance.api.crateApiDesktopDesktopHandleActiveAccount(
+        that: this,
+      );
+
   Future<LoginHandle> beginLogin() =>
       RustLib.instance.api.crateApiDesktopDesktopHandleBeginLogin(
         that: this,
@@ -14816,6 +18350,26 @@ class DesktopHandleImpl extends RustOpaque implements DesktopHandle {
         that: this,
       );
 
+  Stream<AccountNotificationItem> notificationEvents() =>
+      RustLib.instance.api.crateApiDesktopDesktopHandleNotificationEvents(
+        that: this,
+      );
+
+  Future<void> performNotificationAction({
+    required String accountId,
+    required String conversationId,
+    required String messageId,
+    required NotificationActionKind kind,
+    String? replyText,
+  }) => RustLib.instance.api.crateApiDesktopDesktopHandlePerformNotificationAction(
+    that: this,
+    accountId: accountId,
+    conversationId: conversationId,
+    messageId: messageId,
+    kind: kind,
+    replyText: replyText,
+  );
+
   Future<void> recreateAccountDatabase() =>
       RustLib.instance.api.crateApiDesktopDesktopHandleRecreateAccountDatabase(
         that: this,
@@ -14826,10 +18380,26 @@ class DesktopHandleImpl extends RustOpaque implements DesktopHandle {
         that: this,
       );
 
+  Future<List<AccountHandle>> restoreAccounts() =>
+      RustLib.instance.api.crateApiDesktopDesktopHandleRestoreAccounts(
+        that: this,
+      );
+
   SettingsHandle get settings =>
       RustLib.instance.api.crateApiDesktopDesktopHandleSettings(
         that: this,
       );
+
+  Future<AccountHandle> switchAccount({required String accountId}) =>
+      RustLib.instance.api.crateApiDesktopDesktopHandleSwitchAccount(
+        that: this,
+        accountId: accountId,
+      );
+
+  Stream<List<AccountUnreadCountItem>> unreadCountChanges() =>
+      RustLib.instance.api.crateApiDesktopDesktopHandleUnreadCountChanges(
+        that: this,
+      );
 }
 
 @sealed
@@ -14859,6 +18429,11 @@ class LoginHandleImpl extends RustOpaque implements LoginHandle {
     that: this,
   );
 
+  Stream<LoginStatus> statusChanges() =>
+      RustLib.instance.api.crateApiLoginLoginHandleStatusChanges(
+        that: this,
+      );
+
   Future<AccountHandle> wait() =>
       RustLib.instance.api.crateApiLoginLoginHandleWait(
         that: this,
@@ -14971,6 +18546,14 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
         RustLib.instance.api.rust_arc_decrement_strong_count_MessageAccessPtr,
   );
 
+  Future<void> cancelOutgoingMessage({required String messageId}) => RustLib
+      .instance
+      .api
+      .mixinDesktopApiAccessMessageAccessCancelOutgoingMessage(
+        that: this,
+        messageId: messageId,
+      );
+
   Future<String> combineForwardMessages({
     required String targetConversationId,
     required List<String> sourceMessageIds,
@@ -14997,6 +18580,27 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     messageIds: messageIds,
   );
 
+  Future<void> editMessage({
+    required String conversationId,
+    required String messageId,
+    required String content,
+  }) => RustLib.instance.api.mixinDesktopApiAccessMessageAccessEditMessage(
+    that: this,
+    conversationId: conversationId,
+    messageId: messageId,
+    content: content,
+  );
+
+  Future<List<OutgoingMessageItem>> failedOutgoingMessages({
+    String? conversationId,
+    required PlatformInt64 limit,
+  }) => RustLib.instance.api
+      .mixinDesktopApiAccessMessageAccessFailedOutgoingMessages(
+        that: this,
+        conversationId: conversationId,
+        limit: limit,
+      );
+
   Future<List<String>> forwardMessages({
     required String targetConversationId,
     required List<String> sourceMessageIds,
@@ -15020,6 +18624,12 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
         after: after,
       );
 
+  Future<LinkPreviewView?> loadLinkPreview({required String messageId}) =>
+      RustLib.instance.api.mixinDesktopApiAccessMessageAccessLoadLinkPreview(
+        that: this,
+        messageId: messageId,
+      );
+
   Future<void> markConversationRead({required String conversationId}) => RustLib
       .instance
       .api
@@ -15037,6 +18647,14 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     messageId: messageId,
   );
 
+  Future<List<MessageEditItem>> messageEditHistory({
+    required String messageId,
+  }) =>
+      RustLib.instance.api.mixinDesktopApiAccessMessageAccessMessageEditHistory(
+        that: this,
+        messageId: messageId,
+      );
+
   Future<List<String>> messageIdsAfter({
     required String conversationId,
     required PlatformInt64 anchorRowId,
@@ -15077,6 +18695,13 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
         messageId: messageId,
       );
 
+  Future<List<MessageReactionItem>> messageReactions({
+    required String messageId,
+  }) => RustLib.instance.api.mixinDesktopApiAccessMessageAccessMessageReactions(
+    that: this,
+    messageId: messageId,
+  );
+
   Future<List<MessageListView>> messages({
     required String conversationId,
     PlatformInt64? beforeCreatedAtMicros,
@@ -15124,6 +18749,19 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     conversationId: conversationId,
   );
 
+  Future<void> reactToMessage({
+    required String conversationId,
+    required String messageId,
+    required String emoji,
+    required bool removed,
+  }) => RustLib.instance.api.mixinDesktopApiAccessMessageAccessReactToMessage(
+    that: this,
+    conversationId: conversationId,
+    messageId: messageId,
+    emoji: emoji,
+    removed: removed,
+  );
+
   Future<void> recallMessages({
     required String conversationId,
     required List<String> messageIds,
@@ -15133,14 +18771,28 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     messageIds: messageIds,
   );
 
+  Future<void> retryOutgoingMessage({required String messageId}) => RustLib
+      .instance
+      .api
+      .mixinDesktopApiAccessMessageAccessRetryOutgoingMessage(
+        that: this,
+        messageId: messageId,
+      );
+
   Future<List<MessageListView>> searchGlobalMessages({
     required String query,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   }) => RustLib.instance.api
       .mixinDesktopApiAccessMessageAccessSearchGlobalMessages(
         that: this,
         query: query,
+        afterMicros: afterMicros,
+        beforeMicros: beforeMicros,
+        rankByRelevance: rankByRelevance,
         anchorMessageId: anchorMessageId,
         limit: limit,
       );
@@ -15150,6 +18802,9 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     required String query,
     String? senderId,
     required List<String> categories,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   }) => RustLib.instance.api.mixinDesktopApiAccessMessageAccessSearchMessages(
@@ -15158,6 +18813,9 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     query: query,
     senderId: senderId,
     categories: categories,
+    afterMicros: afterMicros,
+    beforeMicros: beforeMicros,
+    rankByRelevance: rankByRelevance,
     anchorMessageId: anchorMessageId,
     limit: limit,
   );
@@ -15228,6 +18886,19 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
     silent: silent,
   );
 
+  Future<String> sendLocation({
+    required String conversationId,
+    required LocationView location,
+    String? quoteMessageId,
+    required bool silent,
+  }) => RustLib.instance.api.mixinDesktopApiAccessMessageAccessSendLocation(
+    that: this,
+    conversationId: conversationId,
+    location: location,
+    quoteMessageId: quoteMessageId,
+    silent: silent,
+  );
+
   Future<String> sendPost({
     required String conversationId,
     required String content,
@@ -15320,6 +18991,79 @@ class MessageAccessImpl extends RustOpaque implements MessageAccess {
 }
 
 @sealed
+class SearchAccessImpl extends RustOpaque implements SearchAccess {
+  // Not to be used by end users
+  SearchAccessImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  SearchAccessImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_SearchAccess,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_SearchAccess,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_SearchAccessPtr,
+  );
+
+  Future<GlobalSearchView> globalSearch({
+    required String query,
+    required int limit,
+  }) => RustLib.instance.api.mixinDesktopApiAccessSearchAccessGlobalSearch(
+    that: this,
+    query: query,
+    limit: limit,
+  );
+
+  Future<GlobalSearchView> globalSearchSection({
+    required String query,
+    required String section,
+    String? cursor,
+    required int limit,
+  }) =>
+      RustLib.instance.api.mixinDesktopApiAccessSearchAccessGlobalSearchSection(
+        that: this,
+        query: query,
+        section: section,
+        cursor: cursor,
+        limit: limit,
+      );
+}
+
+class SearchHandleImpl extends RustOpaque implements SearchHandle {
+  // Not to be used by end users
+  SearchHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  SearchHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_SearchHandle,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_SearchHandle,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_SearchHandlePtr,
+  );
+
+  Stream<GlobalSearchView> results({required int limit}) =>
+      RustLib.instance.api.crateApiSearchSearchHandleResults(
+        that: this,
+        limit: limit,
+      );
+
+  void setQuery({required String query}) =>
+      RustLib.instance.api.crateApiSearchSearchHandleSetQuery(
+        that: this,
+        query: query,
+      );
+}
+
 class SettingsHandleImpl extends RustOpaque implements SettingsHandle {
   // Not to be used by end users
   SettingsHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
@@ -15343,6 +19087,11 @@ class SettingsHandleImpl extends RustOpaque implements SettingsHandle {
         that: this,
       );
 
+  Future<String?> mcpAccountId() =>
+      RustLib.instance.api.crateApiDesktopSettingsHandleMcpAccountId(
+        that: this,
+      );
+
   Future<McpServerStatusItem> mcpServerStatus() =>
       RustLib.instance.api.crateApiDesktopSettingsHandleMcpServerStatus(
         that: this,
@@ -15369,6 +19118,12 @@ class SettingsHandleImpl extends RustOpaque implements SettingsHandle {
         value: value,
       );
 
+  Future<McpServerStatusItem> setMcpAccountId({String? accountId}) =>
+      RustLib.instance.api.crateApiDesktopSettingsHandleSetMcpAccountId(
+        that: this,
+        accountId: accountId,
+      );
+
   Future<void> setPhotoAutoDownload({required bool value}) =>
       RustLib.instance.api.crateApiDesktopSettingsHandleSetPhotoAutoDownload(
         that: this,
@@ -15564,12 +19319,22 @@ class UserAccessImpl extends RustOpaque implements UserAccess {
   Future<void> blockUser({required String userId}) => RustLib.instance.api
       .mixinDesktopApiAccessUserAccessBlockUser(that: this, userId: userId);
 
+  Future<List<UserProfileItem>> blockedUsers() =>
+      RustLib.instance.api.mixinDesktopApiAccessUserAccessBlockedUsers(
+        that: this,
+      );
+
   Future<String?> botCreatorId({required String userId}) => RustLib.instance.api
       .mixinDesktopApiAccessUserAccessBotCreatorId(that: this, userId: userId);
 
   Future<String?> botHomeUri({required String appId}) => RustLib.instance.api
       .mixinDesktopApiAccessUserAccessBotHomeUri(that: this, appId: appId);
 
+  Future<List<ContactGroup>> contacts() =>
+      RustLib.instance.api.mixinDesktopApiAccessUserAccessContacts(
+        that: this,
+      );
+
   Future<List<SharedAppItem>> localSharedApps({required String userId}) =>
       RustLib.instance.api.mixinDesktopApiAccessUserAccessLocalSharedApps(
         that: this,
@@ -15626,6 +19391,17 @@ class UserAccessImpl extends RustOpaque implements UserAccess {
         that: this,
       );
 
+  Future<void> setUserAnnotation({
+    required String userId,
+    String? alias,
+    String? note,
+  }) => RustLib.instance.api.mixinDesktopApiAccessUserAccessSetUserAnnotation(
+    that: this,
+    userId: userId,
+    alias: alias,
+    note: note,
+  );
+
   Future<List<SharedAppItem>> sharedApps({required String userId}) => RustLib
       .instance
       .api
@@ -15634,6 +19410,12 @@ class UserAccessImpl extends RustOpaque implements UserAccess {
   Future<void> unblockUser({required String userId}) => RustLib.instance.api
       .mixinDesktopApiAccessUserAccessUnblockUser(that: this, userId: userId);
 
+  Future<UserAnnotationItem?> userAnnotation({required String userId}) =>
+      RustLib.instance.api.mixinDesktopApiAccessUserAccessUserAnnotation(
+        that: this,
+        userId: userId,
+      );
+
   Future<UserProfileItem?> userProfile({
     String? userId,
     String? identityNumber,
@@ -9,6 +9,7 @@ import 'api/device_transfer.dart';
 import 'api/logging.dart';
 import 'api/login.dart';
 import 'api/media.dart';
+import 'api/search.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -60,6 +61,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_MessageAccessPtr => wire
       ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccessPtr;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SearchAccessPtr => wire
+      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccessPtr;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SearchHandlePtr => wire
+      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandlePtr;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_SettingsHandlePtr => wire
       ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandlePtr;
@@ -123,6 +132,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchAccess
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  );
+
+  @protected
+  SearchHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  );
+
   @protected
   SettingsHandle
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -183,6 +204,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchAccess
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  );
+
+  @protected
+  SearchHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  );
+
   @protected
   SettingsHandle
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -252,6 +285,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchAccess
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  );
+
+  @protected
+  SearchHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  );
+
   @protected
   SettingsHandle
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -273,6 +318,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<AccountNotificationItem>
+  dco_decode_StreamSink_account_notification_item_Sse(dynamic raw);
+
   @protected
   RustStreamSink<AccountProfile> dco_decode_StreamSink_account_profile_Sse(
     dynamic raw,
@@ -289,9 +338,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<DeviceTransferEvent>
   dco_decode_StreamSink_device_transfer_event_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<GlobalSearchView> dco_decode_StreamSink_global_search_view_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<List<AccountUnreadCountItem>>
+  dco_decode_StreamSink_list_account_unread_count_item_Sse(dynamic raw);
+
   @protected
   RustStreamSink<List<CircleItem>> dco_decode_StreamSink_list_circle_item_Sse(
     dynamic raw,
@@ -301,6 +359,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<List<ConversationUnseenCount>>
   dco_decode_StreamSink_list_conversation_unseen_count_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<LoginStatus> dco_decode_StreamSink_login_status_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<MediaPlaybackEvent>
   dco_decode_StreamSink_media_playback_event_Sse(dynamic raw);
@@ -316,15 +379,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<String?> dco_decode_StreamSink_opt_String_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<SessionLinkedItem>
+  dco_decode_StreamSink_session_linked_item_Sse(dynamic raw);
+
   @protected
   RustStreamSink<BigInt> dco_decode_StreamSink_u_64_Sse(dynamic raw);
 
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AccountNotificationItem dco_decode_account_notification_item(dynamic raw);
+
   @protected
   AccountProfile dco_decode_account_profile(dynamic raw);
 
+  @protected
+  AccountSession dco_decode_account_session(dynamic raw);
+
+  @protected
+  AccountUnreadCountItem dco_decode_account_unread_count_item(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -340,6 +416,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  LinkPreviewView dco_decode_box_autoadd_link_preview_view(dynamic raw);
+
+  @protected
+  LocationView dco_decode_box_autoadd_location_view(dynamic raw);
+
   @protected
   McpSettingsItem dco_decode_box_autoadd_mcp_settings_item(dynamic raw);
 
@@ -364,6 +446,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  MessageSnippetView dco_decode_box_autoadd_message_snippet_view(dynamic raw);
+
   @protected
   PinMessagePreviewItem dco_decode_box_autoadd_pin_message_preview_item(
     dynamic raw,
@@ -378,6 +463,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UserAnnotationItem dco_decode_box_autoadd_user_annotation_item(dynamic raw);
+
   @protected
   UserProfileItem dco_decode_box_autoadd_user_profile_item(dynamic raw);
 
@@ -390,6 +478,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectionFailedReason dco_decode_connection_failed_reason(dynamic raw);
 
+  @protected
+  ContactGroup dco_decode_contact_group(dynamic raw);
+
+  @protected
+  ContactItem dco_decode_contact_item(dynamic raw);
+
   @protected
   ConversationChangeEvent dco_decode_conversation_change_event(dynamic raw);
 
@@ -404,6 +498,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ConversationSearchHitView dco_decode_conversation_search_hit_view(
+    dynamic raw,
+  );
+
   @protected
   ConversationStorageUsage dco_decode_conversation_storage_usage(dynamic raw);
 
@@ -422,6 +521,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  GlobalSearchView dco_decode_global_search_view(dynamic raw);
+
   @protected
   GroupAvatar dco_decode_group_avatar(dynamic raw);
 
@@ -440,12 +542,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ImageMessageView dco_decode_image_message_view(dynamic raw);
 
+  @protected
+  LinkPreviewView dco_decode_link_preview_view(dynamic raw);
+
+  @protected
+  List<AccountHandle>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    dynamic raw,
+  );
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AccountSession> dco_decode_list_account_session(dynamic raw);
+
+  @protected
+  List<AccountUnreadCountItem> dco_decode_list_account_unread_count_item(
+    dynamic raw,
+  );
+
   @protected
   List<CircleItem> dco_decode_list_circle_item(dynamic raw);
 
+  @protected
+  List<ContactGroup> dco_decode_list_contact_group(dynamic raw);
+
+  @protected
+  List<ContactItem> dco_decode_list_contact_item(dynamic raw);
+
   @protected
   List<ConversationListData> dco_decode_list_conversation_list_data(
     dynamic raw,
@@ -455,6 +580,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<ConversationParticipantItem>
   dco_decode_list_conversation_participant_item(dynamic raw);
 
+  @protected
+  List<ConversationSearchHitView> dco_decode_list_conversation_search_hit_view(
+    dynamic raw,
+  );
+
   @protected
   List<ConversationStorageUsage> dco_decode_list_conversation_storage_usage(
     dynamic raw,
@@ -479,9 +609,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<MediaAudioItem> dco_decode_list_media_audio_item(dynamic raw);
 
+  @protected
+  List<MessageEditItem> dco_decode_list_message_edit_item(dynamic raw);
+
   @protected
   List<MessageListView> dco_decode_list_message_list_view(dynamic raw);
 
+  @protected
+  List<MessageReactionItem> dco_decode_list_message_reaction_item(dynamic raw);
+
+  @protected
+  List<MessageReactionView> dco_decode_list_message_reaction_view(
+    dynamic raw,
+  );
+
+  @protected
+  List<OutgoingMessageItem> dco_decode_list_outgoing_message_item(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
@@ -508,9 +652,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<TextRangeView> dco_decode_list_text_range_view(dynamic raw);
+
   @protected
   List<UserProfileItem> dco_decode_list_user_profile_item(dynamic raw);
 
+  @protected
+  LocationView dco_decode_location_view(dynamic raw);
+
+  @protected
+  LoginStatus dco_decode_login_status(dynamic raw);
+
   @protected
   McpServerStatusItem dco_decode_mcp_server_status_item(dynamic raw);
 
@@ -541,15 +694,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MediaVoiceRecording dco_decode_media_voice_recording(dynamic raw);
 
+  @protected
+  MessageEditItem dco_decode_message_edit_item(dynamic raw);
+
   @protected
   MessageListView dco_decode_message_list_view(dynamic raw);
 
   @protected
   MessageOrderInfoView dco_decode_message_order_info_view(dynamic raw);
 
+  @protected
+  MessageReactionItem dco_decode_message_reaction_item(dynamic raw);
+
+  @protected
+  MessageReactionView dco_decode_message_reaction_view(dynamic raw);
+
+  @protected
+  MessageSnippetView dco_decode_message_snippet_view(dynamic raw);
+
+  @protected
+  NotificationActionKind dco_decode_notification_action_kind(dynamic raw);
+
   @protected
   NotificationEvent dco_decode_notification_event(dynamic raw);
 
+  @protected
+  AccountHandle?
+  dco_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    dynamic raw,
+  );
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -565,6 +739,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  LinkPreviewView? dco_decode_opt_box_autoadd_link_preview_view(dynamic raw);
+
+  @protected
+  LocationView? dco_decode_opt_box_autoadd_location_view(dynamic raw);
+
   @protected
   MediaAudioItem? dco_decode_opt_box_autoadd_media_audio_item(dynamic raw);
 
@@ -578,6 +758,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  MessageSnippetView? dco_decode_opt_box_autoadd_message_snippet_view(
+    dynamic raw,
+  );
+
   @protected
   PinMessagePreviewItem? dco_decode_opt_box_autoadd_pin_message_preview_item(
     dynamic raw,
@@ -589,12 +774,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UserAnnotationItem? dco_decode_opt_box_autoadd_user_annotation_item(
+    dynamic raw,
+  );
+
   @protected
   UserProfileItem? dco_decode_opt_box_autoadd_user_profile_item(dynamic raw);
 
   @protected
   Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  OutgoingMessageItem dco_decode_outgoing_message_item(dynamic raw);
+
   @protected
   PinMessagePreviewItem dco_decode_pin_message_preview_item(dynamic raw);
 
@@ -607,6 +800,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  SessionLinkedItem dco_decode_session_linked_item(dynamic raw);
+
   @protected
   SharedAppItem dco_decode_shared_app_item(dynamic raw);
 
@@ -625,6 +821,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StorageCategoryUsage dco_decode_storage_category_usage(dynamic raw);
 
+  @protected
+  TextRangeView dco_decode_text_range_view(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -640,12 +839,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UserAnnotationItem dco_decode_user_annotation_item(dynamic raw);
+
   @protected
   UserProfileItem dco_decode_user_profile_item(dynamic raw);
 
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  VideoRangeItem dco_decode_video_range_item(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -697,6 +902,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchAccess
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SettingsHandle
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -757,6 +974,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchAccess
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SettingsHandle
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -828,6 +1057,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchAccess
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SettingsHandle
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -851,6 +1092,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<AccountNotificationItem>
+  sse_decode_StreamSink_account_notification_item_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<AccountProfile> sse_decode_StreamSink_account_profile_Sse(
     SseDeserializer deserializer,
@@ -871,11 +1118,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<DeviceTransferEvent>
   sse_decode_StreamSink_device_transfer_event_Sse(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<GlobalSearchView> sse_decode_StreamSink_global_search_view_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<List<AccountUnreadCountItem>>
+  sse_decode_StreamSink_list_account_unread_count_item_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<List<CircleItem>> sse_decode_StreamSink_list_circle_item_Sse(
     SseDeserializer deserializer,
@@ -887,6 +1145,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<LoginStatus> sse_decode_StreamSink_login_status_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<MediaPlaybackEvent>
   sse_decode_StreamSink_media_playback_event_Sse(SseDeserializer deserializer);
@@ -904,6 +1167,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<SessionLinkedItem>
+  sse_decode_StreamSink_session_linked_item_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<BigInt> sse_decode_StreamSink_u_64_Sse(
     SseDeserializer deserializer,
@@ -912,9 +1179,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AccountNotificationItem sse_decode_account_notification_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   AccountProfile sse_decode_account_profile(SseDeserializer deserializer);
 
+  @protected
+  AccountSession sse_decode_account_session(SseDeserializer deserializer);
+
+  @protected
+  AccountUnreadCountItem sse_decode_account_unread_count_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -930,6 +1210,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  LinkPreviewView sse_decode_box_autoadd_link_preview_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocationView sse_decode_box_autoadd_location_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   McpSettingsItem sse_decode_box_autoadd_mcp_settings_item(
     SseDeserializer deserializer,
@@ -960,6 +1250,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageSnippetView sse_decode_box_autoadd_message_snippet_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PinMessagePreviewItem sse_decode_box_autoadd_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -978,6 +1273,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UserAnnotationItem sse_decode_box_autoadd_user_annotation_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserProfileItem sse_decode_box_autoadd_user_profile_item(
     SseDeserializer deserializer,
@@ -994,6 +1294,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ContactGroup sse_decode_contact_group(SseDeserializer deserializer);
+
+  @protected
+  ContactItem sse_decode_contact_item(SseDeserializer deserializer);
+
   @protected
   ConversationChangeEvent sse_decode_conversation_change_event(
     SseDeserializer deserializer,
@@ -1014,6 +1320,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ConversationSearchHitView sse_decode_conversation_search_hit_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ConversationStorageUsage sse_decode_conversation_storage_usage(
     SseDeserializer deserializer,
@@ -1040,6 +1351,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  GlobalSearchView sse_decode_global_search_view(SseDeserializer deserializer);
+
   @protected
   GroupAvatar sse_decode_group_avatar(SseDeserializer deserializer);
 
@@ -1060,12 +1374,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ImageMessageView sse_decode_image_message_view(SseDeserializer deserializer);
 
+  @protected
+  LinkPreviewView sse_decode_link_preview_view(SseDeserializer deserializer);
+
+  @protected
+  List<AccountHandle>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AccountSession> sse_decode_list_account_session(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AccountUnreadCountItem> sse_decode_list_account_unread_count_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<CircleItem> sse_decode_list_circle_item(SseDeserializer deserializer);
 
+  @protected
+  List<ContactGroup> sse_decode_list_contact_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ContactItem> sse_decode_list_contact_item(SseDeserializer deserializer);
+
   @protected
   List<ConversationListData> sse_decode_list_conversation_list_data(
     SseDeserializer deserializer,
@@ -1075,6 +1416,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<ConversationParticipantItem>
   sse_decode_list_conversation_participant_item(SseDeserializer deserializer);
 
+  @protected
+  List<ConversationSearchHitView> sse_decode_list_conversation_search_hit_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ConversationStorageUsage> sse_decode_list_conversation_storage_usage(
     SseDeserializer deserializer,
@@ -1103,11 +1449,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MessageEditItem> sse_decode_list_message_edit_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<MessageListView> sse_decode_list_message_list_view(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MessageReactionItem> sse_decode_list_message_reaction_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MessageReactionView> sse_decode_list_message_reaction_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OutgoingMessageItem> sse_decode_list_outgoing_message_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
@@ -1140,11 +1506,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<TextRangeView> sse_decode_list_text_range_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<UserProfileItem> sse_decode_list_user_profile_item(
     SseDeserializer deserializer,
   );
 
+  @protected
+  LocationView sse_decode_location_view(SseDeserializer deserializer);
+
+  @protected
+  LoginStatus sse_decode_login_status(SseDeserializer deserializer);
+
   @protected
   McpServerStatusItem sse_decode_mcp_server_status_item(
     SseDeserializer deserializer,
@@ -1191,6 +1568,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageEditItem sse_decode_message_edit_item(SseDeserializer deserializer);
+
   @protected
   MessageListView sse_decode_message_list_view(SseDeserializer deserializer);
 
@@ -1199,9 +1579,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageReactionItem sse_decode_message_reaction_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageReactionView sse_decode_message_reaction_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageSnippetView sse_decode_message_snippet_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotificationActionKind sse_decode_notification_action_kind(
+    SseDeserializer deserializer,
+  );
+
   @protected
   NotificationEvent sse_decode_notification_event(SseDeserializer deserializer);
 
+  @protected
+  AccountHandle?
+  sse_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -1217,6 +1623,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  LinkPreviewView? sse_decode_opt_box_autoadd_link_preview_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocationView? sse_decode_opt_box_autoadd_location_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MediaAudioItem? sse_decode_opt_box_autoadd_media_audio_item(
     SseDeserializer deserializer,
@@ -1232,6 +1648,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageSnippetView? sse_decode_opt_box_autoadd_message_snippet_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PinMessagePreviewItem? sse_decode_opt_box_autoadd_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -1245,6 +1666,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UserAnnotationItem? sse_decode_opt_box_autoadd_user_annotation_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserProfileItem? sse_decode_opt_box_autoadd_user_profile_item(
     SseDeserializer deserializer,
@@ -1253,6 +1679,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  OutgoingMessageItem sse_decode_outgoing_message_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PinMessagePreviewItem sse_decode_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -1271,6 +1702,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SessionLinkedItem sse_decode_session_linked_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SharedAppItem sse_decode_shared_app_item(SseDeserializer deserializer);
 
@@ -1295,6 +1731,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TextRangeView sse_decode_text_range_view(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -1310,12 +1749,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UserAnnotationItem sse_decode_user_annotation_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserProfileItem sse_decode_user_profile_item(SseDeserializer deserializer);
 
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  VideoRangeItem sse_decode_video_range_item(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -1378,6 +1825,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -1448,6 +1909,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -1531,6 +2006,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -1558,6 +2047,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_account_notification_item_Sse(
+    RustStreamSink<AccountNotificationItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_account_profile_Sse(
     RustStreamSink<AccountProfile> self,
@@ -1582,12 +2077,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_global_search_view_Sse(
+    RustStreamSink<GlobalSearchView> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_i_64_Sse(
     RustStreamSink<PlatformInt64> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_list_account_unread_count_item_Sse(
+    RustStreamSink<List<AccountUnreadCountItem>> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_list_circle_item_Sse(
     RustStreamSink<List<CircleItem>> self,
@@ -1600,6 +2107,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_login_status_Sse(
+    RustStreamSink<LoginStatus> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_media_playback_event_Sse(
     RustStreamSink<MediaPlaybackEvent> self,
@@ -1624,6 +2137,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_session_linked_item_Sse(
+    RustStreamSink<SessionLinkedItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_u_64_Sse(
     RustStreamSink<BigInt> self,
@@ -1633,12 +2152,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_account_notification_item(
+    AccountNotificationItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_account_profile(
     AccountProfile self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_account_session(
+    AccountSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_account_unread_count_item(
+    AccountUnreadCountItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -1657,6 +2194,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_link_preview_view(
+    LinkPreviewView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_location_view(
+    LocationView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_mcp_settings_item(
     McpSettingsItem self,
@@ -1693,6 +2242,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_message_snippet_view(
+    MessageSnippetView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_pin_message_preview_item(
     PinMessagePreviewItem self,
@@ -1714,6 +2269,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_user_annotation_item(
+    UserAnnotationItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_user_profile_item(
     UserProfileItem self,
@@ -1732,6 +2293,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_contact_group(ContactGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_contact_item(ContactItem self, SseSerializer serializer);
+
   @protected
   void sse_encode_conversation_change_event(
     ConversationChangeEvent self,
@@ -1756,6 +2323,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_conversation_search_hit_view(
+    ConversationSearchHitView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_conversation_storage_usage(
     ConversationStorageUsage self,
@@ -1786,6 +2359,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_global_search_view(
+    GlobalSearchView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_group_avatar(GroupAvatar self, SseSerializer serializer);
 
@@ -1813,15 +2392,52 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_link_preview_view(
+    LinkPreviewView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    List<AccountHandle> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_account_session(
+    List<AccountSession> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_account_unread_count_item(
+    List<AccountUnreadCountItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_circle_item(
     List<CircleItem> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_contact_group(
+    List<ContactGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_contact_item(
+    List<ContactItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_conversation_list_data(
     List<ConversationListData> self,
@@ -1834,6 +2450,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_conversation_search_hit_view(
+    List<ConversationSearchHitView> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_conversation_storage_usage(
     List<ConversationStorageUsage> self,
@@ -1870,12 +2492,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_message_edit_item(
+    List<MessageEditItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_message_list_view(
     List<MessageListView> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_message_reaction_item(
+    List<MessageReactionItem> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message_reaction_view(
+    List<MessageReactionView> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_outgoing_message_item(
+    List<OutgoingMessageItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -1921,12 +2567,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_text_range_view(
+    List<TextRangeView> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_user_profile_item(
     List<UserProfileItem> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_location_view(LocationView self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_login_status(LoginStatus self, SseSerializer serializer);
+
   @protected
   void sse_encode_mcp_server_status_item(
     McpServerStatusItem self,
@@ -1987,6 +2645,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_message_edit_item(
+    MessageEditItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_message_list_view(
     MessageListView self,
@@ -1999,12 +2663,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_message_reaction_item(
+    MessageReactionItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_reaction_view(
+    MessageReactionView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_snippet_view(
+    MessageSnippetView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_notification_action_kind(
+    NotificationActionKind self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_notification_event(
     NotificationEvent self,
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    AccountHandle? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -2023,6 +2718,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_link_preview_view(
+    LinkPreviewView? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_location_view(
+    LocationView? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_media_audio_item(
     MediaAudioItem? self,
@@ -2041,6 +2748,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_message_snippet_view(
+    MessageSnippetView? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_pin_message_preview_item(
     PinMessagePreviewItem? self,
@@ -2056,6 +2769,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_user_annotation_item(
+    UserAnnotationItem? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_user_profile_item(
     UserProfileItem? self,
@@ -2068,6 +2787,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_outgoing_message_item(
+    OutgoingMessageItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_pin_message_preview_item(
     PinMessagePreviewItem self,
@@ -2089,6 +2814,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_session_linked_item(
+    SessionLinkedItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_shared_app_item(SharedAppItem self, SseSerializer serializer);
 
@@ -2119,6 +2850,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_text_range_view(
+    TextRangeView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -2134,6 +2871,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_user_annotation_item(
+    UserAnnotationItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_user_profile_item(
     UserProfileItem self,
@@ -2142,6 +2885,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_video_range_item(
+    VideoRangeItem self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -2430,6 +3179,74 @@ class RustLibWire implements BaseWire {
       _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccessPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccessPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_mixin_desktop_ui_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess',
+      );
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccessPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccessPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_mixin_desktop_ui_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess',
+      );
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccessPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandlePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_mixin_desktop_ui_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle',
+      );
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandlePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandlePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+        'frbgen_mixin_desktop_ui_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle',
+      );
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandlePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
     ffi.Pointer<ffi.Void> ptr,
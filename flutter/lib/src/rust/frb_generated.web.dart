@@ -12,6 +12,7 @@ import 'api/device_transfer.dart';
 import 'api/logging.dart';
 import 'api/login.dart';
 import 'api/media.dart';
+import 'api/search.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'error.dart';
@@ -62,6 +63,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_MessageAccessPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMessageAccess;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SearchAccessPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SearchHandlePtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_SettingsHandlePtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle;
@@ -125,6 +134,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchAccess
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  );
+
+  @protected
+  SearchHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  );
+
   @protected
   SettingsHandle
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -185,6 +206,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchAccess
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  );
+
+  @protected
+  SearchHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  );
+
   @protected
   SettingsHandle
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -254,6 +287,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchAccess
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    dynamic raw,
+  );
+
+  @protected
+  SearchHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    dynamic raw,
+  );
+
   @protected
   SettingsHandle
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -275,6 +320,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<AccountNotificationItem>
+  dco_decode_StreamSink_account_notification_item_Sse(dynamic raw);
+
   @protected
   RustStreamSink<AccountProfile> dco_decode_StreamSink_account_profile_Sse(
     dynamic raw,
@@ -291,9 +340,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<DeviceTransferEvent>
   dco_decode_StreamSink_device_transfer_event_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<GlobalSearchView> dco_decode_StreamSink_global_search_view_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<PlatformInt64> dco_decode_StreamSink_i_64_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<List<AccountUnreadCountItem>>
+  dco_decode_StreamSink_list_account_unread_count_item_Sse(dynamic raw);
+
   @protected
   RustStreamSink<List<CircleItem>> dco_decode_StreamSink_list_circle_item_Sse(
     dynamic raw,
@@ -303,6 +361,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<List<ConversationUnseenCount>>
   dco_decode_StreamSink_list_conversation_unseen_count_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<LoginStatus> dco_decode_StreamSink_login_status_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<MediaPlaybackEvent>
   dco_decode_StreamSink_media_playback_event_Sse(dynamic raw);
@@ -318,15 +381,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<String?> dco_decode_StreamSink_opt_String_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<SessionLinkedItem>
+  dco_decode_StreamSink_session_linked_item_Sse(dynamic raw);
+
   @protected
   RustStreamSink<BigInt> dco_decode_StreamSink_u_64_Sse(dynamic raw);
 
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AccountNotificationItem dco_decode_account_notification_item(dynamic raw);
+
   @protected
   AccountProfile dco_decode_account_profile(dynamic raw);
 
+  @protected
+  AccountSession dco_decode_account_session(dynamic raw);
+
+  @protected
+  AccountUnreadCountItem dco_decode_account_unread_count_item(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -342,6 +418,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  LinkPreviewView dco_decode_box_autoadd_link_preview_view(dynamic raw);
+
+  @protected
+  LocationView dco_decode_box_autoadd_location_view(dynamic raw);
+
   @protected
   McpSettingsItem dco_decode_box_autoadd_mcp_settings_item(dynamic raw);
 
@@ -366,6 +448,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  MessageSnippetView dco_decode_box_autoadd_message_snippet_view(dynamic raw);
+
   @protected
   PinMessagePreviewItem dco_decode_box_autoadd_pin_message_preview_item(
     dynamic raw,
@@ -380,6 +465,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UserAnnotationItem dco_decode_box_autoadd_user_annotation_item(dynamic raw);
+
   @protected
   UserProfileItem dco_decode_box_autoadd_user_profile_item(dynamic raw);
 
@@ -392,6 +480,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectionFailedReason dco_decode_connection_failed_reason(dynamic raw);
 
+  @protected
+  ContactGroup dco_decode_contact_group(dynamic raw);
+
+  @protected
+  ContactItem dco_decode_contact_item(dynamic raw);
+
   @protected
   ConversationChangeEvent dco_decode_conversation_change_event(dynamic raw);
 
@@ -406,6 +500,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ConversationSearchHitView dco_decode_conversation_search_hit_view(
+    dynamic raw,
+  );
+
   @protected
   ConversationStorageUsage dco_decode_conversation_storage_usage(dynamic raw);
 
@@ -424,6 +523,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  GlobalSearchView dco_decode_global_search_view(dynamic raw);
+
   @protected
   GroupAvatar dco_decode_group_avatar(dynamic raw);
 
@@ -442,12 +544,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ImageMessageView dco_decode_image_message_view(dynamic raw);
 
+  @protected
+  LinkPreviewView dco_decode_link_preview_view(dynamic raw);
+
+  @protected
+  List<AccountHandle>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    dynamic raw,
+  );
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AccountSession> dco_decode_list_account_session(dynamic raw);
+
+  @protected
+  List<AccountUnreadCountItem> dco_decode_list_account_unread_count_item(
+    dynamic raw,
+  );
+
   @protected
   List<CircleItem> dco_decode_list_circle_item(dynamic raw);
 
+  @protected
+  List<ContactGroup> dco_decode_list_contact_group(dynamic raw);
+
+  @protected
+  List<ContactItem> dco_decode_list_contact_item(dynamic raw);
+
   @protected
   List<ConversationListData> dco_decode_list_conversation_list_data(
     dynamic raw,
@@ -457,6 +582,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<ConversationParticipantItem>
   dco_decode_list_conversation_participant_item(dynamic raw);
 
+  @protected
+  List<ConversationSearchHitView> dco_decode_list_conversation_search_hit_view(
+    dynamic raw,
+  );
+
   @protected
   List<ConversationStorageUsage> dco_decode_list_conversation_storage_usage(
     dynamic raw,
@@ -481,9 +611,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<MediaAudioItem> dco_decode_list_media_audio_item(dynamic raw);
 
+  @protected
+  List<MessageEditItem> dco_decode_list_message_edit_item(dynamic raw);
+
   @protected
   List<MessageListView> dco_decode_list_message_list_view(dynamic raw);
 
+  @protected
+  List<MessageReactionItem> dco_decode_list_message_reaction_item(dynamic raw);
+
+  @protected
+  List<MessageReactionView> dco_decode_list_message_reaction_view(
+    dynamic raw,
+  );
+
+  @protected
+  List<OutgoingMessageItem> dco_decode_list_outgoing_message_item(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
@@ -510,9 +654,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<TextRangeView> dco_decode_list_text_range_view(dynamic raw);
+
   @protected
   List<UserProfileItem> dco_decode_list_user_profile_item(dynamic raw);
 
+  @protected
+  LocationView dco_decode_location_view(dynamic raw);
+
+  @protected
+  LoginStatus dco_decode_login_status(dynamic raw);
+
   @protected
   McpServerStatusItem dco_decode_mcp_server_status_item(dynamic raw);
 
@@ -543,15 +696,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MediaVoiceRecording dco_decode_media_voice_recording(dynamic raw);
 
+  @protected
+  MessageEditItem dco_decode_message_edit_item(dynamic raw);
+
   @protected
   MessageListView dco_decode_message_list_view(dynamic raw);
 
   @protected
   MessageOrderInfoView dco_decode_message_order_info_view(dynamic raw);
 
+  @protected
+  MessageReactionItem dco_decode_message_reaction_item(dynamic raw);
+
+  @protected
+  MessageReactionView dco_decode_message_reaction_view(dynamic raw);
+
+  @protected
+  MessageSnippetView dco_decode_message_snippet_view(dynamic raw);
+
+  @protected
+  NotificationActionKind dco_decode_notification_action_kind(dynamic raw);
+
   @protected
   NotificationEvent dco_decode_notification_event(dynamic raw);
 
+  @protected
+  AccountHandle?
+  dco_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    dynamic raw,
+  );
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -567,6 +741,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  LinkPreviewView? dco_decode_opt_box_autoadd_link_preview_view(dynamic raw);
+
+  @protected
+  LocationView? dco_decode_opt_box_autoadd_location_view(dynamic raw);
+
   @protected
   MediaAudioItem? dco_decode_opt_box_autoadd_media_audio_item(dynamic raw);
 
@@ -580,6 +760,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  MessageSnippetView? dco_decode_opt_box_autoadd_message_snippet_view(
+    dynamic raw,
+  );
+
   @protected
   PinMessagePreviewItem? dco_decode_opt_box_autoadd_pin_message_preview_item(
     dynamic raw,
@@ -591,12 +776,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  UserAnnotationItem? dco_decode_opt_box_autoadd_user_annotation_item(
+    dynamic raw,
+  );
+
   @protected
   UserProfileItem? dco_decode_opt_box_autoadd_user_profile_item(dynamic raw);
 
   @protected
   Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  OutgoingMessageItem dco_decode_outgoing_message_item(dynamic raw);
+
   @protected
   PinMessagePreviewItem dco_decode_pin_message_preview_item(dynamic raw);
 
@@ -609,6 +802,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  SessionLinkedItem dco_decode_session_linked_item(dynamic raw);
+
   @protected
   SharedAppItem dco_decode_shared_app_item(dynamic raw);
 
@@ -627,6 +823,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   StorageCategoryUsage dco_decode_storage_category_usage(dynamic raw);
 
+  @protected
+  TextRangeView dco_decode_text_range_view(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -642,12 +841,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UserAnnotationItem dco_decode_user_annotation_item(dynamic raw);
+
   @protected
   UserProfileItem dco_decode_user_profile_item(dynamic raw);
 
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  VideoRangeItem dco_decode_video_range_item(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -699,6 +904,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchAccess
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SettingsHandle
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -759,6 +976,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchAccess
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SettingsHandle
   sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -830,6 +1059,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchAccess
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SettingsHandle
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -853,6 +1094,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<AccountNotificationItem>
+  sse_decode_StreamSink_account_notification_item_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<AccountProfile> sse_decode_StreamSink_account_profile_Sse(
     SseDeserializer deserializer,
@@ -873,11 +1120,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustStreamSink<DeviceTransferEvent>
   sse_decode_StreamSink_device_transfer_event_Sse(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<GlobalSearchView> sse_decode_StreamSink_global_search_view_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<PlatformInt64> sse_decode_StreamSink_i_64_Sse(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<List<AccountUnreadCountItem>>
+  sse_decode_StreamSink_list_account_unread_count_item_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<List<CircleItem>> sse_decode_StreamSink_list_circle_item_Sse(
     SseDeserializer deserializer,
@@ -889,6 +1147,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<LoginStatus> sse_decode_StreamSink_login_status_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<MediaPlaybackEvent>
   sse_decode_StreamSink_media_playback_event_Sse(SseDeserializer deserializer);
@@ -906,6 +1169,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<SessionLinkedItem>
+  sse_decode_StreamSink_session_linked_item_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<BigInt> sse_decode_StreamSink_u_64_Sse(
     SseDeserializer deserializer,
@@ -914,9 +1181,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AccountNotificationItem sse_decode_account_notification_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   AccountProfile sse_decode_account_profile(SseDeserializer deserializer);
 
+  @protected
+  AccountSession sse_decode_account_session(SseDeserializer deserializer);
+
+  @protected
+  AccountUnreadCountItem sse_decode_account_unread_count_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -932,6 +1212,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  LinkPreviewView sse_decode_box_autoadd_link_preview_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocationView sse_decode_box_autoadd_location_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   McpSettingsItem sse_decode_box_autoadd_mcp_settings_item(
     SseDeserializer deserializer,
@@ -962,6 +1252,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageSnippetView sse_decode_box_autoadd_message_snippet_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PinMessagePreviewItem sse_decode_box_autoadd_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -980,6 +1275,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UserAnnotationItem sse_decode_box_autoadd_user_annotation_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserProfileItem sse_decode_box_autoadd_user_profile_item(
     SseDeserializer deserializer,
@@ -996,6 +1296,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ContactGroup sse_decode_contact_group(SseDeserializer deserializer);
+
+  @protected
+  ContactItem sse_decode_contact_item(SseDeserializer deserializer);
+
   @protected
   ConversationChangeEvent sse_decode_conversation_change_event(
     SseDeserializer deserializer,
@@ -1016,6 +1322,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ConversationSearchHitView sse_decode_conversation_search_hit_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ConversationStorageUsage sse_decode_conversation_storage_usage(
     SseDeserializer deserializer,
@@ -1042,6 +1353,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  GlobalSearchView sse_decode_global_search_view(SseDeserializer deserializer);
+
   @protected
   GroupAvatar sse_decode_group_avatar(SseDeserializer deserializer);
 
@@ -1062,12 +1376,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ImageMessageView sse_decode_image_message_view(SseDeserializer deserializer);
 
+  @protected
+  LinkPreviewView sse_decode_link_preview_view(SseDeserializer deserializer);
+
+  @protected
+  List<AccountHandle>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AccountSession> sse_decode_list_account_session(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AccountUnreadCountItem> sse_decode_list_account_unread_count_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<CircleItem> sse_decode_list_circle_item(SseDeserializer deserializer);
 
+  @protected
+  List<ContactGroup> sse_decode_list_contact_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ContactItem> sse_decode_list_contact_item(SseDeserializer deserializer);
+
   @protected
   List<ConversationListData> sse_decode_list_conversation_list_data(
     SseDeserializer deserializer,
@@ -1077,6 +1418,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<ConversationParticipantItem>
   sse_decode_list_conversation_participant_item(SseDeserializer deserializer);
 
+  @protected
+  List<ConversationSearchHitView> sse_decode_list_conversation_search_hit_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<ConversationStorageUsage> sse_decode_list_conversation_storage_usage(
     SseDeserializer deserializer,
@@ -1105,11 +1451,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MessageEditItem> sse_decode_list_message_edit_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<MessageListView> sse_decode_list_message_list_view(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MessageReactionItem> sse_decode_list_message_reaction_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MessageReactionView> sse_decode_list_message_reaction_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OutgoingMessageItem> sse_decode_list_outgoing_message_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
@@ -1142,11 +1508,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<TextRangeView> sse_decode_list_text_range_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<UserProfileItem> sse_decode_list_user_profile_item(
     SseDeserializer deserializer,
   );
 
+  @protected
+  LocationView sse_decode_location_view(SseDeserializer deserializer);
+
+  @protected
+  LoginStatus sse_decode_login_status(SseDeserializer deserializer);
+
   @protected
   McpServerStatusItem sse_decode_mcp_server_status_item(
     SseDeserializer deserializer,
@@ -1193,6 +1570,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageEditItem sse_decode_message_edit_item(SseDeserializer deserializer);
+
   @protected
   MessageListView sse_decode_message_list_view(SseDeserializer deserializer);
 
@@ -1201,9 +1581,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageReactionItem sse_decode_message_reaction_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageReactionView sse_decode_message_reaction_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageSnippetView sse_decode_message_snippet_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotificationActionKind sse_decode_notification_action_kind(
+    SseDeserializer deserializer,
+  );
+
   @protected
   NotificationEvent sse_decode_notification_event(SseDeserializer deserializer);
 
+  @protected
+  AccountHandle?
+  sse_decode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -1219,6 +1625,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  LinkPreviewView? sse_decode_opt_box_autoadd_link_preview_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocationView? sse_decode_opt_box_autoadd_location_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   MediaAudioItem? sse_decode_opt_box_autoadd_media_audio_item(
     SseDeserializer deserializer,
@@ -1234,6 +1650,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  MessageSnippetView? sse_decode_opt_box_autoadd_message_snippet_view(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PinMessagePreviewItem? sse_decode_opt_box_autoadd_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -1247,6 +1668,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  UserAnnotationItem? sse_decode_opt_box_autoadd_user_annotation_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserProfileItem? sse_decode_opt_box_autoadd_user_profile_item(
     SseDeserializer deserializer,
@@ -1255,6 +1681,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  OutgoingMessageItem sse_decode_outgoing_message_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PinMessagePreviewItem sse_decode_pin_message_preview_item(
     SseDeserializer deserializer,
@@ -1273,6 +1704,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SessionLinkedItem sse_decode_session_linked_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   SharedAppItem sse_decode_shared_app_item(SseDeserializer deserializer);
 
@@ -1297,6 +1733,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  TextRangeView sse_decode_text_range_view(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -1312,12 +1751,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UserAnnotationItem sse_decode_user_annotation_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   UserProfileItem sse_decode_user_profile_item(SseDeserializer deserializer);
 
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  VideoRangeItem sse_decode_video_range_item(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -1380,6 +1827,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -1450,6 +1911,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -1533,6 +2008,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    SearchAccess self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    SearchHandle self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
@@ -1560,6 +2049,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_account_notification_item_Sse(
+    RustStreamSink<AccountNotificationItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_account_profile_Sse(
     RustStreamSink<AccountProfile> self,
@@ -1584,12 +2079,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_global_search_view_Sse(
+    RustStreamSink<GlobalSearchView> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_i_64_Sse(
     RustStreamSink<PlatformInt64> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_list_account_unread_count_item_Sse(
+    RustStreamSink<List<AccountUnreadCountItem>> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_list_circle_item_Sse(
     RustStreamSink<List<CircleItem>> self,
@@ -1602,6 +2109,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_login_status_Sse(
+    RustStreamSink<LoginStatus> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_media_playback_event_Sse(
     RustStreamSink<MediaPlaybackEvent> self,
@@ -1626,6 +2139,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_session_linked_item_Sse(
+    RustStreamSink<SessionLinkedItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_u_64_Sse(
     RustStreamSink<BigInt> self,
@@ -1635,12 +2154,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_account_notification_item(
+    AccountNotificationItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_account_profile(
     AccountProfile self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_account_session(
+    AccountSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_account_unread_count_item(
+    AccountUnreadCountItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -1659,6 +2196,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_link_preview_view(
+    LinkPreviewView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_location_view(
+    LocationView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_mcp_settings_item(
     McpSettingsItem self,
@@ -1695,6 +2244,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_message_snippet_view(
+    MessageSnippetView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_pin_message_preview_item(
     PinMessagePreviewItem self,
@@ -1716,6 +2271,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_user_annotation_item(
+    UserAnnotationItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_user_profile_item(
     UserProfileItem self,
@@ -1734,6 +2295,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_contact_group(ContactGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_contact_item(ContactItem self, SseSerializer serializer);
+
   @protected
   void sse_encode_conversation_change_event(
     ConversationChangeEvent self,
@@ -1758,6 +2325,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_conversation_search_hit_view(
+    ConversationSearchHitView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_conversation_storage_usage(
     ConversationStorageUsage self,
@@ -1788,6 +2361,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_global_search_view(
+    GlobalSearchView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_group_avatar(GroupAvatar self, SseSerializer serializer);
 
@@ -1815,15 +2394,52 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_link_preview_view(
+    LinkPreviewView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    List<AccountHandle> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_account_session(
+    List<AccountSession> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_account_unread_count_item(
+    List<AccountUnreadCountItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_circle_item(
     List<CircleItem> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_contact_group(
+    List<ContactGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_contact_item(
+    List<ContactItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_conversation_list_data(
     List<ConversationListData> self,
@@ -1836,6 +2452,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_conversation_search_hit_view(
+    List<ConversationSearchHitView> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_conversation_storage_usage(
     List<ConversationStorageUsage> self,
@@ -1872,12 +2494,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_message_edit_item(
+    List<MessageEditItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_message_list_view(
     List<MessageListView> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_message_reaction_item(
+    List<MessageReactionItem> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message_reaction_view(
+    List<MessageReactionView> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_outgoing_message_item(
+    List<OutgoingMessageItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -1923,12 +2569,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_text_range_view(
+    List<TextRangeView> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_user_profile_item(
     List<UserProfileItem> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_location_view(LocationView self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_login_status(LoginStatus self, SseSerializer serializer);
+
   @protected
   void sse_encode_mcp_server_status_item(
     McpServerStatusItem self,
@@ -1989,6 +2647,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_message_edit_item(
+    MessageEditItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_message_list_view(
     MessageListView self,
@@ -2001,12 +2665,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_message_reaction_item(
+    MessageReactionItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_reaction_view(
+    MessageReactionView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_message_snippet_view(
+    MessageSnippetView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_notification_action_kind(
+    NotificationActionKind self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_notification_event(
     NotificationEvent self,
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_opt_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAccountHandle(
+    AccountHandle? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -2025,6 +2720,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_link_preview_view(
+    LinkPreviewView? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_location_view(
+    LocationView? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_media_audio_item(
     MediaAudioItem? self,
@@ -2043,6 +2750,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_message_snippet_view(
+    MessageSnippetView? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_pin_message_preview_item(
     PinMessagePreviewItem? self,
@@ -2058,6 +2771,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_user_annotation_item(
+    UserAnnotationItem? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_user_profile_item(
     UserProfileItem? self,
@@ -2070,6 +2789,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_outgoing_message_item(
+    OutgoingMessageItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_pin_message_preview_item(
     PinMessagePreviewItem self,
@@ -2091,6 +2816,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_session_linked_item(
+    SessionLinkedItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_shared_app_item(SharedAppItem self, SseSerializer serializer);
 
@@ -2121,6 +2852,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_text_range_view(
+    TextRangeView self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -2136,6 +2873,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_user_annotation_item(
+    UserAnnotationItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_user_profile_item(
     UserProfileItem self,
@@ -2144,6 +2887,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_video_range_item(
+    VideoRangeItem self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -2279,6 +3028,38 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
     int ptr,
@@ -2414,6 +3195,26 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
     int ptr,
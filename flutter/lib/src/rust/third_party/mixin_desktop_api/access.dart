@@ -33,6 +33,12 @@ abstract class AttachmentAccess implements RustOpaqueInterface {
     required String messageId,
   });
 
+  Future<VideoRangeItem> readVideoRange({
+    required String messageId,
+    required BigInt offset,
+    required BigInt length,
+  });
+
   Future<void> retryAttachment({required String messageId});
 
   Future<void> retryTranscriptAttachment({required String transcriptId});
@@ -163,6 +169,8 @@ abstract class ConversationAccess implements RustOpaqueInterface {
   });
 }
 
+  Future<void> cancelOutgoingMessage({required String messageId});
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>>
 abstract class MessageAccess implements RustOpaqueInterface {
   Future<String> combineForwardMessages({
@@ -177,6 +185,17 @@ abstract class MessageAccess implements RustOpaqueInterface {
     required List<String> messageIds,
   });
 
+  Future<void> editMessage({
+    required String conversationId,
+    required String messageId,
+    required String content,
+  });
+
+  Future<List<OutgoingMessageItem>> failedOutgoingMessages({
+    String? conversationId,
+    required PlatformInt64 limit,
+  });
+
   Future<List<String>> forwardMessages({
     required String targetConversationId,
     required List<String> sourceMessageIds,
@@ -189,6 +208,8 @@ abstract class MessageAccess implements RustOpaqueInterface {
     required PlatformInt64 after,
   });
 
+  Future<LinkPreviewView?> loadLinkPreview({required String messageId});
+
   Future<void> markConversationRead({required String conversationId});
 
   Future<void> markMentionRead({
@@ -196,6 +217,8 @@ abstract class MessageAccess implements RustOpaqueInterface {
     required String messageId,
   });
 
+  Future<List<MessageEditItem>> messageEditHistory({required String messageId});
+
   Future<List<String>> messageIdsAfter({
     required String conversationId,
     required PlatformInt64 anchorRowId,
@@ -216,6 +239,10 @@ abstract class MessageAccess implements RustOpaqueInterface {
 
   Future<MessageOrderInfoView?> messageOrderInfo({required String messageId});
 
+  Future<List<MessageReactionItem>> messageReactions({
+    required String messageId,
+  });
+
   Future<List<MessageListView>> messages({
     required String conversationId,
     PlatformInt64? beforeCreatedAtMicros,
@@ -240,13 +267,25 @@ abstract class MessageAccess implements RustOpaqueInterface {
     required String conversationId,
   });
 
+  Future<void> reactToMessage({
+    required String conversationId,
+    required String messageId,
+    required String emoji,
+    required bool removed,
+  });
+
   Future<void> recallMessages({
     required String conversationId,
     required List<String> messageIds,
   });
 
+  Future<void> retryOutgoingMessage({required String messageId});
+
   Future<List<MessageListView>> searchGlobalMessages({
     required String query,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   });
@@ -256,6 +295,9 @@ abstract class MessageAccess implements RustOpaqueInterface {
     required String query,
     String? senderId,
     required List<String> categories,
+    PlatformInt64? afterMicros,
+    PlatformInt64? beforeMicros,
+    required bool rankByRelevance,
     String? anchorMessageId,
     required int limit,
   });
@@ -295,6 +337,13 @@ abstract class MessageAccess implements RustOpaqueInterface {
     required bool silent,
   });
 
+  Future<String> sendLocation({
+    required String conversationId,
+    required LocationView location,
+    String? quoteMessageId,
+    required bool silent,
+  });
+
   Future<String> sendPost({
     required String conversationId,
     required String content,
@@ -344,6 +393,21 @@ abstract class MessageAccess implements RustOpaqueInterface {
   });
 }
 
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>
+abstract class SearchAccess implements RustOpaqueInterface {
+  Future<GlobalSearchView> globalSearch({
+    required String query,
+    required int limit,
+  });
+
+  Future<GlobalSearchView> globalSearchSection({
+    required String query,
+    required String section,
+    String? cursor,
+    required int limit,
+  });
+}
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StickerAccess>>
 abstract class StickerAccess implements RustOpaqueInterface {
   Future<void> addSticker({required String stickerId});
@@ -384,10 +448,14 @@ abstract class UserAccess implements RustOpaqueInterface {
 
   Future<void> blockUser({required String userId});
 
+  Future<List<UserProfileItem>> blockedUsers();
+
   Future<String?> botCreatorId({required String userId});
 
   Future<String?> botHomeUri({required String appId});
 
+  Future<List<ContactGroup>> contacts();
+
   Future<List<SharedAppItem>> localSharedApps({required String userId});
 
   Future<Map<String, String>> mentionNames({required List<String> contents});
@@ -412,10 +480,18 @@ abstract class UserAccess implements RustOpaqueInterface {
 
   Future<List<UserProfileItem>> selectableUsers();
 
+  Future<void> setUserAnnotation({
+    required String userId,
+    String? alias,
+    String? note,
+  });
+
   Future<List<SharedAppItem>> sharedApps({required String userId});
 
   Future<void> unblockUser({required String userId});
 
+  Future<UserAnnotationItem?> userAnnotation({required String userId});
+
   Future<UserProfileItem?> userProfile({
     String? userId,
     String? identityNumber,
@@ -4,10 +4,29 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../mixin_desktop_core/runtime/model.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConversationListItem`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `try_from`, `try_from`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConversationListItem`, `LinkPreviewSettingsItem`, `NotificationRulesItem`, `QuietHoursItem`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `try_from`, `try_from`
+
+class AccountNotificationItem {
+  final String accountId;
+  final NotificationEvent event;
+
+  const AccountNotificationItem({required this.accountId, required this.event});
+
+  @override
+  int get hashCode => accountId.hashCode ^ event.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountNotificationItem &&
+          runtimeType == other.runtimeType &&
+          accountId == other.accountId &&
+          event == other.event;
+}
 
 class AccountProfile {
   final String userId;
@@ -64,6 +83,27 @@ class AccountProfile {
           membership == other.membership;
 }
 
+class AccountUnreadCountItem {
+  final String accountId;
+  final PlatformInt64 unseenMessageCount;
+
+  const AccountUnreadCountItem({
+    required this.accountId,
+    required this.unseenMessageCount,
+  });
+
+  @override
+  int get hashCode => accountId.hashCode ^ unseenMessageCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountUnreadCountItem &&
+          runtimeType == other.runtimeType &&
+          accountId == other.accountId &&
+          unseenMessageCount == other.unseenMessageCount;
+}
+
 class ConversationChangeEvent {
   final List<String> conversationIds;
   final bool reloadAll;
@@ -227,3 +267,25 @@ class ProxySettingsItem {
           selectedProxyId == other.selectedProxyId &&
           proxies == other.proxies;
 }
+
+/// Raised when another device is linked to the account.
+class SessionLinkedItem {
+  final String sessionId;
+  final PlatformInt64 linkedAtMicros;
+
+  const SessionLinkedItem({
+    required this.sessionId,
+    required this.linkedAtMicros,
+  });
+
+  @override
+  int get hashCode => sessionId.hashCode ^ linkedAtMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionLinkedItem &&
+          runtimeType == other.runtimeType &&
+          sessionId == other.sessionId &&
+          linkedAtMicros == other.linkedAtMicros;
+}
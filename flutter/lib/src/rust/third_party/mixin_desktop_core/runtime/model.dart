@@ -7,7 +7,46 @@ import '../../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NotificationEventBatch`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+class AccountSession {
+  final String sessionId;
+  final String? platform;
+  final String? appVersion;
+  final String? lastSeenAt;
+  final bool isCurrent;
+  final bool isPrimary;
+
+  const AccountSession({
+    required this.sessionId,
+    this.platform,
+    this.appVersion,
+    this.lastSeenAt,
+    required this.isCurrent,
+    required this.isPrimary,
+  });
+
+  @override
+  int get hashCode =>
+      sessionId.hashCode ^
+      platform.hashCode ^
+      appVersion.hashCode ^
+      lastSeenAt.hashCode ^
+      isCurrent.hashCode ^
+      isPrimary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountSession &&
+          runtimeType == other.runtimeType &&
+          sessionId == other.sessionId &&
+          platform == other.platform &&
+          appVersion == other.appVersion &&
+          lastSeenAt == other.lastSeenAt &&
+          isCurrent == other.isCurrent &&
+          isPrimary == other.isPrimary;
+}
 
 class CircleItem {
   final String circleId;
@@ -117,6 +156,56 @@ class CodeResult {
           action == other.action;
 }
 
+/// Contacts whose sort key starts with `index`; `#` collects those starting
+/// with anything but a latin letter.
+class ContactGroup {
+  final String index;
+  final List<ContactItem> contacts;
+
+  const ContactGroup({required this.index, required this.contacts});
+
+  @override
+  int get hashCode => index.hashCode ^ contacts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContactGroup &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          contacts == other.contacts;
+}
+
+/// A contact shown under its local alias when one is set.
+class ContactItem {
+  final UserProfileItem user;
+  final String? alias;
+  final String displayName;
+  /// `display_name` in lowercase with Chinese characters spelled in pinyin.
+  final String sortKey;
+
+  const ContactItem({
+    required this.user,
+    this.alias,
+    required this.displayName,
+    required this.sortKey,
+  });
+
+  @override
+  int get hashCode =>
+      user.hashCode ^ alias.hashCode ^ displayName.hashCode ^ sortKey.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContactItem &&
+          runtimeType == other.runtimeType &&
+          user == other.user &&
+          alias == other.alias &&
+          displayName == other.displayName &&
+          sortKey == other.sortKey;
+}
+
 class ConversationDetailItem {
   final String conversationId;
   final String name;
@@ -362,6 +451,33 @@ class ConversationParticipantItem {
           membership == other.membership;
 }
 
+class ConversationSearchHitView {
+  final ConversationListData conversation;
+  final PlatformInt64 hitCount;
+  final PlatformInt64 latestCreatedAtMillis;
+
+  const ConversationSearchHitView({
+    required this.conversation,
+    required this.hitCount,
+    required this.latestCreatedAtMillis,
+  });
+
+  @override
+  int get hashCode =>
+      conversation.hashCode ^
+      hitCount.hashCode ^
+      latestCreatedAtMillis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationSearchHitView &&
+          runtimeType == other.runtimeType &&
+          conversation == other.conversation &&
+          hitCount == other.hitCount &&
+          latestCreatedAtMillis == other.latestCreatedAtMillis;
+}
+
 class ConversationStorageUsage {
   final ConversationListData conversation;
   final PlatformInt64 sizeBytes;
@@ -414,6 +530,59 @@ class ConversationUnseenCount {
           mutedCount == other.mutedCount;
 }
 
+/// Sections of a global search. Each `*_cursor` is set when the section has
+/// more results, and continues it through `global_search_section`.
+class GlobalSearchView {
+  final String query;
+  final List<UserProfileItem> contacts;
+  final String? contactsCursor;
+  final List<ConversationListData> groups;
+  final String? groupsCursor;
+  final List<ConversationSearchHitView> messageConversations;
+  final String? messageConversationsCursor;
+  final List<MessageListView> files;
+  final String? filesCursor;
+
+  const GlobalSearchView({
+    required this.query,
+    required this.contacts,
+    this.contactsCursor,
+    required this.groups,
+    this.groupsCursor,
+    required this.messageConversations,
+    this.messageConversationsCursor,
+    required this.files,
+    this.filesCursor,
+  });
+
+  @override
+  int get hashCode =>
+      query.hashCode ^
+      contacts.hashCode ^
+      contactsCursor.hashCode ^
+      groups.hashCode ^
+      groupsCursor.hashCode ^
+      messageConversations.hashCode ^
+      messageConversationsCursor.hashCode ^
+      files.hashCode ^
+      filesCursor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GlobalSearchView &&
+          runtimeType == other.runtimeType &&
+          query == other.query &&
+          contacts == other.contacts &&
+          contactsCursor == other.contactsCursor &&
+          groups == other.groups &&
+          groupsCursor == other.groupsCursor &&
+          messageConversations == other.messageConversations &&
+          messageConversationsCursor == other.messageConversationsCursor &&
+          files == other.files &&
+          filesCursor == other.filesCursor;
+}
+
 class GroupAvatar {
   final String userId;
   final String name;
@@ -524,6 +693,100 @@ class ImageMessageView {
           avatarUrl == other.avatarUrl;
 }
 
+class LinkPreviewView {
+  final String url;
+  final String siteName;
+  final String title;
+  final String? description;
+  final String? imageUrl;
+
+  const LinkPreviewView({
+    required this.url,
+    required this.siteName,
+    required this.title,
+    this.description,
+    this.imageUrl,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      siteName.hashCode ^
+      title.hashCode ^
+      description.hashCode ^
+      imageUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LinkPreviewView &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          siteName == other.siteName &&
+          title == other.title &&
+          description == other.description &&
+          imageUrl == other.imageUrl;
+}
+
+class LocationView {
+  final double latitude;
+  final double longitude;
+  final String? name;
+  final String? address;
+
+  const LocationView({
+    required this.latitude,
+    required this.longitude,
+    this.name,
+    this.address,
+  });
+
+  @override
+  int get hashCode =>
+      latitude.hashCode ^ longitude.hashCode ^ name.hashCode ^ address.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocationView &&
+          runtimeType == other.runtimeType &&
+          latitude == other.latitude &&
+          longitude == other.longitude &&
+          name == other.name &&
+          address == other.address;
+}
+
+class MessageEditItem {
+  final String editId;
+  final String messageId;
+  final String content;
+  final PlatformInt64 editedAtMicros;
+
+  const MessageEditItem({
+    required this.editId,
+    required this.messageId,
+    required this.content,
+    required this.editedAtMicros,
+  });
+
+  @override
+  int get hashCode =>
+      editId.hashCode ^
+      messageId.hashCode ^
+      content.hashCode ^
+      editedAtMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageEditItem &&
+          runtimeType == other.runtimeType &&
+          editId == other.editId &&
+          messageId == other.messageId &&
+          content == other.content &&
+          editedAtMicros == other.editedAtMicros;
+}
+
 class MessageListView {
   final String messageId;
   final String conversationId;
@@ -599,6 +862,11 @@ class MessageListView {
   final bool? mentionRead;
   final bool pinned;
   final PlatformInt64? expireIn;
+  final PlatformInt64? editedAtMicros;
+  final List<MessageReactionView> reactions;
+  final LocationView? location;
+  final MessageSnippetView? snippet;
+  final LinkPreviewView? linkPreview;
 
   const MessageListView({
     required this.messageId,
@@ -675,6 +943,11 @@ class MessageListView {
     this.mentionRead,
     required this.pinned,
     this.expireIn,
+    this.editedAtMicros,
+    required this.reactions,
+    this.location,
+    this.snippet,
+    this.linkPreview,
   });
 
   @override
@@ -752,7 +1025,12 @@ class MessageListView {
       stickerAssetType.hashCode ^
       mentionRead.hashCode ^
       pinned.hashCode ^
-      expireIn.hashCode;
+      expireIn.hashCode ^
+      editedAtMicros.hashCode ^
+      reactions.hashCode ^
+      location.hashCode ^
+      snippet.hashCode ^
+      linkPreview.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -832,7 +1110,12 @@ class MessageListView {
           stickerAssetType == other.stickerAssetType &&
           mentionRead == other.mentionRead &&
           pinned == other.pinned &&
-          expireIn == other.expireIn;
+          expireIn == other.expireIn &&
+          editedAtMicros == other.editedAtMicros &&
+          reactions == other.reactions &&
+          location == other.location &&
+          snippet == other.snippet &&
+          linkPreview == other.linkPreview;
 }
 
 class MessageOrderInfoView {
@@ -860,6 +1143,73 @@ class MessageOrderInfoView {
           createdAtMicros == other.createdAtMicros;
 }
 
+class MessageReactionItem {
+  final String userId;
+  final String emoji;
+  final PlatformInt64 createdAtMicros;
+
+  const MessageReactionItem({
+    required this.userId,
+    required this.emoji,
+    required this.createdAtMicros,
+  });
+
+  @override
+  int get hashCode =>
+      userId.hashCode ^ emoji.hashCode ^ createdAtMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageReactionItem &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          emoji == other.emoji &&
+          createdAtMicros == other.createdAtMicros;
+}
+
+class MessageReactionView {
+  final String emoji;
+  final PlatformInt64 count;
+  final bool reacted;
+
+  const MessageReactionView({
+    required this.emoji,
+    required this.count,
+    required this.reacted,
+  });
+
+  @override
+  int get hashCode => emoji.hashCode ^ count.hashCode ^ reacted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageReactionView &&
+          runtimeType == other.runtimeType &&
+          emoji == other.emoji &&
+          count == other.count &&
+          reacted == other.reacted;
+}
+
+class MessageSnippetView {
+  final String text;
+  final List<TextRangeView> highlights;
+
+  const MessageSnippetView({required this.text, required this.highlights});
+
+  @override
+  int get hashCode => text.hashCode ^ highlights.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageSnippetView &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          highlights == other.highlights;
+}
+
 class NotificationEvent {
   final String messageId;
   final String conversationId;
@@ -911,6 +1261,53 @@ class NotificationEvent {
           dismissMessageId == other.dismissMessageId;
 }
 
+class OutgoingMessageItem {
+  final String messageId;
+  final String conversationId;
+  final String category;
+  final String status;
+  final PlatformInt64 createdAtMicros;
+  final PlatformInt64 attemptCount;
+  final String? lastError;
+  final PlatformInt64? nextAttemptAtMicros;
+
+  const OutgoingMessageItem({
+    required this.messageId,
+    required this.conversationId,
+    required this.category,
+    required this.status,
+    required this.createdAtMicros,
+    required this.attemptCount,
+    this.lastError,
+    this.nextAttemptAtMicros,
+  });
+
+  @override
+  int get hashCode =>
+      messageId.hashCode ^
+      conversationId.hashCode ^
+      category.hashCode ^
+      status.hashCode ^
+      createdAtMicros.hashCode ^
+      attemptCount.hashCode ^
+      lastError.hashCode ^
+      nextAttemptAtMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutgoingMessageItem &&
+          runtimeType == other.runtimeType &&
+          messageId == other.messageId &&
+          conversationId == other.conversationId &&
+          category == other.category &&
+          status == other.status &&
+          createdAtMicros == other.createdAtMicros &&
+          attemptCount == other.attemptCount &&
+          lastError == other.lastError &&
+          nextAttemptAtMicros == other.nextAttemptAtMicros;
+}
+
 class PinMessagePreviewItem {
   final String messageId;
   final String content;
@@ -1256,6 +1653,45 @@ class StorageCategoryUsage {
           sizeBytes == other.sizeBytes;
 }
 
+class TextRangeView {
+  final int start;
+  final int end;
+
+  const TextRangeView({required this.start, required this.end});
+
+  @override
+  int get hashCode => start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TextRangeView &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end;
+}
+
+/// The local-only alias and private note of a user.
+class UserAnnotationItem {
+  final String userId;
+  final String? alias;
+  final String? note;
+
+  const UserAnnotationItem({required this.userId, this.alias, this.note});
+
+  @override
+  int get hashCode => userId.hashCode ^ alias.hashCode ^ note.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UserAnnotationItem &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          alias == other.alias &&
+          note == other.note;
+}
+
 class UserProfileItem {
   final String userId;
   final String identityNumber;
@@ -1310,3 +1746,33 @@ class UserProfileItem {
           codeUrl == other.codeUrl &&
           membership == other.membership;
 }
+
+/// A slice of a video's plaintext, with what a player needs to serve it as
+/// an HTTP range response.
+class VideoRangeItem {
+  final Uint8List data;
+  final BigInt offset;
+  final BigInt totalSize;
+  final String mimeType;
+
+  const VideoRangeItem({
+    required this.data,
+    required this.offset,
+    required this.totalSize,
+    required this.mimeType,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^ offset.hashCode ^ totalSize.hashCode ^ mimeType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VideoRangeItem &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          offset == other.offset &&
+          totalSize == other.totalSize &&
+          mimeType == other.mimeType;
+}
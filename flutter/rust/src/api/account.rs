@@ -2,12 +2,14 @@ use std::sync::Arc;
 
 use futures::StreamExt;
 use mixin_desktop_api::{
-    AccountClient, AccountProfile, AttachmentAccess, CircleItem, ConversationAccess,
-    ConversationChangeEvent, ConversationStorageUsage, ConversationUnseenCount, MessageAccess,
-    NotificationEvent, SnapshotDetailItem, StickerAccess, StorageCategoryUsage, UserAccess,
+    AccountClient, AccountProfile, AccountSession, AttachmentAccess, CircleItem,
+    ConversationAccess, ConversationChangeEvent, ConversationStorageUsage, ConversationUnseenCount,
+    MessageAccess, NotificationEvent, SearchAccess, SessionLinkedItem, SnapshotDetailItem,
+    StickerAccess, StorageCategoryUsage, UserAccess,
 };
 
 use crate::api::device_transfer::{DeviceTransferCommand, DeviceTransferEvent};
+use crate::api::search::SearchHandle;
 use crate::{frb_generated::StreamSink, CoreError, Result};
 
 #[flutter_rust_bridge::frb(opaque)]
@@ -132,6 +134,18 @@ impl AccountHandle {
     }
 }
 
+impl AccountHandle {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn search(&self) -> SearchAccess {
+        self.client.search()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn search_session(&self) -> SearchHandle {
+        SearchHandle::new(self.client.search())
+    }
+}
+
 impl AccountHandle {
     #[flutter_rust_bridge::frb(sync)]
     pub fn attachment_progress(&self, message_id: String) -> f64 {
@@ -295,3 +309,27 @@ impl AccountHandle {
         Ok(self.client.sign_out().await?)
     }
 }
+
+impl AccountHandle {
+    pub async fn linked_sessions(&self) -> Result<Vec<AccountSession>, CoreError> {
+        Ok(self.client.linked_sessions().await?)
+    }
+
+    pub async fn revoke_linked_session(&self, session_id: String) -> Result<(), CoreError> {
+        Ok(self.client.revoke_linked_session(session_id).await?)
+    }
+
+    pub async fn session_linked_events(
+        &self,
+        sink: StreamSink<SessionLinkedItem>,
+    ) -> Result<(), CoreError> {
+        let events = self.client.session_linked_events();
+        futures::pin_mut!(events);
+        while let Some(event) = events.next().await {
+            if sink.add(event).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,8 +3,9 @@ use std::sync::Arc;
 
 use futures::StreamExt as _;
 use mixin_desktop_api::{
-    DesktopClient, HttpResponseItem, McpServerStatusItem, McpSettingsItem, ProxySettingsItem,
-    SettingsClient,
+    AccountNotificationItem, AccountUnreadCountItem, DesktopClient, HttpResponseItem,
+    McpServerStatusItem, McpSettingsItem, NotificationAction, NotificationActionHandle,
+    ProxySettingsItem, SettingsClient,
 };
 
 use crate::{frb_generated::StreamSink, CoreError, Result};
@@ -23,6 +24,12 @@ pub struct SettingsHandle {
     client: Arc<SettingsClient>,
 }
 
+pub enum NotificationActionKind {
+    Reply,
+    MarkRead,
+    MuteForOneHour,
+}
+
 pub async fn open_desktop() -> Result<DesktopHandle, CoreError> {
     Ok(DesktopHandle {
         client: Arc::new(DesktopClient::open().await?),
@@ -79,6 +86,95 @@ impl DesktopHandle {
     pub async fn restore_account(&self) -> Result<AccountHandle, CoreError> {
         Ok(AccountHandle::new(self.client.restore_account().await?))
     }
+
+    pub async fn restore_accounts(&self) -> Result<Vec<AccountHandle>, CoreError> {
+        Ok(self
+            .client
+            .restore_accounts()
+            .await?
+            .into_iter()
+            .map(AccountHandle::new)
+            .collect())
+    }
+
+    pub async fn accounts(&self) -> Result<Vec<AccountHandle>, CoreError> {
+        Ok(self
+            .client
+            .accounts()
+            .await
+            .into_iter()
+            .map(AccountHandle::new)
+            .collect())
+    }
+
+    pub async fn active_account(&self) -> Result<Option<AccountHandle>, CoreError> {
+        Ok(self.client.active_account().await.map(AccountHandle::new))
+    }
+
+    pub async fn switch_account(&self, account_id: String) -> Result<AccountHandle, CoreError> {
+        Ok(AccountHandle::new(
+            self.client.switch_account(account_id).await?,
+        ))
+    }
+
+    pub async fn unread_count_changes(
+        &self,
+        sink: StreamSink<Vec<AccountUnreadCountItem>>,
+    ) -> Result<(), CoreError> {
+        let changes = self.client.unread_count_changes();
+        futures::pin_mut!(changes);
+        while let Some(counts) = changes.next().await {
+            if sink.add(counts).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn notification_events(
+        &self,
+        sink: StreamSink<AccountNotificationItem>,
+    ) -> Result<(), CoreError> {
+        let events = self.client.notification_events();
+        futures::pin_mut!(events);
+        while let Some(event) = events.next().await {
+            if sink.add(event).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn perform_notification_action(
+        &self,
+        account_id: String,
+        conversation_id: String,
+        message_id: String,
+        kind: NotificationActionKind,
+        reply_text: Option<String>,
+    ) -> Result<(), CoreError> {
+        let action = match kind {
+            NotificationActionKind::Reply => match reply_text {
+                Some(text) if !text.trim().is_empty() => NotificationAction::Reply { text },
+                _ => {
+                    return Err(CoreError::InvalidArgument {
+                        message: "reply text is required".to_string(),
+                    })
+                }
+            },
+            NotificationActionKind::MarkRead => NotificationAction::MarkRead,
+            NotificationActionKind::MuteForOneHour => NotificationAction::MuteForOneHour,
+        };
+        let handle = NotificationActionHandle {
+            account_id,
+            conversation_id,
+            message_id,
+        };
+        Ok(self
+            .client
+            .perform_notification_action(handle, action)
+            .await?)
+    }
 }
 
 impl SettingsHandle {
@@ -210,3 +306,16 @@ impl SettingsHandle {
         Ok(self.client.set_proxy_settings(settings).await?)
     }
 }
+
+impl SettingsHandle {
+    pub async fn mcp_account_id(&self) -> Result<Option<String>, CoreError> {
+        Ok(self.client.mcp_account_id().await?)
+    }
+
+    pub async fn set_mcp_account_id(
+        &self,
+        account_id: Option<String>,
+    ) -> Result<McpServerStatusItem, CoreError> {
+        Ok(self.client.set_mcp_account_id(account_id).await?)
+    }
+}
@@ -1,15 +1,39 @@
 use std::sync::Arc;
 
-use mixin_desktop_api::LoginClient;
+use futures::StreamExt;
+use mixin_desktop_api::{LoginClient, LoginStatus as ApiLoginStatus};
 
 use super::account::AccountHandle;
-use crate::{CoreError, Result};
+use crate::{frb_generated::StreamSink, CoreError, Result};
 
 #[flutter_rust_bridge::frb(opaque)]
 pub struct LoginHandle {
     client: Arc<LoginClient>,
 }
 
+#[flutter_rust_bridge::frb(non_opaque)]
+pub enum LoginStatus {
+    WaitingForScan { auth_url: String },
+    Verifying,
+    SyncingKeys,
+    Ready,
+    Expired,
+    Failed { message: String },
+}
+
+impl From<ApiLoginStatus> for LoginStatus {
+    fn from(value: ApiLoginStatus) -> Self {
+        match value {
+            ApiLoginStatus::WaitingForScan { auth_url } => Self::WaitingForScan { auth_url },
+            ApiLoginStatus::Verifying => Self::Verifying,
+            ApiLoginStatus::SyncingKeys => Self::SyncingKeys,
+            ApiLoginStatus::Ready => Self::Ready,
+            ApiLoginStatus::Expired => Self::Expired,
+            ApiLoginStatus::Failed { message } => Self::Failed { message },
+        }
+    }
+}
+
 impl LoginHandle {
     pub(super) fn new(client: LoginClient) -> Self {
         Self {
@@ -32,4 +56,15 @@ impl LoginHandle {
     pub async fn wait(&self) -> Result<AccountHandle, CoreError> {
         Ok(AccountHandle::new(self.client.wait().await?))
     }
+
+    pub async fn status_changes(&self, sink: StreamSink<LoginStatus>) -> Result<(), CoreError> {
+        let changes = self.client.status_changes();
+        futures::pin_mut!(changes);
+        while let Some(status) = changes.next().await {
+            if sink.add(status.into()).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
 }
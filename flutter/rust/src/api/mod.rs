@@ -4,3 +4,4 @@ pub mod device_transfer;
 pub mod logging;
 pub mod login;
 pub mod media;
+pub mod search;
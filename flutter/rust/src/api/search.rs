@@ -0,0 +1,57 @@
+use std::sync::Mutex;
+
+use futures::channel::mpsc;
+use futures::StreamExt;
+use mixin_desktop_api::{GlobalSearchView, SearchAccess};
+
+use crate::{frb_generated::StreamSink, CoreError, Result};
+
+/// Search-as-you-type: every query set replaces the search still running
+/// for the previous one.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct SearchHandle {
+    access: SearchAccess,
+    queries: mpsc::UnboundedSender<String>,
+    pending: Mutex<Option<mpsc::UnboundedReceiver<String>>>,
+}
+
+impl SearchHandle {
+    pub(super) fn new(access: SearchAccess) -> Self {
+        let (queries, pending) = mpsc::unbounded();
+        Self {
+            access,
+            queries,
+            pending: Mutex::new(Some(pending)),
+        }
+    }
+}
+
+impl SearchHandle {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_query(&self, query: String) {
+        let _ = self.queries.unbounded_send(query);
+    }
+
+    pub async fn results(
+        &self,
+        limit: u32,
+        sink: StreamSink<GlobalSearchView>,
+    ) -> Result<(), CoreError> {
+        let queries = self
+            .pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+            .ok_or_else(|| CoreError::InvalidArgument {
+                message: "search results are already being streamed".to_string(),
+            })?;
+        let results = self.access.subscribe_global_search(queries, limit);
+        futures::pin_mut!(results);
+        while let Some(view) = results.next().await {
+            if sink.add(view?).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
@@ -30,6 +30,7 @@ use crate::api::account::*;
 use crate::api::desktop::*;
 use crate::api::login::*;
 use crate::api::media::*;
+use crate::api::search::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
 use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
@@ -812,6 +813,63 @@ fn wire__crate__api__account__AccountHandle_device_transfer_events_impl(
         },
     )
 }
+fn wire__crate__api__account__AccountHandle_linked_sessions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AccountHandle_linked_sessions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::account::AccountHandle::linked_sessions(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__account__AccountHandle_media_directory_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1244,7 +1302,7 @@ fn wire__crate__api__account__AccountHandle_retry_connection_impl(
         },
     )
 }
-fn wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
+fn wire__crate__api__account__AccountHandle_revoke_linked_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1252,7 +1310,7 @@ fn wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_safe_snapshot_by_id",
+            debug_name: "AccountHandle_revoke_linked_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1269,7 +1327,7 @@ fn wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -1291,9 +1349,9 @@ fn wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::account::AccountHandle::safe_snapshot_by_id(
+                        let output_ok = crate::api::account::AccountHandle::revoke_linked_session(
                             &*api_that_guard,
-                            api_snapshot_id,
+                            api_session_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1304,7 +1362,7 @@ fn wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
         },
     )
 }
-fn wire__crate__api__account__AccountHandle_shutdown_impl(
+fn wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1312,7 +1370,7 @@ fn wire__crate__api__account__AccountHandle_shutdown_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_shutdown",
+            debug_name: "AccountHandle_safe_snapshot_by_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1329,9 +1387,10 @@ fn wire__crate__api__account__AccountHandle_shutdown_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
             >>::sse_decode(&mut deserializer);
+            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, crate::error::CoreError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1350,9 +1409,11 @@ fn wire__crate__api__account__AccountHandle_shutdown_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::account::AccountHandle::shutdown(&*api_that_guard).await;
-                        })?;
+                        let output_ok = crate::api::account::AccountHandle::safe_snapshot_by_id(
+                            &*api_that_guard,
+                            api_snapshot_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1361,7 +1422,103 @@ fn wire__crate__api__account__AccountHandle_shutdown_impl(
         },
     )
 }
-fn wire__crate__api__account__AccountHandle_sign_out_impl(
+fn wire__crate__api__account__AccountHandle_search_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AccountHandle_search",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::account::AccountHandle::search(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__account__AccountHandle_search_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AccountHandle_search_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::account::AccountHandle::search_session(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__account__AccountHandle_session_linked_events_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1369,7 +1526,7 @@ fn wire__crate__api__account__AccountHandle_sign_out_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_sign_out",
+            debug_name: "AccountHandle_session_linked_events",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1386,6 +1543,10 @@ fn wire__crate__api__account__AccountHandle_sign_out_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
             >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                mixin_desktop_api::model::SessionLinkedItem,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -1407,8 +1568,11 @@ fn wire__crate__api__account__AccountHandle_sign_out_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::account::AccountHandle::sign_out(&*api_that_guard).await?;
+                        let output_ok = crate::api::account::AccountHandle::session_linked_events(
+                            &*api_that_guard,
+                            api_sink,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1417,7 +1581,7 @@ fn wire__crate__api__account__AccountHandle_sign_out_impl(
         },
     )
 }
-fn wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
+fn wire__crate__api__account__AccountHandle_shutdown_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1425,7 +1589,7 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_snapshot_by_id",
+            debug_name: "AccountHandle_shutdown",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1442,10 +1606,9 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1464,11 +1627,9 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::account::AccountHandle::snapshot_by_id(
-                            &*api_that_guard,
-                            api_snapshot_id,
-                        )
-                        .await?;
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::account::AccountHandle::shutdown(&*api_that_guard).await;
+                        })?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1477,7 +1638,7 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
         },
     )
 }
-fn wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
+fn wire__crate__api__account__AccountHandle_sign_out_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1485,7 +1646,7 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_snapshot_by_trace",
+            debug_name: "AccountHandle_sign_out",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1502,7 +1663,6 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_trace_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -1524,11 +1684,8 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::account::AccountHandle::snapshot_by_trace(
-                            &*api_that_guard,
-                            api_trace_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::account::AccountHandle::sign_out(&*api_that_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1537,55 +1694,7 @@ fn wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
         },
     )
 }
-fn wire__crate__api__account__AccountHandle_sticker_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_sticker",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::account::AccountHandle::sticker(
-                    &*api_that_guard,
-                ))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__account__AccountHandle_storage_usage_impl(
+fn wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1593,7 +1702,7 @@ fn wire__crate__api__account__AccountHandle_storage_usage_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "AccountHandle_storage_usage",
+            debug_name: "AccountHandle_snapshot_by_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1610,6 +1719,7 @@ fn wire__crate__api__account__AccountHandle_storage_usage_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
             >>::sse_decode(&mut deserializer);
+            let api_snapshot_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -1631,7 +1741,174 @@ fn wire__crate__api__account__AccountHandle_storage_usage_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
+                        let output_ok = crate::api::account::AccountHandle::snapshot_by_id(
+                            &*api_that_guard,
+                            api_snapshot_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AccountHandle_snapshot_by_trace",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_trace_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::account::AccountHandle::snapshot_by_trace(
+                            &*api_that_guard,
+                            api_trace_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__account__AccountHandle_sticker_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AccountHandle_sticker",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::account::AccountHandle::sticker(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__account__AccountHandle_storage_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AccountHandle_storage_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AccountHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
                             crate::api::account::AccountHandle::storage_usage(&*api_that_guard)
                                 .await?;
                         Ok(output_ok)
@@ -2140,6 +2417,71 @@ let decode_indices_ = flutter_rust_bridge::for_generated::lockable_compute_decod
                     })().await)
                 } })
 }
+fn wire__mixin_desktop_api__access__AttachmentAccess_read_video_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AttachmentAccess_read_video_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AttachmentAccess>,
+            >>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_offset = <u64>::sse_decode(&mut deserializer);
+            let api_length = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::AttachmentAccess::read_video_range(
+                                &*api_that_guard,
+                                api_message_id,
+                                api_offset,
+                                api_length,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__mixin_desktop_api__access__AttachmentAccess_retry_attachment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4095,7 +4437,7 @@ fn wire__crate__api__desktop__DesktopHandle_abort_saved_login_impl(
         },
     )
 }
-fn wire__crate__api__desktop__DesktopHandle_begin_login_impl(
+fn wire__crate__api__desktop__DesktopHandle_accounts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4103,7 +4445,7 @@ fn wire__crate__api__desktop__DesktopHandle_begin_login_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DesktopHandle_begin_login",
+            debug_name: "DesktopHandle_accounts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4142,8 +4484,7 @@ fn wire__crate__api__desktop__DesktopHandle_begin_login_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::desktop::DesktopHandle::begin_login(&*api_that_guard)
-                                .await?;
+                            crate::api::desktop::DesktopHandle::accounts(&*api_that_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4152,7 +4493,7 @@ fn wire__crate__api__desktop__DesktopHandle_begin_login_impl(
         },
     )
 }
-fn wire__crate__api__desktop__DesktopHandle_http_request_impl(
+fn wire__crate__api__desktop__DesktopHandle_active_account_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4160,7 +4501,7 @@ fn wire__crate__api__desktop__DesktopHandle_http_request_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DesktopHandle_http_request",
+            debug_name: "DesktopHandle_active_account",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4177,13 +4518,6 @@ fn wire__crate__api__desktop__DesktopHandle_http_request_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_method = <String>::sse_decode(&mut deserializer);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_headers =
-                <std::collections::HashMap<String, String>>::sse_decode(&mut deserializer);
-            let api_body = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
-            let api_timeout_millis = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_max_response_bytes = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -4205,16 +4539,9 @@ fn wire__crate__api__desktop__DesktopHandle_http_request_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::desktop::DesktopHandle::http_request(
-                            &*api_that_guard,
-                            api_method,
-                            api_url,
-                            api_headers,
-                            api_body,
-                            api_timeout_millis,
-                            api_max_response_bytes,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::desktop::DesktopHandle::active_account(&*api_that_guard)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4223,11 +4550,139 @@ fn wire__crate__api__desktop__DesktopHandle_http_request_impl(
         },
     )
 }
-fn wire__crate__api__desktop__DesktopHandle_media_impl(
+fn wire__crate__api__desktop__DesktopHandle_begin_login_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_begin_login",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::desktop::DesktopHandle::begin_login(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_http_request_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_http_request",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_method = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_headers =
+                <std::collections::HashMap<String, String>>::sse_decode(&mut deserializer);
+            let api_body = <Option<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_timeout_millis = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_max_response_bytes = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::desktop::DesktopHandle::http_request(
+                            &*api_that_guard,
+                            api_method,
+                            api_url,
+                            api_headers,
+                            api_body,
+                            api_timeout_millis,
+                            api_max_response_bytes,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_media_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
             debug_name: "DesktopHandle_media",
@@ -4271,6 +4726,139 @@ fn wire__crate__api__desktop__DesktopHandle_media_impl(
         },
     )
 }
+fn wire__crate__api__desktop__DesktopHandle_notification_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_notification_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                mixin_desktop_api::model::AccountNotificationItem,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::desktop::DesktopHandle::notification_events(
+                            &*api_that_guard,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_perform_notification_action_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_perform_notification_action",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_account_id = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_kind =
+                <crate::api::desktop::NotificationActionKind>::sse_decode(&mut deserializer);
+            let api_reply_text = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::desktop::DesktopHandle::perform_notification_action(
+                                &*api_that_guard,
+                                api_account_id,
+                                api_conversation_id,
+                                api_message_id,
+                                api_kind,
+                                api_reply_text,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__desktop__DesktopHandle_recreate_account_database_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -4317,11 +4905,967 @@ fn wire__crate__api__desktop__DesktopHandle_recreate_account_database_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::desktop::DesktopHandle::recreate_account_database(
-                                &*api_that_guard,
-                            )
-                            .await?;
+                        let output_ok =
+                            crate::api::desktop::DesktopHandle::recreate_account_database(
+                                &*api_that_guard,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_restore_account_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_restore_account",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::desktop::DesktopHandle::restore_account(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_restore_accounts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_restore_accounts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::desktop::DesktopHandle::restore_accounts(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::desktop::DesktopHandle::settings(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_unread_count_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_unread_count_changes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                Vec<mixin_desktop_api::model::AccountUnreadCountItem>,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::desktop::DesktopHandle::unread_count_changes(
+                            &*api_that_guard,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop__DesktopHandle_switch_account_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DesktopHandle_switch_account",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_account_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::desktop::DesktopHandle::switch_account(
+                            &*api_that_guard,
+                            api_account_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__login__LoginHandle_auth_url_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LoginHandle_auth_url",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(crate::api::login::LoginHandle::auth_url(
+                    &*api_that_guard,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__login__LoginHandle_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LoginHandle_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::login::LoginHandle::cancel(&*api_that_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__login__LoginHandle_status_changes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LoginHandle_status_changes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::login::LoginStatus,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::login::LoginHandle::status_changes(
+                            &*api_that_guard,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__login__LoginHandle_wait_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "LoginHandle_wait",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::login::LoginHandle::wait(&*api_that_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_audio_playback_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_audio_playback_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::media::MediaPlaybackEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::media::MediaHandle::audio_playback_events(
+                            &*api_that_guard,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_audio_playback_snapshot_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_audio_playback_snapshot",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::media::MediaHandle::audio_playback_snapshot(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_cancel_voice_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::media::MediaHandle::cancel_voice_recording(
+                            &*api_that_guard,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_pause_audio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_pause_audio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::media::MediaHandle::pause_audio(&*api_that_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_play_audio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_play_audio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_playlist =
+                <Vec<crate::api::media::MediaAudioItem>>::sse_decode(&mut deserializer);
+            let api_start_index = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::media::MediaHandle::play_audio(
+                            &*api_that_guard,
+                            api_playlist,
+                            api_start_index,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_resume_audio_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_resume_audio",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::media::MediaHandle::resume_audio(&*api_that_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_seek_audio_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_seek_audio",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_position_millis = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::media::MediaHandle::seek_audio(
+                        &*api_that_guard,
+                        api_position_millis,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_set_audio_speed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_set_audio_speed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_speed = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::media::MediaHandle::set_audio_speed(
+                            &*api_that_guard,
+                            api_speed,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4330,7 +5874,7 @@ fn wire__crate__api__desktop__DesktopHandle_recreate_account_database_impl(
         },
     )
 }
-fn wire__crate__api__desktop__DesktopHandle_restore_account_impl(
+fn wire__crate__api__media__MediaHandle_start_voice_recording_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4338,7 +5882,7 @@ fn wire__crate__api__desktop__DesktopHandle_restore_account_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DesktopHandle_restore_account",
+            debug_name: "MediaHandle_start_voice_recording",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4353,7 +5897,7 @@ fn wire__crate__api__desktop__DesktopHandle_restore_account_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
@@ -4377,7 +5921,7 @@ fn wire__crate__api__desktop__DesktopHandle_restore_account_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::desktop::DesktopHandle::restore_account(&*api_that_guard)
+                            crate::api::media::MediaHandle::start_voice_recording(&*api_that_guard)
                                 .await?;
                         Ok(output_ok)
                     })()
@@ -4387,14 +5931,14 @@ fn wire__crate__api__desktop__DesktopHandle_restore_account_impl(
         },
     )
 }
-fn wire__crate__api__desktop__DesktopHandle_settings_impl(
+fn wire__crate__api__media__MediaHandle_stop_audio_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DesktopHandle_settings",
+            debug_name: "MediaHandle_stop_audio",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4409,7 +5953,7 @@ fn wire__crate__api__desktop__DesktopHandle_settings_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DesktopHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -4427,24 +5971,25 @@ fn wire__crate__api__desktop__DesktopHandle_settings_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::desktop::DesktopHandle::settings(
-                    &*api_that_guard,
-                ))?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::media::MediaHandle::stop_audio(&*api_that_guard);
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__login__LoginHandle_auth_url_impl(
+fn wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "LoginHandle_auth_url",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "MediaHandle_stop_voice_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -4457,40 +6002,111 @@ fn wire__crate__api__login__LoginHandle_auth_url_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(crate::api::login::LoginHandle::auth_url(
-                    &*api_that_guard,
-                ))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::media::MediaHandle::stop_voice_recording(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__login__LoginHandle_cancel_impl(
+fn wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "MediaHandle_voice_recorder_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::media::MediaRecorderEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::error::CoreError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::media::MediaHandle::voice_recorder_events(
+                            &*api_that_guard,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__MediaHandle_voice_recorder_snapshot_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "LoginHandle_cancel",
+            debug_name: "MediaHandle_voice_recorder_snapshot",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -4505,7 +6121,7 @@ fn wire__crate__api__login__LoginHandle_cancel_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
@@ -4523,15 +6139,15 @@ fn wire__crate__api__login__LoginHandle_cancel_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::login::LoginHandle::cancel(&*api_that_guard);
-                })?;
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::media::MediaHandle::voice_recorder_snapshot(&*api_that_guard),
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__login__LoginHandle_wait_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_cancel_outgoing_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4539,7 +6155,7 @@ fn wire__crate__api__login__LoginHandle_wait_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "LoginHandle_wait",
+            debug_name: "MessageAccess_cancel_outgoing_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4554,11 +6170,12 @@ fn wire__crate__api__login__LoginHandle_wait_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LoginHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -4578,7 +6195,11 @@ fn wire__crate__api__login__LoginHandle_wait_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::login::LoginHandle::wait(&*api_that_guard).await?;
+                            mixin_desktop_api::access::MessageAccess::cancel_outgoing_message(
+                                &*api_that_guard,
+                                api_message_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4587,7 +6208,7 @@ fn wire__crate__api__login__LoginHandle_wait_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_audio_playback_events_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4595,7 +6216,7 @@ fn wire__crate__api__media__MediaHandle_audio_playback_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_audio_playback_events",
+            debug_name: "MessageAccess_combine_forward_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4610,15 +6231,13 @@ fn wire__crate__api__media__MediaHandle_audio_playback_events_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
-            >>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::media::MediaPlaybackEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_target_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_source_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -4637,11 +6256,13 @@ fn wire__crate__api__media__MediaHandle_audio_playback_events_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::media::MediaHandle::audio_playback_events(
-                            &*api_that_guard,
-                            api_sink,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::combine_forward_messages(
+                                &*api_that_guard,
+                                api_target_conversation_id,
+                                api_source_message_ids,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4650,16 +6271,17 @@ fn wire__crate__api__media__MediaHandle_audio_playback_events_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_audio_playback_snapshot_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_audio_playback_snapshot",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "MessageAccess_conversation_is_encrypted",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -4672,33 +6294,45 @@ fn wire__crate__api__media__MediaHandle_audio_playback_snapshot_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::media::MediaHandle::audio_playback_snapshot(&*api_that_guard),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::conversation_is_encrypted(
+                                &*api_that_guard,
+                                api_conversation_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4706,7 +6340,7 @@ fn wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_cancel_voice_recording",
+            debug_name: "MessageAccess_delete_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4721,11 +6355,13 @@ fn wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -4744,8 +6380,10 @@ fn wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::media::MediaHandle::cancel_voice_recording(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::delete_messages(
                             &*api_that_guard,
+                            api_conversation_id,
+                            api_message_ids,
                         )
                         .await?;
                         Ok(output_ok)
@@ -4756,7 +6394,7 @@ fn wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_pause_audio_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_edit_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4764,7 +6402,7 @@ fn wire__crate__api__media__MediaHandle_pause_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_pause_audio",
+            debug_name: "MessageAccess_edit_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4779,11 +6417,14 @@ fn wire__crate__api__media__MediaHandle_pause_audio_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -4802,8 +6443,13 @@ fn wire__crate__api__media__MediaHandle_pause_audio_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::media::MediaHandle::pause_audio(&*api_that_guard).await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::edit_message(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_message_id,
+                            api_content,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4812,7 +6458,7 @@ fn wire__crate__api__media__MediaHandle_pause_audio_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_play_audio_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_failed_outgoing_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4820,7 +6466,7 @@ fn wire__crate__api__media__MediaHandle_play_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_play_audio",
+            debug_name: "MessageAccess_failed_outgoing_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4835,14 +6481,13 @@ fn wire__crate__api__media__MediaHandle_play_audio_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_playlist =
-                <Vec<crate::api::media::MediaAudioItem>>::sse_decode(&mut deserializer);
-            let api_start_index = <u64>::sse_decode(&mut deserializer);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -4861,12 +6506,13 @@ fn wire__crate__api__media__MediaHandle_play_audio_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::media::MediaHandle::play_audio(
-                            &*api_that_guard,
-                            api_playlist,
-                            api_start_index,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::failed_outgoing_messages(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_limit,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4875,7 +6521,7 @@ fn wire__crate__api__media__MediaHandle_play_audio_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_resume_audio_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4883,7 +6529,7 @@ fn wire__crate__api__media__MediaHandle_resume_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_resume_audio",
+            debug_name: "MessageAccess_forward_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -4898,11 +6544,13 @@ fn wire__crate__api__media__MediaHandle_resume_audio_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_target_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_source_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -4921,8 +6569,12 @@ fn wire__crate__api__media__MediaHandle_resume_audio_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::media::MediaHandle::resume_audio(&*api_that_guard).await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::forward_messages(
+                            &*api_that_guard,
+                            api_target_conversation_id,
+                            api_source_message_ids,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -4931,16 +6583,17 @@ fn wire__crate__api__media__MediaHandle_resume_audio_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_seek_audio_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_seek_audio",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "MessageAccess_image_messages_around",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -4953,37 +6606,51 @@ fn wire__crate__api__media__MediaHandle_seek_audio_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_position_millis = <u64>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_target_message_id = <String>::sse_decode(&mut deserializer);
+            let api_before = <i64>::sse_decode(&mut deserializer);
+            let api_after = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::media::MediaHandle::seek_audio(
-                        &*api_that_guard,
-                        api_position_millis,
-                    );
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::image_messages_around(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_target_message_id,
+                                api_before,
+                                api_after,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_set_audio_speed_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_load_link_preview_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -4991,7 +6658,7 @@ fn wire__crate__api__media__MediaHandle_set_audio_speed_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_set_audio_speed",
+            debug_name: "MessageAccess_load_link_preview",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5006,12 +6673,12 @@ fn wire__crate__api__media__MediaHandle_set_audio_speed_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_speed = <f64>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -5030,11 +6697,12 @@ fn wire__crate__api__media__MediaHandle_set_audio_speed_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::media::MediaHandle::set_audio_speed(
-                            &*api_that_guard,
-                            api_speed,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::load_link_preview(
+                                &*api_that_guard,
+                                api_message_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5043,7 +6711,7 @@ fn wire__crate__api__media__MediaHandle_set_audio_speed_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_start_voice_recording_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5051,7 +6719,7 @@ fn wire__crate__api__media__MediaHandle_start_voice_recording_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_start_voice_recording",
+            debug_name: "MessageAccess_mark_conversation_read",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5066,11 +6734,12 @@ fn wire__crate__api__media__MediaHandle_start_voice_recording_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -5090,8 +6759,11 @@ fn wire__crate__api__media__MediaHandle_start_voice_recording_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::media::MediaHandle::start_voice_recording(&*api_that_guard)
-                                .await?;
+                            mixin_desktop_api::access::MessageAccess::mark_conversation_read(
+                                &*api_that_guard,
+                                api_conversation_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5100,16 +6772,17 @@ fn wire__crate__api__media__MediaHandle_start_voice_recording_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_stop_audio_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_stop_audio",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "MessageAccess_mark_mention_read",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -5122,33 +6795,47 @@ fn wire__crate__api__media__MediaHandle_stop_audio_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::media::MediaHandle::stop_audio(&*api_that_guard);
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::mark_mention_read(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_message_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_message_edit_history_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5156,7 +6843,7 @@ fn wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_stop_voice_recording",
+            debug_name: "MessageAccess_message_edit_history",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5171,11 +6858,12 @@ fn wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -5195,8 +6883,11 @@ fn wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::media::MediaHandle::stop_voice_recording(&*api_that_guard)
-                                .await?;
+                            mixin_desktop_api::access::MessageAccess::message_edit_history(
+                                &*api_that_guard,
+                                api_message_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5205,7 +6896,7 @@ fn wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5213,7 +6904,7 @@ fn wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_voice_recorder_events",
+            debug_name: "MessageAccess_message_ids_after",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5228,15 +6919,15 @@ fn wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
-            >>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::media::MediaRecorderEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_anchor_row_id = <i64>::sse_decode(&mut deserializer);
+            let api_anchor_created_at_micros = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::error::CoreError>(
+                transform_result_sse::<_, ClientError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -5255,11 +6946,15 @@ fn wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::media::MediaHandle::voice_recorder_events(
-                            &*api_that_guard,
-                            api_sink,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::message_ids_after(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_anchor_row_id,
+                                api_anchor_created_at_micros,
+                                api_limit,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5268,16 +6963,17 @@ fn wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
         },
     )
 }
-fn wire__crate__api__media__MediaHandle_voice_recorder_snapshot_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MediaHandle_voice_recorder_snapshot",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "MessageAccess_message_ids_before",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -5290,33 +6986,51 @@ fn wire__crate__api__media__MediaHandle_voice_recorder_snapshot_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MediaHandle>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_anchor_row_id = <i64>::sse_decode(&mut deserializer);
+            let api_anchor_created_at_micros = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::media::MediaHandle::voice_recorder_snapshot(&*api_that_guard),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::message_ids_before(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_anchor_row_id,
+                                api_anchor_created_at_micros,
+                                api_limit,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5324,7 +7038,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_combine_forward_messages",
+            debug_name: "MessageAccess_message_items_by_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5341,8 +7055,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_target_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_source_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5365,10 +7078,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::combine_forward_messages(
+                            mixin_desktop_api::access::MessageAccess::message_items_by_ids(
                                 &*api_that_guard,
-                                api_target_conversation_id,
-                                api_source_message_ids,
+                                api_message_ids,
                             )
                             .await?;
                         Ok(output_ok)
@@ -5379,7 +7091,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5387,7 +7099,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_conversation_is_encrypted",
+            debug_name: "MessageAccess_message_order_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5404,7 +7116,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5427,9 +7139,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::conversation_is_encrypted(
+                            mixin_desktop_api::access::MessageAccess::message_order_info(
                                 &*api_that_guard,
-                                api_conversation_id,
+                                api_message_id,
                             )
                             .await?;
                         Ok(output_ok)
@@ -5440,7 +7152,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_message_reactions_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5448,7 +7160,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_delete_messages",
+            debug_name: "MessageAccess_message_reactions",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5465,8 +7177,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5488,12 +7199,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::delete_messages(
-                            &*api_that_guard,
-                            api_conversation_id,
-                            api_message_ids,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::message_reactions(
+                                &*api_that_guard,
+                                api_message_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5502,7 +7213,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5510,7 +7221,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_forward_messages",
+            debug_name: "MessageAccess_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5527,8 +7238,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_target_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_source_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_before_created_at_micros = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_before_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5550,10 +7263,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::forward_messages(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::messages(
                             &*api_that_guard,
-                            api_target_conversation_id,
-                            api_source_message_ids,
+                            api_conversation_id,
+                            api_before_created_at_micros,
+                            api_before_message_id,
+                            api_limit,
                         )
                         .await?;
                         Ok(output_ok)
@@ -5564,7 +7279,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5572,7 +7287,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_image_messages_around",
+            debug_name: "MessageAccess_messages_around",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5614,15 +7329,14 @@ fn wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::image_messages_around(
-                                &*api_that_guard,
-                                api_conversation_id,
-                                api_target_message_id,
-                                api_before,
-                                api_after,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::messages_around(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_target_message_id,
+                            api_before,
+                            api_after,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5631,7 +7345,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5639,7 +7353,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_mark_conversation_read",
+            debug_name: "MessageAccess_pin_message_preview",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5679,7 +7393,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::mark_conversation_read(
+                            mixin_desktop_api::access::MessageAccess::pin_message_preview(
                                 &*api_that_guard,
                                 api_conversation_id,
                             )
@@ -5692,7 +7406,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5700,7 +7414,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_mark_mention_read",
+            debug_name: "MessageAccess_pinned_message_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5718,7 +7432,6 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5741,10 +7454,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::mark_mention_read(
+                            mixin_desktop_api::access::MessageAccess::pinned_message_ids(
                                 &*api_that_guard,
                                 api_conversation_id,
-                                api_message_id,
                             )
                             .await?;
                         Ok(output_ok)
@@ -5755,7 +7467,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5763,7 +7475,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_message_ids_after",
+            debug_name: "MessageAccess_pinned_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5781,9 +7493,6 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_anchor_row_id = <i64>::sse_decode(&mut deserializer);
-            let api_anchor_created_at_micros = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5805,15 +7514,11 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::message_ids_after(
-                                &*api_that_guard,
-                                api_conversation_id,
-                                api_anchor_row_id,
-                                api_anchor_created_at_micros,
-                                api_limit,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::pinned_messages(
+                            &*api_that_guard,
+                            api_conversation_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5822,7 +7527,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_react_to_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5830,7 +7535,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_message_ids_before",
+            debug_name: "MessageAccess_react_to_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5848,9 +7553,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_anchor_row_id = <i64>::sse_decode(&mut deserializer);
-            let api_anchor_created_at_micros = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <i64>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_emoji = <String>::sse_decode(&mut deserializer);
+            let api_removed = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -5872,15 +7577,14 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::message_ids_before(
-                                &*api_that_guard,
-                                api_conversation_id,
-                                api_anchor_row_id,
-                                api_anchor_created_at_micros,
-                                api_limit,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::react_to_message(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_message_id,
+                            api_emoji,
+                            api_removed,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5889,7 +7593,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5897,7 +7601,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_message_items_by_ids",
+            debug_name: "MessageAccess_recall_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5914,6 +7618,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
@@ -5936,12 +7641,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::message_items_by_ids(
-                                &*api_that_guard,
-                                api_message_ids,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::recall_messages(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_message_ids,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -5950,7 +7655,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_retry_outgoing_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -5958,7 +7663,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_message_order_info",
+            debug_name: "MessageAccess_retry_outgoing_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -5998,7 +7703,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::message_order_info(
+                            mixin_desktop_api::access::MessageAccess::retry_outgoing_message(
                                 &*api_that_guard,
                                 api_message_id,
                             )
@@ -6011,7 +7716,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6019,7 +7724,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_messages",
+            debug_name: "MessageAccess_search_global_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6036,10 +7741,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_before_created_at_micros = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_before_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <i64>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_after_micros = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_before_micros = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_rank_by_relevance = <bool>::sse_decode(&mut deserializer);
+            let api_anchor_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6061,14 +7768,17 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::messages(
-                            &*api_that_guard,
-                            api_conversation_id,
-                            api_before_created_at_micros,
-                            api_before_message_id,
-                            api_limit,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::search_global_messages(
+                                &*api_that_guard,
+                                api_query,
+                                api_after_micros,
+                                api_before_micros,
+                                api_rank_by_relevance,
+                                api_anchor_message_id,
+                                api_limit,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6077,7 +7787,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6085,7 +7795,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_messages_around",
+            debug_name: "MessageAccess_search_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6103,9 +7813,14 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_target_message_id = <String>::sse_decode(&mut deserializer);
-            let api_before = <i64>::sse_decode(&mut deserializer);
-            let api_after = <i64>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_sender_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_categories = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_after_micros = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_before_micros = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_rank_by_relevance = <bool>::sse_decode(&mut deserializer);
+            let api_anchor_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6127,12 +7842,17 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::messages_around(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::search_messages(
                             &*api_that_guard,
                             api_conversation_id,
-                            api_target_message_id,
-                            api_before,
-                            api_after,
+                            api_query,
+                            api_sender_id,
+                            api_categories,
+                            api_after_micros,
+                            api_before_micros,
+                            api_rank_by_relevance,
+                            api_anchor_message_id,
+                            api_limit,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6143,7 +7863,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6151,7 +7871,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_pin_message_preview",
+            debug_name: "MessageAccess_send_app_card",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6169,6 +7889,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6190,12 +7911,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::pin_message_preview(
-                                &*api_that_guard,
-                                api_conversation_id,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_app_card(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_content,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6204,7 +7925,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6212,7 +7933,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_pinned_message_ids",
+            debug_name: "MessageAccess_send_attachment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6230,6 +7951,17 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_width = <Option<i32>>::sse_decode(&mut deserializer);
+            let api_height = <Option<i32>>::sse_decode(&mut deserializer);
+            let api_duration_millis = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_thumbnail = <Option<String>>::sse_decode(&mut deserializer);
+            let api_caption = <Option<String>>::sse_decode(&mut deserializer);
+            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_silent = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6251,12 +7983,22 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::pinned_message_ids(
-                                &*api_that_guard,
-                                api_conversation_id,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_attachment(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_path,
+                            api_kind,
+                            api_mime_type,
+                            api_name,
+                            api_width,
+                            api_height,
+                            api_duration_millis,
+                            api_thumbnail,
+                            api_caption,
+                            api_quote_message_id,
+                            api_silent,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6265,7 +8007,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6273,7 +8015,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_pinned_messages",
+            debug_name: "MessageAccess_send_audio",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6291,6 +8033,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_duration_millis = <i64>::sse_decode(&mut deserializer);
+            let api_waveform = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6312,9 +8058,13 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::pinned_messages(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_audio(
                             &*api_that_guard,
                             api_conversation_id,
+                            api_path,
+                            api_duration_millis,
+                            api_waveform,
+                            api_quote_message_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6325,7 +8075,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6333,7 +8083,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_recall_messages",
+            debug_name: "MessageAccess_send_contact",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6351,7 +8101,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_shared_user_id = <String>::sse_decode(&mut deserializer);
+            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_silent = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6373,10 +8125,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::recall_messages(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_contact(
                             &*api_that_guard,
                             api_conversation_id,
-                            api_message_ids,
+                            api_shared_user_id,
+                            api_quote_message_id,
+                            api_silent,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6387,7 +8141,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_location_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6395,7 +8149,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_search_global_messages",
+            debug_name: "MessageAccess_send_location",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6412,9 +8166,11 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_anchor_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_location =
+                <mixin_desktop_core::runtime::model::LocationView>::sse_decode(&mut deserializer);
+            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_silent = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6436,14 +8192,14 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::search_global_messages(
-                                &*api_that_guard,
-                                api_query,
-                                api_anchor_message_id,
-                                api_limit,
-                            )
-                            .await?;
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_location(
+                            &*api_that_guard,
+                            api_conversation_id,
+                            api_location,
+                            api_quote_message_id,
+                            api_silent,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6452,7 +8208,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6460,7 +8216,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_search_messages",
+            debug_name: "MessageAccess_send_post",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6478,11 +8234,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_sender_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_categories = <Vec<String>>::sse_decode(&mut deserializer);
-            let api_anchor_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6504,14 +8256,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::search_messages(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_post(
                             &*api_that_guard,
                             api_conversation_id,
-                            api_query,
-                            api_sender_id,
-                            api_categories,
-                            api_anchor_message_id,
-                            api_limit,
+                            api_content,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6522,7 +8270,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6530,7 +8278,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_app_card",
+            debug_name: "MessageAccess_send_remote_image",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6548,7 +8296,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_preview_url = <String>::sse_decode(&mut deserializer);
+            let api_width = <Option<i32>>::sse_decode(&mut deserializer);
+            let api_height = <Option<i32>>::sse_decode(&mut deserializer);
+            let api_mime_type = <String>::sse_decode(&mut deserializer);
+            let api_silent = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6570,12 +8323,18 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_app_card(
-                            &*api_that_guard,
-                            api_conversation_id,
-                            api_content,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::send_remote_image(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_url,
+                                api_preview_url,
+                                api_width,
+                                api_height,
+                                api_mime_type,
+                                api_silent,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6584,7 +8343,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6592,7 +8351,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_attachment",
+            debug_name: "MessageAccess_send_sticker",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6610,17 +8369,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_kind = <String>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_name = <Option<String>>::sse_decode(&mut deserializer);
-            let api_width = <Option<i32>>::sse_decode(&mut deserializer);
-            let api_height = <Option<i32>>::sse_decode(&mut deserializer);
-            let api_duration_millis = <Option<i64>>::sse_decode(&mut deserializer);
-            let api_thumbnail = <Option<String>>::sse_decode(&mut deserializer);
-            let api_caption = <Option<String>>::sse_decode(&mut deserializer);
-            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_silent = <bool>::sse_decode(&mut deserializer);
+            let api_sticker_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6642,20 +8391,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_attachment(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_sticker(
                             &*api_that_guard,
                             api_conversation_id,
-                            api_path,
-                            api_kind,
-                            api_mime_type,
-                            api_name,
-                            api_width,
-                            api_height,
-                            api_duration_millis,
-                            api_thumbnail,
-                            api_caption,
-                            api_quote_message_id,
-                            api_silent,
+                            api_sticker_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6666,7 +8405,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6674,7 +8413,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_audio",
+            debug_name: "MessageAccess_send_text",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6692,10 +8431,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_duration_millis = <i64>::sse_decode(&mut deserializer);
-            let api_waveform = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_silent = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6717,13 +8455,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_audio(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::send_text(
                             &*api_that_guard,
                             api_conversation_id,
-                            api_path,
-                            api_duration_millis,
-                            api_waveform,
+                            api_content,
                             api_quote_message_id,
+                            api_silent,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6734,7 +8471,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6742,7 +8479,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_contact",
+            debug_name: "MessageAccess_set_message_pinned",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6760,9 +8497,8 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_shared_user_id = <String>::sse_decode(&mut deserializer);
-            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_silent = <bool>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_pinned = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6784,14 +8520,14 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_contact(
-                            &*api_that_guard,
-                            api_conversation_id,
-                            api_shared_user_id,
-                            api_quote_message_id,
-                            api_silent,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::set_message_pinned(
+                                &*api_that_guard,
+                                api_conversation_id,
+                                api_message_id,
+                                api_pinned,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6800,7 +8536,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6808,7 +8544,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_post",
+            debug_name: "MessageAccess_shared_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6826,7 +8562,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            let api_offset = <usize>::sse_decode(&mut deserializer);
+            let api_limit = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6848,10 +8586,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_post(
+                        let output_ok = mixin_desktop_api::access::MessageAccess::shared_messages(
                             &*api_that_guard,
                             api_conversation_id,
-                            api_content,
+                            api_kind,
+                            api_offset,
+                            api_limit,
                         )
                         .await?;
                         Ok(output_ok)
@@ -6862,7 +8602,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6870,7 +8610,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_remote_image",
+            debug_name: "MessageAccess_transcript_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6887,13 +8627,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_preview_url = <String>::sse_decode(&mut deserializer);
-            let api_width = <Option<i32>>::sse_decode(&mut deserializer);
-            let api_height = <Option<i32>>::sse_decode(&mut deserializer);
-            let api_mime_type = <String>::sse_decode(&mut deserializer);
-            let api_silent = <bool>::sse_decode(&mut deserializer);
+            let api_transcript_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6916,15 +8650,9 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::send_remote_image(
+                            mixin_desktop_api::access::MessageAccess::transcript_messages(
                                 &*api_that_guard,
-                                api_conversation_id,
-                                api_url,
-                                api_preview_url,
-                                api_width,
-                                api_height,
-                                api_mime_type,
-                                api_silent,
+                                api_transcript_id,
                             )
                             .await?;
                         Ok(output_ok)
@@ -6935,7 +8663,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
+fn wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -6943,7 +8671,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_sticker",
+            debug_name: "MessageAccess_unread_mention_message_ids",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -6961,7 +8689,6 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
             >>::sse_decode(&mut deserializer);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_sticker_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -6983,12 +8710,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_sticker(
-                            &*api_that_guard,
-                            api_conversation_id,
-                            api_sticker_id,
-                        )
-                        .await?;
+                        let output_ok =
+                            mixin_desktop_api::access::MessageAccess::unread_mention_message_ids(
+                                &*api_that_guard,
+                                api_conversation_id,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -6997,7 +8724,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
+fn wire__mixin_desktop_api__access__SearchAccess_global_search_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7005,7 +8732,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_send_text",
+            debug_name: "SearchAccess_global_search",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7020,12 +8747,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_quote_message_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_silent = <bool>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -7047,12 +8772,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::send_text(
+                        let output_ok = mixin_desktop_api::access::SearchAccess::global_search(
                             &*api_that_guard,
-                            api_conversation_id,
-                            api_content,
-                            api_quote_message_id,
-                            api_silent,
+                            api_query,
+                            api_limit,
                         )
                         .await?;
                         Ok(output_ok)
@@ -7063,7 +8786,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
+fn wire__mixin_desktop_api__access__SearchAccess_global_search_section_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7071,7 +8794,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_set_message_pinned",
+            debug_name: "SearchAccess_global_search_section",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7086,11 +8809,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_pinned = <bool>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_section = <String>::sse_decode(&mut deserializer);
+            let api_cursor = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -7113,11 +8837,12 @@ fn wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::set_message_pinned(
+                            mixin_desktop_api::access::SearchAccess::global_search_section(
                                 &*api_that_guard,
-                                api_conversation_id,
-                                api_message_id,
-                                api_pinned,
+                                api_query,
+                                api_section,
+                                api_cursor,
+                                api_limit,
                             )
                             .await?;
                         Ok(output_ok)
@@ -7128,7 +8853,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
+fn wire__crate__api__search__SearchHandle_results_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7136,7 +8861,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_shared_messages",
+            debug_name: "SearchHandle_results",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7151,15 +8876,16 @@ fn wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                mixin_desktop_core::runtime::model::GlobalSearchView,
+                flutter_rust_bridge::for_generated::SseCodec,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_kind = <String>::sse_decode(&mut deserializer);
-            let api_offset = <usize>::sse_decode(&mut deserializer);
-            let api_limit = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ClientError>(
+                transform_result_sse::<_, crate::error::CoreError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -7178,12 +8904,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::MessageAccess::shared_messages(
+                        let output_ok = crate::api::search::SearchHandle::results(
                             &*api_that_guard,
-                            api_conversation_id,
-                            api_kind,
-                            api_offset,
                             api_limit,
+                            api_sink,
                         )
                         .await?;
                         Ok(output_ok)
@@ -7194,7 +8918,56 @@ fn wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
+fn wire__crate__api__search__SearchHandle_set_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "SearchHandle_set_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::search::SearchHandle::set_query(&*api_that_guard, api_query);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7202,7 +8975,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_transcript_messages",
+            debug_name: "SettingsHandle_file_auto_download",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7217,12 +8990,11 @@ fn wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_transcript_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ClientError>(
+                transform_result_sse::<_, crate::error::CoreError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -7241,12 +9013,10 @@ fn wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            mixin_desktop_api::access::MessageAccess::transcript_messages(
-                                &*api_that_guard,
-                                api_transcript_id,
-                            )
-                            .await?;
+                        let output_ok = crate::api::desktop::SettingsHandle::file_auto_download(
+                            &*api_that_guard,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -7255,7 +9025,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_impl(
+fn wire__crate__api__desktop__SettingsHandle_mcp_account_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7263,7 +9033,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_imp
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "MessageAccess_unread_mention_message_ids",
+            debug_name: "SettingsHandle_mcp_account_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7278,12 +9048,11 @@ fn wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_imp
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ClientError>(
+                transform_result_sse::<_, crate::error::CoreError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -7303,11 +9072,8 @@ fn wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_imp
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            mixin_desktop_api::access::MessageAccess::unread_mention_message_ids(
-                                &*api_that_guard,
-                                api_conversation_id,
-                            )
-                            .await?;
+                            crate::api::desktop::SettingsHandle::mcp_account_id(&*api_that_guard)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -7316,7 +9082,7 @@ fn wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_imp
         },
     )
 }
-fn wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
+fn wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7324,7 +9090,7 @@ fn wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SettingsHandle_file_auto_download",
+            debug_name: "SettingsHandle_mcp_server_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7362,7 +9128,7 @@ fn wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::desktop::SettingsHandle::file_auto_download(
+                        let output_ok = crate::api::desktop::SettingsHandle::mcp_server_status(
                             &*api_that_guard,
                         )
                         .await?;
@@ -7374,7 +9140,7 @@ fn wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
         },
     )
 }
-fn wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
+fn wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7382,7 +9148,7 @@ fn wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SettingsHandle_mcp_server_status",
+            debug_name: "SettingsHandle_mcp_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7420,10 +9186,9 @@ fn wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::desktop::SettingsHandle::mcp_server_status(
-                            &*api_that_guard,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::desktop::SettingsHandle::mcp_settings(&*api_that_guard)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -7432,7 +9197,7 @@ fn wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
         },
     )
 }
-fn wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
+fn wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7440,7 +9205,7 @@ fn wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SettingsHandle_mcp_settings",
+            debug_name: "SettingsHandle_photo_auto_download",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7478,9 +9243,10 @@ fn wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::desktop::SettingsHandle::mcp_settings(&*api_that_guard)
-                                .await?;
+                        let output_ok = crate::api::desktop::SettingsHandle::photo_auto_download(
+                            &*api_that_guard,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -7489,7 +9255,7 @@ fn wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
         },
     )
 }
-fn wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
+fn wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7497,7 +9263,7 @@ fn wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SettingsHandle_photo_auto_download",
+            debug_name: "SettingsHandle_proxy_settings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7535,10 +9301,9 @@ fn wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::api::desktop::SettingsHandle::photo_auto_download(
-                            &*api_that_guard,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::desktop::SettingsHandle::proxy_settings(&*api_that_guard)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -7547,7 +9312,7 @@ fn wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
         },
     )
 }
-fn wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
+fn wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7555,7 +9320,7 @@ fn wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SettingsHandle_proxy_settings",
+            debug_name: "SettingsHandle_set_file_auto_download",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7572,6 +9337,7 @@ fn wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>,
             >>::sse_decode(&mut deserializer);
+            let api_value = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -7594,8 +9360,11 @@ fn wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::api::desktop::SettingsHandle::proxy_settings(&*api_that_guard)
-                                .await?;
+                            crate::api::desktop::SettingsHandle::set_file_auto_download(
+                                &*api_that_guard,
+                                api_value,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -7604,7 +9373,7 @@ fn wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
         },
     )
 }
-fn wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
+fn wire__crate__api__desktop__SettingsHandle_set_mcp_account_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -7612,7 +9381,7 @@ fn wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "SettingsHandle_set_file_auto_download",
+            debug_name: "SettingsHandle_set_mcp_account_id",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -7629,7 +9398,7 @@ fn wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>,
             >>::sse_decode(&mut deserializer);
-            let api_value = <bool>::sse_decode(&mut deserializer);
+            let api_account_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::error::CoreError>(
@@ -7651,12 +9420,11 @@ fn wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::api::desktop::SettingsHandle::set_file_auto_download(
-                                &*api_that_guard,
-                                api_value,
-                            )
-                            .await?;
+                        let output_ok = crate::api::desktop::SettingsHandle::set_mcp_account_id(
+                            &*api_that_guard,
+                            api_account_id,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -9265,6 +11033,63 @@ fn wire__mixin_desktop_api__access__UserAccess_block_user_impl(
         },
     )
 }
+fn wire__mixin_desktop_api__access__UserAccess_blocked_users_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "UserAccess_blocked_users",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::UserAccess::blocked_users(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__mixin_desktop_api__access__UserAccess_bot_creator_id_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -9385,6 +11210,63 @@ fn wire__mixin_desktop_api__access__UserAccess_bot_home_uri_impl(
         },
     )
 }
+fn wire__mixin_desktop_api__access__UserAccess_contacts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "UserAccess_contacts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            mixin_desktop_api::access::UserAccess::contacts(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__mixin_desktop_api__access__UserAccess_local_shared_apps_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -9711,7 +11593,131 @@ fn wire__mixin_desktop_api__access__UserAccess_report_user_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = mixin_desktop_api::access::UserAccess::report_user(
+                            &*api_that_guard,
+                            api_user_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "UserAccess_search_local_users",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_category = <String>::sse_decode(&mut deserializer);
+            let api_limit = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ClientError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = mixin_desktop_api::access::UserAccess::search_local_users(
+                            &*api_that_guard,
+                            api_query,
+                            api_category,
+                            api_limit,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "UserAccess_search_mao_user",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
+            >>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -9733,9 +11739,9 @@ fn wire__mixin_desktop_api__access__UserAccess_report_user_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::report_user(
+                        let output_ok = mixin_desktop_api::access::UserAccess::search_mao_user(
                             &*api_that_guard,
-                            api_user_id,
+                            api_query,
                         )
                         .await?;
                         Ok(output_ok)
@@ -9746,7 +11752,7 @@ fn wire__mixin_desktop_api__access__UserAccess_report_user_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
+fn wire__mixin_desktop_api__access__UserAccess_search_user_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -9754,7 +11760,7 @@ fn wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "UserAccess_search_local_users",
+            debug_name: "UserAccess_search_user",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -9772,8 +11778,6 @@ fn wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
             >>::sse_decode(&mut deserializer);
             let api_query = <String>::sse_decode(&mut deserializer);
-            let api_category = <String>::sse_decode(&mut deserializer);
-            let api_limit = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -9795,11 +11799,9 @@ fn wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::search_local_users(
+                        let output_ok = mixin_desktop_api::access::UserAccess::search_user(
                             &*api_that_guard,
                             api_query,
-                            api_category,
-                            api_limit,
                         )
                         .await?;
                         Ok(output_ok)
@@ -9810,7 +11812,7 @@ fn wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
+fn wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -9818,7 +11820,7 @@ fn wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "UserAccess_search_mao_user",
+            debug_name: "UserAccess_selectable_users",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -9835,7 +11837,6 @@ fn wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -9857,9 +11858,8 @@ fn wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::search_mao_user(
+                        let output_ok = mixin_desktop_api::access::UserAccess::selectable_users(
                             &*api_that_guard,
-                            api_query,
                         )
                         .await?;
                         Ok(output_ok)
@@ -9870,7 +11870,7 @@ fn wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__UserAccess_search_user_impl(
+fn wire__mixin_desktop_api__access__UserAccess_set_user_annotation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -9878,7 +11878,7 @@ fn wire__mixin_desktop_api__access__UserAccess_search_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "UserAccess_search_user",
+            debug_name: "UserAccess_set_user_annotation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -9895,7 +11895,9 @@ fn wire__mixin_desktop_api__access__UserAccess_search_user_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
             >>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
+            let api_alias = <Option<String>>::sse_decode(&mut deserializer);
+            let api_note = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -9917,9 +11919,11 @@ fn wire__mixin_desktop_api__access__UserAccess_search_user_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::search_user(
+                        let output_ok = mixin_desktop_api::access::UserAccess::set_user_annotation(
                             &*api_that_guard,
-                            api_query,
+                            api_user_id,
+                            api_alias,
+                            api_note,
                         )
                         .await?;
                         Ok(output_ok)
@@ -9930,7 +11934,7 @@ fn wire__mixin_desktop_api__access__UserAccess_search_user_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
+fn wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -9938,7 +11942,7 @@ fn wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "UserAccess_selectable_users",
+            debug_name: "UserAccess_shared_apps",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -9955,6 +11959,7 @@ fn wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<UserAccess>,
             >>::sse_decode(&mut deserializer);
+            let api_user_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ClientError>(
@@ -9976,8 +11981,9 @@ fn wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::selectable_users(
+                        let output_ok = mixin_desktop_api::access::UserAccess::shared_apps(
                             &*api_that_guard,
+                            api_user_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -9988,7 +11994,7 @@ fn wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
+fn wire__mixin_desktop_api__access__UserAccess_unblock_user_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -9996,7 +12002,7 @@ fn wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "UserAccess_shared_apps",
+            debug_name: "UserAccess_unblock_user",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -10035,7 +12041,7 @@ fn wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::shared_apps(
+                        let output_ok = mixin_desktop_api::access::UserAccess::unblock_user(
                             &*api_that_guard,
                             api_user_id,
                         )
@@ -10048,7 +12054,7 @@ fn wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
         },
     )
 }
-fn wire__mixin_desktop_api__access__UserAccess_unblock_user_impl(
+fn wire__mixin_desktop_api__access__UserAccess_user_annotation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -10056,7 +12062,7 @@ fn wire__mixin_desktop_api__access__UserAccess_unblock_user_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "UserAccess_unblock_user",
+            debug_name: "UserAccess_user_annotation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -10095,7 +12101,7 @@ fn wire__mixin_desktop_api__access__UserAccess_unblock_user_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = mixin_desktop_api::access::UserAccess::unblock_user(
+                        let output_ok = mixin_desktop_api::access::UserAccess::user_annotation(
                             &*api_that_guard,
                             api_user_id,
                         )
@@ -10404,6 +12410,13 @@ fn wire__crate__api__desktop__open_desktop_impl(
 
 #[allow(clippy::unnecessary_literal_unwrap)]
 const _: fn() = || {
+    {
+        let AccountNotificationItem =
+            None::<mixin_desktop_api::model::AccountNotificationItem>.unwrap();
+        let _: String = AccountNotificationItem.account_id;
+        let _: mixin_desktop_core::runtime::model::NotificationEvent =
+            AccountNotificationItem.event;
+    }
     {
         let AccountProfile = None::<mixin_desktop_api::model::AccountProfile>.unwrap();
         let _: String = AccountProfile.user_id;
@@ -10417,6 +12430,21 @@ const _: fn() = || {
         let _: String = AccountProfile.fiat_currency;
         let _: Option<String> = AccountProfile.membership;
     }
+    {
+        let AccountSession = None::<mixin_desktop_core::runtime::model::AccountSession>.unwrap();
+        let _: String = AccountSession.session_id;
+        let _: Option<String> = AccountSession.platform;
+        let _: Option<String> = AccountSession.app_version;
+        let _: Option<String> = AccountSession.last_seen_at;
+        let _: bool = AccountSession.is_current;
+        let _: bool = AccountSession.is_primary;
+    }
+    {
+        let AccountUnreadCountItem =
+            None::<mixin_desktop_api::model::AccountUnreadCountItem>.unwrap();
+        let _: String = AccountUnreadCountItem.account_id;
+        let _: i64 = AccountUnreadCountItem.unseen_message_count;
+    }
     {
         let CircleItem = None::<mixin_desktop_core::runtime::model::CircleItem>.unwrap();
         let _: String = CircleItem.circle_id;
@@ -10444,6 +12472,18 @@ const _: fn() = || {
         let _: Option<String> = CodeResult.state;
         let _: Option<String> = CodeResult.action;
     }
+    {
+        let ContactGroup = None::<mixin_desktop_core::runtime::model::ContactGroup>.unwrap();
+        let _: String = ContactGroup.index;
+        let _: Vec<mixin_desktop_core::runtime::model::ContactItem> = ContactGroup.contacts;
+    }
+    {
+        let ContactItem = None::<mixin_desktop_core::runtime::model::ContactItem>.unwrap();
+        let _: mixin_desktop_core::runtime::model::UserProfileItem = ContactItem.user;
+        let _: Option<String> = ContactItem.alias;
+        let _: String = ContactItem.display_name;
+        let _: String = ContactItem.sort_key;
+    }
     {
         let ConversationChangeEvent =
             None::<mixin_desktop_api::model::ConversationChangeEvent>.unwrap();
@@ -10513,6 +12553,14 @@ const _: fn() = || {
         let _: String = ConversationParticipantItem.relationship;
         let _: Option<String> = ConversationParticipantItem.membership;
     }
+    {
+        let ConversationSearchHitView =
+            None::<mixin_desktop_core::runtime::model::ConversationSearchHitView>.unwrap();
+        let _: mixin_desktop_core::runtime::model::ConversationListData =
+            ConversationSearchHitView.conversation;
+        let _: i64 = ConversationSearchHitView.hit_count;
+        let _: i64 = ConversationSearchHitView.latest_created_at_millis;
+    }
     {
         let ConversationStorageUsage =
             None::<mixin_desktop_core::runtime::model::ConversationStorageUsage>.unwrap();
@@ -10528,6 +12576,21 @@ const _: fn() = || {
         let _: i64 = ConversationUnseenCount.count;
         let _: i64 = ConversationUnseenCount.muted_count;
     }
+    {
+        let GlobalSearchView =
+            None::<mixin_desktop_core::runtime::model::GlobalSearchView>.unwrap();
+        let _: String = GlobalSearchView.query;
+        let _: Vec<mixin_desktop_core::runtime::model::UserProfileItem> = GlobalSearchView.contacts;
+        let _: Option<String> = GlobalSearchView.contacts_cursor;
+        let _: Vec<mixin_desktop_core::runtime::model::ConversationListData> =
+            GlobalSearchView.groups;
+        let _: Option<String> = GlobalSearchView.groups_cursor;
+        let _: Vec<mixin_desktop_core::runtime::model::ConversationSearchHitView> =
+            GlobalSearchView.message_conversations;
+        let _: Option<String> = GlobalSearchView.message_conversations_cursor;
+        let _: Vec<mixin_desktop_core::runtime::model::MessageListView> = GlobalSearchView.files;
+        let _: Option<String> = GlobalSearchView.files_cursor;
+    }
     {
         let GroupAvatar = None::<mixin_desktop_core::runtime::model::GroupAvatar>.unwrap();
         let _: String = GroupAvatar.user_id;
@@ -10575,6 +12638,28 @@ const _: fn() = || {
         let _: bool = McpSettingsItem.draft_tools_enabled;
         let _: bool = McpSettingsItem.circle_management_enabled;
     }
+    {
+        let LinkPreviewView = None::<mixin_desktop_core::runtime::model::LinkPreviewView>.unwrap();
+        let _: String = LinkPreviewView.url;
+        let _: String = LinkPreviewView.site_name;
+        let _: String = LinkPreviewView.title;
+        let _: Option<String> = LinkPreviewView.description;
+        let _: Option<String> = LinkPreviewView.image_url;
+    }
+    {
+        let LocationView = None::<mixin_desktop_core::runtime::model::LocationView>.unwrap();
+        let _: f64 = LocationView.latitude;
+        let _: f64 = LocationView.longitude;
+        let _: Option<String> = LocationView.name;
+        let _: Option<String> = LocationView.address;
+    }
+    {
+        let MessageEditItem = None::<mixin_desktop_core::runtime::model::MessageEditItem>.unwrap();
+        let _: String = MessageEditItem.edit_id;
+        let _: String = MessageEditItem.message_id;
+        let _: String = MessageEditItem.content;
+        let _: i64 = MessageEditItem.edited_at_micros;
+    }
     {
         let MessageListView = None::<mixin_desktop_core::runtime::model::MessageListView>.unwrap();
         let _: String = MessageListView.message_id;
@@ -10651,6 +12736,14 @@ const _: fn() = || {
         let _: Option<bool> = MessageListView.mention_read;
         let _: bool = MessageListView.pinned;
         let _: Option<i64> = MessageListView.expire_in;
+        let _: Option<i64> = MessageListView.edited_at_micros;
+        let _: Vec<mixin_desktop_core::runtime::model::MessageReactionView> =
+            MessageListView.reactions;
+        let _: Option<mixin_desktop_core::runtime::model::LocationView> = MessageListView.location;
+        let _: Option<mixin_desktop_core::runtime::model::MessageSnippetView> =
+            MessageListView.snippet;
+        let _: Option<mixin_desktop_core::runtime::model::LinkPreviewView> =
+            MessageListView.link_preview;
     }
     {
         let MessageOrderInfoView =
@@ -10659,6 +12752,27 @@ const _: fn() = || {
         let _: i64 = MessageOrderInfoView.row_id;
         let _: i64 = MessageOrderInfoView.created_at_micros;
     }
+    {
+        let MessageReactionItem =
+            None::<mixin_desktop_core::runtime::model::MessageReactionItem>.unwrap();
+        let _: String = MessageReactionItem.user_id;
+        let _: String = MessageReactionItem.emoji;
+        let _: i64 = MessageReactionItem.created_at_micros;
+    }
+    {
+        let MessageReactionView =
+            None::<mixin_desktop_core::runtime::model::MessageReactionView>.unwrap();
+        let _: String = MessageReactionView.emoji;
+        let _: i64 = MessageReactionView.count;
+        let _: bool = MessageReactionView.reacted;
+    }
+    {
+        let MessageSnippetView =
+            None::<mixin_desktop_core::runtime::model::MessageSnippetView>.unwrap();
+        let _: String = MessageSnippetView.text;
+        let _: Vec<mixin_desktop_core::runtime::model::TextRangeView> =
+            MessageSnippetView.highlights;
+    }
     {
         let NotificationEvent =
             None::<mixin_desktop_core::runtime::model::NotificationEvent>.unwrap();
@@ -10672,6 +12786,18 @@ const _: fn() = || {
         let _: String = NotificationEvent.conversation_category;
         let _: Option<String> = NotificationEvent.dismiss_message_id;
     }
+    {
+        let OutgoingMessageItem =
+            None::<mixin_desktop_core::runtime::model::OutgoingMessageItem>.unwrap();
+        let _: String = OutgoingMessageItem.message_id;
+        let _: String = OutgoingMessageItem.conversation_id;
+        let _: String = OutgoingMessageItem.category;
+        let _: String = OutgoingMessageItem.status;
+        let _: i64 = OutgoingMessageItem.created_at_micros;
+        let _: i64 = OutgoingMessageItem.attempt_count;
+        let _: Option<String> = OutgoingMessageItem.last_error;
+        let _: Option<i64> = OutgoingMessageItem.next_attempt_at_micros;
+    }
     {
         let PinMessagePreviewItem =
             None::<mixin_desktop_core::runtime::model::PinMessagePreviewItem>.unwrap();
@@ -10694,6 +12820,11 @@ const _: fn() = || {
         let _: Option<String> = ProxySettingsItem.selected_proxy_id;
         let _: Vec<mixin_desktop_api::model::ProxyItem> = ProxySettingsItem.proxies;
     }
+    {
+        let SessionLinkedItem = None::<mixin_desktop_api::model::SessionLinkedItem>.unwrap();
+        let _: String = SessionLinkedItem.session_id;
+        let _: i64 = SessionLinkedItem.linked_at_micros;
+    }
     {
         let SharedAppItem = None::<mixin_desktop_core::runtime::model::SharedAppItem>.unwrap();
         let _: String = SharedAppItem.app_id;
@@ -10776,6 +12907,18 @@ const _: fn() = || {
         let _: String = StorageCategoryUsage.category;
         let _: i64 = StorageCategoryUsage.size_bytes;
     }
+    {
+        let TextRangeView = None::<mixin_desktop_core::runtime::model::TextRangeView>.unwrap();
+        let _: u32 = TextRangeView.start;
+        let _: u32 = TextRangeView.end;
+    }
+    {
+        let UserAnnotationItem =
+            None::<mixin_desktop_core::runtime::model::UserAnnotationItem>.unwrap();
+        let _: String = UserAnnotationItem.user_id;
+        let _: Option<String> = UserAnnotationItem.alias;
+        let _: Option<String> = UserAnnotationItem.note;
+    }
     {
         let UserProfileItem = None::<mixin_desktop_core::runtime::model::UserProfileItem>.unwrap();
         let _: String = UserProfileItem.user_id;
@@ -10789,6 +12932,13 @@ const _: fn() = || {
         let _: String = UserProfileItem.code_url;
         let _: Option<String> = UserProfileItem.membership;
     }
+    {
+        let VideoRangeItem = None::<mixin_desktop_core::runtime::model::VideoRangeItem>.unwrap();
+        let _: Vec<u8> = VideoRangeItem.data;
+        let _: u64 = VideoRangeItem.offset;
+        let _: u64 = VideoRangeItem.total_size;
+        let _: String = VideoRangeItem.mime_type;
+    }
 };
 
 // Section: related_funcs
@@ -10817,6 +12967,12 @@ flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>
 );
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>
+);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>
 );
@@ -10917,6 +13073,26 @@ impl SseDecode for MessageAccess {
     }
 }
 
+impl SseDecode for SearchAccess {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for SearchHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
 impl SseDecode for SettingsHandle {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11035,6 +13211,26 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
 impl SseDecode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>>
 {
@@ -11073,6 +13269,19 @@ impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCod
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        mixin_desktop_api::model::AccountNotificationItem,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         mixin_desktop_api::model::AccountProfile,
@@ -11128,6 +13337,32 @@ impl SseDecode for StreamSink<i64, flutter_rust_bridge::for_generated::SseCodec>
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        mixin_desktop_core::runtime::model::GlobalSearchView,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        Vec<mixin_desktop_api::model::AccountUnreadCountItem>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         Vec<mixin_desktop_core::runtime::model::CircleItem>,
@@ -11154,6 +13389,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<crate::api::login::LoginStatus, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::api::media::MediaPlaybackEvent,
@@ -11201,6 +13446,19 @@ impl SseDecode for StreamSink<Option<String>, flutter_rust_bridge::for_generated
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        mixin_desktop_api::model::SessionLinkedItem,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode for StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11217,6 +13475,19 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for mixin_desktop_api::model::AccountNotificationItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accountId = <String>::sse_decode(deserializer);
+        let mut var_event =
+            <mixin_desktop_core::runtime::model::NotificationEvent>::sse_decode(deserializer);
+        return mixin_desktop_api::model::AccountNotificationItem {
+            account_id: var_accountId,
+            event: var_event,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_api::model::AccountProfile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11245,6 +13516,38 @@ impl SseDecode for mixin_desktop_api::model::AccountProfile {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::AccountSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sessionId = <String>::sse_decode(deserializer);
+        let mut var_platform = <Option<String>>::sse_decode(deserializer);
+        let mut var_appVersion = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastSeenAt = <Option<String>>::sse_decode(deserializer);
+        let mut var_isCurrent = <bool>::sse_decode(deserializer);
+        let mut var_isPrimary = <bool>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::AccountSession {
+            session_id: var_sessionId,
+            platform: var_platform,
+            app_version: var_appVersion,
+            last_seen_at: var_lastSeenAt,
+            is_current: var_isCurrent,
+            is_primary: var_isPrimary,
+        };
+    }
+}
+
+impl SseDecode for mixin_desktop_api::model::AccountUnreadCountItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_accountId = <String>::sse_decode(deserializer);
+        let mut var_unseenMessageCount = <i64>::sse_decode(deserializer);
+        return mixin_desktop_api::model::AccountUnreadCountItem {
+            account_id: var_accountId,
+            unseen_message_count: var_unseenMessageCount,
+        };
+    }
+}
+
 impl SseDecode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11321,6 +13624,36 @@ impl SseDecode for crate::api::device_transfer::ConnectionFailedReason {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::ContactGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <String>::sse_decode(deserializer);
+        let mut var_contacts =
+            <Vec<mixin_desktop_core::runtime::model::ContactItem>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::ContactGroup {
+            index: var_index,
+            contacts: var_contacts,
+        };
+    }
+}
+
+impl SseDecode for mixin_desktop_core::runtime::model::ContactItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_user =
+            <mixin_desktop_core::runtime::model::UserProfileItem>::sse_decode(deserializer);
+        let mut var_alias = <Option<String>>::sse_decode(deserializer);
+        let mut var_displayName = <String>::sse_decode(deserializer);
+        let mut var_sortKey = <String>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::ContactItem {
+            user: var_user,
+            alias: var_alias,
+            display_name: var_displayName,
+            sort_key: var_sortKey,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_api::model::ConversationChangeEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11458,6 +13791,21 @@ impl SseDecode for mixin_desktop_core::runtime::model::ConversationParticipantIt
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::ConversationSearchHitView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversation =
+            <mixin_desktop_core::runtime::model::ConversationListData>::sse_decode(deserializer);
+        let mut var_hitCount = <i64>::sse_decode(deserializer);
+        let mut var_latestCreatedAtMillis = <i64>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::ConversationSearchHitView {
+            conversation: var_conversation,
+            hit_count: var_hitCount,
+            latest_created_at_millis: var_latestCreatedAtMillis,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::ConversationStorageUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11618,6 +13966,39 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::GlobalSearchView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_query = <String>::sse_decode(deserializer);
+        let mut var_contacts =
+            <Vec<mixin_desktop_core::runtime::model::UserProfileItem>>::sse_decode(deserializer);
+        let mut var_contactsCursor = <Option<String>>::sse_decode(deserializer);
+        let mut var_groups =
+            <Vec<mixin_desktop_core::runtime::model::ConversationListData>>::sse_decode(
+                deserializer,
+            );
+        let mut var_groupsCursor = <Option<String>>::sse_decode(deserializer);
+        let mut var_messageConversations = <Vec<
+            mixin_desktop_core::runtime::model::ConversationSearchHitView,
+        >>::sse_decode(deserializer);
+        let mut var_messageConversationsCursor = <Option<String>>::sse_decode(deserializer);
+        let mut var_files =
+            <Vec<mixin_desktop_core::runtime::model::MessageListView>>::sse_decode(deserializer);
+        let mut var_filesCursor = <Option<String>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::GlobalSearchView {
+            query: var_query,
+            contacts: var_contacts,
+            contacts_cursor: var_contactsCursor,
+            groups: var_groups,
+            groups_cursor: var_groupsCursor,
+            message_conversations: var_messageConversations,
+            message_conversations_cursor: var_messageConversationsCursor,
+            files: var_files,
+            files_cursor: var_filesCursor,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::GroupAvatar {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11704,13 +14085,51 @@ impl SseDecode for mixin_desktop_core::runtime::model::ImageMessageView {
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for Vec<AccountHandle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<AccountHandle>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::AccountSession> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::AccountSession>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<mixin_desktop_api::model::AccountUnreadCountItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = Vec::with_capacity(len_ as usize);
         for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+            ans_.push(<mixin_desktop_api::model::AccountUnreadCountItem>::sse_decode(deserializer));
         }
         return ans_;
     }
@@ -11728,6 +14147,30 @@ impl SseDecode for Vec<mixin_desktop_core::runtime::model::CircleItem> {
     }
 }
 
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::ContactGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<mixin_desktop_core::runtime::model::ContactGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::ContactItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<mixin_desktop_core::runtime::model::ContactItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<mixin_desktop_core::runtime::model::ConversationListData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11760,6 +14203,22 @@ impl SseDecode for Vec<mixin_desktop_core::runtime::model::ConversationParticipa
     }
 }
 
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::ConversationSearchHitView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::ConversationSearchHitView>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<mixin_desktop_core::runtime::model::ConversationStorageUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11848,6 +14307,20 @@ impl SseDecode for Vec<crate::api::media::MediaAudioItem> {
     }
 }
 
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::MessageEditItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::MessageEditItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<mixin_desktop_core::runtime::model::MessageListView> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11862,6 +14335,48 @@ impl SseDecode for Vec<mixin_desktop_core::runtime::model::MessageListView> {
     }
 }
 
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::MessageReactionItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::MessageReactionItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::MessageReactionView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::MessageReactionView>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::OutgoingMessageItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::OutgoingMessageItem>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11956,6 +14471,20 @@ impl SseDecode for Vec<mixin_desktop_core::runtime::model::StorageCategoryUsage>
     }
 }
 
+impl SseDecode for Vec<mixin_desktop_core::runtime::model::TextRangeView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(
+                <mixin_desktop_core::runtime::model::TextRangeView>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<mixin_desktop_core::runtime::model::UserProfileItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -11970,6 +14499,42 @@ impl SseDecode for Vec<mixin_desktop_core::runtime::model::UserProfileItem> {
     }
 }
 
+impl SseDecode for crate::api::login::LoginStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_authUrl = <String>::sse_decode(deserializer);
+                return crate::api::login::LoginStatus::WaitingForScan {
+                    auth_url: var_authUrl,
+                };
+            }
+            1 => {
+                return crate::api::login::LoginStatus::Verifying;
+            }
+            2 => {
+                return crate::api::login::LoginStatus::SyncingKeys;
+            }
+            3 => {
+                return crate::api::login::LoginStatus::Ready;
+            }
+            4 => {
+                return crate::api::login::LoginStatus::Expired;
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::login::LoginStatus::Failed {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for mixin_desktop_api::model::McpServerStatusItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12141,6 +14706,56 @@ impl SseDecode for crate::api::media::MediaVoiceRecording {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::LinkPreviewView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_siteName = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_imageUrl = <Option<String>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::LinkPreviewView {
+            url: var_url,
+            site_name: var_siteName,
+            title: var_title,
+            description: var_description,
+            image_url: var_imageUrl,
+        };
+    }
+}
+
+impl SseDecode for mixin_desktop_core::runtime::model::LocationView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_latitude = <f64>::sse_decode(deserializer);
+        let mut var_longitude = <f64>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_address = <Option<String>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::LocationView {
+            latitude: var_latitude,
+            longitude: var_longitude,
+            name: var_name,
+            address: var_address,
+        };
+    }
+}
+
+impl SseDecode for mixin_desktop_core::runtime::model::MessageEditItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_editId = <String>::sse_decode(deserializer);
+        let mut var_messageId = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_editedAtMicros = <i64>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::MessageEditItem {
+            edit_id: var_editId,
+            message_id: var_messageId,
+            content: var_content,
+            edited_at_micros: var_editedAtMicros,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::MessageListView {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12218,6 +14833,19 @@ impl SseDecode for mixin_desktop_core::runtime::model::MessageListView {
         let mut var_mentionRead = <Option<bool>>::sse_decode(deserializer);
         let mut var_pinned = <bool>::sse_decode(deserializer);
         let mut var_expireIn = <Option<i64>>::sse_decode(deserializer);
+        let mut var_editedAtMicros = <Option<i64>>::sse_decode(deserializer);
+        let mut var_reactions =
+            <Vec<mixin_desktop_core::runtime::model::MessageReactionView>>::sse_decode(
+                deserializer,
+            );
+        let mut var_location =
+            <Option<mixin_desktop_core::runtime::model::LocationView>>::sse_decode(deserializer);
+        let mut var_snippet =
+            <Option<mixin_desktop_core::runtime::model::MessageSnippetView>>::sse_decode(
+                deserializer,
+            );
+        let mut var_linkPreview =
+            <Option<mixin_desktop_core::runtime::model::LinkPreviewView>>::sse_decode(deserializer);
         return mixin_desktop_core::runtime::model::MessageListView {
             message_id: var_messageId,
             conversation_id: var_conversationId,
@@ -12293,6 +14921,11 @@ impl SseDecode for mixin_desktop_core::runtime::model::MessageListView {
             mention_read: var_mentionRead,
             pinned: var_pinned,
             expire_in: var_expireIn,
+            edited_at_micros: var_editedAtMicros,
+            reactions: var_reactions,
+            location: var_location,
+            snippet: var_snippet,
+            link_preview: var_linkPreview,
         };
     }
 }
@@ -12311,6 +14944,60 @@ impl SseDecode for mixin_desktop_core::runtime::model::MessageOrderInfoView {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::MessageReactionItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_emoji = <String>::sse_decode(deserializer);
+        let mut var_createdAtMicros = <i64>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::MessageReactionItem {
+            user_id: var_userId,
+            emoji: var_emoji,
+            created_at_micros: var_createdAtMicros,
+        };
+    }
+}
+
+impl SseDecode for mixin_desktop_core::runtime::model::MessageReactionView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_emoji = <String>::sse_decode(deserializer);
+        let mut var_count = <i64>::sse_decode(deserializer);
+        let mut var_reacted = <bool>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::MessageReactionView {
+            emoji: var_emoji,
+            count: var_count,
+            reacted: var_reacted,
+        };
+    }
+}
+
+impl SseDecode for mixin_desktop_core::runtime::model::MessageSnippetView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_highlights =
+            <Vec<mixin_desktop_core::runtime::model::TextRangeView>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::MessageSnippetView {
+            text: var_text,
+            highlights: var_highlights,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop::NotificationActionKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::desktop::NotificationActionKind::Reply,
+            1 => crate::api::desktop::NotificationActionKind::MarkRead,
+            2 => crate::api::desktop::NotificationActionKind::MuteForOneHour,
+            _ => unreachable!("Invalid variant for NotificationActionKind: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::NotificationEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12337,6 +15024,17 @@ impl SseDecode for mixin_desktop_core::runtime::model::NotificationEvent {
     }
 }
 
+impl SseDecode for Option<AccountHandle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<AccountHandle>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12418,6 +15116,32 @@ impl SseDecode for Option<crate::api::media::MediaVoiceRecording> {
     }
 }
 
+impl SseDecode for Option<mixin_desktop_core::runtime::model::LinkPreviewView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <mixin_desktop_core::runtime::model::LinkPreviewView>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<mixin_desktop_core::runtime::model::LocationView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <mixin_desktop_core::runtime::model::LocationView>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<mixin_desktop_core::runtime::model::MessageOrderInfoView> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12433,6 +15157,19 @@ impl SseDecode for Option<mixin_desktop_core::runtime::model::MessageOrderInfoVi
     }
 }
 
+impl SseDecode for Option<mixin_desktop_core::runtime::model::MessageSnippetView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <mixin_desktop_core::runtime::model::MessageSnippetView>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<mixin_desktop_core::runtime::model::PinMessagePreviewItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12472,6 +15209,19 @@ impl SseDecode for Option<u64> {
     }
 }
 
+impl SseDecode for Option<mixin_desktop_core::runtime::model::UserAnnotationItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <mixin_desktop_core::runtime::model::UserAnnotationItem>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<mixin_desktop_core::runtime::model::UserProfileItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12496,6 +15246,30 @@ impl SseDecode for Option<Vec<u8>> {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::OutgoingMessageItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messageId = <String>::sse_decode(deserializer);
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_createdAtMicros = <i64>::sse_decode(deserializer);
+        let mut var_attemptCount = <i64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        let mut var_nextAttemptAtMicros = <Option<i64>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::OutgoingMessageItem {
+            message_id: var_messageId,
+            conversation_id: var_conversationId,
+            category: var_category,
+            status: var_status,
+            created_at_micros: var_createdAtMicros,
+            attempt_count: var_attemptCount,
+            last_error: var_lastError,
+            next_attempt_at_micros: var_nextAttemptAtMicros,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::PinMessagePreviewItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12553,6 +15327,18 @@ impl SseDecode for (String, String) {
     }
 }
 
+impl SseDecode for mixin_desktop_api::model::SessionLinkedItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sessionId = <String>::sse_decode(deserializer);
+        let mut var_linkedAtMicros = <i64>::sse_decode(deserializer);
+        return mixin_desktop_api::model::SessionLinkedItem {
+            session_id: var_sessionId,
+            linked_at_micros: var_linkedAtMicros,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::SharedAppItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12722,6 +15508,18 @@ impl SseDecode for mixin_desktop_core::runtime::model::StorageCategoryUsage {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::TextRangeView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::TextRangeView {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
 impl SseDecode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12755,6 +15553,20 @@ impl SseDecode for () {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::UserAnnotationItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_alias = <Option<String>>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::UserAnnotationItem {
+            user_id: var_userId,
+            alias: var_alias,
+            note: var_note,
+        };
+    }
+}
+
 impl SseDecode for mixin_desktop_core::runtime::model::UserProfileItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -12790,6 +15602,22 @@ impl SseDecode for usize {
     }
 }
 
+impl SseDecode for mixin_desktop_core::runtime::model::VideoRangeItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_offset = <u64>::sse_decode(deserializer);
+        let mut var_totalSize = <u64>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        return mixin_desktop_core::runtime::model::VideoRangeItem {
+            data: var_data,
+            offset: var_offset,
+            total_size: var_totalSize,
+            mime_type: var_mimeType,
+        };
+    }
+}
+
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -12853,103 +15681,121 @@ fn pde_ffi_dispatcher_primary_impl(
             rust_vec_len,
             data_len,
         ),
-        16 => wire__crate__api__account__AccountHandle_message_changes_impl(
+        14 => wire__crate__api__account__AccountHandle_linked_sessions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__account__AccountHandle_message_changes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__account__AccountHandle_profile_changes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        18 => wire__crate__api__account__AccountHandle_profile_changes_impl(
+        20 => wire__crate__api__account__AccountHandle_refresh_account_health_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        19 => wire__crate__api__account__AccountHandle_refresh_account_health_impl(
+        21 => wire__crate__api__account__AccountHandle_refresh_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        20 => wire__crate__api__account__AccountHandle_refresh_profile_impl(
+        23 => wire__crate__api__account__AccountHandle_revoke_linked_session_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        22 => wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
+        24 => wire__crate__api__account__AccountHandle_safe_snapshot_by_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        23 => wire__crate__api__account__AccountHandle_shutdown_impl(
+        27 => wire__crate__api__account__AccountHandle_session_linked_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        24 => wire__crate__api__account__AccountHandle_sign_out_impl(
+        28 => wire__crate__api__account__AccountHandle_shutdown_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        25 => wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
+        29 => wire__crate__api__account__AccountHandle_sign_out_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        26 => wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
+        30 => wire__crate__api__account__AccountHandle_snapshot_by_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__account__AccountHandle_storage_usage_impl(
+        31 => wire__crate__api__account__AccountHandle_snapshot_by_trace_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => wire__crate__api__account__AccountHandle_unseen_count_changes_impl(
+        33 => wire__crate__api__account__AccountHandle_storage_usage_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => wire__crate__api__account__AccountHandle_unseen_message_count_changes_impl(
+        34 => wire__crate__api__account__AccountHandle_unseen_count_changes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__account__AccountHandle_update_profile_impl(
+        35 => wire__crate__api__account__AccountHandle_unseen_message_count_changes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        33 => wire__mixin_desktop_api__access__AttachmentAccess_cancel_attachment_impl(
+        36 => wire__crate__api__account__AccountHandle_update_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        34 => wire__mixin_desktop_api__access__AttachmentAccess_cancel_transcript_attachment_impl(
+        38 => wire__mixin_desktop_api__access__AttachmentAccess_cancel_attachment_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        35 => wire__mixin_desktop_api__access__AttachmentAccess_download_attachment_impl(
+        39 => wire__mixin_desktop_api__access__AttachmentAccess_cancel_transcript_attachment_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => {
+        40 => wire__mixin_desktop_api__access__AttachmentAccess_download_attachment_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => {
             wire__mixin_desktop_api__access__AttachmentAccess_download_transcript_attachment_impl(
                 port,
                 ptr,
@@ -12957,785 +15803,944 @@ fn pde_ffi_dispatcher_primary_impl(
                 data_len,
             )
         }
-        37 => wire__mixin_desktop_api__access__AttachmentAccess_mark_audio_read_impl(
+        42 => wire__mixin_desktop_api__access__AttachmentAccess_mark_audio_read_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__mixin_desktop_api__access__AttachmentAccess_mark_transcript_audio_read_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__mixin_desktop_api__access__AttachmentAccess_read_video_range_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__mixin_desktop_api__access__AttachmentAccess_retry_attachment_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__mixin_desktop_api__access__AttachmentAccess_retry_transcript_attachment_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__mixin_desktop_api__access__ConversationAccess_circles_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__mixin_desktop_api__access__ConversationAccess_clear_conversation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => wire__mixin_desktop_api__access__ConversationAccess_conversation_count_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__mixin_desktop_api__access__AttachmentAccess_mark_transcript_audio_read_impl(
+        50 => wire__mixin_desktop_api__access__ConversationAccess_conversation_detail_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => wire__mixin_desktop_api__access__AttachmentAccess_retry_attachment_impl(
+        51 => wire__mixin_desktop_api__access__ConversationAccess_conversation_items_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        40 => wire__mixin_desktop_api__access__AttachmentAccess_retry_transcript_attachment_impl(
+        52 => wire__mixin_desktop_api__access__ConversationAccess_conversation_items_by_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        41 => wire__mixin_desktop_api__access__ConversationAccess_circles_impl(
+        53 => wire__mixin_desktop_api__access__ConversationAccess_conversation_participants_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        42 => wire__mixin_desktop_api__access__ConversationAccess_clear_conversation_impl(
+        54 => wire__mixin_desktop_api__access__ConversationAccess_conversations_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        43 => wire__mixin_desktop_api__access__ConversationAccess_conversation_count_impl(
+        55 => wire__mixin_desktop_api__access__ConversationAccess_create_circle_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        44 => wire__mixin_desktop_api__access__ConversationAccess_conversation_detail_impl(
+        56 => wire__mixin_desktop_api__access__ConversationAccess_create_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => wire__mixin_desktop_api__access__ConversationAccess_conversation_items_impl(
+        57 => wire__mixin_desktop_api__access__ConversationAccess_current_user_role_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        46 => wire__mixin_desktop_api__access__ConversationAccess_conversation_items_by_ids_impl(
+        58 => wire__mixin_desktop_api__access__ConversationAccess_delete_circle_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        47 => wire__mixin_desktop_api__access__ConversationAccess_conversation_participants_impl(
+        59 => wire__mixin_desktop_api__access__ConversationAccess_delete_conversation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => wire__mixin_desktop_api__access__ConversationAccess_conversations_impl(
+        60 => wire__mixin_desktop_api__access__ConversationAccess_edit_circle_conversation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        49 => wire__mixin_desktop_api__access__ConversationAccess_create_circle_impl(
+        61 => wire__mixin_desktop_api__access__ConversationAccess_edit_conversation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__mixin_desktop_api__access__ConversationAccess_create_group_impl(
+        62 => wire__mixin_desktop_api__access__ConversationAccess_exit_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        51 => wire__mixin_desktop_api__access__ConversationAccess_current_user_role_impl(
+        63 => wire__mixin_desktop_api__access__ConversationAccess_groups_in_common_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        52 => wire__mixin_desktop_api__access__ConversationAccess_delete_circle_impl(
+        64 => wire__mixin_desktop_api__access__ConversationAccess_is_bot_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        53 => wire__mixin_desktop_api__access__ConversationAccess_delete_conversation_impl(
+        65 => wire__mixin_desktop_api__access__ConversationAccess_join_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__mixin_desktop_api__access__ConversationAccess_edit_circle_conversation_impl(
+        66 => wire__mixin_desktop_api__access__ConversationAccess_local_conversation_detail_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        55 => wire__mixin_desktop_api__access__ConversationAccess_edit_conversation_impl(
+        67 => wire__mixin_desktop_api__access__ConversationAccess_open_user_conversation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        56 => wire__mixin_desktop_api__access__ConversationAccess_exit_group_impl(
+        68 => wire__mixin_desktop_api__access__ConversationAccess_reorder_circles_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        57 => wire__mixin_desktop_api__access__ConversationAccess_groups_in_common_impl(
+        69 => wire__mixin_desktop_api__access__ConversationAccess_resolve_code_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        58 => wire__mixin_desktop_api__access__ConversationAccess_is_bot_group_impl(
+        70 => wire__mixin_desktop_api__access__ConversationAccess_rotate_group_invite_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        59 => wire__mixin_desktop_api__access__ConversationAccess_join_group_impl(
+        71 => wire__mixin_desktop_api__access__ConversationAccess_search_bot_group_users_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__mixin_desktop_api__access__ConversationAccess_local_conversation_detail_impl(
+        72 => wire__mixin_desktop_api__access__ConversationAccess_search_group_users_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        61 => wire__mixin_desktop_api__access__ConversationAccess_open_user_conversation_impl(
+        73 => wire__mixin_desktop_api__access__ConversationAccess_set_disappearing_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__mixin_desktop_api__access__ConversationAccess_reorder_circles_impl(
+        74 => wire__mixin_desktop_api__access__ConversationAccess_set_muted_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        63 => wire__mixin_desktop_api__access__ConversationAccess_resolve_code_impl(
+        75 => wire__mixin_desktop_api__access__ConversationAccess_set_pinned_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        64 => wire__mixin_desktop_api__access__ConversationAccess_rotate_group_invite_impl(
+        76 => wire__mixin_desktop_api__access__ConversationAccess_update_circle_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        65 => wire__mixin_desktop_api__access__ConversationAccess_search_bot_group_users_impl(
+        77 => wire__mixin_desktop_api__access__ConversationAccess_update_draft_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        66 => wire__mixin_desktop_api__access__ConversationAccess_search_group_users_impl(
+        78 => wire__mixin_desktop_api__access__ConversationAccess_update_participants_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        67 => wire__mixin_desktop_api__access__ConversationAccess_set_disappearing_messages_impl(
+        79 => wire__crate__api__desktop__DesktopHandle_abort_saved_login_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__mixin_desktop_api__access__ConversationAccess_set_muted_impl(
+        80 => wire__crate__api__desktop__DesktopHandle_accounts_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__mixin_desktop_api__access__ConversationAccess_set_pinned_impl(
+        81 => wire__crate__api__desktop__DesktopHandle_active_account_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__mixin_desktop_api__access__ConversationAccess_update_circle_impl(
+        82 => wire__crate__api__desktop__DesktopHandle_begin_login_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__mixin_desktop_api__access__ConversationAccess_update_draft_impl(
+        83 => wire__crate__api__desktop__DesktopHandle_http_request_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        72 => wire__mixin_desktop_api__access__ConversationAccess_update_participants_impl(
+        85 => wire__crate__api__desktop__DesktopHandle_notification_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__desktop__DesktopHandle_abort_saved_login_impl(
+        86 => wire__crate__api__desktop__DesktopHandle_perform_notification_action_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        74 => wire__crate__api__desktop__DesktopHandle_begin_login_impl(
+        87 => wire__crate__api__desktop__DesktopHandle_recreate_account_database_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        75 => wire__crate__api__desktop__DesktopHandle_http_request_impl(
+        88 => wire__crate__api__desktop__DesktopHandle_restore_account_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        77 => wire__crate__api__desktop__DesktopHandle_recreate_account_database_impl(
+        89 => wire__crate__api__desktop__DesktopHandle_restore_accounts_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__desktop__DesktopHandle_restore_account_impl(
+        91 => wire__crate__api__desktop__DesktopHandle_switch_account_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => wire__crate__api__login__LoginHandle_wait_impl(port, ptr, rust_vec_len, data_len),
-        83 => wire__crate__api__media__MediaHandle_audio_playback_events_impl(
+        92 => wire__crate__api__desktop__DesktopHandle_unread_count_changes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        85 => wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
+        95 => wire__crate__api__login__LoginHandle_status_changes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        86 => {
+        96 => wire__crate__api__login__LoginHandle_wait_impl(port, ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__media__MediaHandle_audio_playback_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__media__MediaHandle_cancel_voice_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => {
             wire__crate__api__media__MediaHandle_pause_audio_impl(port, ptr, rust_vec_len, data_len)
         }
-        87 => {
+        101 => {
             wire__crate__api__media__MediaHandle_play_audio_impl(port, ptr, rust_vec_len, data_len)
         }
-        88 => wire__crate__api__media__MediaHandle_resume_audio_impl(
+        102 => wire__crate__api__media__MediaHandle_resume_audio_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => wire__crate__api__media__MediaHandle_set_audio_speed_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__media__MediaHandle_start_voice_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        107 => wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        108 => wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__mixin_desktop_api__access__MessageAccess_cancel_outgoing_message_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => wire__mixin_desktop_api__access__MessageAccess_edit_message_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__mixin_desktop_api__access__MessageAccess_failed_outgoing_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        118 => wire__mixin_desktop_api__access__MessageAccess_load_link_preview_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        90 => wire__crate__api__media__MediaHandle_set_audio_speed_impl(
+        120 => wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__media__MediaHandle_start_voice_recording_impl(
+        121 => wire__mixin_desktop_api__access__MessageAccess_message_edit_history_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__media__MediaHandle_stop_voice_recording_impl(
+        122 => wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        94 => wire__crate__api__media__MediaHandle_voice_recorder_events_impl(
+        123 => wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        96 => wire__mixin_desktop_api__access__MessageAccess_combine_forward_messages_impl(
+        124 => wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        97 => wire__mixin_desktop_api__access__MessageAccess_conversation_is_encrypted_impl(
+        125 => wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        98 => wire__mixin_desktop_api__access__MessageAccess_delete_messages_impl(
+        126 => wire__mixin_desktop_api__access__MessageAccess_message_reactions_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        99 => wire__mixin_desktop_api__access__MessageAccess_forward_messages_impl(
+        127 => wire__mixin_desktop_api__access__MessageAccess_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        100 => wire__mixin_desktop_api__access__MessageAccess_image_messages_around_impl(
+        128 => wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => wire__mixin_desktop_api__access__MessageAccess_mark_conversation_read_impl(
+        129 => wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => wire__mixin_desktop_api__access__MessageAccess_mark_mention_read_impl(
+        130 => wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        103 => wire__mixin_desktop_api__access__MessageAccess_message_ids_after_impl(
+        131 => wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        104 => wire__mixin_desktop_api__access__MessageAccess_message_ids_before_impl(
+        132 => wire__mixin_desktop_api__access__MessageAccess_react_to_message_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        105 => wire__mixin_desktop_api__access__MessageAccess_message_items_by_ids_impl(
+        133 => wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        106 => wire__mixin_desktop_api__access__MessageAccess_message_order_info_impl(
+        134 => wire__mixin_desktop_api__access__MessageAccess_retry_outgoing_message_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => wire__mixin_desktop_api__access__MessageAccess_messages_impl(
+        135 => wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        108 => wire__mixin_desktop_api__access__MessageAccess_messages_around_impl(
+        136 => wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__mixin_desktop_api__access__MessageAccess_pin_message_preview_impl(
+        137 => wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__mixin_desktop_api__access__MessageAccess_pinned_message_ids_impl(
+        138 => wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        111 => wire__mixin_desktop_api__access__MessageAccess_pinned_messages_impl(
+        139 => wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        112 => wire__mixin_desktop_api__access__MessageAccess_recall_messages_impl(
+        140 => wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        113 => wire__mixin_desktop_api__access__MessageAccess_search_global_messages_impl(
+        141 => wire__mixin_desktop_api__access__MessageAccess_send_location_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        114 => wire__mixin_desktop_api__access__MessageAccess_search_messages_impl(
+        142 => wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        115 => wire__mixin_desktop_api__access__MessageAccess_send_app_card_impl(
+        143 => wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        116 => wire__mixin_desktop_api__access__MessageAccess_send_attachment_impl(
+        144 => wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        117 => wire__mixin_desktop_api__access__MessageAccess_send_audio_impl(
+        145 => wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        118 => wire__mixin_desktop_api__access__MessageAccess_send_contact_impl(
+        146 => wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        119 => wire__mixin_desktop_api__access__MessageAccess_send_post_impl(
+        147 => wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        120 => wire__mixin_desktop_api__access__MessageAccess_send_remote_image_impl(
+        148 => wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        121 => wire__mixin_desktop_api__access__MessageAccess_send_sticker_impl(
+        149 => wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        122 => wire__mixin_desktop_api__access__MessageAccess_send_text_impl(
+        150 => wire__mixin_desktop_api__access__SearchAccess_global_search_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        123 => wire__mixin_desktop_api__access__MessageAccess_set_message_pinned_impl(
+        151 => wire__mixin_desktop_api__access__SearchAccess_global_search_section_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        124 => wire__mixin_desktop_api__access__MessageAccess_shared_messages_impl(
+        152 => {
+            wire__crate__api__search__SearchHandle_results_impl(port, ptr, rust_vec_len, data_len)
+        }
+        154 => wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        155 => wire__crate__api__desktop__SettingsHandle_mcp_account_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        156 => wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        157 => wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        125 => wire__mixin_desktop_api__access__MessageAccess_transcript_messages_impl(
+        158 => wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        126 => wire__mixin_desktop_api__access__MessageAccess_unread_mention_message_ids_impl(
+        159 => wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        127 => wire__crate__api__desktop__SettingsHandle_file_auto_download_impl(
+        160 => wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        128 => wire__crate__api__desktop__SettingsHandle_mcp_server_status_impl(
+        161 => wire__crate__api__desktop__SettingsHandle_set_mcp_account_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        129 => wire__crate__api__desktop__SettingsHandle_mcp_settings_impl(
+        162 => wire__crate__api__desktop__SettingsHandle_set_photo_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        130 => wire__crate__api__desktop__SettingsHandle_photo_auto_download_impl(
+        163 => wire__crate__api__desktop__SettingsHandle_set_proxy_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        131 => wire__crate__api__desktop__SettingsHandle_proxy_settings_impl(
+        164 => wire__crate__api__desktop__SettingsHandle_set_setting_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        132 => wire__crate__api__desktop__SettingsHandle_set_file_auto_download_impl(
+        165 => wire__crate__api__desktop__SettingsHandle_set_video_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        133 => wire__crate__api__desktop__SettingsHandle_set_photo_auto_download_impl(
+        166 => wire__crate__api__desktop__SettingsHandle_setting_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        134 => wire__crate__api__desktop__SettingsHandle_set_proxy_settings_impl(
+        167 => wire__crate__api__desktop__SettingsHandle_subscribe_file_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        135 => wire__crate__api__desktop__SettingsHandle_set_setting_impl(
+        168 => wire__crate__api__desktop__SettingsHandle_subscribe_photo_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        136 => wire__crate__api__desktop__SettingsHandle_set_video_auto_download_impl(
+        169 => wire__crate__api__desktop__SettingsHandle_subscribe_setting_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        137 => wire__crate__api__desktop__SettingsHandle_setting_impl(
+        170 => wire__crate__api__desktop__SettingsHandle_subscribe_video_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        138 => wire__crate__api__desktop__SettingsHandle_subscribe_file_auto_download_impl(
+        171 => wire__crate__api__desktop__SettingsHandle_update_mcp_settings_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        139 => wire__crate__api__desktop__SettingsHandle_subscribe_photo_auto_download_impl(
+        172 => wire__crate__api__desktop__SettingsHandle_video_auto_download_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        140 => wire__crate__api__desktop__SettingsHandle_subscribe_setting_impl(
+        173 => wire__mixin_desktop_api__access__StickerAccess_add_sticker_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        141 => wire__crate__api__desktop__SettingsHandle_subscribe_video_auto_download_impl(
+        174 => wire__mixin_desktop_api__access__StickerAccess_add_sticker_from_file_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        142 => wire__crate__api__desktop__SettingsHandle_update_mcp_settings_impl(
+        175 => wire__mixin_desktop_api__access__StickerAccess_add_sticker_from_path_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        143 => wire__crate__api__desktop__SettingsHandle_video_auto_download_impl(
+        176 => wire__mixin_desktop_api__access__StickerAccess_album_stickers_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        144 => wire__mixin_desktop_api__access__StickerAccess_add_sticker_impl(
+        177 => wire__mixin_desktop_api__access__StickerAccess_personal_stickers_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        145 => wire__mixin_desktop_api__access__StickerAccess_add_sticker_from_file_impl(
+        178 => wire__mixin_desktop_api__access__StickerAccess_recent_stickers_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        146 => wire__mixin_desktop_api__access__StickerAccess_add_sticker_from_path_impl(
+        179 => wire__mixin_desktop_api__access__StickerAccess_refresh_sticker_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        147 => wire__mixin_desktop_api__access__StickerAccess_album_stickers_impl(
+        180 => wire__mixin_desktop_api__access__StickerAccess_refresh_stickers_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        148 => wire__mixin_desktop_api__access__StickerAccess_personal_stickers_impl(
+        181 => wire__mixin_desktop_api__access__StickerAccess_remove_sticker_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        149 => wire__mixin_desktop_api__access__StickerAccess_recent_stickers_impl(
+        182 => wire__mixin_desktop_api__access__StickerAccess_set_sticker_album_added_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        150 => wire__mixin_desktop_api__access__StickerAccess_refresh_sticker_impl(
+        183 => wire__mixin_desktop_api__access__StickerAccess_set_sticker_album_order_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        151 => wire__mixin_desktop_api__access__StickerAccess_refresh_stickers_impl(
+        184 => wire__mixin_desktop_api__access__StickerAccess_sticker_albums_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        152 => wire__mixin_desktop_api__access__StickerAccess_remove_sticker_impl(
+        185 => wire__mixin_desktop_api__access__StickerAccess_sticker_detail_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        153 => wire__mixin_desktop_api__access__StickerAccess_set_sticker_album_added_impl(
+        186 => wire__mixin_desktop_api__access__StickerAccess_sticker_store_albums_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        154 => wire__mixin_desktop_api__access__StickerAccess_set_sticker_album_order_impl(
+        187 => wire__mixin_desktop_api__access__UserAccess_add_contact_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        155 => wire__mixin_desktop_api__access__StickerAccess_sticker_albums_impl(
+        188 => wire__mixin_desktop_api__access__UserAccess_block_user_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        156 => wire__mixin_desktop_api__access__StickerAccess_sticker_detail_impl(
+        189 => wire__mixin_desktop_api__access__UserAccess_blocked_users_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        157 => wire__mixin_desktop_api__access__StickerAccess_sticker_store_albums_impl(
+        190 => wire__mixin_desktop_api__access__UserAccess_bot_creator_id_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        158 => wire__mixin_desktop_api__access__UserAccess_add_contact_impl(
+        191 => wire__mixin_desktop_api__access__UserAccess_bot_home_uri_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        159 => wire__mixin_desktop_api__access__UserAccess_block_user_impl(
+        192 => wire__mixin_desktop_api__access__UserAccess_contacts_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        160 => wire__mixin_desktop_api__access__UserAccess_bot_creator_id_impl(
+        193 => wire__mixin_desktop_api__access__UserAccess_local_shared_apps_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        161 => wire__mixin_desktop_api__access__UserAccess_bot_home_uri_impl(
+        194 => wire__mixin_desktop_api__access__UserAccess_mention_names_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        162 => wire__mixin_desktop_api__access__UserAccess_local_shared_apps_impl(
+        195 => wire__mixin_desktop_api__access__UserAccess_refresh_user_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        163 => wire__mixin_desktop_api__access__UserAccess_mention_names_impl(
+        196 => wire__mixin_desktop_api__access__UserAccess_remove_contact_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        164 => wire__mixin_desktop_api__access__UserAccess_refresh_user_profile_impl(
+        197 => wire__mixin_desktop_api__access__UserAccess_replace_mentions_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        165 => wire__mixin_desktop_api__access__UserAccess_remove_contact_impl(
+        198 => wire__mixin_desktop_api__access__UserAccess_report_user_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        166 => wire__mixin_desktop_api__access__UserAccess_replace_mentions_impl(
+        199 => wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        167 => wire__mixin_desktop_api__access__UserAccess_report_user_impl(
+        200 => wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        168 => wire__mixin_desktop_api__access__UserAccess_search_local_users_impl(
+        201 => wire__mixin_desktop_api__access__UserAccess_search_user_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        169 => wire__mixin_desktop_api__access__UserAccess_search_mao_user_impl(
+        202 => wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        170 => wire__mixin_desktop_api__access__UserAccess_search_user_impl(
+        203 => wire__mixin_desktop_api__access__UserAccess_set_user_annotation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        171 => wire__mixin_desktop_api__access__UserAccess_selectable_users_impl(
+        204 => wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        172 => wire__mixin_desktop_api__access__UserAccess_shared_apps_impl(
+        205 => wire__mixin_desktop_api__access__UserAccess_unblock_user_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        173 => wire__mixin_desktop_api__access__UserAccess_unblock_user_impl(
+        206 => wire__mixin_desktop_api__access__UserAccess_user_annotation_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        174 => wire__mixin_desktop_api__access__UserAccess_user_profile_impl(
+        207 => wire__mixin_desktop_api__access__UserAccess_user_profile_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        175 => wire__mixin_desktop_api__access__UserAccess_users_by_identity_numbers_impl(
+        208 => wire__mixin_desktop_api__access__UserAccess_users_by_identity_numbers_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        176 => wire__crate__api__logging__directory_impl(port, ptr, rust_vec_len, data_len),
-        177 => wire__crate__api__logging__init_impl(port, ptr, rust_vec_len, data_len),
-        178 => wire__crate__api__logging__init_app_impl(port, ptr, rust_vec_len, data_len),
-        180 => wire__crate__api__desktop__open_desktop_impl(port, ptr, rust_vec_len, data_len),
+        209 => wire__crate__api__logging__directory_impl(port, ptr, rust_vec_len, data_len),
+        210 => wire__crate__api__logging__init_impl(port, ptr, rust_vec_len, data_len),
+        211 => wire__crate__api__logging__init_app_impl(port, ptr, rust_vec_len, data_len),
+        213 => wire__crate__api__desktop__open_desktop_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -13758,37 +16763,44 @@ fn pde_ffi_dispatcher_sync_impl(
         8 => {
             wire__crate__api__account__AccountHandle_conversation_impl(ptr, rust_vec_len, data_len)
         }
-        14 => wire__crate__api__account__AccountHandle_media_directory_impl(
+        15 => wire__crate__api__account__AccountHandle_media_directory_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        15 => wire__crate__api__account__AccountHandle_message_impl(ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__account__AccountHandle_profile_impl(ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__account__AccountHandle_retry_connection_impl(
+        16 => wire__crate__api__account__AccountHandle_message_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__account__AccountHandle_profile_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__account__AccountHandle_retry_connection_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        27 => wire__crate__api__account__AccountHandle_sticker_impl(ptr, rust_vec_len, data_len),
-        32 => wire__crate__api__account__AccountHandle_user_impl(ptr, rust_vec_len, data_len),
-        76 => wire__crate__api__desktop__DesktopHandle_media_impl(ptr, rust_vec_len, data_len),
-        79 => wire__crate__api__desktop__DesktopHandle_settings_impl(ptr, rust_vec_len, data_len),
-        80 => wire__crate__api__login__LoginHandle_auth_url_impl(ptr, rust_vec_len, data_len),
-        81 => wire__crate__api__login__LoginHandle_cancel_impl(ptr, rust_vec_len, data_len),
-        84 => wire__crate__api__media__MediaHandle_audio_playback_snapshot_impl(
+        25 => wire__crate__api__account__AccountHandle_search_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__account__AccountHandle_search_session_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        89 => wire__crate__api__media__MediaHandle_seek_audio_impl(ptr, rust_vec_len, data_len),
-        92 => wire__crate__api__media__MediaHandle_stop_audio_impl(ptr, rust_vec_len, data_len),
-        95 => wire__crate__api__media__MediaHandle_voice_recorder_snapshot_impl(
+        32 => wire__crate__api__account__AccountHandle_sticker_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__account__AccountHandle_user_impl(ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__desktop__DesktopHandle_media_impl(ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__desktop__DesktopHandle_settings_impl(ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__login__LoginHandle_auth_url_impl(ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__login__LoginHandle_cancel_impl(ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__media__MediaHandle_audio_playback_snapshot_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        179 => wire__crate__api__logging__log_flutter_impl(ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__media__MediaHandle_seek_audio_impl(ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__media__MediaHandle_stop_audio_impl(ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__media__MediaHandle_voice_recorder_snapshot_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        153 => wire__crate__api__search__SearchHandle_set_query_impl(ptr, rust_vec_len, data_len),
+        212 => wire__crate__api__logging__log_flutter_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -13918,6 +16930,36 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<MessageAccess>> for MessageAcc
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SearchAccess> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SearchAccess> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SearchAccess>> for SearchAccess {
+    fn into_into_dart(self) -> FrbWrapper<SearchAccess> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<SearchHandle> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<SearchHandle> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<SearchHandle>> for SearchHandle {
+    fn into_into_dart(self) -> FrbWrapper<SearchHandle> {
+        self.into()
+    }
+}
+
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<SettingsHandle> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -13963,6 +17005,30 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<UserAccess>> for UserAccess {
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_api::model::AccountNotificationItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.account_id.into_into_dart().into_dart(),
+            self.0.event.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_api::model::AccountNotificationItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_api::model::AccountNotificationItem>>
+    for mixin_desktop_api::model::AccountNotificationItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_api::model::AccountNotificationItem> {
+        self.into()
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<mixin_desktop_api::model::AccountProfile> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -13982,13 +17048,65 @@ impl flutter_rust_bridge::IntoDart for FrbWrapper<mixin_desktop_api::model::Acco
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<mixin_desktop_api::model::AccountProfile>
+    for FrbWrapper<mixin_desktop_api::model::AccountProfile>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_api::model::AccountProfile>>
+    for mixin_desktop_api::model::AccountProfile
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_api::model::AccountProfile> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::AccountSession>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.session_id.into_into_dart().into_dart(),
+            self.0.platform.into_into_dart().into_dart(),
+            self.0.app_version.into_into_dart().into_dart(),
+            self.0.last_seen_at.into_into_dart().into_dart(),
+            self.0.is_current.into_into_dart().into_dart(),
+            self.0.is_primary.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::AccountSession>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::AccountSession>,
+    > for mixin_desktop_core::runtime::model::AccountSession
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::AccountSession> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_api::model::AccountUnreadCountItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.account_id.into_into_dart().into_dart(),
+            self.0.unseen_message_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_api::model::AccountUnreadCountItem>
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_api::model::AccountProfile>>
-    for mixin_desktop_api::model::AccountProfile
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_api::model::AccountUnreadCountItem>>
+    for mixin_desktop_api::model::AccountUnreadCountItem
 {
-    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_api::model::AccountProfile> {
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_api::model::AccountUnreadCountItem> {
         self.into()
     }
 }
@@ -14072,6 +17190,52 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::device_transfer::ConnectionFa
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::ContactGroup>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.index.into_into_dart().into_dart(),
+            self.0.contacts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::ContactGroup>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_core::runtime::model::ContactGroup>>
+    for mixin_desktop_core::runtime::model::ContactGroup
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::ContactGroup> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<mixin_desktop_core::runtime::model::ContactItem> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.user.into_into_dart().into_dart(),
+            self.0.alias.into_into_dart().into_dart(),
+            self.0.display_name.into_into_dart().into_dart(),
+            self.0.sort_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::ContactItem>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_core::runtime::model::ContactItem>>
+    for mixin_desktop_core::runtime::model::ContactItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::ContactItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for FrbWrapper<mixin_desktop_api::model::ConversationChangeEvent>
 {
@@ -14227,6 +17391,34 @@ impl
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::ConversationSearchHitView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.conversation.into_into_dart().into_dart(),
+            self.0.hit_count.into_into_dart().into_dart(),
+            self.0.latest_created_at_millis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::ConversationSearchHitView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::ConversationSearchHitView>,
+    > for mixin_desktop_core::runtime::model::ConversationSearchHitView
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<mixin_desktop_core::runtime::model::ConversationSearchHitView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for FrbWrapper<mixin_desktop_core::runtime::model::ConversationStorageUsage>
 {
@@ -14401,6 +17593,41 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::device_transfer::DeviceTransf
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::GlobalSearchView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.query.into_into_dart().into_dart(),
+            self.0.contacts.into_into_dart().into_dart(),
+            self.0.contacts_cursor.into_into_dart().into_dart(),
+            self.0.groups.into_into_dart().into_dart(),
+            self.0.groups_cursor.into_into_dart().into_dart(),
+            self.0.message_conversations.into_into_dart().into_dart(),
+            self.0
+                .message_conversations_cursor
+                .into_into_dart()
+                .into_dart(),
+            self.0.files.into_into_dart().into_dart(),
+            self.0.files_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::GlobalSearchView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::GlobalSearchView>,
+    > for mixin_desktop_core::runtime::model::GlobalSearchView
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::GlobalSearchView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<mixin_desktop_core::runtime::model::GroupAvatar> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -14507,6 +17734,37 @@ impl
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::login::LoginStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::login::LoginStatus::WaitingForScan { auth_url } => {
+                [0.into_dart(), auth_url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::login::LoginStatus::Verifying => [1.into_dart()].into_dart(),
+            crate::api::login::LoginStatus::SyncingKeys => [2.into_dart()].into_dart(),
+            crate::api::login::LoginStatus::Ready => [3.into_dart()].into_dart(),
+            crate::api::login::LoginStatus::Expired => [4.into_dart()].into_dart(),
+            crate::api::login::LoginStatus::Failed { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::login::LoginStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::login::LoginStatus>
+    for crate::api::login::LoginStatus
+{
+    fn into_into_dart(self) -> crate::api::login::LoginStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<mixin_desktop_api::model::McpServerStatusItem> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -14748,6 +18006,86 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::media::MediaVoiceRecording>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::LinkPreviewView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.url.into_into_dart().into_dart(),
+            self.0.site_name.into_into_dart().into_dart(),
+            self.0.title.into_into_dart().into_dart(),
+            self.0.description.into_into_dart().into_dart(),
+            self.0.image_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::LinkPreviewView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::LinkPreviewView>,
+    > for mixin_desktop_core::runtime::model::LinkPreviewView
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::LinkPreviewView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::LocationView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.latitude.into_into_dart().into_dart(),
+            self.0.longitude.into_into_dart().into_dart(),
+            self.0.name.into_into_dart().into_dart(),
+            self.0.address.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::LocationView>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_core::runtime::model::LocationView>>
+    for mixin_desktop_core::runtime::model::LocationView
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::LocationView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageEditItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.edit_id.into_into_dart().into_dart(),
+            self.0.message_id.into_into_dart().into_dart(),
+            self.0.content.into_into_dart().into_dart(),
+            self.0.edited_at_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageEditItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::MessageEditItem>,
+    > for mixin_desktop_core::runtime::model::MessageEditItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::MessageEditItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for FrbWrapper<mixin_desktop_core::runtime::model::MessageListView>
 {
@@ -14836,6 +18174,11 @@ impl flutter_rust_bridge::IntoDart
             self.0.mention_read.into_into_dart().into_dart(),
             self.0.pinned.into_into_dart().into_dart(),
             self.0.expire_in.into_into_dart().into_dart(),
+            self.0.edited_at_micros.into_into_dart().into_dart(),
+            self.0.reactions.into_into_dart().into_dart(),
+            self.0.location.into_into_dart().into_dart(),
+            self.0.snippet.into_into_dart().into_dart(),
+            self.0.link_preview.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -14867,49 +18210,179 @@ impl flutter_rust_bridge::IntoDart
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<mixin_desktop_core::runtime::model::MessageOrderInfoView>
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageOrderInfoView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::MessageOrderInfoView>,
+    > for mixin_desktop_core::runtime::model::MessageOrderInfoView
+{
+    fn into_into_dart(
+        self,
+    ) -> FrbWrapper<mixin_desktop_core::runtime::model::MessageOrderInfoView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.user_id.into_into_dart().into_dart(),
+            self.0.emoji.into_into_dart().into_dart(),
+            self.0.created_at_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionItem>,
+    > for mixin_desktop_core::runtime::model::MessageReactionItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.emoji.into_into_dart().into_dart(),
+            self.0.count.into_into_dart().into_dart(),
+            self.0.reacted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionView>,
+    > for mixin_desktop_core::runtime::model::MessageReactionView
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::MessageReactionView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageSnippetView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.text.into_into_dart().into_dart(),
+            self.0.highlights.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::MessageSnippetView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::MessageSnippetView>,
+    > for mixin_desktop_core::runtime::model::MessageSnippetView
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::MessageSnippetView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop::NotificationActionKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Reply => 0.into_dart(),
+            Self::MarkRead => 1.into_dart(),
+            Self::MuteForOneHour => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop::NotificationActionKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop::NotificationActionKind>
+    for crate::api::desktop::NotificationActionKind
+{
+    fn into_into_dart(self) -> crate::api::desktop::NotificationActionKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.message_id.into_into_dart().into_dart(),
+            self.0.conversation_id.into_into_dart().into_dart(),
+            self.0.sender_name.into_into_dart().into_dart(),
+            self.0.category.into_into_dart().into_dart(),
+            self.0.content.into_into_dart().into_dart(),
+            self.0.created_at_micros.into_into_dart().into_dart(),
+            self.0.conversation_name.into_into_dart().into_dart(),
+            self.0.conversation_category.into_into_dart().into_dart(),
+            self.0.dismiss_message_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent>
 {
 }
 impl
     flutter_rust_bridge::IntoIntoDart<
-        FrbWrapper<mixin_desktop_core::runtime::model::MessageOrderInfoView>,
-    > for mixin_desktop_core::runtime::model::MessageOrderInfoView
+        FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent>,
+    > for mixin_desktop_core::runtime::model::NotificationEvent
 {
-    fn into_into_dart(
-        self,
-    ) -> FrbWrapper<mixin_desktop_core::runtime::model::MessageOrderInfoView> {
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent> {
         self.into()
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
-    for FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent>
+    for FrbWrapper<mixin_desktop_core::runtime::model::OutgoingMessageItem>
 {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.0.message_id.into_into_dart().into_dart(),
             self.0.conversation_id.into_into_dart().into_dart(),
-            self.0.sender_name.into_into_dart().into_dart(),
             self.0.category.into_into_dart().into_dart(),
-            self.0.content.into_into_dart().into_dart(),
+            self.0.status.into_into_dart().into_dart(),
             self.0.created_at_micros.into_into_dart().into_dart(),
-            self.0.conversation_name.into_into_dart().into_dart(),
-            self.0.conversation_category.into_into_dart().into_dart(),
-            self.0.dismiss_message_id.into_into_dart().into_dart(),
+            self.0.attempt_count.into_into_dart().into_dart(),
+            self.0.last_error.into_into_dart().into_dart(),
+            self.0.next_attempt_at_micros.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent>
+    for FrbWrapper<mixin_desktop_core::runtime::model::OutgoingMessageItem>
 {
 }
 impl
     flutter_rust_bridge::IntoIntoDart<
-        FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent>,
-    > for mixin_desktop_core::runtime::model::NotificationEvent
+        FrbWrapper<mixin_desktop_core::runtime::model::OutgoingMessageItem>,
+    > for mixin_desktop_core::runtime::model::OutgoingMessageItem
 {
-    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::NotificationEvent> {
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::OutgoingMessageItem> {
         self.into()
     }
 }
@@ -14989,6 +18462,27 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_api::model::Prox
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<mixin_desktop_api::model::SessionLinkedItem> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.session_id.into_into_dart().into_dart(),
+            self.0.linked_at_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_api::model::SessionLinkedItem>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_api::model::SessionLinkedItem>>
+    for mixin_desktop_api::model::SessionLinkedItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_api::model::SessionLinkedItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for FrbWrapper<mixin_desktop_core::runtime::model::SharedAppItem>
 {
@@ -15182,6 +18676,56 @@ impl
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::TextRangeView>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.start.into_into_dart().into_dart(),
+            self.0.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::TextRangeView>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<FrbWrapper<mixin_desktop_core::runtime::model::TextRangeView>>
+    for mixin_desktop_core::runtime::model::TextRangeView
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::TextRangeView> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::UserAnnotationItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.user_id.into_into_dart().into_dart(),
+            self.0.alias.into_into_dart().into_dart(),
+            self.0.note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::UserAnnotationItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::UserAnnotationItem>,
+    > for mixin_desktop_core::runtime::model::UserAnnotationItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::UserAnnotationItem> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart
     for FrbWrapper<mixin_desktop_core::runtime::model::UserProfileItem>
 {
@@ -15215,6 +18759,34 @@ impl
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for FrbWrapper<mixin_desktop_core::runtime::model::VideoRangeItem>
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.0.data.into_into_dart().into_dart(),
+            self.0.offset.into_into_dart().into_dart(),
+            self.0.total_size.into_into_dart().into_dart(),
+            self.0.mime_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<mixin_desktop_core::runtime::model::VideoRangeItem>
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        FrbWrapper<mixin_desktop_core::runtime::model::VideoRangeItem>,
+    > for mixin_desktop_core::runtime::model::VideoRangeItem
+{
+    fn into_into_dart(self) -> FrbWrapper<mixin_desktop_core::runtime::model::VideoRangeItem> {
+        self.into()
+    }
+}
+
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15278,6 +18850,20 @@ impl SseEncode for MessageAccess {
     }
 }
 
+impl SseEncode for SearchAccess {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for SearchHandle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
 impl SseEncode for SettingsHandle {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15394,6 +18980,28 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
 impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SettingsHandle>>
 {
@@ -15434,6 +19042,18 @@ impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCod
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        mixin_desktop_api::model::AccountNotificationItem,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         mixin_desktop_api::model::AccountProfile,
@@ -15484,6 +19104,30 @@ impl SseEncode for StreamSink<i64, flutter_rust_bridge::for_generated::SseCodec>
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        mixin_desktop_core::runtime::model::GlobalSearchView,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        Vec<mixin_desktop_api::model::AccountUnreadCountItem>,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         Vec<mixin_desktop_core::runtime::model::CircleItem>,
@@ -15508,6 +19152,15 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<crate::api::login::LoginStatus, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::api::media::MediaPlaybackEvent,
@@ -15551,6 +19204,18 @@ impl SseEncode for StreamSink<Option<String>, flutter_rust_bridge::for_generated
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        mixin_desktop_api::model::SessionLinkedItem,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for StreamSink<u64, flutter_rust_bridge::for_generated::SseCodec> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15565,6 +19230,14 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for mixin_desktop_api::model::AccountNotificationItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.account_id, serializer);
+        <mixin_desktop_core::runtime::model::NotificationEvent>::sse_encode(self.event, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_api::model::AccountProfile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15581,6 +19254,26 @@ impl SseEncode for mixin_desktop_api::model::AccountProfile {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::AccountSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.session_id, serializer);
+        <Option<String>>::sse_encode(self.platform, serializer);
+        <Option<String>>::sse_encode(self.app_version, serializer);
+        <Option<String>>::sse_encode(self.last_seen_at, serializer);
+        <bool>::sse_encode(self.is_current, serializer);
+        <bool>::sse_encode(self.is_primary, serializer);
+    }
+}
+
+impl SseEncode for mixin_desktop_api::model::AccountUnreadCountItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.account_id, serializer);
+        <i64>::sse_encode(self.unseen_message_count, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15639,6 +19332,27 @@ impl SseEncode for crate::api::device_transfer::ConnectionFailedReason {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::ContactGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.index, serializer);
+        <Vec<mixin_desktop_core::runtime::model::ContactItem>>::sse_encode(
+            self.contacts,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for mixin_desktop_core::runtime::model::ContactItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <mixin_desktop_core::runtime::model::UserProfileItem>::sse_encode(self.user, serializer);
+        <Option<String>>::sse_encode(self.alias, serializer);
+        <String>::sse_encode(self.display_name, serializer);
+        <String>::sse_encode(self.sort_key, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_api::model::ConversationChangeEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15718,6 +19432,18 @@ impl SseEncode for mixin_desktop_core::runtime::model::ConversationParticipantIt
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::ConversationSearchHitView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <mixin_desktop_core::runtime::model::ConversationListData>::sse_encode(
+            self.conversation,
+            serializer,
+        );
+        <i64>::sse_encode(self.hit_count, serializer);
+        <i64>::sse_encode(self.latest_created_at_millis, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::ConversationStorageUsage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15859,6 +19585,32 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::GlobalSearchView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.query, serializer);
+        <Vec<mixin_desktop_core::runtime::model::UserProfileItem>>::sse_encode(
+            self.contacts,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.contacts_cursor, serializer);
+        <Vec<mixin_desktop_core::runtime::model::ConversationListData>>::sse_encode(
+            self.groups,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.groups_cursor, serializer);
+        <Vec<mixin_desktop_core::runtime::model::ConversationSearchHitView>>::sse_encode(
+            self.message_conversations,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.message_conversations_cursor, serializer);
+        <Vec<mixin_desktop_core::runtime::model::MessageListView>>::sse_encode(
+            self.files, serializer,
+        );
+        <Option<String>>::sse_encode(self.files_cursor, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::GroupAvatar {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -15917,22 +19669,72 @@ impl SseEncode for mixin_desktop_core::runtime::model::ImageMessageView {
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<AccountHandle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <AccountHandle>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::AccountSession> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::AccountSession>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<mixin_desktop_api::model::AccountUnreadCountItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_api::model::AccountUnreadCountItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::CircleItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::CircleItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::ContactGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <mixin_desktop_core::runtime::model::ContactGroup>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<mixin_desktop_core::runtime::model::CircleItem> {
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::ContactItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <mixin_desktop_core::runtime::model::CircleItem>::sse_encode(item, serializer);
+            <mixin_desktop_core::runtime::model::ContactItem>::sse_encode(item, serializer);
         }
     }
 }
@@ -15961,6 +19763,18 @@ impl SseEncode for Vec<mixin_desktop_core::runtime::model::ConversationParticipa
     }
 }
 
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::ConversationSearchHitView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::ConversationSearchHitView>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for Vec<mixin_desktop_core::runtime::model::ConversationStorageUsage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16027,6 +19841,16 @@ impl SseEncode for Vec<crate::api::media::MediaAudioItem> {
     }
 }
 
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::MessageEditItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::MessageEditItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<mixin_desktop_core::runtime::model::MessageListView> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16037,6 +19861,36 @@ impl SseEncode for Vec<mixin_desktop_core::runtime::model::MessageListView> {
     }
 }
 
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::MessageReactionItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::MessageReactionItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::MessageReactionView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::MessageReactionView>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::OutgoingMessageItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::OutgoingMessageItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16109,6 +19963,16 @@ impl SseEncode for Vec<mixin_desktop_core::runtime::model::StorageCategoryUsage>
     }
 }
 
+impl SseEncode for Vec<mixin_desktop_core::runtime::model::TextRangeView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <mixin_desktop_core::runtime::model::TextRangeView>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<mixin_desktop_core::runtime::model::UserProfileItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16119,6 +19983,37 @@ impl SseEncode for Vec<mixin_desktop_core::runtime::model::UserProfileItem> {
     }
 }
 
+impl SseEncode for crate::api::login::LoginStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::login::LoginStatus::WaitingForScan { auth_url } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(auth_url, serializer);
+            }
+            crate::api::login::LoginStatus::Verifying => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::login::LoginStatus::SyncingKeys => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::login::LoginStatus::Ready => {
+                <i32>::sse_encode(3, serializer);
+            }
+            crate::api::login::LoginStatus::Expired => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::login::LoginStatus::Failed { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for mixin_desktop_api::model::McpServerStatusItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16252,6 +20147,37 @@ impl SseEncode for crate::api::media::MediaVoiceRecording {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::LinkPreviewView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <String>::sse_encode(self.site_name, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.image_url, serializer);
+    }
+}
+
+impl SseEncode for mixin_desktop_core::runtime::model::LocationView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.latitude, serializer);
+        <f64>::sse_encode(self.longitude, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.address, serializer);
+    }
+}
+
+impl SseEncode for mixin_desktop_core::runtime::model::MessageEditItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.edit_id, serializer);
+        <String>::sse_encode(self.message_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <i64>::sse_encode(self.edited_at_micros, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::MessageListView {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16329,6 +20255,23 @@ impl SseEncode for mixin_desktop_core::runtime::model::MessageListView {
         <Option<bool>>::sse_encode(self.mention_read, serializer);
         <bool>::sse_encode(self.pinned, serializer);
         <Option<i64>>::sse_encode(self.expire_in, serializer);
+        <Option<i64>>::sse_encode(self.edited_at_micros, serializer);
+        <Vec<mixin_desktop_core::runtime::model::MessageReactionView>>::sse_encode(
+            self.reactions,
+            serializer,
+        );
+        <Option<mixin_desktop_core::runtime::model::LocationView>>::sse_encode(
+            self.location,
+            serializer,
+        );
+        <Option<mixin_desktop_core::runtime::model::MessageSnippetView>>::sse_encode(
+            self.snippet,
+            serializer,
+        );
+        <Option<mixin_desktop_core::runtime::model::LinkPreviewView>>::sse_encode(
+            self.link_preview,
+            serializer,
+        );
     }
 }
 
@@ -16341,6 +20284,52 @@ impl SseEncode for mixin_desktop_core::runtime::model::MessageOrderInfoView {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::MessageReactionItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.user_id, serializer);
+        <String>::sse_encode(self.emoji, serializer);
+        <i64>::sse_encode(self.created_at_micros, serializer);
+    }
+}
+
+impl SseEncode for mixin_desktop_core::runtime::model::MessageReactionView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.emoji, serializer);
+        <i64>::sse_encode(self.count, serializer);
+        <bool>::sse_encode(self.reacted, serializer);
+    }
+}
+
+impl SseEncode for mixin_desktop_core::runtime::model::MessageSnippetView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <Vec<mixin_desktop_core::runtime::model::TextRangeView>>::sse_encode(
+            self.highlights,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop::NotificationActionKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::desktop::NotificationActionKind::Reply => 0,
+                crate::api::desktop::NotificationActionKind::MarkRead => 1,
+                crate::api::desktop::NotificationActionKind::MuteForOneHour => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::NotificationEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16356,6 +20345,16 @@ impl SseEncode for mixin_desktop_core::runtime::model::NotificationEvent {
     }
 }
 
+impl SseEncode for Option<AccountHandle> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <AccountHandle>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16426,6 +20425,26 @@ impl SseEncode for Option<crate::api::media::MediaVoiceRecording> {
     }
 }
 
+impl SseEncode for Option<mixin_desktop_core::runtime::model::LinkPreviewView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <mixin_desktop_core::runtime::model::LinkPreviewView>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<mixin_desktop_core::runtime::model::LocationView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <mixin_desktop_core::runtime::model::LocationView>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<mixin_desktop_core::runtime::model::MessageOrderInfoView> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16438,6 +20457,16 @@ impl SseEncode for Option<mixin_desktop_core::runtime::model::MessageOrderInfoVi
     }
 }
 
+impl SseEncode for Option<mixin_desktop_core::runtime::model::MessageSnippetView> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <mixin_desktop_core::runtime::model::MessageSnippetView>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<mixin_desktop_core::runtime::model::PinMessagePreviewItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16470,6 +20499,16 @@ impl SseEncode for Option<u64> {
     }
 }
 
+impl SseEncode for Option<mixin_desktop_core::runtime::model::UserAnnotationItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <mixin_desktop_core::runtime::model::UserAnnotationItem>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<mixin_desktop_core::runtime::model::UserProfileItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16490,6 +20529,20 @@ impl SseEncode for Option<Vec<u8>> {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::OutgoingMessageItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.message_id, serializer);
+        <String>::sse_encode(self.conversation_id, serializer);
+        <String>::sse_encode(self.category, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <i64>::sse_encode(self.created_at_micros, serializer);
+        <i64>::sse_encode(self.attempt_count, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+        <Option<i64>>::sse_encode(self.next_attempt_at_micros, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::PinMessagePreviewItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16528,6 +20581,14 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for mixin_desktop_api::model::SessionLinkedItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.session_id, serializer);
+        <i64>::sse_encode(self.linked_at_micros, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::SharedAppItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16627,6 +20688,14 @@ impl SseEncode for mixin_desktop_core::runtime::model::StorageCategoryUsage {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::TextRangeView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+    }
+}
+
 impl SseEncode for u16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16660,6 +20729,15 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::UserAnnotationItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.user_id, serializer);
+        <Option<String>>::sse_encode(self.alias, serializer);
+        <Option<String>>::sse_encode(self.note, serializer);
+    }
+}
+
 impl SseEncode for mixin_desktop_core::runtime::model::UserProfileItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -16686,6 +20764,16 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for mixin_desktop_core::runtime::model::VideoRangeItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <u64>::sse_encode(self.offset, serializer);
+        <u64>::sse_encode(self.total_size, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -16822,6 +20910,34 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_mixin_desktop_ui_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_mixin_desktop_ui_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_mixin_desktop_ui_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_mixin_desktop_ui_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_mixin_desktop_ui_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
         ptr: *const std::ffi::c_void,
@@ -17006,6 +21122,34 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MessageAccess>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchAccess(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchAccess>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSearchHandle(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SearchHandle>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSettingsHandle(
         ptr: *const std::ffi::c_void,
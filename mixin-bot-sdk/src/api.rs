@@ -5,6 +5,7 @@ pub mod circle_api;
 pub mod conversation_api;
 pub mod message_api;
 pub mod provisioning_api;
+pub mod safe_api;
 pub mod snapshot_api;
 pub mod token_api;
 pub mod user_api;
@@ -20,6 +20,20 @@ impl AccountApi {
         self.client.get("fiats").await
     }
 
+    pub async fn get_app(&self, app_id: &str) -> Result<App, ApiError> {
+        self.client.get(&format!("apps/{app_id}")).await
+    }
+
+    /// Updates the properties of an app owned by the account; unset fields
+    /// are left unchanged.
+    pub async fn update_app(
+        &self,
+        app_id: &str,
+        request: &AppUpdateRequest,
+    ) -> Result<App, ApiError> {
+        self.client.post(&format!("apps/{app_id}"), request).await
+    }
+
     pub(crate) fn new(client: Arc<ClientRef>) -> Self {
         AccountApi { client }
     }
@@ -51,6 +65,26 @@ pub struct App {
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AppUpdateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_base64: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_patterns: Option<Vec<String>>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Account {
     pub user_id: String,
@@ -19,6 +19,11 @@ impl AssetApi {
         self.client.get(&format!("assets/{asset_id}")).await
     }
 
+    /// Every asset of the account, including those with a zero balance.
+    pub async fn get_assets(&self) -> Result<Vec<Asset>, ApiError> {
+        self.client.get("assets").await
+    }
+
     pub async fn get_chain(&self, chain_id: &str) -> Result<Chain, ApiError> {
         self.client.get(&format!("network/chains/{chain_id}")).await
     }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::client::ClientRef;
+use crate::pagination::{Page, Paginator};
 use crate::{ApiError, UserSession};
 
 pub struct ConversationApi {
@@ -92,6 +93,29 @@ impl ConversationApi {
             .await
     }
 
+    /// Conversations the bot takes part in, oldest first, created after
+    /// `offset` (RFC 3339).
+    pub async fn get_conversations(
+        &self,
+        offset: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<Conversation>, ApiError> {
+        fetch_conversations(&self.client, offset, limit).await
+    }
+
+    pub fn conversations(&self, limit: usize) -> Paginator<Conversation> {
+        let client = self.client.clone();
+        Paginator::new(move |offset: Option<String>| {
+            let client = client.clone();
+            async move {
+                let conversations = fetch_conversations(&client, offset.as_deref(), limit).await?;
+                Ok(Page::from_offset(conversations, limit, |conversation| {
+                    rfc3339_offset(&conversation.created_at)
+                }))
+            }
+        })
+    }
+
     pub async fn get_conversation(&self, conversation_id: &str) -> Result<Conversation, ApiError> {
         self.client
             .get(&format!("conversations/{conversation_id}"))
@@ -144,6 +168,25 @@ impl ConversationApi {
     }
 }
 
+async fn fetch_conversations(
+    client: &ClientRef,
+    offset: Option<&str>,
+    limit: usize,
+) -> Result<Vec<Conversation>, ApiError> {
+    let limit = limit.to_string();
+    let mut query = vec![("limit", limit.as_str())];
+    if let Some(offset) = offset {
+        query.push(("offset", offset));
+    }
+    client.get_with_query("conversations", &query).await
+}
+
+/// Offsets are created-at timestamps with full precision, so a page starts
+/// right after the last item of the previous one.
+pub(crate) fn rfc3339_offset(created_at: &DateTime<Utc>) -> String {
+    created_at.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ConversationCategory, ConversationRequest, ParticipantRequest};
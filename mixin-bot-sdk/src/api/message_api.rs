@@ -1,5 +1,8 @@
 use std::sync::Arc;
 
+use base64ct::{Base64, Encoding};
+use serde::{Deserialize, Serialize};
+
 use crate::client::ClientRef;
 use crate::{ApiError, BlazeAckMessage, BlazeMessageData};
 
@@ -16,11 +19,28 @@ impl MessageApi {
     }
 
     pub async fn acknowledgements(&self, acks: &[BlazeAckMessage]) -> Result<(), ApiError> {
+        self.post_without_data("acknowledgements", acks).await
+    }
+
+    /// Sends a plain (unencrypted) message over REST, as bots usually do.
+    pub async fn send_message(&self, message: &MessageRequest) -> Result<(), ApiError> {
+        self.send_messages(std::slice::from_ref(message)).await
+    }
+
+    /// Sends up to 100 plain messages in one request.
+    pub async fn send_messages(&self, messages: &[MessageRequest]) -> Result<(), ApiError> {
+        self.post_without_data("messages", messages).await
+    }
+
+    async fn post_without_data<B>(&self, path: &str, body: &B) -> Result<(), ApiError>
+    where
+        B: ?Sized + Serialize,
+    {
         let request = self
             .client
             .client
-            .post(format!("{}/acknowledgements", self.client.base_url))
-            .body(serde_json::to_string(&acks)?)
+            .post(format!("{}/{path}", self.client.base_url))
+            .body(serde_json::to_string(body)?)
             .build()?;
         let body = self.client.raw_request(request).await?;
         if !is_empty_success_body(&body) {
@@ -30,6 +50,41 @@ impl MessageApi {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageRequest {
+    pub conversation_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_id: Option<String>,
+    pub message_id: String,
+    pub category: String,
+    /// Base64 of the message content.
+    pub data: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub representative_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_message_id: Option<String>,
+}
+
+impl MessageRequest {
+    /// A message with a fresh id and `data` base64-encoded.
+    pub fn new(conversation_id: &str, category: &str, data: impl AsRef<[u8]>) -> Self {
+        Self {
+            conversation_id: conversation_id.to_string(),
+            recipient_id: None,
+            message_id: uuid::Uuid::new_v4().to_string(),
+            category: category.to_string(),
+            data: Base64::encode_string(data.as_ref()),
+            representative_id: None,
+            quote_message_id: None,
+        }
+    }
+
+    pub fn with_recipient(mut self, recipient_id: &str) -> Self {
+        self.recipient_id = Some(recipient_id.to_string());
+        self
+    }
+}
+
 fn is_empty_success_body(body: &[u8]) -> bool {
     body.is_empty()
         || serde_json::from_slice::<serde_json::Value>(body)
@@ -38,7 +93,7 @@ fn is_empty_success_body(body: &[u8]) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::is_empty_success_body;
+    use super::{is_empty_success_body, MessageRequest};
 
     #[test]
     fn accepts_empty_acknowledgement_response() {
@@ -46,4 +101,17 @@ mod tests {
         assert!(is_empty_success_body(b"{}"));
         assert!(!is_empty_success_body(br#"{"data":{}}"#));
     }
+
+    #[test]
+    fn message_requests_encode_data_and_skip_unset_fields() {
+        let message =
+            MessageRequest::new("conversation", "PLAIN_TEXT", "hello").with_recipient("user");
+
+        let value = serde_json::to_value(&message).unwrap();
+
+        assert_eq!(value["data"], "aGVsbG8=");
+        assert_eq!(value["recipient_id"], "user");
+        assert!(value.get("quote_message_id").is_none());
+        assert_eq!(message.message_id.len(), 36);
+    }
 }
@@ -0,0 +1,391 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, ensure};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::client::ClientRef;
+use crate::err::error_code;
+use crate::pagination::{Page, Paginator};
+use crate::safe::transaction::{
+    decode_hash, select_outputs, threshold_script, Input, Output, Transaction,
+};
+use crate::safe::{members_hash, parse_amount, SpendKey};
+use crate::{unique_object_id, ApiError};
+
+const OUTPUTS_LIMIT: usize = 500;
+
+pub struct SafeApi {
+    client: Arc<ClientRef>,
+}
+
+impl SafeApi {
+    pub(crate) fn new(client: Arc<ClientRef>) -> Self {
+        Self { client }
+    }
+
+    /// Unspent outputs owned by `members` with `threshold`, oldest first.
+    pub async fn get_outputs(
+        &self,
+        members: &[String],
+        threshold: u8,
+        asset_id: Option<&str>,
+        offset: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<SafeUtxo>, ApiError> {
+        let members = members_hash(members);
+        let threshold = threshold.to_string();
+        let offset = offset.map(|offset| offset.to_string());
+        let limit = limit.to_string();
+        let mut query = vec![
+            ("members", members.as_str()),
+            ("threshold", threshold.as_str()),
+            ("state", "unspent"),
+            ("order", "ASC"),
+            ("limit", limit.as_str()),
+        ];
+        if let Some(asset_id) = asset_id {
+            query.push(("asset", asset_id));
+        }
+        if let Some(offset) = &offset {
+            query.push(("offset", offset.as_str()));
+        }
+        self.client.get_with_query("safe/outputs", &query).await
+    }
+
+    /// Walks every unspent output owned by `members` with `threshold`.
+    pub fn outputs(
+        &self,
+        members: &[String],
+        threshold: u8,
+        asset_id: Option<&str>,
+        limit: usize,
+    ) -> Paginator<SafeUtxo> {
+        let client = self.client.clone();
+        let members = members.to_vec();
+        let asset_id = asset_id.map(str::to_string);
+        Paginator::new(move |offset: Option<String>| {
+            let api = SafeApi::new(client.clone());
+            let members = members.clone();
+            let asset_id = asset_id.clone();
+            async move {
+                let offset = offset.and_then(|offset| offset.parse().ok());
+                let outputs = api
+                    .get_outputs(&members, threshold, asset_id.as_deref(), offset, limit)
+                    .await?;
+                Ok(Page::from_offset(outputs, limit, |output| {
+                    (output.sequence + 1).to_string()
+                }))
+            }
+        })
+    }
+
+    pub async fn request_ghost_keys(
+        &self,
+        requests: &[GhostKeyRequest],
+    ) -> Result<Vec<GhostKeys>, ApiError> {
+        self.client.post("safe/keys", requests).await
+    }
+
+    /// Registers unsigned transactions. The response carries the view
+    /// scalars needed to sign each input.
+    pub async fn create_transaction_requests(
+        &self,
+        requests: &[SafeTransactionRequest],
+    ) -> Result<Vec<SafeTransaction>, ApiError> {
+        self.client
+            .post("safe/transaction/requests", requests)
+            .await
+    }
+
+    pub async fn submit_transactions(
+        &self,
+        requests: &[SafeTransactionRequest],
+    ) -> Result<Vec<SafeTransaction>, ApiError> {
+        self.client.post("safe/transactions", requests).await
+    }
+
+    pub async fn get_transaction(&self, request_id: &str) -> Result<SafeTransaction, ApiError> {
+        self.client
+            .get(&format!("safe/transactions/{request_id}"))
+            .await
+    }
+
+    /// Sends `request.amount` of an asset from this account to the receivers,
+    /// returning change to the account. Outputs holding inscriptions are
+    /// never spent. Repeating a transfer with the same trace id returns the
+    /// earlier transaction instead of paying twice.
+    pub async fn transfer(
+        &self,
+        spend_key: &SpendKey,
+        request: &TransferRequest,
+    ) -> Result<SafeTransaction, ApiError> {
+        if let Some(transaction) = self.find_transaction(&request.trace_id).await? {
+            return Ok(transaction);
+        }
+        let sender_id = self.sender_id()?;
+        let amount = request.validate()?;
+
+        let unspent = self
+            .get_outputs(
+                std::slice::from_ref(&sender_id),
+                1,
+                Some(&request.asset_id),
+                None,
+                OUTPUTS_LIMIT,
+            )
+            .await?
+            .into_iter()
+            .filter(|output| output.inscription_hash.is_none())
+            .collect::<Vec<_>>();
+        let amounts = unspent
+            .iter()
+            .map(|output| Ok((output.sequence, parse_amount(&output.amount)?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let (selected, total) = select_outputs(&amounts, amount)?;
+        let inputs = selected
+            .iter()
+            .map(|index| &unspent[*index])
+            .collect::<Vec<_>>();
+        self.spend(spend_key, request, &sender_id, &inputs, amount, total)
+            .await
+    }
+
+    /// Like [`SafeApi::transfer`], but spends exactly `outputs`, e.g. the
+    /// output holding an inscription.
+    pub async fn transfer_outputs(
+        &self,
+        spend_key: &SpendKey,
+        request: &TransferRequest,
+        outputs: &[SafeUtxo],
+    ) -> Result<SafeTransaction, ApiError> {
+        if let Some(transaction) = self.find_transaction(&request.trace_id).await? {
+            return Ok(transaction);
+        }
+        let sender_id = self.sender_id()?;
+        let amount = request.validate()?;
+        let mut total = 0;
+        for output in outputs {
+            total += parse_amount(&output.amount)?;
+        }
+        if outputs.is_empty() || total < amount {
+            return Err(anyhow!("outputs do not cover the amount").into());
+        }
+        let inputs = outputs.iter().collect::<Vec<_>>();
+        self.spend(spend_key, request, &sender_id, &inputs, amount, total)
+            .await
+    }
+
+    async fn find_transaction(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<SafeTransaction>, ApiError> {
+        match self.get_transaction(request_id).await {
+            Ok(transaction) => Ok(Some(transaction)),
+            Err(ApiError::Server(error)) if error.code == error_code::NOT_FOUND => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn sender_id(&self) -> Result<String, ApiError> {
+        Ok(self
+            .client
+            .user_id()
+            .ok_or_else(|| anyhow!("transfers need a signed-in account"))?
+            .to_string())
+    }
+
+    /// Builds, signs and submits a transaction spending `inputs`, worth
+    /// `total`, into `amount` for the receivers and change for `sender_id`.
+    async fn spend(
+        &self,
+        spend_key: &SpendKey,
+        request: &TransferRequest,
+        sender_id: &str,
+        inputs: &[&SafeUtxo],
+        amount: u128,
+        total: u128,
+    ) -> Result<SafeTransaction, ApiError> {
+        let change = total - amount;
+        let mut ghost_requests = vec![GhostKeyRequest {
+            receivers: request.receivers.clone(),
+            index: 0,
+            hint: unique_object_id(&[request.trace_id.as_str(), "OUTPUT", "0"]).to_string(),
+        }];
+        if change > 0 {
+            ghost_requests.push(GhostKeyRequest {
+                receivers: vec![sender_id.to_string()],
+                index: 1,
+                hint: unique_object_id(&[request.trace_id.as_str(), "OUTPUT", "1"]).to_string(),
+            });
+        }
+        let ghost_keys = self.request_ghost_keys(&ghost_requests).await?;
+        let mut transaction = request.build_transaction(inputs, &ghost_keys, [amount, change])?;
+
+        let hash = base16ct::lower::encode_string(&transaction.payload_hash()?);
+        let unsigned = SafeTransactionRequest {
+            request_id: request.trace_id.clone(),
+            raw: base16ct::lower::encode_string(&transaction.payload()?),
+        };
+        let created = self
+            .create_transaction_requests(std::slice::from_ref(&unsigned))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("transaction request is missing"))?;
+        // A mismatch means the server decoded something other than what
+        // would be signed here.
+        if created.transaction_hash != hash {
+            return Err(anyhow!(
+                "transaction hash mismatch: {} != {hash}",
+                created.transaction_hash
+            )
+            .into());
+        }
+        transaction.sign_inputs(&created.views, spend_key)?;
+
+        let signed = SafeTransactionRequest {
+            request_id: request.trace_id.clone(),
+            raw: base16ct::lower::encode_string(&transaction.marshal()?),
+        };
+        self.submit_transactions(std::slice::from_ref(&signed))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("submitted transaction is missing").into())
+    }
+}
+
+/// A transfer for [`SafeApi::transfer`].
+#[derive(Clone, Debug)]
+pub struct TransferRequest {
+    /// Idempotency key of the transfer, also the transaction request id.
+    pub trace_id: String,
+    pub asset_id: String,
+    pub amount: String,
+    pub receivers: Vec<String>,
+    /// Receivers needed to spend the output.
+    pub threshold: u8,
+    pub memo: String,
+}
+
+impl TransferRequest {
+    /// Checks the request and returns its amount in units.
+    fn validate(&self) -> anyhow::Result<u128> {
+        ensure!(!self.receivers.is_empty(), "transfer has no receivers");
+        ensure!(
+            self.threshold >= 1 && usize::from(self.threshold) <= self.receivers.len(),
+            "invalid receiver threshold"
+        );
+        let amount = parse_amount(&self.amount)?;
+        ensure!(amount > 0, "transfer amount must be positive");
+        Ok(amount)
+    }
+
+    /// Spends `inputs` into the receiver output and, when `amounts[1]` is
+    /// not zero, a change output, using one set of ghost keys per output.
+    fn build_transaction(
+        &self,
+        inputs: &[&SafeUtxo],
+        ghost_keys: &[GhostKeys],
+        amounts: [u128; 2],
+    ) -> anyhow::Result<Transaction> {
+        let outputs = if amounts[1] > 0 { 2 } else { 1 };
+        ensure!(
+            ghost_keys.len() == outputs,
+            "expected {outputs} ghost keys, got {}",
+            ghost_keys.len()
+        );
+        let first = inputs
+            .first()
+            .ok_or_else(|| anyhow!("no inputs selected"))?;
+        let mut transaction = Transaction::new(decode_hash(&first.asset)?);
+        for input in inputs {
+            transaction.inputs.push(Input {
+                hash: decode_hash(&input.transaction_hash)?,
+                index: u16::try_from(input.output_index)
+                    .map_err(|_| anyhow!("invalid output index {}", input.output_index))?,
+            });
+        }
+        for ((keys, amount), threshold) in ghost_keys.iter().zip(amounts).zip([self.threshold, 1]) {
+            transaction.outputs.push(Output {
+                amount,
+                keys: keys
+                    .keys
+                    .iter()
+                    .map(|key| decode_hash(key))
+                    .collect::<anyhow::Result<_>>()?,
+                mask: decode_hash(&keys.mask)?,
+                script: threshold_script(threshold),
+            });
+        }
+        transaction.extra = self.memo.as_bytes().to_vec();
+        Ok(transaction)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SafeUtxo {
+    pub output_id: String,
+    pub transaction_hash: String,
+    pub output_index: i64,
+    /// Kernel asset hash.
+    pub asset: String,
+    #[serde(default)]
+    pub asset_id: Option<String>,
+    #[serde(default)]
+    pub kernel_asset_id: Option<String>,
+    pub amount: String,
+    pub mask: String,
+    pub keys: Vec<String>,
+    #[serde(default)]
+    pub receivers: Vec<String>,
+    pub receivers_hash: String,
+    pub receivers_threshold: i64,
+    #[serde(default)]
+    pub extra: String,
+    pub state: String,
+    pub sequence: i64,
+    #[serde(default)]
+    pub inscription_hash: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GhostKeyRequest {
+    pub receivers: Vec<String>,
+    pub index: u32,
+    pub hint: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GhostKeys {
+    pub mask: String,
+    pub keys: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SafeTransactionRequest {
+    pub request_id: String,
+    /// Hex of the unsigned payload or the signed transaction.
+    pub raw: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SafeTransaction {
+    pub request_id: String,
+    pub transaction_hash: String,
+    #[serde(default)]
+    pub asset: Option<String>,
+    #[serde(default)]
+    pub amount: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub views: Vec<String>,
+    #[serde(default)]
+    pub snapshot_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
+use crate::api::conversation_api::rfc3339_offset;
 use crate::client::ClientRef;
+use crate::pagination::{Page, Paginator};
 use serde::Deserialize;
 
 use crate::{ApiError, SnapshotMessage};
@@ -27,6 +29,33 @@ impl SnapshotApi {
         self.client.get(&format!("snapshots/{snapshot_id}")).await
     }
 
+    /// The account's snapshots, newest first, created before `offset`
+    /// (RFC 3339), optionally limited to one asset.
+    pub async fn get_snapshots(
+        &self,
+        asset_id: Option<&str>,
+        offset: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SnapshotMessage>, ApiError> {
+        fetch_snapshots(&self.client, asset_id, offset, limit).await
+    }
+
+    pub fn snapshots(&self, asset_id: Option<&str>, limit: usize) -> Paginator<SnapshotMessage> {
+        let client = self.client.clone();
+        let asset_id = asset_id.map(str::to_string);
+        Paginator::new(move |offset: Option<String>| {
+            let client = client.clone();
+            let asset_id = asset_id.clone();
+            async move {
+                let snapshots =
+                    fetch_snapshots(&client, asset_id.as_deref(), offset.as_deref(), limit).await?;
+                Ok(Page::from_offset(snapshots, limit, |snapshot| {
+                    rfc3339_offset(&snapshot.created_at)
+                }))
+            }
+        })
+    }
+
     pub async fn get_ticker(
         &self,
         asset_id: &str,
@@ -40,6 +69,23 @@ impl SnapshotApi {
     }
 }
 
+async fn fetch_snapshots(
+    client: &ClientRef,
+    asset_id: Option<&str>,
+    offset: Option<&str>,
+    limit: usize,
+) -> Result<Vec<SnapshotMessage>, ApiError> {
+    let limit = limit.to_string();
+    let mut query = vec![("limit", limit.as_str()), ("order", "DESC")];
+    if let Some(asset_id) = asset_id {
+        query.push(("asset", asset_id));
+    }
+    if let Some(offset) = offset {
+        query.push(("offset", offset));
+    }
+    client.get_with_query("snapshots", &query).await
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct Ticker {
     pub price_usd: String,
@@ -83,6 +83,10 @@ pub struct UserSession {
     pub session_id: String,
     pub platform: Option<String>,
     pub public_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_at: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,705 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::io::{Cursor, Read, Write};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::channel::mpsc::UnboundedSender;
+use futures::{SinkExt, Stream, StreamExt};
+use log::{error, info, warn};
+use reqwest::header::HeaderValue;
+use reqwest::Method;
+use tokio::sync::{mpsc, watch, Notify};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::{Error as WebSocketError, Message};
+
+use crate::blaze_message::{
+    BlazeMessage, BlazeMessageData, ACKNOWLEDGE_MESSAGE_RECEIPT, CREATE_CALL, CREATE_KRAKEN,
+    CREATE_MESSAGE,
+};
+use crate::completer::Completer;
+use crate::err::error_code;
+use crate::{BlazeAckMessage, Client, Credential, MessageStatus, ERROR_ACTION};
+
+const WS_HOSTS: [&str; 2] = [
+    "wss://blaze.mixin.one:443",
+    "wss://mixin-blaze.zeromesh.net:443",
+];
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const PING_INTERVAL: Duration = Duration::from_secs(10);
+const PONG_TIMEOUT: Duration = Duration::from_secs(30);
+const ACK_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, thiserror::Error)]
+#[error("blaze authentication failed")]
+pub struct BlazeAuthenticationError;
+
+/// Storage and message handling behind a [`BlazeClient`].
+pub trait BlazeHandler: Send + Sync + 'static {
+    /// Where `LIST_PENDING_MESSAGES` resumes after (re)connecting: the
+    /// `created_at` of the newest message already handled.
+    fn pending_messages_offset(&self)
+        -> impl Future<Output = Result<Option<DateTime<Utc>>>> + Send;
+
+    /// Handles a `CREATE_MESSAGE`, `ACKNOWLEDGE_MESSAGE_RECEIPT`,
+    /// `CREATE_CALL` or `CREATE_KRAKEN` message. A returned status is
+    /// acknowledged to the server in the next batch.
+    fn handle_message(
+        &self,
+        action: &str,
+        data: BlazeMessageData,
+    ) -> impl Future<Output = Result<Option<MessageStatus>>> + Send;
+
+    /// Runs alongside each connection once it is open, e.g. to catch up on
+    /// changes missed while offline.
+    fn on_connected(&self) -> impl Future<Output = Result<()>> + Send {
+        async { Ok(()) }
+    }
+}
+
+/// A reconnecting Blaze websocket with request/response transactions and
+/// batched acknowledgements.
+pub struct BlazeClient<H> {
+    handler: H,
+    client: Arc<Client>,
+    credential: Credential,
+    user_agent: Option<String>,
+    connection: Arc<Mutex<BlazeConnection>>,
+    connection_status: watch::Sender<bool>,
+    transactions: Arc<Mutex<HashMap<String, Completer<BlazeMessage>>>>,
+    connect_running: Arc<AtomicBool>,
+    reconnect: Notify,
+    acknowledgements: Mutex<VecDeque<BlazeAckMessage>>,
+    acknowledgements_ready: Notify,
+}
+
+struct BlazeConnection {
+    sink: Option<UnboundedSender<Message>>,
+}
+
+enum SocketSessionEnd {
+    Disconnected,
+    AuthenticationFailed,
+}
+
+enum SocketMessageResult {
+    Continue,
+    AuthenticationFailed,
+}
+
+struct ConnectLoopGuard(Arc<AtomicBool>);
+
+impl Drop for ConnectLoopGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+struct SocketSessionGuard {
+    connection: Arc<Mutex<BlazeConnection>>,
+    connection_status: watch::Sender<bool>,
+    transactions: Arc<Mutex<HashMap<String, Completer<BlazeMessage>>>>,
+}
+
+impl Drop for SocketSessionGuard {
+    fn drop(&mut self) {
+        self.connection_status.send_replace(false);
+        self.connection.lock().unwrap().sink = None;
+        fail_transactions(&self.transactions, "blaze disconnected");
+    }
+}
+
+fn fail_transactions(transactions: &Mutex<HashMap<String, Completer<BlazeMessage>>>, reason: &str) {
+    let pending = transactions.lock().unwrap().drain().collect::<Vec<_>>();
+    for (_, transaction) in pending {
+        transaction.complete(Err(anyhow!(reason.to_string())));
+    }
+}
+
+fn is_authentication_handshake_error(error: &WebSocketError) -> bool {
+    matches!(
+        error,
+        WebSocketError::Http(response) if matches!(response.status().as_u16(), 401 | 403)
+    )
+}
+
+/// Whether `error` is the REST API rejecting the credential.
+pub fn is_api_authentication_error(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<crate::ApiError>()
+        .is_some_and(|error| {
+            matches!(
+                error,
+                crate::ApiError::Server(crate::Error {
+                    code: error_code::AUTHENTICATION,
+                    ..
+                })
+            )
+        })
+}
+
+async fn await_transaction(
+    transactions: &Mutex<HashMap<String, Completer<BlazeMessage>>>,
+    message_id: &str,
+    completer: Completer<BlazeMessage>,
+    timeout: Duration,
+) -> Result<BlazeMessage> {
+    match tokio::time::timeout(timeout, completer).await {
+        Ok(result) => result,
+        Err(_) => {
+            transactions.lock().unwrap().remove(message_id);
+            Err(anyhow!("blaze request {message_id} timed out"))
+        }
+    }
+}
+
+async fn send_blaze_message(
+    sender: &mut UnboundedSender<Message>,
+    message: BlazeMessage,
+) -> Result<()> {
+    let bytes = serde_json::to_vec(&message)?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    encoder.write_all(&bytes)?;
+    let compressed_data = encoder.finish()?;
+    sender.send(Message::Binary(compressed_data.into())).await?;
+    Ok(())
+}
+
+impl<H: BlazeHandler> BlazeClient<H> {
+    pub fn new(client: Arc<Client>, credential: Credential, handler: H) -> Self {
+        let (connection_status, _) = watch::channel(false);
+        Self {
+            handler,
+            client,
+            credential,
+            user_agent: None,
+            connection: Arc::new(Mutex::new(BlazeConnection { sink: None })),
+            connection_status,
+            transactions: Arc::new(Mutex::new(HashMap::new())),
+            connect_running: Arc::new(AtomicBool::new(false)),
+            reconnect: Notify::new(),
+            acknowledgements: Mutex::new(VecDeque::new()),
+            acknowledgements_ready: Notify::new(),
+        }
+    }
+
+    pub fn with_user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub fn subscribe_connection_status(&self) -> watch::Receiver<bool> {
+        self.connection_status.subscribe()
+    }
+
+    pub fn retry_connection(&self) {
+        self.reconnect.notify_one();
+    }
+
+    /// Queues an acknowledgement for the next batch.
+    pub fn acknowledge(&self, message_id: &str, status: MessageStatus) {
+        let status: &str = status.into();
+        self.acknowledgements
+            .lock()
+            .unwrap()
+            .push_back(BlazeAckMessage {
+                message_id: message_id.to_string(),
+                status: status.to_string(),
+                expire_at: None,
+            });
+        self.acknowledgements_ready.notify_one();
+    }
+
+    /// Keeps the websocket connected, switching hosts after failures, until
+    /// the credential is rejected with [`BlazeAuthenticationError`].
+    pub async fn connect(&self) -> Result<()> {
+        if self
+            .connect_running
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(anyhow!("blaze connection loop is already running"));
+        }
+        let _connect_guard = ConnectLoopGuard(self.connect_running.clone());
+
+        tokio::select! {
+            result = self.connect_loop() => result,
+            _ = self.flush_acknowledgements() => Ok(()),
+        }
+    }
+
+    async fn connect_loop(&self) -> Result<()> {
+        let mut host_index = 0;
+        loop {
+            let host = WS_HOSTS[host_index];
+            match self.connect_once(host).await {
+                Ok(SocketSessionEnd::AuthenticationFailed) => {
+                    return Err(BlazeAuthenticationError.into());
+                }
+                Ok(SocketSessionEnd::Disconnected) => {
+                    warn!("blaze disconnected from {host}");
+                }
+                Err(err) => {
+                    warn!("blaze connection to {host} failed: {err:?}");
+                }
+            }
+
+            host_index = (host_index + 1) % WS_HOSTS.len();
+            tokio::select! {
+                _ = tokio::time::sleep(RECONNECT_DELAY) => {}
+                _ = self.reconnect.notified() => {}
+            }
+        }
+    }
+
+    async fn flush_acknowledgements(&self) {
+        loop {
+            let batch = {
+                let mut pending = self.acknowledgements.lock().unwrap();
+                let count = pending.len().min(ACK_BATCH_SIZE);
+                pending.drain(..count).collect::<Vec<_>>()
+            };
+            if batch.is_empty() {
+                self.acknowledgements_ready.notified().await;
+                continue;
+            }
+            if let Err(err) = self.client.message_api.acknowledgements(&batch).await {
+                warn!("failed to acknowledge {} messages: {err}", batch.len());
+                {
+                    let mut pending = self.acknowledgements.lock().unwrap();
+                    for acknowledgement in batch.into_iter().rev() {
+                        pending.push_front(acknowledgement);
+                    }
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        }
+    }
+
+    async fn connect_once(&self, host: &str) -> Result<SocketSessionEnd> {
+        let token = self
+            .credential
+            .sign_authentication_token(&Method::GET, &"/".to_string(), [])
+            .map_err(|e| anyhow!("can not sign request: {}", e))?;
+
+        let mut request = host.into_client_request()?;
+        request.headers_mut().insert(
+            "Sec-WebSocket-Protocol",
+            HeaderValue::try_from("Mixin-Blaze-1")?,
+        );
+        request.headers_mut().insert(
+            "Authorization",
+            HeaderValue::try_from(format!("Bearer {}", token))?,
+        );
+        if let Some(user_agent) = &self.user_agent {
+            request
+                .headers_mut()
+                .insert("User-Agent", HeaderValue::try_from(user_agent.as_str())?);
+        }
+
+        let connection = tokio::time::timeout(CONNECT_TIMEOUT, connect_async(request))
+            .await
+            .map_err(|_| anyhow!("blaze connection timed out"))?;
+        let (ws_stream, _) = match connection {
+            Ok(connection) => connection,
+            Err(err) if is_authentication_handshake_error(&err) => {
+                return Ok(SocketSessionEnd::AuthenticationFailed);
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let (mut socket_sink, mut socket_stream) = ws_stream.split();
+        let (mut sender, mut receiver) = futures::channel::mpsc::unbounded();
+
+        self.connection.lock().unwrap().sink = Some(sender.clone());
+        self.connection_status.send_replace(true);
+        let _session_guard = SocketSessionGuard {
+            connection: self.connection.clone(),
+            connection_status: self.connection_status.clone(),
+            transactions: self.transactions.clone(),
+        };
+
+        let offset = self.handler.pending_messages_offset().await?;
+        info!("list pending messages from offset: {:?}", offset);
+        send_blaze_message(
+            &mut sender,
+            BlazeMessage::new_list_pending_blaze(offset.map(|offset| offset.to_rfc3339())),
+        )
+        .await?;
+        let mut heartbeat = tokio::time::interval(PING_INTERVAL);
+        heartbeat.tick().await;
+        let mut last_pong = tokio::time::Instant::now();
+        let mut connected = Box::pin(self.handler.on_connected());
+        let mut connected_complete = false;
+
+        loop {
+            tokio::select! {
+                result = connected.as_mut(), if !connected_complete => {
+                    connected_complete = true;
+                    if let Err(err) = result {
+                        if is_api_authentication_error(&err) {
+                            return Ok(SocketSessionEnd::AuthenticationFailed);
+                        }
+                        warn!("blaze connection hook failed: {err}");
+                    }
+                }
+                _ = heartbeat.tick() => {
+                    if last_pong.elapsed() >= PONG_TIMEOUT {
+                        return Ok(SocketSessionEnd::Disconnected);
+                    }
+                    socket_sink.send(Message::Ping(Vec::new().into())).await?;
+                }
+                outgoing = receiver.next() => {
+                    let Some(outgoing) = outgoing else {
+                        return Ok(SocketSessionEnd::Disconnected);
+                    };
+                    socket_sink.send(outgoing).await?;
+                }
+                incoming = socket_stream.next() => {
+                    let Some(incoming) = incoming else {
+                        return Ok(SocketSessionEnd::Disconnected);
+                    };
+                    let incoming = incoming?;
+                    match incoming {
+                        Message::Ping(data) => socket_sink.send(Message::Pong(data)).await?,
+                        Message::Pong(_) => last_pong = tokio::time::Instant::now(),
+                        Message::Close(_) => return Ok(SocketSessionEnd::Disconnected),
+                        message => {
+                            match self.on_socket_message(message).await? {
+                                SocketMessageResult::Continue => {}
+                                SocketMessageResult::AuthenticationFailed => {
+                                    return Ok(SocketSessionEnd::AuthenticationFailed);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn on_socket_message(&self, message: Message) -> Result<SocketMessageResult> {
+        let data = message.into_data();
+
+        let mut decoder = GzDecoder::new(Cursor::new(&data));
+        let mut decompressed_data = Vec::new();
+        decoder.read_to_end(&mut decompressed_data)?;
+        let message: BlazeMessage = serde_json::from_slice(&decompressed_data)?;
+
+        let authentication_failed = message.action == ERROR_ACTION
+            && message
+                .error
+                .as_ref()
+                .is_some_and(|e| e.code == error_code::AUTHENTICATION);
+
+        {
+            let mut transactions = self.transactions.lock().unwrap();
+            if let Some(transaction) = transactions.remove(&message.id) {
+                transaction.complete(Ok(message.clone()));
+            }
+        }
+
+        if authentication_failed {
+            return Ok(SocketMessageResult::AuthenticationFailed);
+        }
+
+        if message.data.is_some()
+            && (message.action == ACKNOWLEDGE_MESSAGE_RECEIPT
+                || message.action == CREATE_MESSAGE
+                || message.action == CREATE_CALL
+                || message.action == CREATE_KRAKEN)
+        {
+            if let Err(err) = self.handle_receive_message(message).await {
+                if is_api_authentication_error(&err) {
+                    return Ok(SocketMessageResult::AuthenticationFailed);
+                }
+                error!("failed to handle_receive_message, error: {:?} ", err);
+            }
+        }
+        Ok(SocketMessageResult::Continue)
+    }
+
+    async fn handle_receive_message(&self, message: BlazeMessage) -> Result<()> {
+        let data = message.data.ok_or(anyhow!("blaze message no data"))?;
+        let data: BlazeMessageData = serde_json::from_value(data)?;
+        info!("handle receive message: {}", message.action);
+        let message_id = data.message_id.clone();
+        if let Some(status) = self.handler.handle_message(&message.action, data).await? {
+            self.acknowledge(&message_id, status);
+        }
+        Ok(())
+    }
+
+    pub fn try_get_sender(&self) -> Result<UnboundedSender<Message>> {
+        let connection = self
+            .connection
+            .lock()
+            .map_err(|_| anyhow!("blaze connection lock is poisoned"))?;
+        connection.sink.clone().ok_or(anyhow!("not connected"))
+    }
+
+    async fn get_sender(&self) -> Result<UnboundedSender<Message>> {
+        let mut connection_status = self.connection_status.subscribe();
+        loop {
+            if let Ok(sender) = self.try_get_sender() {
+                return Ok(sender);
+            }
+            connection_status
+                .wait_for(|connected| *connected)
+                .await
+                .map_err(|_| anyhow!("blaze connection stopped"))?;
+        }
+    }
+
+    /// Sends `message` and waits for the server's reply with the same id.
+    pub async fn send_message(&self, message: BlazeMessage) -> Result<BlazeMessage> {
+        let mut sender = self.get_sender().await?;
+        let completer = Completer::default();
+        let message_id = message.id.clone();
+        {
+            let mut transactions = self.transactions.lock().unwrap();
+            transactions.insert(message_id.clone(), completer.clone());
+        }
+        if let Err(err) = send_blaze_message(&mut sender, message).await {
+            self.transactions.lock().unwrap().remove(&message_id);
+            return Err(err);
+        }
+
+        await_transaction(&self.transactions, &message_id, completer, REQUEST_TIMEOUT).await
+    }
+}
+
+impl BlazeClient<ChannelHandler> {
+    /// Connects `credential` and yields the messages other users send to it,
+    /// acknowledging each one as read. Pending messages are listed from the
+    /// newest one yielded so far; the stream ends when the credential is
+    /// rejected, and dropping it disconnects.
+    pub fn messages(client: Arc<Client>, credential: Credential) -> BlazeMessageStream {
+        let user_id = match &credential {
+            Credential::KeyStore(key_store) => Some(key_store.app_id.clone()),
+            Credential::None => None,
+        };
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let blaze = Arc::new(BlazeClient::new(
+            client,
+            credential,
+            ChannelHandler {
+                user_id,
+                sender,
+                offset: Mutex::new(None),
+            },
+        ));
+        let connection = blaze.clone();
+        let task = tokio::spawn(async move {
+            if let Err(err) = connection.connect().await {
+                error!("blaze message stream stopped: {err:?}");
+            }
+        });
+        BlazeMessageStream {
+            blaze,
+            receiver,
+            task,
+        }
+    }
+}
+
+/// Forwards messages to a [`BlazeMessageStream`].
+pub struct ChannelHandler {
+    user_id: Option<String>,
+    sender: mpsc::UnboundedSender<BlazeMessageData>,
+    offset: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl BlazeHandler for ChannelHandler {
+    async fn pending_messages_offset(&self) -> Result<Option<DateTime<Utc>>> {
+        Ok(*self.offset.lock().unwrap())
+    }
+
+    async fn handle_message(
+        &self,
+        action: &str,
+        data: BlazeMessageData,
+    ) -> Result<Option<MessageStatus>> {
+        if action != CREATE_MESSAGE {
+            return Ok(None);
+        }
+        // Status updates of our own messages arrive without a category.
+        if self.user_id.as_deref() == Some(data.user_id.as_str()) && data.category.is_empty() {
+            return Ok(None);
+        }
+        {
+            let mut offset = self.offset.lock().unwrap();
+            if offset.is_none_or(|offset| offset < data.created_at) {
+                *offset = Some(data.created_at);
+            }
+        }
+        self.sender
+            .send(data)
+            .map_err(|_| anyhow!("blaze message stream was dropped"))?;
+        Ok(Some(MessageStatus::Read))
+    }
+}
+
+pub struct BlazeMessageStream {
+    blaze: Arc<BlazeClient<ChannelHandler>>,
+    receiver: mpsc::UnboundedReceiver<BlazeMessageData>,
+    task: JoinHandle<()>,
+}
+
+impl BlazeMessageStream {
+    /// The underlying client, e.g. to send Blaze requests on the same socket.
+    pub fn client(&self) -> &Arc<BlazeClient<ChannelHandler>> {
+        &self.blaze
+    }
+}
+
+impl Stream for BlazeMessageStream {
+    type Item = BlazeMessageData;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for BlazeMessageStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn disconnect_clears_sender_and_fails_pending_transactions() {
+        let (sender, _receiver) = futures::channel::mpsc::unbounded();
+        let connection = Arc::new(Mutex::new(BlazeConnection { sink: Some(sender) }));
+        let (connection_status, _) = watch::channel(true);
+        let transactions = Arc::new(Mutex::new(HashMap::new()));
+        let completer = Completer::<BlazeMessage>::default();
+        transactions
+            .lock()
+            .unwrap()
+            .insert("request-id".to_string(), completer.clone());
+
+        let guard = SocketSessionGuard {
+            connection: connection.clone(),
+            connection_status,
+            transactions: transactions.clone(),
+        };
+        drop(guard);
+
+        assert!(connection.lock().unwrap().sink.is_none());
+        assert!(transactions.lock().unwrap().is_empty());
+        assert_eq!(
+            completer.await.unwrap_err().to_string(),
+            "blaze disconnected"
+        );
+    }
+
+    #[tokio::test]
+    async fn request_timeout_removes_pending_transaction() {
+        let transactions = Mutex::new(HashMap::new());
+        let completer = Completer::<BlazeMessage>::default();
+        transactions
+            .lock()
+            .unwrap()
+            .insert("request-id".to_string(), completer.clone());
+
+        let result = await_transaction(
+            &transactions,
+            "request-id",
+            completer,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "blaze request request-id timed out"
+        );
+        assert!(transactions.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn unauthorized_handshake_is_an_authentication_error() {
+        for status in [401, 403] {
+            let response = tokio_tungstenite::tungstenite::http::Response::builder()
+                .status(status)
+                .body(None)
+                .unwrap();
+            assert!(is_authentication_handshake_error(&WebSocketError::Http(
+                Box::new(response)
+            )));
+        }
+
+        let api_error = anyhow::Error::new(crate::ApiError::Server(crate::Error {
+            status: 401,
+            code: error_code::AUTHENTICATION,
+            description: "unauthorized".to_string(),
+        }));
+        assert!(is_api_authentication_error(&api_error));
+    }
+
+    #[tokio::test]
+    async fn channel_handler_forwards_messages_and_tracks_the_offset() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let handler = ChannelHandler {
+            user_id: Some("bot".to_string()),
+            sender,
+            offset: Mutex::new(None),
+        };
+        let message = |user_id: &str, category: &str, created_at: &str| {
+            serde_json::from_value::<BlazeMessageData>(serde_json::json!({
+                "conversation_id": "conversation",
+                "user_id": user_id,
+                "message_id": format!("{user_id}-{created_at}"),
+                "category": category,
+                "data": "",
+                "status": "SENT",
+                "source": "",
+                "session_id": "session",
+                "created_at": created_at,
+                "updated_at": created_at,
+            }))
+            .unwrap()
+        };
+
+        let status = handler
+            .handle_message(
+                CREATE_MESSAGE,
+                message("user", "PLAIN_TEXT", "2024-01-01T00:00:02Z"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status, Some(MessageStatus::Read));
+        let own_status = handler
+            .handle_message(CREATE_MESSAGE, message("bot", "", "2024-01-01T00:00:03Z"))
+            .await
+            .unwrap();
+        assert_eq!(own_status, None);
+
+        assert_eq!(receiver.recv().await.unwrap().user_id, "user");
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(
+            handler.pending_messages_offset().await.unwrap(),
+            Some("2024-01-01T00:00:02Z".parse().unwrap())
+        );
+    }
+}
@@ -10,6 +10,8 @@ pub const DEVICE_TRANSFER: &str = "DEVICE_TRANSFER";
 pub const SENDING_MESSAGE: &str = "SENDING_MESSAGE";
 pub const RECALL_MESSAGE: &str = "RECALL_MESSAGE";
 pub const PIN_MESSAGE: &str = "PIN_MESSAGE";
+pub const EDIT_MESSAGE: &str = "EDIT_MESSAGE";
+pub const REACTION_MESSAGE: &str = "REACTION_MESSAGE";
 pub const RESEND_MESSAGES: &str = "RESEND_MESSAGES";
 pub const CREATE_MESSAGE: &str = "CREATE_MESSAGE";
 pub const CREATE_CALL: &str = "CREATE_CALL";
@@ -281,6 +283,8 @@ pub mod message_action {
     pub const UPDATE: &str = "UPDATE";
     pub const ROLE: &str = "ROLE";
     pub const EXPIRE: &str = "EXPIRE";
+    pub const PROVISION: &str = "PROVISION";
+    pub const DESTROY: &str = "DESTROY";
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -300,6 +304,15 @@ pub struct SystemUserMessage {
     pub user_id: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SystemSessionMessage {
+    pub action: String,
+    pub user_id: String,
+    pub session_id: String,
+    #[serde(default)]
+    pub public_key: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SystemCircleAction {
@@ -1,19 +1,26 @@
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context};
 use bytes::Bytes;
-use reqwest::header::HeaderValue;
-use reqwest::{Method, Request};
+use chrono::Utc;
+use log::{debug, warn};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Method, Request, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::sync::watch;
+use uuid::Uuid;
 
 use crate::credential::Credential;
+use crate::middleware::{
+    is_idempotent, is_retryable_envelope, is_retryable_status, retry_after, ClientOptions,
+    RequestTrace, ResponseTrace, REQUEST_ID_HEADER,
+};
 use crate::{
     AccountApi, ApiError, AssetApi, AttachmentApi, CircleApi, ConversationApi, MessageApi,
-    ProvisioningApi, SnapshotApi, TokenApi, UserApi,
+    ProvisioningApi, SafeApi, SnapshotApi, TokenApi, UserApi,
 };
 
 pub struct Client {
@@ -28,6 +35,7 @@ pub struct Client {
     pub circle_api: CircleApi,
     pub message_api: MessageApi,
     pub snapshot_api: SnapshotApi,
+    pub safe_api: SafeApi,
 }
 
 impl Client {
@@ -36,7 +44,17 @@ impl Client {
     }
 
     pub fn new_with_user_agent(credential: Credential, user_agent: Option<String>) -> Self {
-        let inner = Arc::new(ClientRef::new(credential, user_agent));
+        Self::new_with_options(credential, user_agent, ClientOptions::default())
+    }
+
+    /// Creates a client whose requests go through the retry, timeout and
+    /// tracing settings in `options`.
+    pub fn new_with_options(
+        credential: Credential,
+        user_agent: Option<String>,
+        options: ClientOptions,
+    ) -> Self {
+        let inner = Arc::new(ClientRef::new(credential, user_agent, options));
         Client {
             inner: inner.clone(),
             user_api: UserApi::new(inner.clone()),
@@ -53,6 +71,7 @@ impl Client {
                 client: inner.clone(),
             },
             snapshot_api: SnapshotApi::new(inner.clone()),
+            safe_api: SafeApi::new(inner.clone()),
         }
     }
 
@@ -76,12 +95,13 @@ pub(crate) struct ClientRef {
     credential: Credential,
     pub(crate) base_url: String,
     pub(crate) client: reqwest::Client,
+    options: ClientOptions,
     authentication_failed: watch::Sender<bool>,
     server_error_code: watch::Sender<Option<i64>>,
 }
 
 const MIXIN_BASE_URL: &str = "https://api.mixin.one";
-const API_TIMEOUT: Duration = Duration::from_secs(10);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "lowercase")]
@@ -91,10 +111,9 @@ pub(crate) enum MixinResponse {
 }
 
 impl ClientRef {
-    pub fn new(credential: Credential, user_agent: Option<String>) -> Self {
-        let mut builder = reqwest::Client::builder()
-            .connect_timeout(API_TIMEOUT)
-            .read_timeout(API_TIMEOUT);
+    pub fn new(credential: Credential, user_agent: Option<String>, options: ClientOptions) -> Self {
+        // Overall timeouts are set per request from `options`.
+        let mut builder = reqwest::Client::builder().connect_timeout(CONNECT_TIMEOUT);
         if let Some(user_agent) = user_agent.filter(|value| !value.is_empty()) {
             builder = builder.user_agent(user_agent);
         }
@@ -102,11 +121,20 @@ impl ClientRef {
             credential,
             base_url: MIXIN_BASE_URL.to_string(),
             client: builder.build().expect("failed to build Mixin HTTP client"),
+            options,
             authentication_failed: watch::channel(false).0,
             server_error_code: watch::channel(None).0,
         }
     }
 
+    /// The signed-in user, or `None` for anonymous clients.
+    pub(crate) fn user_id(&self) -> Option<&str> {
+        match &self.credential {
+            Credential::KeyStore(key_store) => Some(&key_store.app_id),
+            Credential::None => None,
+        }
+    }
+
     fn notify_authentication_failed(&self) {
         self.authentication_failed.send_replace(true);
     }
@@ -153,18 +181,93 @@ impl ClientRef {
         self.request(request).await
     }
 
-    pub(crate) async fn raw_request(&self, mut request: Request) -> Result<Bytes, ApiError> {
-        let path = match request.method() {
-            &Method::GET => {
-                let path = request.url().path();
-                if let Some(query) = request.url().query() {
-                    format!("{}?{}", path, query)
-                } else {
-                    path.to_string()
+    pub(crate) async fn raw_request(&self, request: Request) -> Result<Bytes, ApiError> {
+        let request_id = Uuid::new_v4().to_string();
+        let method = request.method().clone();
+        let path = signing_path(&request);
+        let idempotent = is_idempotent(&method);
+        let timeout = request
+            .timeout()
+            .copied()
+            .unwrap_or_else(|| self.options.timeout_for(request.url().path()));
+        let mut next = Some(request);
+        let mut attempt = 0;
+        loop {
+            let mut current = next
+                .take()
+                .ok_or_else(|| anyhow!("request can not be retried"))?;
+            if idempotent {
+                next = current.try_clone();
+            }
+            *current.timeout_mut() = Some(timeout);
+            self.authorize(&mut current, &path, &request_id)?;
+
+            let trace = RequestTrace {
+                request_id: request_id.clone(),
+                method: method.clone(),
+                path: path.clone(),
+                attempt,
+            };
+            if let Some(tracer) = &self.options.tracer {
+                tracer.on_request(&trace);
+            }
+            let started = Instant::now();
+            let result = self.execute(current).await;
+
+            let (status, retryable, retry_after) = match &result {
+                Ok((status, headers, body)) => (
+                    Some(*status),
+                    is_retryable_status(*status)
+                        || (status.is_success() && is_retryable_envelope(body)),
+                    retry_after(headers, Utc::now()),
+                ),
+                Err(ApiError::Request(error)) => {
+                    (None, error.is_timeout() || error.is_connect(), None)
                 }
+                Err(_) => (None, false, None),
+            };
+            attempt += 1;
+            let retry_in = (idempotent && retryable && next.is_some())
+                .then(|| self.options.retry.delay(attempt, retry_after))
+                .flatten();
+
+            debug!(
+                "[{request_id}] {method} {path} -> {} in {:?}",
+                status.map_or_else(|| "no response".to_string(), |status| status.to_string()),
+                started.elapsed(),
+            );
+            if let Some(tracer) = &self.options.tracer {
+                tracer.on_response(&ResponseTrace {
+                    request: trace,
+                    status,
+                    elapsed: started.elapsed(),
+                    error: result.as_ref().err().map(ToString::to_string),
+                    retry_in,
+                });
             }
-            _ => request.url().path().to_string(),
-        };
+            if let Some(delay) = retry_in {
+                warn!("[{request_id}] retrying {method} {path} in {delay:?} (attempt {attempt})");
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            let (status, _, bytes) = result?;
+            if !status.is_success() {
+                return match self.parse_response::<Value>(&bytes) {
+                    Err(error) => Err(error),
+                    Ok(_) => Err(anyhow!("unexpected response status {status}").into()),
+                };
+            }
+            return Ok(bytes);
+        }
+    }
+
+    fn authorize(
+        &self,
+        request: &mut Request,
+        path: &str,
+        request_id: &str,
+    ) -> Result<(), ApiError> {
         let body: &[u8] = match request.method() {
             &Method::POST => request
                 .body()
@@ -184,21 +287,22 @@ impl ClientRef {
             Ok(h) => header.append("Authorization", h),
             Err(err) => return Err(anyhow!("can not set auth header: {}", err).into()),
         };
+        header.insert(
+            REQUEST_ID_HEADER,
+            HeaderValue::from_str(request_id).map_err(|e| anyhow!("invalid request id: {e}"))?,
+        );
+        Ok(())
+    }
 
+    async fn execute(&self, request: Request) -> Result<(StatusCode, HeaderMap, Bytes), ApiError> {
         let resp = self.client.execute(request).await?;
         let status = resp.status();
         if status.as_u16() == crate::err::error_code::AUTHENTICATION as u16 {
             self.notify_authentication_failed();
         }
+        let headers = resp.headers().clone();
         let bytes = resp.bytes().await?;
-        if !status.is_success() {
-            return match self.parse_response::<Value>(&bytes) {
-                Err(error) => Err(error),
-                Ok(_) => Err(anyhow!("unexpected response status {status}").into()),
-            };
-        }
-
-        Ok(bytes)
+        Ok((status, headers, bytes))
     }
 
     pub(crate) async fn request<T>(&self, request: Request) -> Result<T, ApiError>
@@ -238,6 +342,15 @@ impl ClientRef {
     }
 }
 
+/// The path and, for GET requests, query that request signatures cover.
+fn signing_path(request: &Request) -> String {
+    let path = request.url().path();
+    match (request.method(), request.url().query()) {
+        (&Method::GET, Some(query)) => format!("{}?{}", path, query),
+        _ => path.to_string(),
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -281,4 +394,76 @@ pub mod tests {
         server_errors.changed().await.unwrap();
         assert_eq!(*server_errors.borrow(), Some(911));
     }
+
+    /// Answers each connection with the next canned response and records
+    /// the request heads it received.
+    async fn serve(responses: Vec<&'static str>) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let received = requests.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 4096];
+                let read = socket.read(&mut buffer).await.unwrap();
+                received
+                    .lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&buffer[..read]).to_lowercase());
+                socket.write_all(response.as_bytes()).await.unwrap();
+                let _ = socket.shutdown().await;
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn local_client(base_url: String) -> ClientRef {
+        let options = ClientOptions::default().with_retry(crate::RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_secs(1),
+        });
+        let mut client = ClientRef::new(Credential::None, None, options);
+        client.base_url = base_url;
+        client
+    }
+
+    const RATE_LIMITED: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const OK: &str =
+        "HTTP/1.1 200 OK\r\nContent-Length: 15\r\nConnection: close\r\n\r\n{\"data\":\"done\"}";
+
+    #[tokio::test]
+    async fn idempotent_requests_retry_with_the_same_request_id() {
+        let (base_url, requests) = serve(vec![RATE_LIMITED, OK]).await;
+        let client = local_client(base_url);
+
+        let data: String = client.get("me").await.unwrap();
+
+        assert_eq!(data, "done");
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let request_id = |request: &str| {
+            request
+                .lines()
+                .find_map(|line| line.strip_prefix("x-request-id: "))
+                .map(str::to_string)
+        };
+        assert!(request_id(&requests[0]).is_some());
+        assert_eq!(request_id(&requests[0]), request_id(&requests[1]));
+    }
+
+    #[tokio::test]
+    async fn posts_are_not_retried() {
+        let (base_url, requests) = serve(vec![RATE_LIMITED, OK]).await;
+        let client = local_client(base_url);
+
+        let result = client.post::<String, _>("acknowledgements", &[1]).await;
+
+        assert!(result.is_err());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
 }
@@ -5,21 +5,31 @@ pub use api::circle_api::*;
 pub use api::conversation_api::*;
 pub use api::message_api::*;
 pub use api::provisioning_api::*;
+pub use api::safe_api::*;
 pub use api::snapshot_api::*;
 pub use api::token_api::*;
 pub use api::user_api::*;
+pub use blaze::{BlazeAuthenticationError, BlazeClient, BlazeHandler, BlazeMessageStream};
 pub use blaze_message::*;
 pub use client::Client;
 pub use credential::{Credential, KeyStore};
 pub use err::{ApiError, Error};
 pub use message::*;
+pub use middleware::{ClientOptions, RequestTracer, RetryPolicy};
+pub use pagination::{Page, Paginator};
+pub use safe::SpendKey;
 pub use util::{generate_conversation_id, group_conversation_id, unique_object_id};
 
 pub mod api;
+pub mod blaze;
 pub mod blaze_message;
 pub mod client;
+pub mod completer;
 pub mod credential;
 pub mod err;
 pub mod message;
 pub mod message_category;
+pub mod middleware;
+pub mod pagination;
+pub mod safe;
 pub mod util;
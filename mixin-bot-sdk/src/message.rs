@@ -18,6 +18,20 @@ pub struct RecallMessage {
     pub message_id: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EditMessage {
+    pub message_id: String,
+    pub content: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ReactionMessage {
+    pub message_id: String,
+    pub emoji: String,
+    #[serde(default)]
+    pub removed: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AttachmentMessage {
     #[serde(default, with = "optional_base64_bytes")]
@@ -106,6 +120,18 @@ pub struct LocationMessage {
     pub venue_type: Option<String>,
 }
 
+impl LocationMessage {
+    /// Whether the coordinates are finite, in range and not the `0, 0`
+    /// placeholder some clients send when no fix is available.
+    pub fn is_valid(&self) -> bool {
+        self.latitude.is_finite()
+            && self.longitude.is_finite()
+            && (-90.0..=90.0).contains(&self.latitude)
+            && (-180.0..=180.0).contains(&self.longitude)
+            && !(self.latitude == 0.0 && self.longitude == 0.0)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AppButton {
     pub label: String,
@@ -197,4 +223,24 @@ mod tests {
 
         assert!(card.actions.is_empty());
     }
+
+    #[test]
+    fn location_message_rejects_out_of_range_coordinates() {
+        let location = |latitude, longitude| LocationMessage {
+            latitude,
+            longitude,
+            name: None,
+            address: None,
+            venue_type: None,
+        };
+
+        assert!(location(31.2304, 121.4737).is_valid());
+        assert!(location(-90.0, -180.0).is_valid());
+        assert!(location(0.0, 121.4737).is_valid());
+        assert!(location(51.4779, 0.0).is_valid());
+        assert!(!location(0.0, 0.0).is_valid());
+        assert!(!location(90.5, 121.4737).is_valid());
+        assert!(!location(31.2304, 180.5).is_valid());
+        assert!(!location(f64::NAN, 121.4737).is_valid());
+    }
 }
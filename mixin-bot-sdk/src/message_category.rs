@@ -28,6 +28,8 @@ pub const PLAIN_LOCATION: &str = "PLAIN_LOCATION";
 pub const PLAIN_TRANSCRIPT: &str = "PLAIN_TRANSCRIPT";
 pub const MESSAGE_RECALL: &str = "MESSAGE_RECALL";
 pub const MESSAGE_PIN: &str = "MESSAGE_PIN";
+pub const MESSAGE_EDIT: &str = "MESSAGE_EDIT";
+pub const MESSAGE_REACTION: &str = "MESSAGE_REACTION";
 pub const STRANGER: &str = "STRANGER";
 pub const SECRET: &str = "SECRET";
 pub const SYSTEM_CONVERSATION: &str = "SYSTEM_CONVERSATION";
@@ -78,6 +80,9 @@ pub trait MessageCategory {
     fn is_call(&self) -> bool;
     fn is_kraken(&self) -> bool;
     fn is_recall(&self) -> bool;
+    fn is_edit(&self) -> bool;
+    fn can_edit(&self) -> bool;
+    fn is_reaction(&self) -> bool;
     fn is_fts_message(&self) -> bool;
     fn is_text(&self) -> bool;
     fn is_live(&self) -> bool;
@@ -134,6 +139,18 @@ impl MessageCategory for String {
         self == MESSAGE_RECALL
     }
 
+    fn is_edit(&self) -> bool {
+        self == MESSAGE_EDIT
+    }
+
+    fn can_edit(&self) -> bool {
+        self.is_plain() && (self.is_text() || self.is_post())
+    }
+
+    fn is_reaction(&self) -> bool {
+        self == MESSAGE_REACTION
+    }
+
     fn is_fts_message(&self) -> bool {
         self.ends_with("TEXT")
             || self.ends_with("DATA")
@@ -314,6 +331,8 @@ impl MessageCategory for String {
                 ENCRYPTED_LOCATION,
                 ENCRYPTED_TRANSCRIPT,
                 MESSAGE_PIN,
+                MESSAGE_EDIT,
+                MESSAGE_REACTION,
             ]);
         }
         !illegal_categories.contains(&self.as_str())
@@ -343,6 +362,13 @@ mod tests {
     #[test]
     fn test() {
         assert!("MESSAGE_PIN".to_string().is_pin());
+        assert!("MESSAGE_EDIT".to_string().is_edit());
+        assert!(!"MESSAGE_EDIT".to_string().is_illegal_message_category());
+        assert!("MESSAGE_REACTION".to_string().is_reaction());
+        assert!(!"MESSAGE_REACTION".to_string().is_illegal_message_category());
+        assert!("PLAIN_POST".to_string().can_edit());
+        assert!(!"SIGNAL_TEXT".to_string().can_edit());
+        assert!(!"PLAIN_IMAGE".to_string().can_edit());
         _ = "ABC".to_string().can_recall();
         _ = "DEFG".to_string().can_recall();
     }
@@ -0,0 +1,262 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How idempotent requests are retried after rate limiting, server errors
+/// and dropped connections. Other requests are never retried.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    /// Upper bound for backoff. A `Retry-After` longer than this is not
+    /// waited for and the error is returned instead.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// The delay before retry number `attempt` (starting at 1), preferring
+    /// the server's `Retry-After`. `None` means the request should fail.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+        match retry_after {
+            Some(delay) if delay > self.max_delay => None,
+            Some(delay) => Some(delay),
+            None => {
+                let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+                Some(self.base_delay.saturating_mul(factor).min(self.max_delay))
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A timeout for every request whose path starts with `path_prefix`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndpointTimeout {
+    pub path_prefix: String,
+    pub timeout: Duration,
+}
+
+/// Describes one attempt of a request, shared with [`RequestTracer`]s.
+#[derive(Clone, Debug)]
+pub struct RequestTrace {
+    pub request_id: String,
+    pub method: Method,
+    pub path: String,
+    /// Zero for the first attempt.
+    pub attempt: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct ResponseTrace {
+    pub request: RequestTrace,
+    /// `None` when no response arrived, e.g. on timeout.
+    pub status: Option<StatusCode>,
+    pub elapsed: Duration,
+    pub error: Option<String>,
+    /// Set when the request will be retried after this delay.
+    pub retry_in: Option<Duration>,
+}
+
+/// Hooks called around every attempt, e.g. to record metrics or dump
+/// traffic while debugging.
+pub trait RequestTracer: Send + Sync {
+    fn on_request(&self, _request: &RequestTrace) {}
+
+    fn on_response(&self, _response: &ResponseTrace) {}
+}
+
+/// Settings for the request pipeline of a [`crate::Client`].
+#[derive(Clone)]
+pub struct ClientOptions {
+    pub retry: RetryPolicy,
+    pub timeout: Duration,
+    pub endpoint_timeouts: Vec<EndpointTimeout>,
+    pub tracer: Option<Arc<dyn RequestTracer>>,
+}
+
+impl ClientOptions {
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Uses `timeout` for paths starting with `path_prefix`, e.g.
+    /// `"safe/snapshots"`. The longest matching prefix wins.
+    pub fn with_endpoint_timeout(mut self, path_prefix: &str, timeout: Duration) -> Self {
+        self.endpoint_timeouts.push(EndpointTimeout {
+            path_prefix: path_prefix.trim_start_matches('/').to_string(),
+            timeout,
+        });
+        self
+    }
+
+    pub fn with_tracer(mut self, tracer: Arc<dyn RequestTracer>) -> Self {
+        self.tracer = Some(tracer);
+        self
+    }
+
+    pub(crate) fn timeout_for(&self, path: &str) -> Duration {
+        let path = path.trim_start_matches('/');
+        self.endpoint_timeouts
+            .iter()
+            .filter(|endpoint| path.starts_with(&endpoint.path_prefix))
+            .max_by_key(|endpoint| endpoint.path_prefix.len())
+            .map_or(self.timeout, |endpoint| endpoint.timeout)
+    }
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            retry: RetryPolicy::default(),
+            timeout: DEFAULT_TIMEOUT,
+            endpoint_timeouts: Vec::new(),
+            tracer: None,
+        }
+    }
+}
+
+pub(crate) fn is_idempotent(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD)
+}
+
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Mixin also reports rate limiting and server failures in the error
+/// envelope of a 200 response.
+pub(crate) fn is_retryable_envelope(body: &[u8]) -> bool {
+    #[derive(Deserialize)]
+    struct Envelope {
+        error: Option<crate::Error>,
+    }
+
+    serde_json::from_slice::<Envelope>(body)
+        .ok()
+        .and_then(|envelope| envelope.error)
+        .is_some_and(|error| {
+            error.code == crate::err::error_code::TOO_MANY_REQUEST
+                || (crate::err::error_code::SERVER..600).contains(&error.code)
+        })
+}
+
+/// Reads `Retry-After` as either delay seconds or an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((at.with_timezone(&Utc) - now).to_std().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_until_capped_and_honours_retry_after() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+        };
+
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(300)));
+        assert_eq!(policy.delay(6, None), None);
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_millis(250))),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(1))), None);
+        assert_eq!(RetryPolicy::none().delay(1, None), None);
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_dates() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut headers = HeaderMap::new();
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(7)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:30 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(30)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers, now), None);
+    }
+
+    #[test]
+    fn longest_endpoint_prefix_sets_the_timeout() {
+        let options = ClientOptions::default()
+            .with_endpoint_timeout("safe", Duration::from_secs(20))
+            .with_endpoint_timeout("/safe/snapshots", Duration::from_secs(40));
+
+        assert_eq!(options.timeout_for("/me"), DEFAULT_TIMEOUT);
+        assert_eq!(
+            options.timeout_for("/safe/assets/1"),
+            Duration::from_secs(20)
+        );
+        assert_eq!(
+            options.timeout_for("/safe/snapshots/1"),
+            Duration::from_secs(40)
+        );
+    }
+
+    #[test]
+    fn envelope_rate_limits_and_server_errors_are_retryable() {
+        assert!(is_retryable_envelope(
+            br#"{"error":{"status":202,"code":429,"description":"Too Many Requests"}}"#
+        ));
+        assert!(is_retryable_envelope(
+            br#"{"error":{"status":202,"code":500,"description":"Internal"}}"#
+        ));
+        assert!(!is_retryable_envelope(
+            br#"{"error":{"status":202,"code":404,"description":"Not found"}}"#
+        ));
+        assert!(!is_retryable_envelope(br#"{"data":{"user_id":"1"}}"#));
+    }
+}
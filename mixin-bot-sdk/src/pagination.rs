@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::ApiError;
+
+type PageFuture<T> = Pin<Box<dyn Future<Output = Result<Page<T>, ApiError>> + Send>>;
+type PageFetcher<T> = Box<dyn FnMut(Option<String>) -> PageFuture<T> + Send>;
+
+/// One page of a listing and the cursor of the page after it.
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page for offset-style endpoints: a full page continues from
+    /// the `cursor` of its last item, a short page is the last one.
+    pub(crate) fn from_offset(items: Vec<T>, limit: usize, cursor: impl Fn(&T) -> String) -> Self {
+        let next_cursor = if items.len() < limit {
+            None
+        } else {
+            items.last().map(cursor)
+        };
+        Self { items, next_cursor }
+    }
+}
+
+/// Walks a paginated listing, fetching pages on demand.
+pub struct Paginator<T> {
+    fetch: PageFetcher<T>,
+    cursor: Option<String>,
+    buffered: VecDeque<T>,
+    finished: bool,
+}
+
+impl<T: Send + 'static> Paginator<T> {
+    pub(crate) fn new<F, Fut>(mut fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Page<T>, ApiError>> + Send + 'static,
+    {
+        Self {
+            fetch: Box::new(move |cursor| Box::pin(fetch(cursor))),
+            cursor: None,
+            buffered: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// The next page, or `None` after the last one. Items already taken with
+    /// [`Paginator::next`] are not returned again.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<T>>, ApiError> {
+        if !self.buffered.is_empty() {
+            return Ok(Some(self.buffered.drain(..).collect()));
+        }
+        if self.finished {
+            return Ok(None);
+        }
+        let page = (self.fetch)(self.cursor.take()).await?;
+        self.finished = page.next_cursor.is_none() || page.items.is_empty();
+        self.cursor = page.next_cursor;
+        Ok((!page.items.is_empty()).then_some(page.items))
+    }
+
+    pub async fn next(&mut self) -> Option<Result<T, ApiError>> {
+        if self.buffered.is_empty() {
+            match self.next_page().await {
+                Ok(Some(items)) => self.buffered.extend(items),
+                Ok(None) => return None,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+        self.buffered.pop_front().map(Ok)
+    }
+
+    /// Fetches every remaining item.
+    pub async fn collect(mut self) -> Result<Vec<T>, ApiError> {
+        let mut items = Vec::new();
+        while let Some(page) = self.next_page().await? {
+            items.extend(page);
+        }
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn follows_cursors_until_a_short_page() {
+        let cursors = Arc::new(Mutex::new(Vec::new()));
+        let seen = cursors.clone();
+        let mut paginator = Paginator::new(move |cursor: Option<String>| {
+            seen.lock().unwrap().push(cursor.clone());
+            async move {
+                let start = cursor.map_or(0, |cursor| cursor.parse::<u32>().unwrap() + 1);
+                let items = (start..5).take(2).collect::<Vec<_>>();
+                Ok(Page::from_offset(items, 2, u32::to_string))
+            }
+        });
+
+        assert_eq!(paginator.next().await.unwrap().unwrap(), 0);
+        assert_eq!(paginator.next_page().await.unwrap(), Some(vec![1]));
+        assert_eq!(paginator.collect().await.unwrap(), vec![2, 3, 4]);
+        assert_eq!(
+            *cursors.lock().unwrap(),
+            vec![None, Some("1".to_string()), Some("3".to_string())]
+        );
+    }
+}
@@ -0,0 +1,405 @@
+//! Version 5 kernel transactions: encoding, hashing and input signing.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, ensure, Result};
+
+use super::{scalar_from_hex, sign, SpendKey};
+
+pub const TRANSACTION_VERSION: u8 = 5;
+/// Inputs a single transaction may spend.
+pub const MAX_INPUTS: usize = 256;
+/// Extra bytes carried without paying for storage.
+pub const MAX_EXTRA_SIZE: usize = 256;
+
+const MAGIC: [u8; 2] = [0x77, 0x77];
+const OUTPUT_TYPE_SCRIPT: u8 = 0x00;
+const OPERATOR_CMP: u8 = 0xff;
+const OPERATOR_SUM: u8 = 0xfe;
+
+pub type Hash = [u8; 32];
+pub type Key = [u8; 32];
+pub type Signature = [u8; 64];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    pub hash: Hash,
+    pub index: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Output {
+    /// Units of 10^-8, see [`super::parse_amount`].
+    pub amount: u128,
+    pub keys: Vec<Key>,
+    pub mask: Key,
+    pub script: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transaction {
+    pub asset: Hash,
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+    pub references: Vec<Hash>,
+    pub extra: Vec<u8>,
+    /// One map per input, from key index to signature.
+    pub signatures: Vec<BTreeMap<u16, Signature>>,
+}
+
+/// The script of an output spendable by `threshold` of its keys.
+pub fn threshold_script(threshold: u8) -> Vec<u8> {
+    vec![OPERATOR_CMP, OPERATOR_SUM, threshold]
+}
+
+impl Transaction {
+    pub fn new(asset: Hash) -> Self {
+        Self {
+            asset,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            references: Vec::new(),
+            extra: Vec::new(),
+            signatures: Vec::new(),
+        }
+    }
+
+    /// The signed encoding submitted to `safe/transactions`.
+    pub fn marshal(&self) -> Result<Vec<u8>> {
+        self.encode(true)
+    }
+
+    /// The encoding without signatures, used for transaction requests and
+    /// hashing.
+    pub fn payload(&self) -> Result<Vec<u8>> {
+        self.encode(false)
+    }
+
+    /// The transaction hash: BLAKE3 of the payload.
+    pub fn payload_hash(&self) -> Result<Hash> {
+        Ok(*blake3::hash(&self.payload()?).as_bytes())
+    }
+
+    /// Signs every input with its view scalar from the transaction request
+    /// added to the spend key. Inputs owned by a single key sign as index 0.
+    pub fn sign_inputs(&mut self, views: &[String], spend_key: &SpendKey) -> Result<()> {
+        ensure!(
+            views.len() == self.inputs.len(),
+            "expected {} views, got {}",
+            self.inputs.len(),
+            views.len()
+        );
+        let hash = self.payload_hash()?;
+        self.signatures = views
+            .iter()
+            .map(|view| {
+                let key = scalar_from_hex(view)? + spend_key.scalar();
+                Ok(BTreeMap::from([(0, sign(&key, &hash))]))
+            })
+            .collect::<Result<_>>()?;
+        Ok(())
+    }
+
+    fn encode(&self, signed: bool) -> Result<Vec<u8>> {
+        ensure!(!self.inputs.is_empty(), "transaction has no inputs");
+        ensure!(
+            self.inputs.len() <= MAX_INPUTS,
+            "transaction has too many inputs"
+        );
+        ensure!(
+            self.extra.len() <= MAX_EXTRA_SIZE,
+            "transaction extra is too long"
+        );
+        let mut encoder = Encoder::default();
+        encoder.write(&MAGIC);
+        encoder.write(&[0x00, TRANSACTION_VERSION]);
+        encoder.write(&self.asset);
+
+        encoder.write_len(self.inputs.len())?;
+        for input in &self.inputs {
+            encoder.write(&input.hash);
+            encoder.write_u16(input.index);
+            // Empty genesis, no deposit and no mint.
+            encoder.write_len(0)?;
+            encoder.write(&[0x00, 0x00, 0x00, 0x00]);
+        }
+
+        encoder.write_len(self.outputs.len())?;
+        for output in &self.outputs {
+            encoder.write(&[0x00, OUTPUT_TYPE_SCRIPT]);
+            encoder.write_integer(output.amount)?;
+            encoder.write_len(output.keys.len())?;
+            for key in &output.keys {
+                encoder.write(key);
+            }
+            encoder.write(&output.mask);
+            encoder.write_len(output.script.len())?;
+            encoder.write(&output.script);
+            // No withdrawal.
+            encoder.write(&[0x00, 0x00]);
+        }
+
+        encoder.write_len(self.references.len())?;
+        for reference in &self.references {
+            encoder.write(reference);
+        }
+
+        encoder.write(&(self.extra.len() as u32).to_be_bytes());
+        encoder.write(&self.extra);
+
+        let signatures = if signed { &self.signatures[..] } else { &[] };
+        encoder.write_len(signatures.len())?;
+        for map in signatures {
+            encoder.write_len(map.len())?;
+            for (index, signature) in map {
+                encoder.write_u16(*index);
+                encoder.write(signature);
+            }
+        }
+        Ok(encoder.bytes)
+    }
+}
+
+#[derive(Default)]
+struct Encoder {
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_be_bytes());
+    }
+
+    fn write_len(&mut self, len: usize) -> Result<()> {
+        let len = u16::try_from(len)
+            .ok()
+            .filter(|len| *len < u16::MAX)
+            .ok_or_else(|| anyhow!("transaction field is too long"))?;
+        self.write_u16(len);
+        Ok(())
+    }
+
+    /// Amounts are big-endian without leading zero bytes, zero being empty.
+    fn write_integer(&mut self, value: u128) -> Result<()> {
+        let bytes = value.to_be_bytes();
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(bytes.len());
+        self.write_len(bytes.len() - start)?;
+        self.write(&bytes[start..]);
+        Ok(())
+    }
+}
+
+/// Decodes a 32-byte hex value such as a hash, key or mask.
+pub fn decode_hash(hex: &str) -> Result<Hash> {
+    base16ct::mixed::decode_vec(hex)
+        .map_err(|error| anyhow!("invalid hex {hex}: {error}"))?
+        .try_into()
+        .map_err(|_| anyhow!("expected 32 bytes: {hex}"))
+}
+
+/// Picks unspent outputs, oldest first, until they cover `amount`.
+/// `outputs` are `(sequence, amount)` pairs; the indices of the chosen
+/// ones are returned with their total.
+pub fn select_outputs(outputs: &[(i64, u128)], amount: u128) -> Result<(Vec<usize>, u128)> {
+    let mut order = (0..outputs.len()).collect::<Vec<_>>();
+    order.sort_by_key(|index| outputs[*index].0);
+    let mut selected = Vec::new();
+    let mut total = 0u128;
+    for index in order {
+        if total >= amount && !selected.is_empty() {
+            break;
+        }
+        if selected.len() == MAX_INPUTS {
+            bail!("amount needs more than {MAX_INPUTS} outputs");
+        }
+        selected.push(index);
+        total += outputs[index].1;
+    }
+    ensure!(
+        total >= amount && !selected.is_empty(),
+        "insufficient balance"
+    );
+    Ok((selected, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::signature::{UnparsedPublicKey, ED25519};
+
+    use super::*;
+    use crate::safe::scalar_from_hex;
+
+    fn sample() -> Transaction {
+        let mut transaction = Transaction::new([0xaa; 32]);
+        transaction.inputs.push(Input {
+            hash: [0x11; 32],
+            index: 2,
+        });
+        transaction.outputs.push(Output {
+            amount: 100_000_000,
+            keys: vec![[0x22; 32]],
+            mask: [0x33; 32],
+            script: threshold_script(1),
+        });
+        transaction.extra = b"memo".to_vec();
+        transaction
+    }
+
+    #[test]
+    fn payload_follows_the_version_5_layout() {
+        let payload = sample().payload().unwrap();
+        let expected = [
+            &[0x77, 0x77, 0x00, 0x05][..],
+            &[0xaa; 32],
+            &[0x00, 0x01],
+            &[0x11; 32],
+            &[0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            &[0x00, 0x01, 0x00, 0x00],
+            &[0x00, 0x04, 0x05, 0xf5, 0xe1, 0x00],
+            &[0x00, 0x01],
+            &[0x22; 32],
+            &[0x33; 32],
+            &[0x00, 0x03, 0xff, 0xfe, 0x01],
+            &[0x00, 0x00],
+            &[0x00, 0x00],
+            &[0x00, 0x00, 0x00, 0x04],
+            b"memo",
+            &[0x00, 0x00],
+        ]
+        .concat();
+        assert_eq!(payload, expected);
+    }
+
+    #[test]
+    fn signing_keeps_the_hash_and_appends_signatures() {
+        let mut transaction = sample();
+        let hash = transaction.payload_hash().unwrap();
+        let spend_key = SpendKey::from_seed(&[9; 32]);
+        let view = base16ct::lower::encode_string(&[5; 32]);
+        transaction
+            .sign_inputs(std::slice::from_ref(&view), &spend_key)
+            .unwrap();
+
+        assert_eq!(transaction.payload_hash().unwrap(), hash);
+        let signed = transaction.marshal().unwrap();
+        let payload = transaction.payload().unwrap();
+        assert_eq!(signed.len(), payload.len() + 2 + 2 + 64);
+        assert_eq!(&signed[payload.len() - 2..payload.len() + 2], &[0, 1, 0, 1]);
+
+        let key = scalar_from_hex(&view).unwrap() + spend_key.scalar();
+        let public = curve25519_dalek::EdwardsPoint::mul_base(&key).compress();
+        UnparsedPublicKey::new(&ED25519, public.as_bytes())
+            .verify(&hash, &transaction.signatures[0][&0])
+            .unwrap();
+        assert!(transaction.sign_inputs(&[], &spend_key).is_err());
+    }
+
+    /// Two inputs, a single-key and a 2-of-2 output, a reference and a memo.
+    /// The expected payload, hash and signature were computed with an
+    /// independent implementation of the kernel encoding, BLAKE3 and the
+    /// kernel signing scheme.
+    fn reference() -> Transaction {
+        let hash = |hex: &str| decode_hash(hex).unwrap();
+        let mut transaction = Transaction::new(hash(
+            "b9f49cf777dc4d03bc54cd1367eebca319f8603ea1ce18910d09e2c540c630d8",
+        ));
+        transaction.inputs = vec![
+            Input {
+                hash: hash("2c6c5e1a13a0bd0bf0cc04c6b3c39e2b26caa0a7ae1d2e4a4a0c0bdc2b5c2a8e"),
+                index: 0,
+            },
+            Input {
+                hash: hash("f0d1f8a3c4e5b6a7988776655443322110ffeeddccbbaa998877665544332211"),
+                index: 3,
+            },
+        ];
+        transaction.outputs = vec![
+            Output {
+                amount: 12_345_678,
+                keys: vec![[0x1f; 32]],
+                mask: [0x2e; 32],
+                script: threshold_script(1),
+            },
+            Output {
+                amount: 87_654_321,
+                keys: vec![[0x3d; 32], [0x4c; 32]],
+                mask: [0x5b; 32],
+                script: threshold_script(2),
+            },
+        ];
+        transaction.references = vec![[0x6a; 32]];
+        transaction.extra = b"order 42".to_vec();
+        transaction
+    }
+
+    #[test]
+    fn payload_hash_matches_the_reference_vector() {
+        let transaction = reference();
+        assert_eq!(
+            base16ct::lower::encode_string(&transaction.payload().unwrap()),
+            concat!(
+                "77770005b9f49cf777dc4d03bc54cd1367eebca319f8603ea1ce18910d09e2c540c630d8",
+                "00022c6c5e1a13a0bd0bf0cc04c6b3c39e2b26caa0a7ae1d2e4a4a0c0bdc2b5c2a8e",
+                "0000000000000000f0d1f8a3c4e5b6a7988776655443322110ffeeddccbbaa9988776655",
+                "443322110003000000000000000200000003bc614e0001",
+                "1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f1f",
+                "2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e2e",
+                "0003fffe0100000000000405397fb10002",
+                "3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d",
+                "4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c4c",
+                "5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b5b",
+                "0003fffe020000",
+                "00016a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a6a",
+                "000000086f72646572203432",
+                "0000",
+            )
+        );
+        assert_eq!(
+            base16ct::lower::encode_string(&transaction.payload_hash().unwrap()),
+            "71ea447870bf1003d42346a9054d99cc05c079f63ecbe2bd72f9f77be383ae2b"
+        );
+    }
+
+    #[test]
+    fn input_signature_matches_the_reference_vector() {
+        let spend_key =
+            SpendKey::from_hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap();
+        let view = "a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a105".to_string();
+        let key = scalar_from_hex(&view).unwrap() + spend_key.scalar();
+        assert_eq!(
+            base16ct::lower::encode_string(
+                curve25519_dalek::EdwardsPoint::mul_base(&key)
+                    .compress()
+                    .as_bytes()
+            ),
+            "30dfba24c872e4c82239ab67c83a31ff279dd0ca180c4f44902d1cdc89a0ffd8"
+        );
+
+        let mut transaction = reference();
+        transaction
+            .sign_inputs(&[view.clone(), view], &spend_key)
+            .unwrap();
+        assert_eq!(
+            base16ct::lower::encode_string(&transaction.signatures[0][&0]),
+            "cabb105acbcb49f4104d0fac6303c09eb1b82056395f07a7b4f98cbc9899fb91\
+             f757c2c59551c38ba6986bd765faf5e5bd0466a662b27be054b7f67071e4bb0f"
+        );
+    }
+
+    #[test]
+    fn selects_oldest_outputs_until_covered() {
+        let outputs = [(3, 50), (1, 30), (2, 40)];
+        assert_eq!(select_outputs(&outputs, 60).unwrap(), (vec![1, 2], 70));
+        assert_eq!(select_outputs(&outputs, 0).unwrap(), (vec![1], 30));
+        assert!(select_outputs(&outputs, 121).is_err());
+        assert!(select_outputs(&[], 0).is_err());
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
+use futures::{Stream, StreamExt};
 use mixin_desktop_core::runtime::{
     AttachmentAccess as CoreAttachmentAccess, ConversationAccess as CoreConversationAccess,
-    MessageAccess as CoreMessageAccess, StickerAccess as CoreStickerAccess,
-    UserAccess as CoreUserAccess,
+    MessageAccess as CoreMessageAccess, SearchAccess as CoreSearchAccess,
+    StickerAccess as CoreStickerAccess, UserAccess as CoreUserAccess,
+    WalletAccess as CoreWalletAccess,
 };
 
 pub use crate::dto::*;
@@ -24,6 +26,10 @@ impl ConversationAccess {
         Ok(self.inner.resolve_code(code).await?)
     }
 
+    pub async fn resolve_uri(&self, uri: String) -> Result<model::ResolvedUri, ClientError> {
+        Ok(self.inner.resolve_uri(uri).await?)
+    }
+
     pub async fn join_group(&self, code: String) -> Result<String, ClientError> {
         Ok(self.inner.join_group(code).await?)
     }
@@ -156,6 +162,70 @@ impl ConversationAccess {
             .await?)
     }
 
+    pub async fn set_participant_role(
+        &self,
+        conversation_id: String,
+        user_id: String,
+        role: Option<String>,
+    ) -> Result<(), ClientError> {
+        Ok(self
+            .inner
+            .set_participant_role(conversation_id, user_id, role)
+            .await?)
+    }
+
+    pub async fn transfer_ownership(
+        &self,
+        conversation_id: String,
+        user_id: String,
+    ) -> Result<(), ClientError> {
+        Ok(self
+            .inner
+            .transfer_ownership(conversation_id, user_id)
+            .await?)
+    }
+
+    pub async fn participant_events(
+        &self,
+        conversation_id: String,
+        participant_id: Option<String>,
+        before_created_at_millis: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<model::ParticipantEventView>, ClientError> {
+        Ok(self
+            .inner
+            .participant_events(
+                conversation_id,
+                participant_id,
+                before_created_at_millis,
+                limit,
+            )
+            .await?)
+    }
+
+    pub async fn pending_joins(
+        &self,
+        conversation_id: String,
+    ) -> Result<Vec<model::ParticipantEventView>, ClientError> {
+        Ok(self.inner.pending_joins(conversation_id).await?)
+    }
+
+    pub async fn approve_join(
+        &self,
+        conversation_id: String,
+        message_id: String,
+    ) -> Result<(), ClientError> {
+        Ok(self.inner.approve_join(conversation_id, message_id).await?)
+    }
+
+    pub async fn reject_join(
+        &self,
+        conversation_id: String,
+        message_id: String,
+    ) -> Result<(), ClientError> {
+        Ok(self.inner.reject_join(conversation_id, message_id).await?)
+    }
+
     pub async fn set_disappearing_messages(
         &self,
         conversation_id: String,
@@ -191,6 +261,35 @@ impl ConversationAccess {
         Ok(self.inner.reorder_circles(circle_ids).await?)
     }
 
+    pub async fn create_smart_circle(
+        &self,
+        name: String,
+        rules: Vec<model::SmartCircleRule>,
+        match_all: bool,
+    ) -> Result<model::SmartCircleItem, ClientError> {
+        Ok(self
+            .inner
+            .create_smart_circle(name, rules, match_all)
+            .await?)
+    }
+
+    pub async fn update_smart_circle(
+        &self,
+        circle_id: String,
+        name: String,
+        rules: Vec<model::SmartCircleRule>,
+        match_all: bool,
+    ) -> Result<(), ClientError> {
+        Ok(self
+            .inner
+            .update_smart_circle(circle_id, name, rules, match_all)
+            .await?)
+    }
+
+    pub async fn delete_smart_circle(&self, circle_id: String) -> Result<(), ClientError> {
+        Ok(self.inner.delete_smart_circle(circle_id).await?)
+    }
+
     pub async fn create_group(
         &self,
         name: String,
@@ -227,6 +326,10 @@ impl ConversationAccess {
         Ok(self.inner.circles().await?)
     }
 
+    pub async fn smart_circles(&self) -> Result<Vec<model::SmartCircleItem>, ClientError> {
+        Ok(self.inner.smart_circles().await?)
+    }
+
     pub async fn set_pinned(
         &self,
         conversation_id: String,
@@ -278,6 +381,13 @@ impl From<CoreMessageAccess> for MessageAccess {
 }
 
 impl MessageAccess {
+    pub async fn load_link_preview(
+        &self,
+        message_id: String,
+    ) -> Result<Option<model::LinkPreviewView>, ClientError> {
+        Ok(self.inner.load_link_preview(message_id).await?)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn send_remote_image(
         &self,
@@ -371,12 +481,16 @@ impl MessageAccess {
             .await?)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn search_messages(
         &self,
         conversation_id: String,
         query: String,
         sender_id: Option<String>,
         categories: Vec<String>,
+        after_micros: Option<i64>,
+        before_micros: Option<i64>,
+        rank_by_relevance: bool,
         anchor_message_id: Option<String>,
         limit: u32,
     ) -> Result<Vec<model::MessageListView>, ClientError> {
@@ -387,6 +501,9 @@ impl MessageAccess {
                 query,
                 sender_id,
                 categories,
+                after_micros,
+                before_micros,
+                rank_by_relevance,
                 anchor_message_id,
                 limit,
             )
@@ -396,12 +513,22 @@ impl MessageAccess {
     pub async fn search_global_messages(
         &self,
         query: String,
+        after_micros: Option<i64>,
+        before_micros: Option<i64>,
+        rank_by_relevance: bool,
         anchor_message_id: Option<String>,
         limit: u32,
     ) -> Result<Vec<model::MessageListView>, ClientError> {
         Ok(self
             .inner
-            .search_global_messages(query, anchor_message_id, limit)
+            .search_global_messages(
+                query,
+                after_micros,
+                before_micros,
+                rank_by_relevance,
+                anchor_message_id,
+                limit,
+            )
             .await?)
     }
 
@@ -524,6 +651,19 @@ impl MessageAccess {
             .await?)
     }
 
+    pub async fn send_location(
+        &self,
+        conversation_id: String,
+        location: model::LocationView,
+        quote_message_id: Option<String>,
+        silent: bool,
+    ) -> Result<String, ClientError> {
+        Ok(self
+            .inner
+            .send_location(conversation_id, location, quote_message_id, silent)
+            .await?)
+    }
+
     pub async fn send_sticker(
         &self,
         conversation_id: String,
@@ -631,6 +771,45 @@ impl MessageAccess {
             .await?)
     }
 
+    pub async fn edit_message(
+        &self,
+        conversation_id: String,
+        message_id: String,
+        content: String,
+    ) -> Result<(), ClientError> {
+        Ok(self
+            .inner
+            .edit_message(conversation_id, message_id, content)
+            .await?)
+    }
+
+    pub async fn message_edit_history(
+        &self,
+        message_id: String,
+    ) -> Result<Vec<model::MessageEditItem>, ClientError> {
+        Ok(self.inner.message_edit_history(message_id).await?)
+    }
+
+    pub async fn react_to_message(
+        &self,
+        conversation_id: String,
+        message_id: String,
+        emoji: String,
+        removed: bool,
+    ) -> Result<(), ClientError> {
+        Ok(self
+            .inner
+            .react_to_message(conversation_id, message_id, emoji, removed)
+            .await?)
+    }
+
+    pub async fn message_reactions(
+        &self,
+        message_id: String,
+    ) -> Result<Vec<model::MessageReactionItem>, ClientError> {
+        Ok(self.inner.message_reactions(message_id).await?)
+    }
+
     pub async fn set_message_pinned(
         &self,
         conversation_id: String,
@@ -643,6 +822,25 @@ impl MessageAccess {
             .await?)
     }
 
+    pub async fn failed_outgoing_messages(
+        &self,
+        conversation_id: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<model::OutgoingMessageItem>, ClientError> {
+        Ok(self
+            .inner
+            .failed_outgoing_messages(conversation_id, limit)
+            .await?)
+    }
+
+    pub async fn retry_outgoing_message(&self, message_id: String) -> Result<(), ClientError> {
+        Ok(self.inner.retry_outgoing_message(message_id).await?)
+    }
+
+    pub async fn cancel_outgoing_message(&self, message_id: String) -> Result<(), ClientError> {
+        Ok(self.inner.cancel_outgoing_message(message_id).await?)
+    }
+
     pub async fn mark_mention_read(
         &self,
         conversation_id: String,
@@ -727,6 +925,18 @@ impl AttachmentAccess {
             .await?)
     }
 
+    pub async fn read_video_range(
+        &self,
+        message_id: String,
+        offset: u64,
+        length: u64,
+    ) -> Result<model::VideoRangeItem, ClientError> {
+        Ok(self
+            .inner
+            .read_video_range(message_id, offset, length)
+            .await?)
+    }
+
     pub async fn download_attachment(&self, message_id: String) -> Result<(), ClientError> {
         Ok(self.inner.download_attachment(message_id).await?)
     }
@@ -740,6 +950,49 @@ impl AttachmentAccess {
     }
 }
 
+pub struct SearchAccess {
+    inner: CoreSearchAccess,
+}
+
+impl From<CoreSearchAccess> for SearchAccess {
+    fn from(inner: CoreSearchAccess) -> Self {
+        Self { inner }
+    }
+}
+
+impl SearchAccess {
+    pub async fn global_search(
+        &self,
+        query: String,
+        limit: u32,
+    ) -> Result<model::GlobalSearchView, ClientError> {
+        Ok(self.inner.global_search(query, limit).await?)
+    }
+
+    pub async fn global_search_section(
+        &self,
+        query: String,
+        section: String,
+        cursor: Option<String>,
+        limit: u32,
+    ) -> Result<model::GlobalSearchView, ClientError> {
+        Ok(self
+            .inner
+            .global_search_section(query, section, cursor, limit)
+            .await?)
+    }
+
+    pub fn subscribe_global_search(
+        &self,
+        queries: impl Stream<Item = String> + Send + 'static,
+        limit: u32,
+    ) -> impl Stream<Item = Result<model::GlobalSearchView, ClientError>> + Send + 'static {
+        self.inner
+            .subscribe_global_search(queries, limit)
+            .map(|result| result.map_err(Into::into))
+    }
+}
+
 pub struct StickerAccess {
     inner: CoreStickerAccess,
 }
@@ -840,6 +1093,30 @@ impl UserAccess {
         Ok(self.inner.selectable_users().await?)
     }
 
+    pub async fn contacts(&self) -> Result<Vec<model::ContactGroup>, ClientError> {
+        Ok(self.inner.contacts().await?)
+    }
+
+    pub async fn blocked_users(&self) -> Result<Vec<model::UserProfileItem>, ClientError> {
+        Ok(self.inner.blocked_users().await?)
+    }
+
+    pub async fn user_annotation(
+        &self,
+        user_id: String,
+    ) -> Result<Option<model::UserAnnotationItem>, ClientError> {
+        Ok(self.inner.user_annotation(user_id).await?)
+    }
+
+    pub async fn set_user_annotation(
+        &self,
+        user_id: String,
+        alias: Option<String>,
+        note: Option<String>,
+    ) -> Result<(), ClientError> {
+        Ok(self.inner.set_user_annotation(user_id, alias, note).await?)
+    }
+
     pub async fn search_local_users(
         &self,
         query: String,
@@ -937,3 +1214,74 @@ impl UserAccess {
         Ok(self.inner.bot_home_uri(app_id).await?)
     }
 }
+
+pub struct WalletAccess {
+    inner: CoreWalletAccess,
+}
+
+impl From<CoreWalletAccess> for WalletAccess {
+    fn from(inner: CoreWalletAccess) -> Self {
+        Self { inner }
+    }
+}
+
+impl WalletAccess {
+    pub fn unlock_spend_key(&self, spend_key: String) -> Result<(), ClientError> {
+        Ok(self.inner.unlock_spend_key(spend_key)?)
+    }
+
+    pub fn lock_spend_key(&self) {
+        self.inner.lock_spend_key()
+    }
+
+    pub fn is_spend_key_unlocked(&self) -> bool {
+        self.inner.is_spend_key_unlocked()
+    }
+
+    pub async fn preview_payment(
+        &self,
+        code: String,
+    ) -> Result<model::PaymentPreview, ClientError> {
+        Ok(self.inner.preview_payment(code).await?)
+    }
+
+    pub async fn pay_code(&self, code: String) -> Result<model::PaymentState, ClientError> {
+        Ok(self.inner.pay_code(code).await?)
+    }
+
+    pub async fn payment_state(
+        &self,
+        trace_id: String,
+    ) -> Result<model::PaymentState, ClientError> {
+        Ok(self.inner.payment_state(trace_id).await?)
+    }
+
+    pub async fn inscriptions(&self) -> Result<Vec<model::InscriptionCollectionView>, ClientError> {
+        Ok(self.inner.inscriptions().await?)
+    }
+
+    pub async fn inscription_artwork(
+        &self,
+        inscription_hash: String,
+    ) -> Result<String, ClientError> {
+        Ok(self.inner.inscription_artwork(inscription_hash).await?)
+    }
+
+    pub async fn inscription_history(
+        &self,
+        inscription_hash: String,
+    ) -> Result<Vec<model::SnapshotDetailItem>, ClientError> {
+        Ok(self.inner.inscription_history(inscription_hash).await?)
+    }
+
+    pub async fn send_inscription(
+        &self,
+        inscription_hash: String,
+        user_id: String,
+    ) -> Result<model::PaymentState, ClientError> {
+        Ok(self
+            .inner
+            .send_inscription(inscription_hash, user_id)
+            .await?)
+    }
+}
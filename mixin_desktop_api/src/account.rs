@@ -7,10 +7,12 @@ use mixin_desktop_core::core::conversation_change::ConversationChange;
 use mixin_desktop_core::runtime::{desktop::DesktopRuntime, AccountRuntime};
 
 use crate::{
-    AccountProfile, AttachmentAccess, CircleItem, ClientResult, ConversationAccess,
+    AccountProfile, AccountSession, AttachmentAccess, CircleItem, ClientResult, ConversationAccess,
     ConversationChangeEvent, ConversationListItem, ConversationStorageUsage,
     ConversationUnseenCount, DeviceTransferCommand, DeviceTransferEvent, MessageAccess,
-    NotificationEvent, SnapshotDetailItem, StickerAccess, StorageCategoryUsage, UserAccess,
+    NotificationAction, NotificationActionHandle, NotificationDecision, NotificationEvent,
+    SearchAccess, SessionLinkedItem, SnapshotDetailItem, StickerAccess, StorageCategoryUsage,
+    UserAccess, WalletAccess,
 };
 
 pub struct AccountClient {
@@ -97,6 +99,36 @@ impl AccountClient {
         Ok(self.runtime.safe_snapshot_by_id(snapshot_id).await?)
     }
 
+    pub fn notification_action_handle(
+        &self,
+        event: &NotificationEvent,
+    ) -> NotificationActionHandle {
+        NotificationActionHandle {
+            account_id: self.account_id(),
+            conversation_id: event.conversation_id.clone(),
+            message_id: event.message_id.clone(),
+        }
+    }
+
+    pub async fn perform_notification_action(
+        &self,
+        handle: NotificationActionHandle,
+        action: NotificationAction,
+    ) -> ClientResult<()> {
+        Ok(self
+            .runtime
+            .perform_notification_action(&handle, action)
+            .await?)
+    }
+
+    /// Explains whether the notification rules let a received message notify.
+    pub async fn explain_notification(
+        &self,
+        message_id: String,
+    ) -> ClientResult<NotificationDecision> {
+        Ok(self.runtime.explain_notification(&message_id).await?)
+    }
+
     pub fn media_directory(&self) -> ClientResult<String> {
         Ok(self
             .runtime
@@ -142,6 +174,10 @@ impl AccountClient {
         self.runtime.attachment_progress(&message_id)
     }
 
+    pub fn search(&self) -> SearchAccess {
+        self.runtime.search_access().into()
+    }
+
     pub fn sticker(&self) -> StickerAccess {
         self.runtime.sticker_access().into()
     }
@@ -150,6 +186,10 @@ impl AccountClient {
         self.runtime.user_access().into()
     }
 
+    pub fn wallet(&self) -> WalletAccess {
+        self.runtime.wallet_access().into()
+    }
+
     pub fn conversation_changes(
         &self,
     ) -> impl Stream<Item = ConversationChangeEvent> + Send + 'static {
@@ -406,6 +446,35 @@ impl AccountClient {
         Ok(conversations.into_iter().map(Into::into).collect())
     }
 
+    pub async fn linked_sessions(&self) -> ClientResult<Vec<AccountSession>> {
+        Ok(self.runtime.linked_sessions().await?)
+    }
+
+    pub async fn revoke_linked_session(&self, session_id: String) -> ClientResult<()> {
+        Ok(self.runtime.revoke_linked_session(&session_id).await?)
+    }
+
+    pub fn session_linked_events(&self) -> impl Stream<Item = SessionLinkedItem> + Send + 'static {
+        let mut events = self.runtime.subscribe_session_linked_events();
+        let mut shutdown = self.runtime.subscribe_shutdown();
+        stream! {
+            loop {
+                tokio::select! {
+                    event = events.recv() => match event {
+                        Ok(event) => yield event.into(),
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    },
+                    changed = shutdown.changed() => {
+                        if changed.is_err() || *shutdown.borrow() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     pub async fn shutdown(&self) {
         self.desktop.shutdown_account(&self.runtime).await;
     }
@@ -1,14 +1,19 @@
 use std::collections::HashMap;
+use std::pin::Pin;
 use std::sync::Arc;
 
+use async_stream::stream;
 use futures::{Stream, StreamExt as _};
+use mixin_desktop_core::db::app::NotificationRules;
 use mixin_desktop_core::network::ProxySettings;
-use mixin_desktop_core::runtime::{desktop::DesktopRuntime, logging};
+use mixin_desktop_core::runtime::{desktop::DesktopRuntime, logging, AccountRuntime};
 use tokio::sync::OnceCell;
+use tokio_stream::StreamMap;
 
 use crate::{
-    AccountClient, ClientResult, HttpResponseItem, LoginClient, McpServerStatusItem,
-    McpSettingsItem, MediaClient, ProxySettingsItem,
+    AccountClient, AccountNotificationItem, AccountUnreadCountItem, ClientResult, HttpResponseItem,
+    LinkPreviewSettingsItem, LoginClient, McpServerStatusItem, McpSettingsItem, MediaClient,
+    NotificationAction, NotificationActionHandle, NotificationRulesItem, ProxySettingsItem,
 };
 
 #[derive(Clone)]
@@ -52,6 +57,108 @@ impl DesktopClient {
         Ok(AccountClient::new(runtime, self.runtime.clone()))
     }
 
+    /// Starts every saved account, the active account first.
+    pub async fn restore_accounts(&self) -> ClientResult<Vec<AccountClient>> {
+        Ok(self
+            .runtime
+            .restore_accounts()
+            .await?
+            .into_iter()
+            .map(|runtime| AccountClient::new(runtime, self.runtime.clone()))
+            .collect())
+    }
+
+    pub async fn accounts(&self) -> Vec<AccountClient> {
+        self.runtime
+            .accounts()
+            .await
+            .into_iter()
+            .map(|runtime| AccountClient::new(runtime, self.runtime.clone()))
+            .collect()
+    }
+
+    pub async fn active_account(&self) -> Option<AccountClient> {
+        let runtime = self.runtime.active_account().await?;
+        Some(AccountClient::new(runtime, self.runtime.clone()))
+    }
+
+    pub async fn switch_account(&self, account_id: String) -> ClientResult<AccountClient> {
+        let runtime = self.runtime.switch_account(&account_id).await?;
+        Ok(AccountClient::new(runtime, self.runtime.clone()))
+    }
+
+    /// Unseen message counts of every running account, emitted whenever one
+    /// of them changes or an account starts or stops.
+    pub fn unread_count_changes(
+        &self,
+    ) -> impl Stream<Item = Vec<AccountUnreadCountItem>> + Send + 'static {
+        let desktop = self.runtime.clone();
+        stream! {
+            let mut accounts = desktop.subscribe_account_changes();
+            let mut streams = AccountStreams::default();
+            let mut counts = HashMap::<String, i64>::new();
+            loop {
+                let running = accounts.borrow_and_update().clone();
+                streams.follow(&running, |runtime| {
+                    AccountClient::new(runtime, desktop.clone())
+                        .unseen_message_count_changes()
+                        .boxed()
+                });
+                let before = counts.len();
+                counts.retain(|account_id, _| streams.contains(account_id));
+                if counts.len() != before {
+                    yield unread_counts(&running, &counts);
+                }
+                tokio::select! {
+                    changed = accounts.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                    }
+                    Some((account_id, count)) = streams.next() => {
+                        counts.insert(account_id, count);
+                        yield unread_counts(&running, &counts);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Notifications of every running account. An account whose stream
+    /// fails is logged and skipped until it restarts.
+    pub fn notification_events(
+        &self,
+    ) -> impl Stream<Item = AccountNotificationItem> + Send + 'static {
+        let desktop = self.runtime.clone();
+        stream! {
+            let mut accounts = desktop.subscribe_account_changes();
+            let mut streams = AccountStreams::default();
+            loop {
+                let running = accounts.borrow_and_update().clone();
+                streams.follow(&running, |runtime| {
+                    AccountClient::new(runtime, desktop.clone())
+                        .notification_events()
+                        .boxed()
+                });
+                tokio::select! {
+                    changed = accounts.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                    }
+                    Some((account_id, event)) = streams.next() => match event {
+                        Ok(event) => {
+                            yield AccountNotificationItem { account_id, event };
+                        }
+                        Err(error) => {
+                            log::warn!("notification stream for {account_id} failed: {error}");
+                        }
+                    },
+                }
+            }
+        }
+    }
+
     pub async fn begin_login(&self) -> ClientResult<LoginClient> {
         Ok(LoginClient::new(
             self.runtime.begin_login().await?,
@@ -96,6 +203,19 @@ impl DesktopClient {
         })
     }
 
+    /// Acts on a notification without an `AccountClient`, queueing the action
+    /// while no account runtime has started yet.
+    pub async fn perform_notification_action(
+        &self,
+        handle: NotificationActionHandle,
+        action: NotificationAction,
+    ) -> ClientResult<()> {
+        Ok(self
+            .runtime
+            .perform_notification_action(handle, action)
+            .await?)
+    }
+
     pub async fn recreate_account_database(&self) -> ClientResult<()> {
         Ok(self.runtime.recreate_account_database().await?)
     }
@@ -195,6 +315,18 @@ impl SettingsClient {
         Ok(self.runtime.mcp_server.status().await.into())
     }
 
+    /// The account MCP serves, or `None` when it follows the active account.
+    pub async fn mcp_account_id(&self) -> ClientResult<Option<String>> {
+        Ok(self.runtime.mcp_server.account_preference().await?)
+    }
+
+    pub async fn set_mcp_account_id(
+        &self,
+        account_id: Option<String>,
+    ) -> ClientResult<McpServerStatusItem> {
+        Ok(self.runtime.attach_mcp(account_id.as_deref()).await?.into())
+    }
+
     pub async fn proxy_settings(&self) -> ClientResult<ProxySettingsItem> {
         Ok(self.runtime.settings.proxy_settings().await?.into())
     }
@@ -204,4 +336,93 @@ impl SettingsClient {
         settings.validate()?;
         Ok(self.runtime.settings.set_proxy_settings(settings).await?)
     }
+
+    pub async fn link_preview_settings(&self) -> ClientResult<LinkPreviewSettingsItem> {
+        Ok(self.runtime.settings.link_preview_settings().await?.into())
+    }
+
+    pub async fn set_link_preview_settings(
+        &self,
+        settings: LinkPreviewSettingsItem,
+    ) -> ClientResult<()> {
+        Ok(self
+            .runtime
+            .settings
+            .set_link_preview_settings(settings.into())
+            .await?)
+    }
+
+    pub async fn notification_rules(&self) -> ClientResult<NotificationRulesItem> {
+        Ok(self.runtime.settings.notification_rules().await?.into())
+    }
+
+    pub async fn set_notification_rules(&self, rules: NotificationRulesItem) -> ClientResult<()> {
+        let rules: NotificationRules = rules.into();
+        rules.validate()?;
+        Ok(self.runtime.settings.set_notification_rules(rules).await?)
+    }
+}
+
+/// One stream per running account, reopened when an account restarts.
+struct AccountStreams<T> {
+    runtimes: HashMap<String, Arc<AccountRuntime>>,
+    streams: StreamMap<String, Pin<Box<dyn Stream<Item = T> + Send>>>,
+}
+
+impl<T> Default for AccountStreams<T> {
+    fn default() -> Self {
+        Self {
+            runtimes: HashMap::new(),
+            streams: StreamMap::new(),
+        }
+    }
+}
+
+impl<T> AccountStreams<T> {
+    fn follow(
+        &mut self,
+        running: &[Arc<AccountRuntime>],
+        open: impl Fn(Arc<AccountRuntime>) -> Pin<Box<dyn Stream<Item = T> + Send>>,
+    ) {
+        self.runtimes.retain(|account_id, runtime| {
+            let current = running.iter().any(|running| Arc::ptr_eq(running, runtime));
+            if !current {
+                self.streams.remove(account_id);
+            }
+            current
+        });
+        for runtime in running {
+            if !self.runtimes.contains_key(runtime.account_id()) {
+                let account_id = runtime.account_id().to_owned();
+                self.streams
+                    .insert(account_id.clone(), open(runtime.clone()));
+                self.runtimes.insert(account_id, runtime.clone());
+            }
+        }
+    }
+
+    fn contains(&self, account_id: &str) -> bool {
+        self.streams.contains_key(account_id)
+    }
+
+    async fn next(&mut self) -> Option<(String, T)> {
+        self.streams.next().await
+    }
+}
+
+fn unread_counts(
+    running: &[Arc<AccountRuntime>],
+    counts: &HashMap<String, i64>,
+) -> Vec<AccountUnreadCountItem> {
+    running
+        .iter()
+        .filter_map(|runtime| {
+            counts
+                .get(runtime.account_id())
+                .map(|count| AccountUnreadCountItem {
+                    account_id: runtime.account_id().to_owned(),
+                    unseen_message_count: *count,
+                })
+        })
+        .collect()
 }
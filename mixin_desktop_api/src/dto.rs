@@ -1,9 +1,18 @@
 //! Application-facing data transfer objects shared with the core runtime.
 
+pub use mixin_desktop_core::core::mixin_uri::{MixinUri, PayUri};
+pub use mixin_desktop_core::db::mixin::smart_circle::{SmartCircleCondition, SmartCircleRule};
+pub use mixin_desktop_core::runtime::login::LoginStatus;
 pub use mixin_desktop_core::runtime::model::{
-    CircleItem, CodeResult, ConversationDetailItem, ConversationListData,
-    ConversationParticipantItem, ConversationStorageUsage, ConversationUnseenCount, GroupAvatar,
-    GroupConversationItem, ImageMessageView, MessageListView, MessageOrderInfoView,
-    NotificationEvent, PinMessagePreviewItem, SharedAppItem, SnapshotDetailItem, StickerAlbumItem,
-    StickerDetailItem, StickerItem, StorageCategoryUsage, UserProfileItem,
+    AccountSession, CircleItem, CodeResult, ContactGroup, ContactItem, ConversationDetailItem,
+    ConversationListData, ConversationParticipantItem, ConversationSearchHitView,
+    ConversationStorageUsage, ConversationUnseenCount, GlobalSearchView, GroupAvatar,
+    GroupConversationItem, ImageMessageView, InscriptionCollectionView, InscriptionView,
+    LinkPreviewView, LocationView, MessageEditItem, MessageListView, MessageOrderInfoView,
+    MessageReactionItem, MessageReactionView, MessageSnippetView, NotificationAction,
+    NotificationActionHandle, NotificationDecision, NotificationEvent, NotificationReason,
+    OutgoingMessageItem, ParticipantEventView, PaymentPreview, PaymentState, PinMessagePreviewItem,
+    ResolvedUri, SharedAppItem, SmartCircleItem, SnapshotDetailItem, StickerAlbumItem,
+    StickerDetailItem, StickerItem, StorageCategoryUsage, TextRangeView, UserAnnotationItem,
+    UserProfileItem, VideoRangeItem,
 };
@@ -8,15 +8,25 @@ mod login;
 mod media;
 pub mod model;
 
-pub use access::{AttachmentAccess, ConversationAccess, MessageAccess, StickerAccess, UserAccess};
+pub use access::{
+    AttachmentAccess, ConversationAccess, MessageAccess, SearchAccess, StickerAccess, UserAccess,
+    WalletAccess,
+};
 pub use account::AccountClient;
 pub use desktop::{DesktopClient, SettingsClient};
 pub use dto::{
-    CircleItem, CodeResult, ConversationDetailItem, ConversationListData,
-    ConversationParticipantItem, ConversationStorageUsage, ConversationUnseenCount, GroupAvatar,
-    GroupConversationItem, ImageMessageView, MessageListView, MessageOrderInfoView,
-    NotificationEvent, PinMessagePreviewItem, SharedAppItem, SnapshotDetailItem, StickerAlbumItem,
-    StickerDetailItem, StickerItem, StorageCategoryUsage, UserProfileItem,
+    AccountSession, CircleItem, CodeResult, ContactGroup, ContactItem, ConversationDetailItem,
+    ConversationListData, ConversationParticipantItem, ConversationSearchHitView,
+    ConversationStorageUsage, ConversationUnseenCount, GlobalSearchView, GroupAvatar,
+    GroupConversationItem, ImageMessageView, InscriptionCollectionView, InscriptionView,
+    LinkPreviewView, LocationView, LoginStatus, MessageEditItem, MessageListView,
+    MessageOrderInfoView, MessageReactionItem, MessageReactionView, MessageSnippetView, MixinUri,
+    NotificationAction, NotificationActionHandle, NotificationDecision, NotificationEvent,
+    NotificationReason, OutgoingMessageItem, ParticipantEventView, PayUri, PaymentPreview,
+    PaymentState, PinMessagePreviewItem, ResolvedUri, SharedAppItem, SmartCircleCondition,
+    SmartCircleItem, SmartCircleRule, SnapshotDetailItem, StickerAlbumItem, StickerDetailItem,
+    StickerItem, StorageCategoryUsage, TextRangeView, UserAnnotationItem, UserProfileItem,
+    VideoRangeItem,
 };
 pub use error::{ClientError, ClientResult};
 pub use logging::{init_logging, log_directory, write_log, LogLevel};
@@ -27,7 +37,8 @@ pub use mixin_desktop_media::{
     VoiceRecorderEvent, VoiceRecorderSnapshot, VoiceRecorderStatus, VoiceRecording,
 };
 pub use model::{
-    AccountProfile, ConnectionFailedReason, ConversationChangeEvent, ConversationListItem,
-    DeviceTransferCommand, DeviceTransferEvent, HttpResponseItem, McpServerStatusItem,
-    McpSettingsItem, ProxyItem, ProxySettingsItem,
+    AccountNotificationItem, AccountProfile, AccountUnreadCountItem, ConnectionFailedReason,
+    ConversationChangeEvent, ConversationListItem, DeviceTransferCommand, DeviceTransferEvent,
+    HttpResponseItem, LinkPreviewSettingsItem, McpServerStatusItem, McpSettingsItem,
+    NotificationRulesItem, ProxyItem, ProxySettingsItem, QuietHoursItem, SessionLinkedItem,
 };
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
+use async_stream::stream;
+use futures::Stream;
 use mixin_desktop_core::runtime::{desktop::DesktopRuntime, login::LoginRuntime};
+use tokio::sync::broadcast::error::RecvError;
 
-use crate::{AccountClient, ClientResult};
+use crate::{AccountClient, ClientResult, LoginStatus};
 
 pub struct LoginClient {
     runtime: LoginRuntime,
@@ -15,7 +18,40 @@ impl LoginClient {
     }
 
     pub fn auth_url(&self) -> String {
-        self.runtime.auth_url().to_string()
+        self.runtime.auth_url()
+    }
+
+    /// The current login QR code drawn with Unicode half blocks.
+    pub fn qr_half_blocks(&self, invert: bool) -> ClientResult<String> {
+        Ok(self.runtime.qr_half_blocks(invert)?)
+    }
+
+    pub fn qr_png(&self, scale: u32) -> ClientResult<Vec<u8>> {
+        Ok(self.runtime.qr_png(scale)?)
+    }
+
+    pub fn status(&self) -> LoginStatus {
+        self.runtime.status()
+    }
+
+    /// Yields the current status, then every change until the login ends.
+    pub fn status_changes(&self) -> impl Stream<Item = LoginStatus> + Send + 'static {
+        let (initial, mut status) = self.runtime.subscribe_status();
+        stream! {
+            yield initial;
+            loop {
+                let current = match status.recv().await {
+                    Ok(current) => current,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                let finished = matches!(current, LoginStatus::Ready | LoginStatus::Failed { .. });
+                yield current;
+                if finished {
+                    break;
+                }
+            }
+        }
     }
 
     pub fn cancel(&self) {
@@ -5,9 +5,11 @@ use mixin_desktop_core::core::device_transfer::{
     DeviceTransferCommand as CoreDeviceTransferCommand,
     DeviceTransferEvent as CoreDeviceTransferEvent,
 };
+use mixin_desktop_core::core::message::decrypt::SessionLinkedEvent;
+use mixin_desktop_core::db::app::{LinkPreviewSettings, NotificationRules, QuietHours};
 use mixin_desktop_core::network::{ProxyConfig, ProxySettings, ProxyType};
 use mixin_desktop_core::runtime::mcp::{McpServerStatus, McpSettings};
-use mixin_desktop_core::runtime::model::ConversationListData;
+use mixin_desktop_core::runtime::model::{ConversationListData, NotificationEvent};
 
 use crate::{ClientError, ClientResult};
 
@@ -25,6 +27,34 @@ pub struct AccountProfile {
     pub membership: Option<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct AccountUnreadCountItem {
+    pub account_id: String,
+    pub unseen_message_count: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct AccountNotificationItem {
+    pub account_id: String,
+    pub event: NotificationEvent,
+}
+
+/// Raised when another device is linked to the account.
+#[derive(Clone, Debug)]
+pub struct SessionLinkedItem {
+    pub session_id: String,
+    pub linked_at_micros: i64,
+}
+
+impl From<SessionLinkedEvent> for SessionLinkedItem {
+    fn from(event: SessionLinkedEvent) -> Self {
+        Self {
+            session_id: event.session_id,
+            linked_at_micros: event.linked_at.timestamp_micros(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ConversationChangeEvent {
     pub conversation_ids: Vec<String>,
@@ -192,6 +222,30 @@ pub struct ProxySettingsItem {
     pub proxies: Vec<ProxyItem>,
 }
 
+#[derive(Clone, Debug)]
+pub struct LinkPreviewSettingsItem {
+    pub enabled: bool,
+    pub received_messages: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuietHoursItem {
+    pub start_minute: u16,
+    pub end_minute: u16,
+    pub weekdays: Vec<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub struct NotificationRulesItem {
+    pub quiet_hours: Vec<QuietHoursItem>,
+    pub muted_circle_ids: Vec<String>,
+    pub keywords: Vec<String>,
+    pub mentions_only_conversation_ids: Vec<String>,
+    pub allowed_sender_ids: Vec<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct HttpResponseItem {
     pub status_code: u16,
@@ -312,6 +366,68 @@ impl TryFrom<ProxySettingsItem> for ProxySettings {
     }
 }
 
+impl From<LinkPreviewSettings> for LinkPreviewSettingsItem {
+    fn from(settings: LinkPreviewSettings) -> Self {
+        Self {
+            enabled: settings.enabled,
+            received_messages: settings.received_messages,
+            allowed_domains: settings.allowed_domains,
+            denied_domains: settings.denied_domains,
+        }
+    }
+}
+
+impl From<LinkPreviewSettingsItem> for LinkPreviewSettings {
+    fn from(settings: LinkPreviewSettingsItem) -> Self {
+        Self {
+            enabled: settings.enabled,
+            received_messages: settings.received_messages,
+            allowed_domains: settings.allowed_domains,
+            denied_domains: settings.denied_domains,
+        }
+    }
+}
+
+impl From<NotificationRules> for NotificationRulesItem {
+    fn from(rules: NotificationRules) -> Self {
+        Self {
+            quiet_hours: rules
+                .quiet_hours
+                .into_iter()
+                .map(|quiet_hours| QuietHoursItem {
+                    start_minute: quiet_hours.start_minute,
+                    end_minute: quiet_hours.end_minute,
+                    weekdays: quiet_hours.weekdays,
+                })
+                .collect(),
+            muted_circle_ids: rules.muted_circle_ids,
+            keywords: rules.keywords,
+            mentions_only_conversation_ids: rules.mentions_only_conversation_ids,
+            allowed_sender_ids: rules.allowed_sender_ids,
+        }
+    }
+}
+
+impl From<NotificationRulesItem> for NotificationRules {
+    fn from(rules: NotificationRulesItem) -> Self {
+        Self {
+            quiet_hours: rules
+                .quiet_hours
+                .into_iter()
+                .map(|quiet_hours| QuietHours {
+                    start_minute: quiet_hours.start_minute,
+                    end_minute: quiet_hours.end_minute,
+                    weekdays: quiet_hours.weekdays,
+                })
+                .collect(),
+            muted_circle_ids: rules.muted_circle_ids,
+            keywords: rules.keywords,
+            mentions_only_conversation_ids: rules.mentions_only_conversation_ids,
+            allowed_sender_ids: rules.allowed_sender_ids,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ProxyItem;
@@ -1,8 +1,9 @@
+use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
-use std::io::{BufReader, BufWriter, Read, Write};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::path::{Component, Path, PathBuf};
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::task::{Context as TaskContext, Poll};
 
 use aes::cipher::{Block, BlockCipherDecrypt, BlockCipherEncrypt, KeyInit};
@@ -13,7 +14,8 @@ use hmac::{Hmac, KeyInit as HmacKeyInit, Mac};
 use reqwest::header::{CONNECTION, CONTENT_LENGTH, CONTENT_TYPE};
 use reqwest::Client as HttpClient;
 use sha2::{Digest, Sha256};
-use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, ReadBuf};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, ReadBuf};
+use tokio::sync::watch;
 use tokio_util::io::ReaderStream;
 use tokio_util::sync::CancellationToken;
 
@@ -22,6 +24,7 @@ use sdk::Client as MixinClient;
 
 use crate::core::model::AttachmentExtra;
 use crate::db::mixin::message::{MediaStatus, Message};
+use crate::network::NetworkService;
 
 const AES_KEY_SIZE: usize = 32;
 const MAC_KEY_SIZE: usize = 32;
@@ -30,10 +33,34 @@ const CBC_BLOCK_SIZE: usize = 16;
 const MAC_SIZE: usize = 32;
 const IO_BUFFER_SIZE: usize = 64 * 1024;
 
+/// Largest plaintext range returned by one
+/// [`AttachmentService::read_video_range`] call.
+pub const MAX_RANGE_READ_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Largest file [`AttachmentService::download_public`] accepts.
+pub const MAX_PUBLIC_DOWNLOAD_SIZE: usize = 32 * 1024 * 1024;
+
 pub struct AttachmentService {
     mixin_client: Arc<MixinClient>,
     http_client: HttpClient,
     account_data_dir: PathBuf,
+    streams: Mutex<HashMap<String, Arc<StreamSource>>>,
+}
+
+#[derive(Debug, Clone)]
+enum StreamState {
+    Downloading { received: u64 },
+    Completed(PathBuf),
+    Failed,
+}
+
+/// An encrypted video download that range reads can decrypt while it is
+/// still being written.
+struct StreamSource {
+    encrypted_path: PathBuf,
+    key: Vec<u8>,
+    size: u64,
+    state: watch::Receiver<StreamState>,
 }
 
 #[derive(Debug)]
@@ -44,6 +71,14 @@ pub struct AttachmentDownloadResult {
     pub attachment: AttachmentExtra,
 }
 
+/// Plaintext bytes of a video together with the size of the whole video,
+/// so players can answer range requests.
+#[derive(Debug)]
+pub struct VideoRange {
+    pub bytes: Vec<u8>,
+    pub total_size: u64,
+}
+
 #[derive(Debug)]
 pub struct AttachmentUploadResult {
     pub attachment_id: String,
@@ -100,6 +135,7 @@ impl AttachmentService {
             mixin_client,
             http_client,
             account_data_dir: account_data_dir.into(),
+            streams: Mutex::new(HashMap::new()),
         }
     }
 
@@ -111,9 +147,51 @@ impl AttachmentService {
         self.download_to(message, extra, false, None, None).await
     }
 
-    pub async fn download_public(&self, url: &str) -> Result<Vec<u8>> {
-        let response = self.http_client.get(url).send().await?.error_for_status()?;
-        Ok(response.bytes().await?.to_vec())
+    /// Downloads a file from a URL someone else supplied, so only public
+    /// addresses are reached and the body is capped at
+    /// [`MAX_PUBLIC_DOWNLOAD_SIZE`].
+    pub async fn download_public(&self, network: &NetworkService, url: &str) -> Result<Vec<u8>> {
+        let response = network
+            .get_public(url, HashMap::new(), None, Some(MAX_PUBLIC_DOWNLOAD_SIZE))
+            .await?;
+        if !(200..300).contains(&response.status_code) {
+            bail!("public download failed with HTTP {}", response.status_code);
+        }
+        Ok(response.body)
+    }
+
+    /// Downloads a public file into `Media/<directory>` of the account once
+    /// and returns its path. Files are named after the SHA-256 of `url`.
+    pub async fn cache_public(
+        &self,
+        network: &NetworkService,
+        url: &str,
+        directory: &str,
+    ) -> Result<PathBuf> {
+        validate_path_component("media cache directory", directory)?;
+        let path = self
+            .account_data_dir
+            .join("Media")
+            .join(directory)
+            .join(media_cache_name(url)?);
+        if tokio::fs::try_exists(&path).await? {
+            return Ok(path);
+        }
+        let bytes = self.download_public(network, url).await?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let partial = temp_path(&path, "part")?;
+        let result = async {
+            tokio::fs::write(&partial, bytes).await?;
+            tokio::fs::rename(&partial, &path).await
+        }
+        .await;
+        if let Err(error) = result {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(error.into());
+        }
+        Ok(path)
     }
 
     pub async fn download_cancellable(
@@ -146,6 +224,45 @@ impl AttachmentService {
             .await
     }
 
+    /// Reads up to `length` plaintext bytes of a video attachment starting at
+    /// `offset`. While the video is downloading, the bytes are decrypted from
+    /// the partial download as soon as they arrive, which is before the
+    /// attachment MAC can be checked. The download still verifies the MAC
+    /// when it completes, and reads fail from then on if it did not match.
+    pub async fn read_video_range(
+        &self,
+        message: &Message,
+        offset: u64,
+        length: u64,
+    ) -> Result<VideoRange> {
+        let length = length.min(MAX_RANGE_READ_SIZE);
+        let source = self.lock_streams().get(&message.message_id).cloned();
+        let Some(source) = source else {
+            if !matches!(message.media_status, MediaStatus::Done | MediaStatus::Read) {
+                bail!("video is not downloaded: {}", message.message_id);
+            }
+            let path = attachment_path(&self.account_data_dir, message)?;
+            let total_size = tokio::fs::metadata(&path)
+                .await
+                .with_context(|| format!("read attachment size {}", path.display()))?
+                .len();
+            return Ok(VideoRange {
+                bytes: read_plain_range(&path, offset, length).await?,
+                total_size,
+            });
+        };
+        Ok(VideoRange {
+            bytes: read_stream_range(&source, &message.message_id, offset, length).await?,
+            total_size: source.size,
+        })
+    }
+
+    fn lock_streams(&self) -> MutexGuard<'_, HashMap<String, Arc<StreamSource>>> {
+        self.streams
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     pub async fn copy_for_forward(
         &self,
         source: &Path,
@@ -385,12 +502,39 @@ impl AttachmentService {
 
         let download_temp = temp_path(&target, "download")?;
         let output_temp = temp_path(&target, "part")?;
+        let stream = match (&message.media_key, message.media_size) {
+            (Some(key), Some(size))
+                if !transcript
+                    && message.category.is_video()
+                    && key.len() >= ATTACHMENT_KEY_SIZE
+                    && size > 0 =>
+            {
+                let (sender, state) = watch::channel(StreamState::Downloading { received: 0 });
+                self.lock_streams().insert(
+                    message.message_id.clone(),
+                    Arc::new(StreamSource {
+                        encrypted_path: download_temp.clone(),
+                        key: key.clone(),
+                        size: size as u64,
+                        state,
+                    }),
+                );
+                Some(sender)
+            }
+            _ => None,
+        };
         let operation = async {
             match (&message.media_key, &message.media_digest) {
                 (Some(key), Some(digest)) => {
                     validate_encryption_material(key, digest)?;
-                    self.download_to_file(&view_url, &download_temp, cancellation, progress)
-                        .await?;
+                    self.download_to_file(
+                        &view_url,
+                        &download_temp,
+                        cancellation,
+                        progress,
+                        stream.as_ref(),
+                    )
+                    .await?;
 
                     let input = download_temp.clone();
                     let output = output_temp.clone();
@@ -402,10 +546,9 @@ impl AttachmentService {
                     .await
                     .context("attachment decrypt task failed")??;
                     ensure_not_cancelled(cancellation)?;
-                    tokio::fs::remove_file(&download_temp).await?;
                 }
                 (None, None) => {
-                    self.download_to_file(&view_url, &output_temp, cancellation, progress)
+                    self.download_to_file(&view_url, &output_temp, cancellation, progress, None)
                         .await?
                 }
                 _ => bail!("attachment key and digest must be provided together"),
@@ -421,6 +564,14 @@ impl AttachmentService {
         }
         .await;
 
+        if let Some(stream) = stream {
+            stream.send_replace(if operation.is_ok() {
+                StreamState::Completed(target.clone())
+            } else {
+                StreamState::Failed
+            });
+            self.lock_streams().remove(&message.message_id);
+        }
         let _ = tokio::fs::remove_file(&download_temp).await;
         if operation.is_err() {
             let _ = tokio::fs::remove_file(&output_temp).await;
@@ -446,6 +597,7 @@ impl AttachmentService {
         path: &Path,
         cancellation: Option<&CancellationToken>,
         progress: Option<&(dyn Fn(u64, u64) + Send + Sync)>,
+        stream: Option<&watch::Sender<StreamState>>,
     ) -> Result<()> {
         let request = self
             .http_client
@@ -485,6 +637,12 @@ impl AttachmentService {
             let chunk = chunk?;
             file.write_all(&chunk).await?;
             received = received.saturating_add(chunk.len() as u64);
+            if let Some(stream) = stream {
+                // Range readers open the file separately, so the bytes have to
+                // reach it before they are announced.
+                file.flush().await?;
+                stream.send_replace(StreamState::Downloading { received });
+            }
             if let Some(callback) = progress {
                 callback(received, total);
             }
@@ -539,6 +697,23 @@ fn validate_message(message: &Message, extra: &AttachmentExtra) -> Result<()> {
     Ok(())
 }
 
+fn media_cache_name(url: &str) -> Result<String> {
+    let extension = reqwest::Url::parse(url)?
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .filter(|extension| {
+            (1..=5).contains(&extension.len())
+                && extension.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+    let name = hex::encode(Sha256::digest(url.as_bytes()));
+    Ok(match extension {
+        Some(extension) => format!("{name}.{extension}"),
+        None => name,
+    })
+}
+
 fn validate_path_component(label: &str, value: &str) -> Result<()> {
     let mut components = Path::new(value).components();
     if !matches!(components.next(), Some(Component::Normal(_))) || components.next().is_some() {
@@ -766,6 +941,102 @@ fn decrypt_attachment_file(input: &Path, output: &Path, key: &[u8], digest: &[u8
     Ok(())
 }
 
+/// Reads plaintext bytes of a video that may still be downloading.
+async fn read_stream_range(
+    source: &StreamSource,
+    message_id: &str,
+    offset: u64,
+    length: u64,
+) -> Result<Vec<u8>> {
+    if offset >= source.size || length == 0 {
+        return Ok(Vec::new());
+    }
+    let end = offset + length.min(source.size - offset);
+    let block_size = CBC_BLOCK_SIZE as u64;
+    // The IV followed by every ciphertext block up to the one holding
+    // the last requested byte.
+    let needed = ((end - 1) / block_size + 2) * block_size;
+    let state = source
+        .state
+        .clone()
+        .wait_for(
+            |state| !matches!(state, StreamState::Downloading { received } if *received < needed),
+        )
+        .await
+        .map_err(|_| anyhow!("video download stopped"))?
+        .clone();
+    match state {
+        StreamState::Completed(path) => read_plain_range(&path, offset, end - offset).await,
+        StreamState::Failed => bail!("video download failed: {message_id}"),
+        StreamState::Downloading { .. } => {
+            let encrypted_path = source.encrypted_path.clone();
+            let key = source.key.clone();
+            let decrypted = tokio::task::spawn_blocking(move || {
+                decrypt_attachment_range(&encrypted_path, &key, offset, end)
+            })
+            .await
+            .context("video decrypt task failed")?;
+            if decrypted.is_ok() {
+                return decrypted;
+            }
+            // The download may have finished and removed the encrypted
+            // file between the state check and the read.
+            let completed = match &*source.state.borrow() {
+                StreamState::Completed(path) => Some(path.clone()),
+                _ => None,
+            };
+            match completed {
+                Some(path) => read_plain_range(&path, offset, end - offset).await,
+                None => decrypted,
+            }
+        }
+    }
+}
+
+async fn read_plain_range(path: &Path, offset: u64, length: u64) -> Result<Vec<u8>> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("open attachment {}", path.display()))?;
+    file.seek(SeekFrom::Start(offset)).await?;
+    let mut bytes = Vec::new();
+    file.take(length).read_to_end(&mut bytes).await?;
+    Ok(bytes)
+}
+
+/// Decrypts plaintext bytes `offset..end` of an encrypted attachment. CBC
+/// lets every block be decrypted from itself and the ciphertext block before
+/// it, so only the covering blocks are read.
+fn decrypt_attachment_range(path: &Path, key: &[u8], offset: u64, end: u64) -> Result<Vec<u8>> {
+    let block_size = CBC_BLOCK_SIZE as u64;
+    let first = offset / block_size;
+    let last = (end - 1) / block_size;
+    let mut reader = File::open(path)?;
+    // The block before `first` is the IV when `first` is zero.
+    reader.seek(SeekFrom::Start(first * block_size))?;
+    let mut ciphertext = vec![0_u8; usize::try_from((last - first + 2) * block_size)?];
+    reader.read_exact(&mut ciphertext)?;
+    let cipher = Aes256::new_from_slice(&key[..AES_KEY_SIZE])
+        .map_err(|_| anyhow!("invalid attachment AES key"))?;
+    let mut plaintext = Vec::with_capacity(ciphertext.len() - CBC_BLOCK_SIZE);
+    for pair in ciphertext
+        .windows(CBC_BLOCK_SIZE * 2)
+        .step_by(CBC_BLOCK_SIZE)
+    {
+        let (previous, encrypted) = pair.split_at(CBC_BLOCK_SIZE);
+        let encrypted: [u8; CBC_BLOCK_SIZE] = encrypted.try_into()?;
+        let mut block = Block::<Aes256>::from(encrypted);
+        cipher.decrypt_block(&mut block);
+        for (byte, previous_byte) in block.iter_mut().zip(previous) {
+            *byte ^= previous_byte;
+        }
+        plaintext.extend_from_slice(&block);
+    }
+    let start = (offset - first * block_size) as usize;
+    plaintext.truncate(start + (end - offset) as usize);
+    plaintext.drain(..start);
+    Ok(plaintext)
+}
+
 fn verify_attachment(input: &Path, input_size: u64, mac_key: &[u8], digest: &[u8]) -> Result<()> {
     let authenticated_size = input_size - MAC_SIZE as u64;
     let mut reader = BufReader::with_capacity(IO_BUFFER_SIZE, File::open(input)?);
@@ -853,6 +1124,23 @@ mod tests {
         assert_eq!(std::fs::read(output).unwrap(), content);
     }
 
+    #[test]
+    fn decrypts_arbitrary_ranges_of_an_encrypted_attachment() {
+        let directory = tempfile::tempdir().unwrap();
+        let input = directory.path().join("input");
+        let encrypted = directory.path().join("encrypted");
+        let content = (0..1000_u32).map(|value| value as u8).collect::<Vec<_>>();
+        std::fs::write(&input, &content).unwrap();
+        let (key, _) = encrypt_attachment_file(&input, &encrypted).unwrap();
+
+        for (offset, end) in [(0, 1), (0, 16), (15, 17), (100, 613), (990, 1000)] {
+            assert_eq!(
+                decrypt_attachment_range(&encrypted, &key, offset, end).unwrap(),
+                content[offset as usize..end as usize]
+            );
+        }
+    }
+
     #[test]
     fn encrypts_and_decrypts_empty_attachment_file() {
         let directory = tempfile::tempdir().unwrap();
@@ -873,6 +1161,45 @@ mod tests {
         assert!(https_url("https://example.com/file", "attachment URL").is_ok());
     }
 
+    #[test]
+    fn media_cache_names_keep_short_extensions() {
+        let name = media_cache_name("https://example.com/art/1.PNG?size=2").unwrap();
+        assert_eq!(name.len(), 64 + 4);
+        assert!(name.ends_with(".png"));
+        assert_eq!(
+            media_cache_name("https://example.com/art").unwrap().len(),
+            64
+        );
+        assert_eq!(
+            media_cache_name("https://example.com/a.tar.../b")
+                .unwrap()
+                .len(),
+            64
+        );
+        assert!(media_cache_name("not a url").is_err());
+    }
+
+    #[tokio::test]
+    async fn refuses_to_cache_public_files_from_private_addresses() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = crate::db::app::AppDatabase::connect_at(directory.path().join("app.db"))
+            .await
+            .unwrap();
+        let network = NetworkService::new(database.setting_dao).await.unwrap();
+        let service = AttachmentService::new(
+            Arc::new(MixinClient::new(sdk::Credential::None)),
+            HttpClient::new(),
+            directory.path(),
+        );
+
+        let result = service
+            .cache_public(&network, "http://127.0.0.1/art.png", "Inscriptions")
+            .await;
+
+        assert!(result.is_err());
+        assert!(!directory.path().join("Media").exists());
+    }
+
     #[tokio::test]
     async fn copies_forwarded_attachment_inside_account_directory() {
         let directory = tempfile::tempdir().unwrap();
@@ -1027,6 +1354,32 @@ mod tests {
         assert!(!output.exists());
     }
 
+    #[tokio::test]
+    async fn reads_downloaded_video_ranges_with_the_total_size() {
+        let directory = tempfile::tempdir().unwrap();
+        let service = AttachmentService::new(
+            Arc::new(MixinClient::new(sdk::Credential::None)),
+            HttpClient::new(),
+            directory.path(),
+        );
+        let message = Message {
+            message_id: "message-id".to_string(),
+            conversation_id: "conversation-id".to_string(),
+            category: sdk::message_category::SIGNAL_VIDEO.to_string(),
+            media_mime_type: Some("video/mp4".to_string()),
+            media_status: MediaStatus::Done,
+            ..Message::default()
+        };
+        let path = attachment_path(directory.path(), &message).unwrap();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"0123456789").unwrap();
+
+        let range = service.read_video_range(&message, 2, 3).await.unwrap();
+
+        assert_eq!(range.bytes, b"234");
+        assert_eq!(range.total_size, 10);
+    }
+
     #[test]
     fn builds_flutter_compatible_media_path() {
         let message = Message {
@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use url::Url;
+
+use crate::core::user_agent::generate_user_agent;
+use crate::db::mixin::hyperlink::Hyperlink;
+use crate::network::NetworkService;
+
+const FETCH_TIMEOUT_MILLIS: u64 = 10_000;
+/// Previews only read the document head, so larger pages are not worth
+/// downloading.
+const MAX_DOCUMENT_BYTES: usize = 1024 * 1024;
+const MAX_TITLE_CHARS: usize = 256;
+const MAX_DESCRIPTION_CHARS: usize = 1024;
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"', '>', '*'];
+
+/// Finds `http` and `https` URLs in message text, in order of appearance and
+/// without duplicates.
+pub fn find_urls(text: &str) -> Vec<Url> {
+    let mut urls: Vec<Url> = Vec::new();
+    let lower = text.to_ascii_lowercase();
+    let mut position = 0;
+    while let Some(start) = next_url_start(&lower, position) {
+        let end = text[start..]
+            .find(|character: char| character.is_whitespace() || "<>\"`{}|\\^".contains(character))
+            .map_or(text.len(), |offset| start + offset);
+        position = end;
+        let candidate = trim_url(&text[start..end]);
+        let Ok(url) = Url::parse(candidate) else {
+            continue;
+        };
+        if url.host_str().is_some() && !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+/// Fetches `url` through `network` and reads its OpenGraph, Twitter card or
+/// HTML meta data. Returns `None` when the page has no title. Hosts on the
+/// local network are refused, also behind redirects.
+pub async fn fetch_link_preview(network: &NetworkService, url: &Url) -> Result<Option<Hyperlink>> {
+    let response = network
+        .get_public(
+            url.as_str(),
+            HashMap::from([
+                (
+                    "accept".to_owned(),
+                    "text/html,application/xhtml+xml".to_owned(),
+                ),
+                ("user-agent".to_owned(), generate_user_agent()),
+            ]),
+            Some(FETCH_TIMEOUT_MILLIS),
+            Some(MAX_DOCUMENT_BYTES),
+        )
+        .await?;
+    if !(200..300).contains(&response.status_code) {
+        return Err(anyhow!(
+            "link preview request failed with status {}",
+            response.status_code
+        ));
+    }
+    let is_html = response
+        .headers
+        .get("content-type")
+        .is_none_or(|content_type| content_type.to_ascii_lowercase().contains("html"));
+    if !is_html {
+        return Ok(None);
+    }
+    Ok(parse_link_preview(
+        url,
+        &String::from_utf8_lossy(&response.body),
+    ))
+}
+
+/// Reads the preview of `url` from its HTML document.
+pub fn parse_link_preview(url: &Url, html: &str) -> Option<Hyperlink> {
+    let lower = html.to_ascii_lowercase();
+    let head_end = lower.find("</head").unwrap_or(html.len());
+    let head = &html[..head_end];
+    let lower = &lower[..head_end];
+
+    let mut meta = HashMap::new();
+    let mut document_title = None;
+    let mut position = 0;
+    while let Some(offset) = lower[position..].find('<') {
+        let start = position + offset + 1;
+        if lower[start..].starts_with("!--") {
+            position = lower[start..]
+                .find("-->")
+                .map_or(head.len(), |offset| start + offset + 3);
+            continue;
+        }
+        let Some(end) = tag_end(head, start) else {
+            break;
+        };
+        position = end + 1;
+        let tag = &head[start..end];
+        let name_end = tag
+            .find(|character: char| character.is_whitespace() || character == '/')
+            .unwrap_or(tag.len());
+        match tag[..name_end].to_ascii_lowercase().as_str() {
+            "meta" => {
+                let attributes = attributes(&tag[name_end..]);
+                let key = attributes
+                    .get("property")
+                    .or_else(|| attributes.get("name"))
+                    .map(|key| key.to_ascii_lowercase());
+                if let (Some(key), Some(content)) = (key, attributes.get("content")) {
+                    meta.entry(key).or_insert_with(|| content.clone());
+                }
+            }
+            "title" if document_title.is_none() => {
+                let close = lower[position..]
+                    .find("</title")
+                    .map_or(head.len(), |offset| position + offset);
+                document_title = Some(decode_entities(&head[position..close]));
+                position = close;
+            }
+            "script" | "style" => {
+                let close = format!("</{}", &lower[start..start + name_end]);
+                position = lower[position..]
+                    .find(&close)
+                    .map_or(head.len(), |offset| position + offset);
+            }
+            _ => {}
+        }
+    }
+
+    let value = |keys: &[&str]| {
+        keys.iter()
+            .filter_map(|key| meta.get(*key))
+            .map(|value| collapse_whitespace(value))
+            .find(|value| !value.is_empty())
+    };
+    let title = value(&["og:title", "twitter:title"])
+        .or_else(|| document_title.map(|title| collapse_whitespace(&title)))
+        .filter(|title| !title.is_empty())?;
+    let site_name = value(&["og:site_name", "application-name"])
+        .or_else(|| url.host_str().map(str::to_owned))
+        .unwrap_or_default();
+    let description = value(&["og:description", "twitter:description", "description"]);
+    let image = value(&["og:image:secure_url", "og:image", "twitter:image"])
+        .and_then(|image| url.join(&image).ok())
+        .filter(|image| matches!(image.scheme(), "http" | "https"))
+        .map(String::from);
+    Some(Hyperlink {
+        hyperlink: url.to_string(),
+        site_name,
+        site_title: truncate(title, MAX_TITLE_CHARS),
+        site_description: description
+            .map(|description| truncate(description, MAX_DESCRIPTION_CHARS)),
+        site_image: image,
+    })
+}
+
+fn next_url_start(lower: &str, position: usize) -> Option<usize> {
+    ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| lower[position..].find(scheme))
+        .min()
+        .map(|offset| position + offset)
+}
+
+/// Drops punctuation that ends a sentence rather than the URL, and closing
+/// brackets that have no opening bracket inside the URL.
+fn trim_url(mut url: &str) -> &str {
+    loop {
+        let trimmed = url.trim_end_matches(TRAILING_PUNCTUATION);
+        let trimmed = match trimmed.chars().last() {
+            Some(close @ (')' | ']')) => {
+                let open = if close == ')' { '(' } else { '[' };
+                if trimmed.matches(open).count() < trimmed.matches(close).count() {
+                    &trimmed[..trimmed.len() - 1]
+                } else {
+                    trimmed
+                }
+            }
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            return url;
+        }
+        url = trimmed;
+    }
+}
+
+fn tag_end(html: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (offset, character) in html[start..].char_indices() {
+        match (quote, character) {
+            (Some(open), _) if character == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(character),
+            (None, '>') => return Some(start + offset),
+            (None, _) => {}
+        }
+    }
+    None
+}
+
+fn attributes(source: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut rest = source;
+    loop {
+        rest = rest
+            .trim_start_matches(|character: char| character.is_whitespace() || character == '/');
+        if rest.is_empty() {
+            return attributes;
+        }
+        let name_end = rest
+            .find(|character: char| {
+                character.is_whitespace() || character == '=' || character == '/'
+            })
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let value = if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (value, remaining) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    (&body[..end], body.get(end + 1..).unwrap_or_default())
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            rest = remaining;
+            decode_entities(value)
+        } else {
+            String::new()
+        };
+        if name.is_empty() {
+            rest = rest.get(1..).unwrap_or_default();
+            continue;
+        }
+        attributes.entry(name).or_insert(value);
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (character, entity) {
+            (Some(character), Some(entity)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate(text: String, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::{find_urls, parse_link_preview};
+
+    #[test]
+    fn finds_urls_without_trailing_punctuation() {
+        let urls = find_urls(
+            "See https://mixin.one/pages/(about), and (HTTP://example.com/a?b=c). \
+             Again: https://mixin.one/pages/(about)! ftp://ignored.example",
+        );
+
+        assert_eq!(
+            urls.iter().map(Url::as_str).collect::<Vec<_>>(),
+            [
+                "https://mixin.one/pages/(about)",
+                "http://example.com/a?b=c"
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_open_graph_data_before_html_fallbacks() {
+        let url = Url::parse("https://news.example/posts/1").unwrap();
+        let html = r#"<!doctype html><html><head>
+            <!-- it's <meta property="og:title" content="comment"> -->
+            <title>Fallback   title</title>
+            <script>var x = "<meta property='og:title' content='script'>";</script>
+            <meta property="og:title" content="Mixin &amp; &#x4E16;&#30028;">
+            <meta name=description content='A "quoted" summary'>
+            <meta property="og:image" content="/images/cover.png" />
+            </head><body><meta property="og:site_name" content="Body"></body></html>"#;
+
+        let preview = parse_link_preview(&url, html).unwrap();
+
+        assert_eq!(preview.hyperlink, "https://news.example/posts/1");
+        assert_eq!(preview.site_name, "news.example");
+        assert_eq!(preview.site_title, "Mixin & 世界");
+        assert_eq!(
+            preview.site_description.as_deref(),
+            Some("A \"quoted\" summary")
+        );
+        assert_eq!(
+            preview.site_image.as_deref(),
+            Some("https://news.example/images/cover.png")
+        );
+
+        let fallback =
+            parse_link_preview(&url, "<head><TITLE>\n Plain\n page </TITLE></head>").unwrap();
+        assert_eq!(fallback.site_title, "Plain page");
+        assert_eq!(parse_link_preview(&url, "<p>no head</p>"), None);
+    }
+}
@@ -1,4 +1,3 @@
 pub mod blaze;
-mod completer;
 pub mod decrypt;
 pub mod sender;
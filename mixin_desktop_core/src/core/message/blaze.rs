@@ -1,65 +1,36 @@
 use std::collections::HashMap;
-use std::io::{Cursor, Read, Write};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{anyhow, Result};
-use flate2::read::GzDecoder;
-use flate2::write::GzEncoder;
-use flate2::Compression;
-use futures::{SinkExt, StreamExt};
+use chrono::{DateTime, Utc};
 use futures_channel::mpsc::UnboundedSender;
-use log::{error, info, warn};
-use reqwest::header::HeaderValue;
-use reqwest::Method;
-use tokio::sync::{watch, Notify};
-use tokio_tungstenite::connect_async;
-use tokio_tungstenite::tungstenite::client::IntoClientRequest;
-use tokio_tungstenite::tungstenite::{Error as WebSocketError, Message};
+use log::{info, warn};
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::Message;
 
+use sdk::blaze::{BlazeClient, BlazeHandler};
 use sdk::blaze_message::{
     BlazeMessage, BlazeMessageData, ACKNOWLEDGE_MESSAGE_RECEIPT, CREATE_CALL, CREATE_KRAKEN,
     CREATE_MESSAGE,
 };
-use sdk::err::error_code;
-use sdk::{
-    BlazeAckMessage, Client, Credential, MessageStatus, ACKNOWLEDGE_MESSAGE_RECEIPTS, ERROR_ACTION,
-};
+use sdk::{BlazeAckMessage, Client, Credential, MessageStatus, ACKNOWLEDGE_MESSAGE_RECEIPTS};
+
+pub use sdk::blaze::BlazeAuthenticationError;
 
 use crate::core::conversation_change::ConversationChangeNotifier;
-use crate::core::message::completer::Completer;
 use crate::core::user_agent::generate_user_agent;
 use crate::db::mixin::flood_message::FloodMessage;
 use crate::db::mixin::job::Job;
 use crate::db::mixin::message::Message as StoredMessage;
 use crate::db::mixin::MixinDatabase;
 
-const WS_HOSTS: [&str; 2] = [
-    "wss://blaze.mixin.one:443",
-    "wss://mixin-blaze.zeromesh.net:443",
-];
-const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
-const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 const STATUS_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
-const RECONNECT_DELAY: Duration = Duration::from_secs(5);
-const PING_INTERVAL: Duration = Duration::from_secs(10);
-const PONG_TIMEOUT: Duration = Duration::from_secs(30);
-
-#[derive(Debug, thiserror::Error)]
-#[error("blaze authentication failed")]
-pub struct BlazeAuthenticationError;
 
+/// The account's Blaze connection. Incoming messages are queued as flood
+/// messages and status updates are applied to `MixinDatabase`.
 pub struct Blaze {
-    database: Arc<MixinDatabase>,
-    client: Arc<Client>,
-    credential: Credential,
-    user_id: String,
-    connection: Arc<Mutex<BlazeConnection>>,
-    connection_status: watch::Sender<bool>,
-    transactions: Arc<Mutex<HashMap<String, Completer<BlazeMessage>>>>,
-    connect_running: Arc<AtomicBool>,
-    reconnect: Notify,
+    client: BlazeClient<DatabaseHandler>,
     pending_message_statuses: PendingMessageStatusStore,
 }
 
@@ -144,98 +115,6 @@ impl PendingMessageStatusStore {
     }
 }
 
-struct BlazeConnection {
-    sink: Option<UnboundedSender<Message>>,
-}
-
-enum SocketSessionEnd {
-    Disconnected,
-    AuthenticationFailed,
-}
-
-enum SocketMessageResult {
-    Continue,
-    AuthenticationFailed,
-}
-
-struct ConnectLoopGuard(Arc<AtomicBool>);
-
-impl Drop for ConnectLoopGuard {
-    fn drop(&mut self) {
-        self.0.store(false, Ordering::Release);
-    }
-}
-
-struct SocketSessionGuard {
-    connection: Arc<Mutex<BlazeConnection>>,
-    connection_status: watch::Sender<bool>,
-    transactions: Arc<Mutex<HashMap<String, Completer<BlazeMessage>>>>,
-}
-
-impl Drop for SocketSessionGuard {
-    fn drop(&mut self) {
-        self.connection_status.send_replace(false);
-        self.connection.lock().unwrap().sink = None;
-        fail_transactions(&self.transactions, "blaze disconnected");
-    }
-}
-
-fn fail_transactions(transactions: &Mutex<HashMap<String, Completer<BlazeMessage>>>, reason: &str) {
-    let pending = transactions.lock().unwrap().drain().collect::<Vec<_>>();
-    for (_, transaction) in pending {
-        transaction.complete(Err(anyhow!(reason.to_string())));
-    }
-}
-
-fn is_authentication_handshake_error(error: &WebSocketError) -> bool {
-    matches!(
-        error,
-        WebSocketError::Http(response) if matches!(response.status().as_u16(), 401 | 403)
-    )
-}
-
-fn is_api_authentication_error(error: &anyhow::Error) -> bool {
-    error.downcast_ref::<sdk::ApiError>().is_some_and(|error| {
-        matches!(
-            error,
-            sdk::ApiError::Server(sdk::Error {
-                code: error_code::AUTHENTICATION,
-                ..
-            })
-        )
-    })
-}
-
-async fn await_transaction(
-    transactions: &Mutex<HashMap<String, Completer<BlazeMessage>>>,
-    message_id: &str,
-    completer: Completer<BlazeMessage>,
-    timeout: Duration,
-) -> Result<BlazeMessage> {
-    match tokio::time::timeout(timeout, completer).await {
-        Ok(result) => result,
-        Err(_) => {
-            transactions.lock().unwrap().remove(message_id);
-            Err(anyhow!("blaze request {message_id} timed out"))
-        }
-    }
-}
-
-trait SendBlazeMessage {
-    async fn send_blaze_message(&mut self, message: BlazeMessage) -> Result<()>;
-}
-
-impl SendBlazeMessage for UnboundedSender<Message> {
-    async fn send_blaze_message(&mut self, message: BlazeMessage) -> Result<()> {
-        let bytes = serde_json::to_vec(&message)?;
-        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
-        encoder.write_all(&bytes)?;
-        let compressed_data = encoder.finish()?;
-        self.send(Message::Binary(compressed_data.into())).await?;
-        Ok(())
-    }
-}
-
 impl Blaze {
     pub fn new(
         database: Arc<MixinDatabase>,
@@ -244,18 +123,17 @@ impl Blaze {
         user_id: String,
         changes: Option<ConversationChangeNotifier>,
     ) -> Self {
-        let (connection_status, _) = watch::channel(false);
-        Blaze {
+        let pending_message_statuses = PendingMessageStatusStore::new(changes);
+        let handler = DatabaseHandler {
             database,
-            client,
-            credential,
-            connection: Arc::new(Mutex::new(BlazeConnection { sink: None })),
-            connection_status,
+            client: client.clone(),
             user_id,
-            transactions: Arc::new(Mutex::new(HashMap::new())),
-            connect_running: Arc::new(AtomicBool::new(false)),
-            reconnect: Notify::new(),
-            pending_message_statuses: PendingMessageStatusStore::new(changes),
+            pending_message_statuses: pending_message_statuses.clone(),
+        };
+        Blaze {
+            client: BlazeClient::new(client, credential, handler)
+                .with_user_agent(generate_user_agent()),
+            pending_message_statuses,
         }
     }
 
@@ -264,199 +142,52 @@ impl Blaze {
     }
 
     pub fn subscribe_connection_status(&self) -> watch::Receiver<bool> {
-        self.connection_status.subscribe()
+        self.client.subscribe_connection_status()
     }
 
     pub fn retry_connection(&self) {
-        self.reconnect.notify_one();
+        self.client.retry_connection();
     }
 
     pub async fn connect(&self) -> Result<()> {
-        if self
-            .connect_running
-            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
-            .is_err()
-        {
-            return Err(anyhow!("blaze connection loop is already running"));
-        }
-        let _connect_guard = ConnectLoopGuard(self.connect_running.clone());
-
-        let mut host_index = 0;
-        loop {
-            let host = WS_HOSTS[host_index];
-            match self.connect_once(host).await {
-                Ok(SocketSessionEnd::AuthenticationFailed) => {
-                    return Err(BlazeAuthenticationError.into());
-                }
-                Ok(SocketSessionEnd::Disconnected) => {
-                    warn!("blaze disconnected from {host}");
-                }
-                Err(err) => {
-                    warn!("blaze connection to {host} failed: {err:?}");
-                }
-            }
-
-            host_index = (host_index + 1) % WS_HOSTS.len();
-            tokio::select! {
-                _ = tokio::time::sleep(RECONNECT_DELAY) => {}
-                _ = self.reconnect.notified() => {}
-            }
-        }
+        self.client.connect().await
     }
 
-    async fn connect_once(&self, host: &str) -> Result<SocketSessionEnd> {
-        let token = self
-            .credential
-            .sign_authentication_token(&Method::GET, &"/".to_string(), [])
-            .map_err(|e| anyhow!("can not sign request: {}", e))?;
+    pub fn try_get_sender(&self) -> Result<UnboundedSender<Message>> {
+        self.client.try_get_sender()
+    }
 
-        let mut request = host.into_client_request()?;
-        request.headers_mut().insert(
-            "Sec-WebSocket-Protocol",
-            HeaderValue::try_from("Mixin-Blaze-1")?,
-        );
-        request.headers_mut().insert(
-            "Authorization",
-            HeaderValue::try_from(format!("Bearer {}", token))?,
-        );
-        request
-            .headers_mut()
-            .insert("User-Agent", HeaderValue::try_from(generate_user_agent())?);
+    pub async fn send_message(&self, message: BlazeMessage) -> Result<BlazeMessage> {
+        self.client.send_message(message).await
+    }
+}
 
-        let connection = tokio::time::timeout(CONNECT_TIMEOUT, connect_async(request))
-            .await
-            .map_err(|_| anyhow!("blaze connection timed out"))?;
-        let (ws_stream, _) = match connection {
-            Ok(connection) => connection,
-            Err(err) if is_authentication_handshake_error(&err) => {
-                return Ok(SocketSessionEnd::AuthenticationFailed);
-            }
-            Err(err) => return Err(err.into()),
-        };
-        let (mut socket_sink, mut socket_stream) = ws_stream.split();
-        let (mut sender, mut receiver) = futures_channel::mpsc::unbounded();
-
-        self.connection.lock().unwrap().sink = Some(sender.clone());
-        self.connection_status.send_replace(true);
-        let _session_guard = SocketSessionGuard {
-            connection: self.connection.clone(),
-            connection_status: self.connection_status.clone(),
-            transactions: self.transactions.clone(),
-        };
+struct DatabaseHandler {
+    database: Arc<MixinDatabase>,
+    client: Arc<Client>,
+    user_id: String,
+    pending_message_statuses: PendingMessageStatusStore,
+}
 
+impl BlazeHandler for DatabaseHandler {
+    async fn pending_messages_offset(&self) -> Result<Option<DateTime<Utc>>> {
         let offset = self
             .database
             .flood_message_dao
             .latest_flood_message_created_at()
             .await?;
         info!("latest flood message created at offset: {:?}", offset);
-        sender
-            .send_blaze_message(BlazeMessage::new_list_pending_blaze(
-                offset.map(|e| e.and_utc().to_rfc3339()),
-            ))
-            .await?;
-        let mut heartbeat = tokio::time::interval(PING_INTERVAL);
-        heartbeat.tick().await;
-        let mut last_pong = tokio::time::Instant::now();
-        let mut status_refresh = Box::pin(self.refresh_message_status_offset());
-        let mut status_refresh_complete = false;
-
-        loop {
-            tokio::select! {
-                result = status_refresh.as_mut(), if !status_refresh_complete => {
-                    status_refresh_complete = true;
-                    if let Err(err) = result {
-                        if is_api_authentication_error(&err) {
-                            return Ok(SocketSessionEnd::AuthenticationFailed);
-                        }
-                        warn!("failed to refresh message status offset: {err}");
-                    }
-                }
-                _ = heartbeat.tick() => {
-                    if last_pong.elapsed() >= PONG_TIMEOUT {
-                        return Ok(SocketSessionEnd::Disconnected);
-                    }
-                    socket_sink.send(Message::Ping(Vec::new().into())).await?;
-                }
-                outgoing = receiver.next() => {
-                    let Some(outgoing) = outgoing else {
-                        return Ok(SocketSessionEnd::Disconnected);
-                    };
-                    socket_sink.send(outgoing).await?;
-                }
-                incoming = socket_stream.next() => {
-                    let Some(incoming) = incoming else {
-                        return Ok(SocketSessionEnd::Disconnected);
-                    };
-                    let incoming = incoming?;
-                    match incoming {
-                        Message::Ping(data) => socket_sink.send(Message::Pong(data)).await?,
-                        Message::Pong(_) => last_pong = tokio::time::Instant::now(),
-                        Message::Close(_) => return Ok(SocketSessionEnd::Disconnected),
-                        message => {
-                            match self.on_socket_message(message).await? {
-                                SocketMessageResult::Continue => {}
-                                SocketMessageResult::AuthenticationFailed => {
-                                    return Ok(SocketSessionEnd::AuthenticationFailed);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-
-impl Blaze {
-    async fn on_socket_message(&self, message: Message) -> Result<SocketMessageResult> {
-        let data = message.into_data();
-
-        let mut decoder = GzDecoder::new(Cursor::new(&data));
-        let mut decompressed_data = Vec::new();
-        decoder.read_to_end(&mut decompressed_data)?;
-        let message: BlazeMessage = serde_json::from_slice(&decompressed_data)?;
-
-        let authentication_failed = message.action == ERROR_ACTION
-            && message
-                .error
-                .as_ref()
-                .is_some_and(|e| e.code == error_code::AUTHENTICATION);
-
-        {
-            let mut transactions = self.transactions.lock().unwrap();
-            if let Some(transaction) = transactions.remove(&message.id) {
-                transaction.complete(Ok(message.clone()));
-            }
-        }
-
-        if authentication_failed {
-            return Ok(SocketMessageResult::AuthenticationFailed);
-        }
-
-        if message.data.is_some()
-            && (message.action == ACKNOWLEDGE_MESSAGE_RECEIPT
-                || message.action == CREATE_MESSAGE
-                || message.action == CREATE_CALL
-                || message.action == CREATE_KRAKEN)
-        {
-            if let Err(err) = self.handle_receive_message(message).await {
-                if is_api_authentication_error(&err) {
-                    return Ok(SocketMessageResult::AuthenticationFailed);
-                }
-                error!("failed to handle_receive_message, error: {:?} ", err);
-            }
-        }
-        Ok(SocketMessageResult::Continue)
+        Ok(offset.map(|offset| offset.and_utc()))
     }
 
-    async fn handle_receive_message(&self, message: BlazeMessage) -> Result<()> {
-        let data = message.data.ok_or(anyhow!("blaze message no data"))?;
-        let data: BlazeMessageData = serde_json::from_value(data)?;
-        info!("handle receive message: {}", message.action);
-        if message.action == ACKNOWLEDGE_MESSAGE_RECEIPT {
+    async fn handle_message(
+        &self,
+        action: &str,
+        data: BlazeMessageData,
+    ) -> Result<Option<MessageStatus>> {
+        if action == ACKNOWLEDGE_MESSAGE_RECEIPT {
             self.apply_message_status(&data).await?;
-        } else if message.action == CREATE_MESSAGE {
+        } else if action == CREATE_MESSAGE {
             if data.user_id == self.user_id
                 && (data.category.is_empty() || data.conversation_id.is_empty())
             {
@@ -475,16 +206,23 @@ impl Blaze {
                     .insert_flood_message(flood_message)
                     .await?;
             }
-        } else if message.action == CREATE_CALL || message.action == CREATE_KRAKEN {
+        } else if action == CREATE_CALL || action == CREATE_KRAKEN {
             self.acknowledge_message(&data.message_id, MessageStatus::Read)
                 .await?;
         } else {
             self.acknowledge_message(&data.message_id, MessageStatus::Delivered)
                 .await?;
         }
-        Ok(())
+        // Acknowledgements go through the job queue so they survive restarts.
+        Ok(None)
     }
 
+    async fn on_connected(&self) -> Result<()> {
+        self.refresh_message_status_offset().await
+    }
+}
+
+impl DatabaseHandler {
     async fn apply_message_status(&self, data: &BlazeMessageData) -> Result<()> {
         self.pending_message_statuses
             .apply(&self.database, &data.message_id, data.status)
@@ -566,120 +304,11 @@ impl Blaze {
         });
         Ok(())
     }
-
-    pub fn try_get_sender(&self) -> Result<UnboundedSender<Message>> {
-        let connection = self
-            .connection
-            .lock()
-            .map_err(|_| anyhow!("blaze connection lock is poisoned"))?;
-        connection.sink.clone().ok_or(anyhow!("not connected"))
-    }
-
-    async fn get_sender(&self) -> Result<UnboundedSender<Message>> {
-        let mut connection_status = self.connection_status.subscribe();
-        loop {
-            if let Ok(sender) = self.try_get_sender() {
-                return Ok(sender);
-            }
-            connection_status
-                .wait_for(|connected| *connected)
-                .await
-                .map_err(|_| anyhow!("blaze connection stopped"))?;
-        }
-    }
-
-    pub async fn send_message(&self, message: BlazeMessage) -> Result<BlazeMessage> {
-        let mut sender = self.get_sender().await?;
-        let completer = Completer::default();
-        let message_id = message.id.clone();
-        {
-            let mut transactions = self.transactions.lock().unwrap();
-            transactions.insert(message_id.clone(), completer.clone());
-        }
-        if let Err(err) = sender.send_blaze_message(message).await {
-            self.transactions.lock().unwrap().remove(&message_id);
-            return Err(err);
-        }
-
-        await_transaction(&self.transactions, &message_id, completer, REQUEST_TIMEOUT).await
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
-
-    #[tokio::test]
-    async fn disconnect_clears_sender_and_fails_pending_transactions() {
-        let (sender, _receiver) = futures_channel::mpsc::unbounded();
-        let connection = Arc::new(Mutex::new(BlazeConnection { sink: Some(sender) }));
-        let (connection_status, _) = watch::channel(true);
-        let transactions = Arc::new(Mutex::new(HashMap::new()));
-        let completer = Completer::<BlazeMessage>::default();
-        transactions
-            .lock()
-            .unwrap()
-            .insert("request-id".to_string(), completer.clone());
-
-        let guard = SocketSessionGuard {
-            connection: connection.clone(),
-            connection_status,
-            transactions: transactions.clone(),
-        };
-        drop(guard);
-
-        assert!(connection.lock().unwrap().sink.is_none());
-        assert!(transactions.lock().unwrap().is_empty());
-        assert_eq!(
-            completer.await.unwrap_err().to_string(),
-            "blaze disconnected"
-        );
-    }
-
-    #[tokio::test]
-    async fn request_timeout_removes_pending_transaction() {
-        let transactions = Mutex::new(HashMap::new());
-        let completer = Completer::<BlazeMessage>::default();
-        transactions
-            .lock()
-            .unwrap()
-            .insert("request-id".to_string(), completer.clone());
-
-        let result = await_transaction(
-            &transactions,
-            "request-id",
-            completer,
-            Duration::from_millis(1),
-        )
-        .await;
-
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            "blaze request request-id timed out"
-        );
-        assert!(transactions.lock().unwrap().is_empty());
-    }
-
-    #[test]
-    fn unauthorized_handshake_is_an_authentication_error() {
-        for status in [401, 403] {
-            let response = tokio_tungstenite::tungstenite::http::Response::builder()
-                .status(status)
-                .body(None)
-                .unwrap();
-            assert!(is_authentication_handshake_error(&WebSocketError::Http(
-                Box::new(response)
-            )));
-        }
-
-        let api_error = anyhow::Error::new(sdk::ApiError::Server(sdk::Error {
-            status: 401,
-            code: error_code::AUTHENTICATION,
-            description: "unauthorized".to_string(),
-        }));
-        assert!(is_api_authentication_error(&api_error));
-    }
 
     #[tokio::test]
     async fn status_received_before_insert_is_applied_after_insert() {
@@ -5,13 +5,14 @@ use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, bail, Context, Result};
 use base64ct::{Base64, Encoding};
+use chrono::{DateTime, Utc};
 use log::{error, info, warn};
 use tokio::sync::{broadcast, mpsc, watch};
 use uuid::Uuid;
 
 use sdk::blaze_message::{
     message_action, BlazeMessageData, MessageStatus, PlainJsonMessage, SnapshotMessage,
-    SystemCircleMessage, SystemConversationMessage, SystemUserMessage,
+    SystemCircleMessage, SystemConversationMessage, SystemSessionMessage, SystemUserMessage,
     ACKNOWLEDGE_MESSAGE_RECEIPTS, RESEND_KEY, RESEND_MESSAGES,
 };
 use sdk::message_category::MessageCategory;
@@ -28,6 +29,7 @@ use crate::core::crypto::signal_protocol::SignalProtocol;
 use crate::core::device_transfer::{DeviceTransferControlEvent, DEVICE_TRANSFER_ACTION};
 use crate::core::message::blaze::PendingMessageStatusStore;
 use crate::core::message::sender::{MessageSender, ProcessSignalKeyAction};
+use crate::core::model::auth::AuthService;
 use crate::core::model::{AppService, AttachmentExtra};
 use crate::db::app::Auth;
 use crate::db::mixin::conversation::ConversationStatus;
@@ -35,7 +37,9 @@ use crate::db::mixin::flood_message::FloodMessage;
 use crate::db::mixin::job::Job;
 use crate::db::mixin::message::{AttachmentMessageUpdate, MediaStatus, Message};
 use crate::db::mixin::message_fts::message_fts_content;
+use crate::db::mixin::message_reaction::MessageReaction;
 use crate::db::mixin::participant::Participant;
+use crate::db::mixin::participant_event::ParticipantEvent;
 use crate::db::mixin::pin_message::{PinMessage, PinMessageMinimal};
 use crate::db::mixin::transcript_message::TranscriptMessage;
 use crate::db::mixin::MixinDatabase;
@@ -56,9 +60,19 @@ pub struct ServiceDecryptMessage {
     conversation_changes: Option<ConversationChangeNotifier>,
     notification_changes: Option<watch::Sender<u64>>,
     device_transfer_controls: Option<broadcast::Sender<DeviceTransferControlEvent>>,
+    session_linked_events: Option<broadcast::Sender<SessionLinkedEvent>>,
+    auth_service: Option<Arc<AuthService>>,
     attachment_transfer_requests: Option<mpsc::UnboundedSender<AttachmentTransferRequest>>,
 }
 
+/// Another device was linked to the signed-in account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionLinkedEvent {
+    pub session_id: String,
+    pub public_key: Option<String>,
+    pub linked_at: DateTime<Utc>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) struct AttachmentTransferRequest {
     pub(crate) message_id: String,
@@ -100,6 +114,8 @@ impl ServiceDecryptMessage {
             conversation_changes: None,
             notification_changes: None,
             device_transfer_controls: None,
+            session_linked_events: None,
+            auth_service: None,
             attachment_transfer_requests: None,
         }
     }
@@ -122,6 +138,21 @@ impl ServiceDecryptMessage {
         self
     }
 
+    pub fn with_session_linked_events(
+        mut self,
+        sender: broadcast::Sender<SessionLinkedEvent>,
+    ) -> Self {
+        self.session_linked_events = Some(sender);
+        self
+    }
+
+    /// Keeps the saved primary session in step with `SYSTEM_SESSION`
+    /// messages.
+    pub fn with_auth_service(mut self, auth_service: Arc<AuthService>) -> Self {
+        self.auth_service = Some(auth_service);
+        self
+    }
+
     pub(crate) fn with_attachment_transfer_requests(
         mut self,
         sender: mpsc::UnboundedSender<AttachmentTransferRequest>,
@@ -337,6 +368,12 @@ impl ServiceDecryptMessage {
         } else if category.is_recall() {
             status = MessageStatus::Read;
             self.process_recall(data).await
+        } else if category.is_edit() {
+            status = MessageStatus::Read;
+            self.process_edit(data).await
+        } else if category.is_reaction() {
+            status = MessageStatus::Read;
+            self.process_reaction(data).await
         } else {
             Ok(())
         };
@@ -934,7 +971,7 @@ impl ServiceDecryptMessage {
         } else if data.category.is_location() {
             let plain = decode_content(data, plain_text)?;
             let location_message: sdk::LocationMessage = serde_json::from_str(&plain)?;
-            if location_message.latitude == 0.0 || location_message.longitude == 0.0 {
+            if !location_message.is_valid() {
                 return Err(anyhow!("invalid location message: {}", plain));
             }
             let message = Message {
@@ -1284,6 +1321,119 @@ impl ServiceDecryptMessage {
         }
         Ok(())
     }
+
+    async fn process_edit(&self, data: &BlazeMessageData) -> Result<()> {
+        let edit: sdk::EditMessage = serde_json::from_str(&decode(&data.data)?)?;
+        let message = self
+            .database
+            .message_dao
+            .find_message_by_id(&edit.message_id)
+            .await?;
+        if let Some(message) = message.filter(|message| {
+            message.conversation_id == data.conversation_id
+                && &message.user_id == data.sender_id()
+                && message.category.can_edit()
+        }) {
+            let edited = self
+                .database
+                .message_dao
+                .edit_message(
+                    &data.message_id,
+                    &data.conversation_id,
+                    &edit.message_id,
+                    &edit.content,
+                    data.created_at,
+                    None,
+                )
+                .await?;
+            if edited {
+                let message = Message {
+                    content: Some(edit.content),
+                    ..message
+                };
+                self.database
+                    .message_fts_dao
+                    .upsert(
+                        &message.message_id,
+                        &message.conversation_id,
+                        &message_fts_content(&message).unwrap_or_default(),
+                    )
+                    .await?;
+                if message.category.is_text() {
+                    let quote_message = match message.quote_message_id.as_ref() {
+                        Some(quote_message_id) => {
+                            self.database
+                                .message_dao
+                                .find_quote_message_by_id(quote_message_id)
+                                .await?
+                        }
+                        None => None,
+                    };
+                    self.database
+                        .message_mention_dao
+                        .delete_message_mention(&message.message_id)
+                        .await?;
+                    self.database
+                        .message_mention_dao
+                        .parse_and_save_mention_data(
+                            &message.message_id,
+                            &message.conversation_id,
+                            message.content.as_deref(),
+                            &message.user_id,
+                            &quote_message,
+                            self.user_id.as_str(),
+                            self.identity_number.as_str(),
+                        )
+                        .await?;
+                }
+            }
+        } else {
+            warn!(
+                "ignore edit {} for message {} from {}",
+                data.message_id,
+                edit.message_id,
+                data.sender_id()
+            );
+        }
+        self.database
+            .message_history_dao
+            .insert(&data.message_id)
+            .await?;
+        Ok(())
+    }
+
+    async fn process_reaction(&self, data: &BlazeMessageData) -> Result<()> {
+        let reaction: sdk::ReactionMessage = serde_json::from_str(&decode(&data.data)?)?;
+        let applied = !reaction.emoji.trim().is_empty()
+            && self
+                .database
+                .message_reaction_dao
+                .apply(
+                    &MessageReaction {
+                        message_id: reaction.message_id.clone(),
+                        conversation_id: data.conversation_id.clone(),
+                        user_id: data.sender_id().to_string(),
+                        emoji: reaction.emoji,
+                        created_at: data.created_at,
+                    },
+                    reaction.removed,
+                    None,
+                )
+                .await?;
+        if !applied {
+            info!(
+                "ignore reaction {} for message {} from {}",
+                data.message_id,
+                reaction.message_id,
+                data.sender_id()
+            );
+        }
+        self.database
+            .message_history_dao
+            .insert(&data.message_id)
+            .await?;
+        Ok(())
+    }
 }
 
 fn decode(data: &str) -> Result<String> {
@@ -1376,6 +1526,9 @@ impl ServiceDecryptMessage {
         } else if data.category == message_category::SYSTEM_USER {
             let message: SystemUserMessage = serde_json::from_str(&content)?;
             self.process_system_user_message(message).await?
+        } else if data.category == message_category::SYSTEM_SESSION {
+            let message: SystemSessionMessage = serde_json::from_str(&content)?;
+            self.process_system_session_message(data, message).await?
         } else if data.category == message_category::SYSTEM_CIRCLE {
             let message: SystemCircleMessage = serde_json::from_str(&content)?;
             self.process_system_circle_message(data, message).await?
@@ -1414,6 +1567,18 @@ impl ServiceDecryptMessage {
                 .insert_system_user_if_not_exist()
                 .await?
         }
+        self.database
+            .participant_event_dao
+            .insert(&ParticipantEvent {
+                message_id: data.message_id.clone(),
+                conversation_id: data.conversation_id.clone(),
+                action: message.action.clone(),
+                actor_id: user_id.clone(),
+                participant_id: message.participant_id.clone(),
+                role: message.role.clone(),
+                created_at: data.created_at,
+            })
+            .await?;
 
         if message.action == message_action::JOIN || message.action == message_action::ADD {
             self.database
@@ -1544,6 +1709,29 @@ impl ServiceDecryptMessage {
         Ok(())
     }
 
+    async fn process_system_session_message(
+        &self,
+        data: &BlazeMessageData,
+        message: SystemSessionMessage,
+    ) -> Result<()> {
+        let event = apply_system_session_message(
+            &self.database,
+            self.auth_service.as_deref(),
+            &self.user_id,
+            &self.session_id,
+            &message,
+            data.created_at,
+        )
+        .await?;
+        if let Some(event) = event {
+            info!("new session linked: {}", event.session_id);
+            if let Some(sender) = &self.session_linked_events {
+                let _ = sender.send(event);
+            }
+        }
+        Ok(())
+    }
+
     async fn process_system_circle_message(
         &self,
         data: &BlazeMessageData,
@@ -1666,6 +1854,13 @@ impl ServiceDecryptMessage {
                 sender: String::new(),
             });
         }
+        if snapshot.type_field != "pending" {
+            // Settles the pending row of a payment submitted from here.
+            self.database
+                .safe_snapshot_dao
+                .delete_pending_snapshot_by_hash(&snapshot.transaction_hash)
+                .await?;
+        }
         self.database.safe_snapshot_dao.insert(&snapshot).await?;
 
         let message = Message {
@@ -1813,6 +2008,79 @@ impl ServiceDecryptMessage {
     }
 }
 
+/// Applies a `SYSTEM_SESSION` message about another session of the account.
+/// A provisioned session is always another device linked to the account: it
+/// receives sender keys and raises an alert, while the primary device saved at
+/// login stays primary. A destroyed primary leaves the account without one.
+pub(crate) async fn apply_system_session_message(
+    database: &MixinDatabase,
+    auth_service: Option<&AuthService>,
+    user_id: &str,
+    session_id: &str,
+    message: &SystemSessionMessage,
+    created_at: DateTime<Utc>,
+) -> Result<Option<SessionLinkedEvent>> {
+    if message.user_id != user_id || message.session_id == session_id {
+        return Ok(None);
+    }
+    let primary_session_id = auth_service
+        .and_then(|auth_service| auth_service.get_auth_for(user_id))
+        .and_then(|auth| auth.primary_session_id);
+    match message.action.as_str() {
+        message_action::PROVISION => {
+            database
+                .participant_session_dao
+                .add_user_session(
+                    &message.user_id,
+                    &message.session_id,
+                    message.public_key.as_deref(),
+                )
+                .await?;
+        }
+        message_action::DESTROY => {
+            database
+                .participant_session_dao
+                .remove_user_session(&message.user_id, &message.session_id)
+                .await?;
+            if primary_session_id.as_deref() == Some(message.session_id.as_str()) {
+                if let Some(auth_service) = auth_service {
+                    auth_service
+                        .update_primary_session_id(user_id, None)
+                        .await?;
+                }
+            }
+        }
+        _ => return Ok(None),
+    }
+    Ok(session_linked_event(
+        message,
+        user_id,
+        session_id,
+        primary_session_id.as_deref(),
+        created_at,
+    ))
+}
+
+/// Alerts only for sessions that are neither this desktop nor the primary
+/// device already known.
+fn session_linked_event(
+    message: &SystemSessionMessage,
+    user_id: &str,
+    session_id: &str,
+    primary_session_id: Option<&str>,
+    created_at: DateTime<Utc>,
+) -> Option<SessionLinkedEvent> {
+    (message.action == message_action::PROVISION
+        && message.user_id == user_id
+        && message.session_id != session_id
+        && primary_session_id != Some(message.session_id.as_str()))
+    .then(|| SessionLinkedEvent {
+        session_id: message.session_id.clone(),
+        public_key: message.public_key.clone(),
+        linked_at: created_at,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1868,4 +2136,42 @@ mod tests {
         ))
         .is_none());
     }
+
+    #[test]
+    fn only_new_secondary_sessions_of_the_account_raise_linked_events() {
+        let message = |action: &str, user_id: &str, session_id: &str| SystemSessionMessage {
+            action: action.to_string(),
+            user_id: user_id.to_string(),
+            session_id: session_id.to_string(),
+            public_key: Some("key".to_string()),
+        };
+        let now = Utc::now();
+
+        let event = session_linked_event(
+            &message(message_action::PROVISION, "me", "tablet"),
+            "me",
+            "desktop",
+            Some("phone"),
+            now,
+        )
+        .unwrap();
+        assert_eq!(event.session_id, "tablet");
+        assert_eq!(event.linked_at, now);
+
+        for (action, user_id, session_id) in [
+            (message_action::PROVISION, "me", "desktop"),
+            (message_action::PROVISION, "me", "phone"),
+            (message_action::PROVISION, "someone", "tablet"),
+            (message_action::DESTROY, "me", "tablet"),
+        ] {
+            assert!(session_linked_event(
+                &message(action, user_id, session_id),
+                "me",
+                "desktop",
+                Some("phone"),
+                now
+            )
+            .is_none());
+        }
+    }
 }
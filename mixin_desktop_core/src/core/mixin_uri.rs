@@ -0,0 +1,323 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use url::Url;
+use uuid::Uuid;
+
+const SCHEME: &str = "mixin";
+const WEB_HOSTS: &[&str] = &["mixin.one", "www.mixin.one"];
+/// Everything but the unreserved characters, so ids stay readable.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// A link understood by Mixin clients, in either the `mixin://` form or the
+/// `https://mixin.one/` form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MixinUri {
+    User {
+        user_id: String,
+    },
+    /// Opens a conversation, or the contact conversation with `user_id` when
+    /// `conversation_id` is missing.
+    Conversation {
+        conversation_id: Option<String>,
+        user_id: Option<String>,
+    },
+    Code {
+        code: String,
+    },
+    App {
+        app_id: String,
+        action: Option<String>,
+    },
+    Transfer {
+        user_id: String,
+    },
+    Pay(PayUri),
+    /// Points at a snapshot by id, or by the trace id of its transfer.
+    Snapshot {
+        snapshot_id: Option<String>,
+        trace_id: Option<String>,
+    },
+    Sticker {
+        sticker_id: String,
+    },
+    DeviceAuth {
+        device_id: String,
+        public_key: String,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PayUri {
+    pub recipient: String,
+    pub asset_id: Option<String>,
+    pub amount: Option<String>,
+    pub memo: Option<String>,
+    pub trace_id: Option<String>,
+}
+
+impl MixinUri {
+    pub fn parse(uri: &str) -> Result<Self> {
+        let url = Url::parse(uri.trim()).map_err(|_| anyhow!("invalid Mixin URI: {uri}"))?;
+        let mut segments = Vec::new();
+        match url.scheme() {
+            SCHEME => segments.extend(url.host_str().map(str::to_owned)),
+            "https" | "http" if url.host_str().is_some_and(|host| WEB_HOSTS.contains(&host)) => {}
+            _ => return Err(anyhow!("unsupported Mixin URI: {uri}")),
+        }
+        segments.extend(
+            url.path_segments()
+                .into_iter()
+                .flatten()
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| percent_decode_str(segment).decode_utf8_lossy().into_owned()),
+        );
+        let query = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.trim().to_owned())
+                .filter(|value| !value.is_empty())
+        };
+        let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+        let parsed = match segments.as_slice() {
+            ["users", user_id] => Self::User {
+                user_id: uuid(user_id)?,
+            },
+            ["conversations"] | ["conversations", _] => {
+                let conversation_id = segments.get(1).map(|id| uuid(id)).transpose()?;
+                let user_id = query("user").map(|id| uuid(&id)).transpose()?;
+                if conversation_id.is_none() && user_id.is_none() {
+                    return Err(anyhow!("conversation URI has no conversation or user"));
+                }
+                Self::Conversation {
+                    conversation_id,
+                    user_id,
+                }
+            }
+            ["codes", code] => Self::Code {
+                code: (*code).to_owned(),
+            },
+            ["apps", app_id] => Self::App {
+                app_id: uuid(app_id)?,
+                action: query("action"),
+            },
+            ["transfer", user_id] => Self::Transfer {
+                user_id: uuid(user_id)?,
+            },
+            ["pay"] | ["pay", _] => {
+                let recipient = match segments.get(1) {
+                    Some(recipient) => (*recipient).to_owned(),
+                    None => {
+                        query("recipient").ok_or_else(|| anyhow!("pay URI has no recipient"))?
+                    }
+                };
+                Self::Pay(PayUri {
+                    recipient,
+                    asset_id: query("asset").map(|id| uuid(&id)).transpose()?,
+                    amount: query("amount"),
+                    memo: query("memo"),
+                    trace_id: query("trace").map(|id| uuid(&id)).transpose()?,
+                })
+            }
+            ["snapshots"] | ["snapshots", _] => {
+                let snapshot_id = segments.get(1).map(|id| uuid(id)).transpose()?;
+                let trace_id = query("trace").map(|id| uuid(&id)).transpose()?;
+                if snapshot_id.is_none() && trace_id.is_none() {
+                    return Err(anyhow!("snapshot URI has no snapshot or trace"));
+                }
+                Self::Snapshot {
+                    snapshot_id,
+                    trace_id,
+                }
+            }
+            ["stickers", sticker_id] => Self::Sticker {
+                sticker_id: uuid(sticker_id)?,
+            },
+            ["device", "auth"] => Self::DeviceAuth {
+                device_id: query("id").ok_or_else(|| anyhow!("device URI has no id"))?,
+                public_key: query("pub_key")
+                    .ok_or_else(|| anyhow!("device URI has no public key"))?,
+            },
+            _ => return Err(anyhow!("unsupported Mixin URI: {uri}")),
+        };
+        Ok(parsed)
+    }
+}
+
+impl FromStr for MixinUri {
+    type Err = anyhow::Error;
+
+    fn from_str(uri: &str) -> Result<Self> {
+        Self::parse(uri)
+    }
+}
+
+/// Formats the `mixin://` form of the URI.
+impl fmt::Display for MixinUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut query = Query::default();
+        match self {
+            Self::User { user_id } => write!(f, "{SCHEME}://users/{user_id}")?,
+            Self::Conversation {
+                conversation_id,
+                user_id,
+            } => {
+                write!(
+                    f,
+                    "{SCHEME}://conversations/{}",
+                    conversation_id.as_deref().unwrap_or_default()
+                )?;
+                query.push("user", user_id.as_deref());
+            }
+            Self::Code { code } => write!(f, "{SCHEME}://codes/{}", encode(code))?,
+            Self::App { app_id, action } => {
+                write!(f, "{SCHEME}://apps/{app_id}")?;
+                query.push("action", action.as_deref());
+            }
+            Self::Transfer { user_id } => write!(f, "{SCHEME}://transfer/{user_id}")?,
+            Self::Pay(pay) => {
+                write!(f, "{SCHEME}://pay")?;
+                query.push("recipient", Some(&pay.recipient));
+                query.push("asset", pay.asset_id.as_deref());
+                query.push("amount", pay.amount.as_deref());
+                query.push("memo", pay.memo.as_deref());
+                query.push("trace", pay.trace_id.as_deref());
+            }
+            Self::Snapshot {
+                snapshot_id,
+                trace_id,
+            } => {
+                write!(
+                    f,
+                    "{SCHEME}://snapshots/{}",
+                    snapshot_id.as_deref().unwrap_or_default()
+                )?;
+                query.push("trace", trace_id.as_deref());
+            }
+            Self::Sticker { sticker_id } => write!(f, "{SCHEME}://stickers/{sticker_id}")?,
+            Self::DeviceAuth {
+                device_id,
+                public_key,
+            } => {
+                write!(f, "{SCHEME}://device/auth")?;
+                query.push("id", Some(device_id));
+                query.push("pub_key", Some(public_key));
+            }
+        }
+        f.write_str(&query.0)
+    }
+}
+
+#[derive(Default)]
+struct Query(String);
+
+impl Query {
+    fn push(&mut self, name: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            let separator = if self.0.is_empty() { '?' } else { '&' };
+            self.0
+                .push_str(&format!("{separator}{name}={}", encode(value)));
+        }
+    }
+}
+
+fn encode(value: &str) -> String {
+    utf8_percent_encode(value, COMPONENT).to_string()
+}
+
+fn uuid(value: &str) -> Result<String> {
+    Uuid::parse_str(value)
+        .map(|id| id.to_string())
+        .map_err(|_| anyhow!("invalid id in Mixin URI: {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MixinUri, PayUri};
+
+    const USER_ID: &str = "773e5e77-4107-45c2-b648-8fc722ed77f5";
+    const ASSET_ID: &str = "c6d0c728-2624-429b-8e0d-d9d19b6592fa";
+
+    #[test]
+    fn parses_mixin_and_web_forms() {
+        for uri in [
+            format!("mixin://users/{USER_ID}"),
+            format!("https://mixin.one/users/{}", USER_ID.to_uppercase()),
+        ] {
+            assert_eq!(
+                MixinUri::parse(&uri).unwrap(),
+                MixinUri::User {
+                    user_id: USER_ID.to_owned()
+                }
+            );
+        }
+        assert_eq!(
+            MixinUri::parse("https://mixin.one/codes/abc123").unwrap(),
+            MixinUri::Code {
+                code: "abc123".to_owned()
+            }
+        );
+        assert_eq!(
+            MixinUri::parse(&format!("mixin://conversations/?user={USER_ID}")).unwrap(),
+            MixinUri::Conversation {
+                conversation_id: None,
+                user_id: Some(USER_ID.to_owned())
+            }
+        );
+        assert_eq!(
+            MixinUri::parse(&format!(
+                "https://mixin.one/pay/{USER_ID}?asset={ASSET_ID}&amount=0.5&memo=hi%20there"
+            ))
+            .unwrap(),
+            MixinUri::Pay(PayUri {
+                recipient: USER_ID.to_owned(),
+                asset_id: Some(ASSET_ID.to_owned()),
+                amount: Some("0.5".to_owned()),
+                memo: Some("hi there".to_owned()),
+                trace_id: None,
+            })
+        );
+
+        assert!(MixinUri::parse("https://example.com/users/x").is_err());
+        assert!(MixinUri::parse("mixin://users/not-a-uuid").is_err());
+        assert!(MixinUri::parse("mixin://conversations/").is_err());
+        assert!(MixinUri::parse("mixin://unknown/path").is_err());
+    }
+
+    #[test]
+    fn formats_uris_that_parse_back() {
+        let device = MixinUri::DeviceAuth {
+            device_id: "device".to_owned(),
+            public_key: "a+b/c=".to_owned(),
+        };
+        assert_eq!(
+            device.to_string(),
+            "mixin://device/auth?id=device&pub_key=a%2Bb%2Fc%3D"
+        );
+
+        for uri in [
+            device,
+            MixinUri::App {
+                app_id: USER_ID.to_owned(),
+                action: Some("open".to_owned()),
+            },
+            MixinUri::Snapshot {
+                snapshot_id: None,
+                trace_id: Some(ASSET_ID.to_owned()),
+            },
+            MixinUri::Pay(PayUri {
+                recipient: USER_ID.to_owned(),
+                memo: Some("a&b=c".to_owned()),
+                ..Default::default()
+            }),
+        ] {
+            assert_eq!(MixinUri::parse(&uri.to_string()).unwrap(), uri);
+        }
+    }
+}
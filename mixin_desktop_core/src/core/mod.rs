@@ -3,6 +3,10 @@ pub mod constants;
 pub mod conversation_change;
 pub mod crypto;
 pub mod device_transfer;
+pub mod link_preview;
 pub mod message;
+pub mod mixin_uri;
 pub mod model;
+pub mod qr_code;
 pub mod user_agent;
+pub mod video;
@@ -5,7 +5,6 @@ use anyhow::anyhow;
 use base64ct::{Base64, Encoding};
 use libsignal_protocol::KeyPair;
 use log::info;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use ring::signature::{Ed25519KeyPair, KeyPair as SignatureKeyPair};
 use serde::{Deserialize, Serialize};
 use tokio::time::sleep;
@@ -14,12 +13,15 @@ use sdk::{Client, Credential, ProvisioningRequest};
 
 use crate::core::crypto::key_help::generate_registration_id;
 use crate::core::crypto::provisioning_cipher::decrypt;
+use crate::core::mixin_uri::MixinUri;
+use crate::core::qr_code::{QrMatrix, DEFAULT_QUIET_ZONE};
 use crate::core::user_agent::{provisioning_app_version, provisioning_platform_version};
 use crate::db::app::{AppDatabase, Auth, AuthDao};
 
 pub struct AuthService {
     auth_dao: AuthDao,
-    auth: Arc<Mutex<Option<Auth>>>,
+    /// Saved authorizations, the active account first.
+    auths: Arc<Mutex<Vec<Auth>>>,
 }
 
 pub struct AuthorizationSession {
@@ -38,13 +40,19 @@ impl AuthService {
     pub fn new(app_db: Arc<AppDatabase>) -> Self {
         AuthService {
             auth_dao: app_db.auth_dao.clone(),
-            auth: Arc::new(Mutex::new(None)),
+            auths: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
     pub async fn authorize(&self) -> anyhow::Result<AuthResult> {
         let session = self.begin_authorization("rust").await?;
         info!("login url: {}", session.auth_url());
+        if let Ok(matrix) = QrMatrix::encode(session.auth_url()) {
+            info!(
+                "scan to log in:\n{}",
+                matrix.to_half_blocks(DEFAULT_QUIET_ZONE, true)
+            );
+        }
 
         tokio::select! {
             result = self.wait_authorization(&session, Duration::from_secs(60)) => result,
@@ -56,24 +64,16 @@ impl AuthService {
         &self,
         platform: &str,
     ) -> anyhow::Result<AuthorizationSession> {
-        let client = sdk::Client::new_with_user_agent(
-            Credential::None,
-            Some(crate::core::user_agent::generate_user_agent()),
-        );
-        let response = client
+        let response = provisioning_client()
             .provisioning_api
             .get_provisioning_id(platform)
             .await?;
         let key_pair = KeyPair::generate(&mut rand_core::OsRng);
-        let public_key = utf8_percent_encode(
-            &Base64::encode_string(&key_pair.public_key.serialize()),
-            NON_ALPHANUMERIC,
-        )
+        let auth_url = MixinUri::DeviceAuth {
+            device_id: response.device_id.clone(),
+            public_key: Base64::encode_string(&key_pair.public_key.serialize()),
+        }
         .to_string();
-        let auth_url = format!(
-            "mixin://device/auth?id={}&pub_key={}",
-            response.device_id, public_key
-        );
 
         Ok(AuthorizationSession {
             device_id: response.device_id,
@@ -86,11 +86,37 @@ impl AuthService {
         &self,
         session: &AuthorizationSession,
     ) -> anyhow::Result<Option<AuthResult>> {
-        let client = Arc::new(sdk::Client::new_with_user_agent(
-            Credential::None,
-            Some(crate::core::user_agent::generate_user_agent()),
-        ));
-        check_auth(client, &session.device_id, &session.key_pair).await
+        let Some(secret) = self.provisioning_secret(session).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.verify_authorization(session, &secret).await?))
+    }
+
+    /// The encrypted provisioning secret once the primary device has scanned
+    /// the session's QR code, or `None` while it is still waiting.
+    pub async fn provisioning_secret(
+        &self,
+        session: &AuthorizationSession,
+    ) -> anyhow::Result<Option<String>> {
+        info!("check auth: {}", session.device_id);
+        let secret = provisioning_client()
+            .provisioning_api
+            .get_provisioning(&session.device_id)
+            .await;
+        Ok(secret
+            .ok()
+            .map(|secret| secret.secret)
+            .filter(|secret| !secret.is_empty()))
+    }
+
+    /// Decrypts a provisioning secret and registers this device as a new
+    /// session of the account.
+    pub async fn verify_authorization(
+        &self,
+        session: &AuthorizationSession,
+        secret: &str,
+    ) -> anyhow::Result<AuthResult> {
+        verify_auth(&provisioning_client(), secret, &session.key_pair).await
     }
 
     pub async fn wait_authorization(
@@ -128,61 +154,89 @@ impl AuthService {
 
     pub async fn initialize(&self) -> anyhow::Result<()> {
         let auth_list = self.auth_dao.find_all_auth().await?;
-        let Some(auth) = auth_list.first() else {
-            return Ok(());
-        };
-
-        let mut a = self.auth.lock().unwrap();
-        *a = Some(auth.clone());
-
+        *self.auths.lock().unwrap() = auth_list;
         Ok(())
     }
 
+    /// The authorization of the active account.
     pub fn get_auth(&self) -> Option<Auth> {
-        self.auth.lock().unwrap().clone()
+        self.auths.lock().unwrap().first().cloned()
+    }
+
+    pub fn get_auth_for(&self, user_id: &str) -> Option<Auth> {
+        self.auths
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|auth| auth.user_id == user_id)
+            .cloned()
+    }
+
+    pub fn auths(&self) -> Vec<Auth> {
+        self.auths.lock().unwrap().clone()
     }
 
     pub fn has_auth(&self) -> bool {
-        self.auth.lock().unwrap().is_some()
+        !self.auths.lock().unwrap().is_empty()
     }
 
+    /// Saves `auth` and makes it the active account.
     pub async fn save_auth(&self, auth: &Auth) -> anyhow::Result<()> {
         self.auth_dao.save_auth(auth).await?;
-        {
-            let mut a = self.auth.lock().unwrap();
-            *a = Some(auth.clone());
-        }
+        let mut auths = self.auths.lock().unwrap();
+        auths.retain(|item| item.user_id != auth.user_id);
+        auths.insert(0, auth.clone());
         Ok(())
     }
 
-    pub async fn clear_auth(&self, id: &str) -> anyhow::Result<()> {
-        self.auth_dao.remove_auth(id).await?;
-
-        let mut a = self.auth.lock().unwrap();
-        *a = None;
-
+    /// Replaces a saved authorization without changing the active account.
+    pub async fn update_auth(&self, auth: &Auth) -> anyhow::Result<()> {
+        self.auth_dao.update_auth(auth).await?;
+        let mut auths = self.auths.lock().unwrap();
+        match auths.iter_mut().find(|item| item.user_id == auth.user_id) {
+            Some(item) => *item = auth.clone(),
+            None => auths.push(auth.clone()),
+        }
         Ok(())
     }
-}
 
-async fn check_auth(
-    client: Arc<Client>,
-    device_id: &str,
-    key_pair: &KeyPair,
-) -> anyhow::Result<Option<AuthResult>> {
-    info!("check auth: {}", device_id);
-    let secret = client.provisioning_api.get_provisioning(device_id).await;
+    /// Records the primary device of `user_id`, `None` once it was removed.
+    pub async fn update_primary_session_id(
+        &self,
+        user_id: &str,
+        primary_session_id: Option<String>,
+    ) -> anyhow::Result<()> {
+        let Some(mut auth) = self.get_auth_for(user_id) else {
+            return Ok(());
+        };
+        if auth.primary_session_id == primary_session_id {
+            return Ok(());
+        }
+        auth.primary_session_id = primary_session_id;
+        self.update_auth(&auth).await
+    }
 
-    let Ok(secret) = secret.map(|s| s.secret) else {
-        return Ok(None);
-    };
+    pub async fn set_active_auth(&self, user_id: &str) -> anyhow::Result<()> {
+        self.auth_dao.set_active_auth(user_id).await?;
+        let mut auths = self.auths.lock().unwrap();
+        if let Some(index) = auths.iter().position(|auth| auth.user_id == user_id) {
+            auths[..=index].rotate_right(1);
+        }
+        Ok(())
+    }
 
-    if secret.is_empty() {
-        return Ok(None);
+    pub async fn clear_auth(&self, id: &str) -> anyhow::Result<()> {
+        self.auth_dao.remove_auth(id).await?;
+        self.auths.lock().unwrap().retain(|auth| auth.user_id != id);
+        Ok(())
     }
+}
 
-    let auth = verify_auth(&client, &secret, key_pair).await?;
-    Ok(Some(auth))
+fn provisioning_client() -> Client {
+    Client::new_with_user_agent(
+        Credential::None,
+        Some(crate::core::user_agent::generate_user_agent()),
+    )
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,8 +32,8 @@ use tokio_stream::wrappers::ReceiverStream;
 use sdk::err::error_code::{BAD_DATA, FORBIDDEN};
 use sdk::message_category::MessageCategory;
 use sdk::{
-    Client, ACKNOWLEDGE_MESSAGE_RECEIPTS, CREATE_MESSAGE, PIN_MESSAGE, RECALL_MESSAGE,
-    SENDING_MESSAGE,
+    Client, ACKNOWLEDGE_MESSAGE_RECEIPTS, CREATE_MESSAGE, EDIT_MESSAGE, PIN_MESSAGE,
+    REACTION_MESSAGE, RECALL_MESSAGE, SENDING_MESSAGE,
 };
 
 use crate::core::conversation_change::ConversationChangeNotifier;
@@ -151,7 +151,9 @@ impl JobService {
         let signaler = match action {
             ACKNOWLEDGE_MESSAGE_RECEIPTS => &self.ack_job_signer,
             CREATE_MESSAGE => &self.session_ack_job_signer,
-            SENDING_MESSAGE | PIN_MESSAGE | RECALL_MESSAGE => &self.sending_job_signer,
+            SENDING_MESSAGE | PIN_MESSAGE | RECALL_MESSAGE | EDIT_MESSAGE | REACTION_MESSAGE => {
+                &self.sending_job_signer
+            }
             UPDATE_ASSET => &self.update_asset_job_signer,
             UPDATE_TOKEN => &self.update_token_job_signer,
             UPDATE_STICKER => &self.update_sticker_job_signer,
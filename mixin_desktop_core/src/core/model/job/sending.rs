@@ -12,7 +12,7 @@ use sdk::message::RecallMessage;
 use sdk::message_category::MessageCategory;
 use sdk::{
     message_category, AttachmentMessage, BlazeMessage, BlazeMessageParam, Client, MessageStatus,
-    PIN_MESSAGE, RECALL_MESSAGE, SENDING_MESSAGE,
+    EDIT_MESSAGE, PIN_MESSAGE, REACTION_MESSAGE, RECALL_MESSAGE, SENDING_MESSAGE,
 };
 
 use crate::core::attachment::{attachment_path, transcript_attachment_path};
@@ -75,25 +75,35 @@ impl SendingJobRunner {
         loop {
             let jobs = self.database.job_dao.sending_jobs().await?;
             if jobs.is_empty() {
-                return Ok(false);
+                // Postponed jobs are not announced again, so keep polling
+                // until their backoff runs out.
+                return Ok(self.database.job_dao.has_postponed_sending_jobs().await?);
             }
             let mut retry = false;
             for job in jobs {
                 let result = match job.action.as_str() {
                     PIN_MESSAGE => {
-                        self.send_control_message(&job, message_category::MESSAGE_PIN)
+                        let message_id = uuid::Uuid::new_v4().to_string();
+                        self.send_control_message(&job, message_category::MESSAGE_PIN, &message_id)
                             .await
                     }
+                    EDIT_MESSAGE => self.send_edit_message(&job).await,
+                    REACTION_MESSAGE => {
+                        self.send_control_message(
+                            &job,
+                            message_category::MESSAGE_REACTION,
+                            &job.job_id,
+                        )
+                        .await
+                    }
                     RECALL_MESSAGE => self.send_recall_message(&job).await,
                     SENDING_MESSAGE => self.send_user_message(&job).await,
-                    _ => Ok(false),
+                    _ => Ok(()),
                 };
-                match result {
-                    Ok(job_retry) => retry |= job_retry,
-                    Err(err) => {
-                        error!("failed to run sending job {}: {err}", job.job_id);
-                        retry = true;
-                    }
+                if let Err(err) = result {
+                    error!("failed to run sending job {}: {err}", job.job_id);
+                    self.postpone_sending_job(&job, &err.to_string()).await?;
+                    retry = true;
                 }
             }
             if retry {
@@ -102,7 +112,27 @@ impl SendingJobRunner {
         }
     }
 
-    async fn send_control_message(&self, job: &Job, category: &str) -> Result<bool> {
+    async fn postpone_sending_job(&self, job: &Job, error: &str) -> Result<()> {
+        self.database
+            .job_dao
+            .record_job_failure(
+                &job.job_id,
+                error,
+                chrono::Utc::now().naive_utc() + sending_backoff(job.run_count),
+            )
+            .await?;
+        if let Some(conversation_id) = job.conversation_id.as_deref() {
+            self.notify_changes(conversation_id);
+        }
+        Ok(())
+    }
+
+    async fn send_control_message(
+        &self,
+        job: &Job,
+        category: &str,
+        message_id: &str,
+    ) -> Result<()> {
         let conversation_id = job
             .conversation_id
             .as_deref()
@@ -116,7 +146,7 @@ impl SendingJobRunner {
             .deliver(BlazeMessage::new_param_blaze(BlazeMessageParam {
                 conversation_id: Some(conversation_id.to_string()),
                 conversation_checksum: Some(self.sender.get_check_sum(conversation_id).await?),
-                message_id: Some(uuid::Uuid::new_v4().to_string()),
+                message_id: Some(message_id.to_string()),
                 category: Some(category.to_string()),
                 data: Some(Base64::encode_string(content.as_bytes())),
                 status: Some(MessageStatus::Sending.into()),
@@ -125,12 +155,40 @@ impl SendingJobRunner {
             .await?;
         if is_terminal_result(&result) {
             self.database.job_dao.delete_job_by_id(&job.job_id).await?;
-            return Ok(false);
+            return Ok(());
+        }
+        Err(delivery_failure(&result))
+    }
+
+    /// Edits travel as plain `MESSAGE_EDIT` data, so an edit of a message
+    /// that cannot be edited, such as one sent encrypted, is dropped rather
+    /// than leaked in plaintext.
+    async fn send_edit_message(&self, job: &Job) -> Result<()> {
+        let content = job
+            .blaze_message
+            .as_deref()
+            .ok_or_else(|| anyhow!("edit job has no payload"))?;
+        let edit: sdk::EditMessage =
+            serde_json::from_str(content).context("decode edit job payload")?;
+        let editable = self
+            .database
+            .message_dao
+            .find_message_by_id(&edit.message_id)
+            .await?
+            .is_some_and(|message| message.category.can_edit());
+        if !editable {
+            error!(
+                "drop edit job {} of uneditable message {}",
+                job.job_id, edit.message_id
+            );
+            self.database.job_dao.delete_job_by_id(&job.job_id).await?;
+            return Ok(());
         }
-        Ok(true)
+        self.send_control_message(job, message_category::MESSAGE_EDIT, &job.job_id)
+            .await
     }
 
-    async fn send_recall_message(&self, job: &Job) -> Result<bool> {
+    async fn send_recall_message(&self, job: &Job) -> Result<()> {
         let conversation_id = job
             .conversation_id
             .as_deref()
@@ -141,10 +199,6 @@ impl SendingJobRunner {
             .ok_or_else(|| anyhow!("recall job has no payload"))?;
         let recall: RecallMessage =
             serde_json::from_str(content).context("decode recall job payload")?;
-        if job.run_count > 0 {
-            self.finish_recall_job(job, &recall).await?;
-            return Ok(false);
-        }
 
         let result = self
             .sender
@@ -159,18 +213,16 @@ impl SendingJobRunner {
             }))
             .await?;
         if result.success && result.error_code.is_none() {
-            self.database
-                .job_dao
-                .reschedule_job(&job.job_id, chrono::Utc::now().naive_utc(), 1)
-                .await?;
+            // Redelivering a recall under the same message id is harmless, so
+            // a failed local cleanup simply retries the whole job.
             self.finish_recall_job(job, &recall).await?;
-            return Ok(false);
+            return Ok(());
         }
         if is_terminal_result(&result) {
             self.database.job_dao.delete_job_by_id(&job.job_id).await?;
-            return Ok(false);
+            return Ok(());
         }
-        Ok(true)
+        Err(delivery_failure(&result))
     }
 
     async fn finish_recall_job(&self, job: &Job, recall: &RecallMessage) -> Result<()> {
@@ -263,7 +315,7 @@ impl SendingJobRunner {
         Ok(paths)
     }
 
-    async fn send_user_message(&self, job: &Job) -> Result<bool> {
+    async fn send_user_message(&self, job: &Job) -> Result<()> {
         let payload_text = job
             .blaze_message
             .as_deref()
@@ -292,7 +344,7 @@ impl SendingJobRunner {
         };
         let Some(message) = message else {
             self.database.job_dao.delete_job_by_id(&job.job_id).await?;
-            return Ok(false);
+            return Ok(());
         };
         let Some(conversation) = self
             .database
@@ -300,8 +352,7 @@ impl SendingJobRunner {
             .find_conversation_by_id(&message.conversation_id)
             .await?
         else {
-            error!("conversation {} not found", message.conversation_id);
-            return Ok(true);
+            bail!("conversation {} not found", message.conversation_id);
         };
         let conversation_service = ConversationService::new(
             self.database.clone(),
@@ -330,7 +381,7 @@ impl SendingJobRunner {
                     self.database.job_dao.delete_job_by_id(&job.job_id).await?;
                 }
                 self.notify_changes(&message.conversation_id);
-                return Ok(false);
+                return Ok(());
             }
             return Err(error);
         }
@@ -394,7 +445,7 @@ impl SendingJobRunner {
                 )
                 .await?;
             self.notify_changes(&message.conversation_id);
-            return Ok(false);
+            return Ok(());
         }
         let expire_in = i32::try_from(payload.expire_in)
             .with_context(|| format!("invalid expire_in: {}", payload.expire_in))?;
@@ -491,7 +542,7 @@ impl SendingJobRunner {
                 )
                 .await?;
             self.notify_changes(&message.conversation_id);
-            return Ok(false);
+            return Ok(());
         };
 
         if is_terminal_result(&result) {
@@ -518,9 +569,9 @@ impl SendingJobRunner {
                 }
             }
             self.notify_changes(&message.conversation_id);
-            return Ok(false);
+            return Ok(());
         }
-        Ok(true)
+        Err(delivery_failure(&result))
     }
 
     async fn send_plain_message(
@@ -655,6 +706,17 @@ impl SendingJobRunner {
     }
 }
 
+fn delivery_failure(result: &MessageResult) -> anyhow::Error {
+    match result.error_code {
+        Some(code) => anyhow!("message delivery failed with error code {code}"),
+        None => anyhow!("message delivery was not acknowledged"),
+    }
+}
+
+fn sending_backoff(run_count: i32) -> chrono::Duration {
+    chrono::Duration::seconds((1_i64 << run_count.clamp(0, 9)).min(300))
+}
+
 fn truncate_utf16(value: &str, max_code_units: usize) -> &str {
     if value.encode_utf16().count() <= max_code_units {
         return value;
@@ -694,7 +756,9 @@ fn encrypted_to_plain_category(category: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::{encrypted_to_plain_category, mention_identity_numbers, truncate_utf16};
+    use super::{
+        encrypted_to_plain_category, mention_identity_numbers, sending_backoff, truncate_utf16,
+    };
 
     #[test]
     fn truncates_using_dart_utf16_length_semantics() {
@@ -711,6 +775,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn backs_off_failed_sends_exponentially_up_to_five_minutes() {
+        assert_eq!(sending_backoff(0), chrono::Duration::seconds(1));
+        assert_eq!(sending_backoff(3), chrono::Duration::seconds(8));
+        assert_eq!(sending_backoff(8), chrono::Duration::seconds(256));
+        assert_eq!(sending_backoff(9), chrono::Duration::minutes(5));
+        assert_eq!(sending_backoff(i32::MAX), chrono::Duration::minutes(5));
+    }
+
     #[test]
     fn maps_encrypted_category_to_flutter_plain_fallback() {
         assert_eq!(
@@ -0,0 +1,147 @@
+//! QR rendering of provisioning links for screens without a QR widget.
+
+use std::io::Cursor;
+
+use anyhow::{anyhow, Result};
+use image::{GrayImage, ImageFormat, Luma};
+use qrcode::{Color, EcLevel, QrCode};
+
+/// Modules of light border the QR specification asks readers to expect.
+pub const DEFAULT_QUIET_ZONE: usize = 4;
+
+/// The modules of an encoded QR code, row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QrMatrix {
+    width: usize,
+    dark: Vec<bool>,
+}
+
+impl QrMatrix {
+    pub fn encode(data: &str) -> Result<Self> {
+        let code = QrCode::with_error_correction_level(data.as_bytes(), EcLevel::M)
+            .map_err(|error| anyhow!("failed to encode QR code: {error}"))?;
+        Ok(Self {
+            width: code.width(),
+            dark: code
+                .to_colors()
+                .into_iter()
+                .map(|color| color == Color::Dark)
+                .collect(),
+        })
+    }
+
+    /// Width of the code in modules, without the quiet zone.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Whether the module at (`x`, `y`) is dark. Positions in the quiet zone
+    /// or beyond are light.
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.width && self.dark[y * self.width + x]
+    }
+
+    /// Renders the code as 8-bit grayscale pixels, `scale` pixels per module,
+    /// returning the side length and the row-major pixels.
+    pub fn to_luma(&self, scale: u32, quiet_zone: usize) -> (u32, Vec<u8>) {
+        let image = self.to_image(scale, quiet_zone);
+        (image.width(), image.into_raw())
+    }
+
+    pub fn to_png(&self, scale: u32, quiet_zone: usize) -> Result<Vec<u8>> {
+        let mut bytes = Cursor::new(Vec::new());
+        self.to_image(scale, quiet_zone)
+            .write_to(&mut bytes, ImageFormat::Png)?;
+        Ok(bytes.into_inner())
+    }
+
+    /// Renders the code with Unicode half blocks, two module rows per line.
+    /// Dark modules are drawn as blocks; set `invert` for terminals with a
+    /// dark background so the code still reads dark-on-light.
+    pub fn to_half_blocks(&self, quiet_zone: usize, invert: bool) -> String {
+        let side = self.width + quiet_zone * 2;
+        let filled = |x: usize, y: usize| {
+            let dark =
+                x >= quiet_zone && y >= quiet_zone && self.is_dark(x - quiet_zone, y - quiet_zone);
+            dark != invert
+        };
+        let mut text = String::with_capacity((side + 1) * side.div_ceil(2) * 3);
+        for y in (0..side).step_by(2) {
+            for x in 0..side {
+                // The row below the last one belongs to the quiet zone.
+                let lower = if y + 1 < side {
+                    filled(x, y + 1)
+                } else {
+                    invert
+                };
+                text.push(match (filled(x, y), lower) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                });
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    fn to_image(&self, scale: u32, quiet_zone: usize) -> GrayImage {
+        let scale = scale.max(1);
+        let side = (self.width + quiet_zone * 2) as u32 * scale;
+        let quiet_zone = quiet_zone as u32;
+        GrayImage::from_fn(side, side, |x, y| {
+            let (x, y) = (x / scale, y / scale);
+            let dark = x >= quiet_zone
+                && y >= quiet_zone
+                && self.is_dark((x - quiet_zone) as usize, (y - quiet_zone) as usize);
+            Luma([if dark { 0 } else { 255 }])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTH_URL: &str = "mixin://device/auth?id=5c5d9f4e-0ad2-4a43-9a8b-2a3f0b3f3c11&pub_key=BQ%2BQm0ltDbE9uXz0JHkIyNa5dB7bGv7U3S5rjqF0Rk1g";
+
+    #[test]
+    fn half_blocks_pack_two_module_rows_per_line() {
+        let matrix = QrMatrix::encode(AUTH_URL).unwrap();
+        let side = matrix.width() + DEFAULT_QUIET_ZONE * 2;
+        let text = matrix.to_half_blocks(DEFAULT_QUIET_ZONE, false);
+        let lines = text.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), side.div_ceil(2));
+        assert!(lines.iter().all(|line| line.chars().count() == side));
+        assert!(lines[0].chars().all(|character| character == ' '));
+        // The top-left finder pattern starts with a full dark row.
+        let finder_row = &lines[DEFAULT_QUIET_ZONE / 2];
+        assert!(finder_row
+            .chars()
+            .skip(DEFAULT_QUIET_ZONE)
+            .take(7)
+            .all(|character| character == '█' || character == '▀'));
+
+        let inverted = matrix.to_half_blocks(DEFAULT_QUIET_ZONE, true);
+        assert!(inverted.lines().next().unwrap().chars().all(|c| c == '█'));
+    }
+
+    #[test]
+    fn bitmap_scales_modules_and_keeps_the_quiet_zone_light() {
+        let matrix = QrMatrix::encode(AUTH_URL).unwrap();
+        let (side, pixels) = matrix.to_luma(3, DEFAULT_QUIET_ZONE);
+
+        assert_eq!(side as usize, (matrix.width() + DEFAULT_QUIET_ZONE * 2) * 3);
+        assert_eq!(pixels.len(), (side * side) as usize);
+        assert!(pixels[..side as usize * 12]
+            .iter()
+            .all(|pixel| *pixel == 255));
+        let corner = (12 * side + 12) as usize;
+        assert_eq!(pixels[corner], 0);
+
+        let png = matrix.to_png(3, DEFAULT_QUIET_ZONE).unwrap();
+        assert_eq!(image::load_from_memory(&png).unwrap().width(), side);
+    }
+}
@@ -0,0 +1,592 @@
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+pub const MP4_MIME_TYPE: &str = "video/mp4";
+pub const QUICKTIME_MIME_TYPE: &str = "video/quicktime";
+pub const WEBM_MIME_TYPE: &str = "video/webm";
+pub const MATROSKA_MIME_TYPE: &str = "video/x-matroska";
+
+const EBML_MAGIC: [u8; 4] = [0x1A, 0x45, 0xDF, 0xA3];
+const EBML_HEADER: u32 = 0x1A45_DFA3;
+const EBML_DOC_TYPE: u32 = 0x4282;
+const MATROSKA_SEGMENT: u32 = 0x1853_8067;
+const MATROSKA_INFO: u32 = 0x1549_A966;
+const MATROSKA_TIMECODE_SCALE: u32 = 0x2A_D7B1;
+const MATROSKA_DURATION: u32 = 0x4489;
+const MATROSKA_TRACKS: u32 = 0x1654_AE6B;
+const MATROSKA_TRACK_ENTRY: u32 = 0xAE;
+const MATROSKA_TRACK_TYPE: u32 = 0x83;
+const MATROSKA_VIDEO: u32 = 0xE0;
+const MATROSKA_PIXEL_WIDTH: u32 = 0xB0;
+const MATROSKA_PIXEL_HEIGHT: u32 = 0xBA;
+const MATROSKA_DISPLAY_WIDTH: u32 = 0x54B0;
+const MATROSKA_DISPLAY_HEIGHT: u32 = 0x54BA;
+const MATROSKA_VIDEO_TRACK: u64 = 1;
+
+/// Container metadata of a local video, read without decoding any frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoInfo {
+    pub mime_type: &'static str,
+    pub width: i32,
+    pub height: i32,
+    /// `None` when the container does not record a duration, as with WebM
+    /// files written by live recorders.
+    pub duration_millis: Option<i64>,
+}
+
+/// Reads the display size and duration of an MP4/QuickTime or WebM/Matroska
+/// file. Blocking; run it on a blocking task.
+pub fn probe_video(path: &Path) -> Result<VideoInfo> {
+    let file = File::open(path).with_context(|| format!("open video {}", path.display()))?;
+    probe(&mut BufReader::new(file))
+}
+
+fn probe<R: Read + Seek>(reader: &mut R) -> Result<VideoInfo> {
+    let mut magic = [0_u8; 8];
+    reader
+        .read_exact(&mut magic)
+        .context("video is truncated")?;
+    reader.seek(SeekFrom::Start(0))?;
+    if magic[..4] == EBML_MAGIC {
+        probe_matroska(reader)
+    } else if &magic[4..] == b"ftyp" {
+        probe_mp4(reader)
+    } else {
+        bail!("unsupported video container")
+    }
+}
+
+fn video_info(
+    mime_type: &'static str,
+    width: u64,
+    height: u64,
+    duration_millis: Option<i64>,
+) -> Result<VideoInfo> {
+    let width = i32::try_from(width).context("video width is too large")?;
+    let height = i32::try_from(height).context("video height is too large")?;
+    if width <= 0 || height <= 0 {
+        bail!("video has no dimensions");
+    }
+    Ok(VideoInfo {
+        mime_type,
+        width,
+        height,
+        duration_millis: duration_millis.filter(|duration| *duration > 0),
+    })
+}
+
+struct Mp4Box {
+    kind: [u8; 4],
+    end: u64,
+}
+
+#[derive(Default)]
+struct Mp4Movie {
+    timescale: u32,
+    duration: u64,
+    fragment_duration: u64,
+    track: Option<Mp4Track>,
+}
+
+#[derive(Default)]
+struct Mp4Track {
+    width: u64,
+    height: u64,
+    rotated: bool,
+    video: bool,
+    timescale: u32,
+    duration: u64,
+}
+
+fn probe_mp4<R: Read + Seek>(reader: &mut R) -> Result<VideoInfo> {
+    let end = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+    let mut mime_type = MP4_MIME_TYPE;
+    let mut movie = None;
+    while let Some(header) = read_mp4_box(reader, end)? {
+        match &header.kind {
+            b"ftyp" => {
+                if &read_array::<_, 4>(reader)? == b"qt  " {
+                    mime_type = QUICKTIME_MIME_TYPE;
+                }
+            }
+            b"moov" => {
+                movie = Some(read_mp4_movie(reader, header.end)?);
+                break;
+            }
+            _ => {}
+        }
+        reader.seek(SeekFrom::Start(header.end))?;
+    }
+    let movie = movie.ok_or_else(|| anyhow!("MP4 has no movie box"))?;
+    let track = movie
+        .track
+        .ok_or_else(|| anyhow!("MP4 has no video track"))?;
+    let duration_millis = match (movie.duration, movie.fragment_duration) {
+        (0, 0) => scale_millis(track.duration, track.timescale),
+        (0, duration) | (duration, _) => scale_millis(duration, movie.timescale),
+    };
+    let (width, height) = if track.rotated {
+        (track.height, track.width)
+    } else {
+        (track.width, track.height)
+    };
+    video_info(mime_type, width, height, duration_millis)
+}
+
+fn scale_millis(duration: u64, timescale: u32) -> Option<i64> {
+    if timescale == 0 {
+        return None;
+    }
+    i64::try_from(u128::from(duration) * 1000 / u128::from(timescale)).ok()
+}
+
+fn read_mp4_box<R: Read + Seek>(reader: &mut R, parent_end: u64) -> Result<Option<Mp4Box>> {
+    let offset = reader.stream_position()?;
+    if offset.saturating_add(8) > parent_end {
+        return Ok(None);
+    }
+    let size = u64::from(read_u32(reader)?);
+    let kind = read_array::<_, 4>(reader)?;
+    let (header_size, size) = match size {
+        0 => (8, parent_end - offset),
+        1 => (16, read_u64(reader)?),
+        size => (8, size),
+    };
+    if size < header_size || offset.saturating_add(size) > parent_end {
+        bail!("invalid MP4 box size");
+    }
+    Ok(Some(Mp4Box {
+        kind,
+        end: offset + size,
+    }))
+}
+
+fn read_mp4_movie<R: Read + Seek>(reader: &mut R, end: u64) -> Result<Mp4Movie> {
+    let mut movie = Mp4Movie::default();
+    while let Some(header) = read_mp4_box(reader, end)? {
+        match &header.kind {
+            b"mvhd" => (movie.timescale, movie.duration) = read_mp4_media_header(reader)?,
+            b"mvex" => {
+                while let Some(child) = read_mp4_box(reader, header.end)? {
+                    if &child.kind == b"mehd" {
+                        let version = read_full_box_version(reader)?;
+                        movie.fragment_duration = read_versioned(reader, version)?;
+                    }
+                    reader.seek(SeekFrom::Start(child.end))?;
+                }
+            }
+            b"trak" if movie.track.is_none() => {
+                let track = read_mp4_track(reader, header.end)?;
+                if track.video {
+                    movie.track = Some(track);
+                }
+            }
+            _ => {}
+        }
+        reader.seek(SeekFrom::Start(header.end))?;
+    }
+    Ok(movie)
+}
+
+fn read_mp4_track<R: Read + Seek>(reader: &mut R, end: u64) -> Result<Mp4Track> {
+    let mut track = Mp4Track::default();
+    while let Some(header) = read_mp4_box(reader, end)? {
+        match &header.kind {
+            b"tkhd" => {
+                let version = read_full_box_version(reader)?;
+                // Creation and modification times, track id, a reserved
+                // word and the duration, then reserved words, layer,
+                // alternate group and volume.
+                let skipped = 16 + if version == 1 { 32 } else { 20 };
+                reader.seek(SeekFrom::Current(skipped))?;
+                let mut matrix = [0_i32; 9];
+                for value in &mut matrix {
+                    *value = read_u32(reader)? as i32;
+                }
+                track.rotated = matrix[0] == 0 && matrix[1] != 0;
+                track.width = u64::from(read_u32(reader)? >> 16);
+                track.height = u64::from(read_u32(reader)? >> 16);
+            }
+            b"mdia" => {
+                while let Some(child) = read_mp4_box(reader, header.end)? {
+                    match &child.kind {
+                        b"mdhd" => {
+                            (track.timescale, track.duration) = read_mp4_media_header(reader)?
+                        }
+                        b"hdlr" => {
+                            read_full_box_version(reader)?;
+                            reader.seek(SeekFrom::Current(4))?;
+                            track.video = &read_array::<_, 4>(reader)? == b"vide";
+                        }
+                        _ => {}
+                    }
+                    reader.seek(SeekFrom::Start(child.end))?;
+                }
+            }
+            _ => {}
+        }
+        reader.seek(SeekFrom::Start(header.end))?;
+    }
+    Ok(track)
+}
+
+/// Reads the timescale and duration shared by the `mvhd` and `mdhd` layouts.
+fn read_mp4_media_header<R: Read + Seek>(reader: &mut R) -> Result<(u32, u64)> {
+    let version = read_full_box_version(reader)?;
+    reader.seek(SeekFrom::Current(if version == 1 { 16 } else { 8 }))?;
+    let timescale = read_u32(reader)?;
+    let duration = read_versioned(reader, version)?;
+    Ok((timescale, duration))
+}
+
+fn read_full_box_version<R: Read>(reader: &mut R) -> Result<u8> {
+    let [version, ..] = read_array::<_, 4>(reader)?;
+    Ok(version)
+}
+
+fn read_versioned<R: Read>(reader: &mut R, version: u8) -> Result<u64> {
+    if version == 1 {
+        read_u64(reader)
+    } else {
+        let value = read_u32(reader)?;
+        // An all-ones 32-bit duration means the duration is unknown.
+        Ok(if value == u32::MAX {
+            0
+        } else {
+            u64::from(value)
+        })
+    }
+}
+
+struct EbmlElement {
+    id: u32,
+    start: u64,
+    /// `None` for elements written with an unknown size.
+    end: Option<u64>,
+}
+
+#[derive(Default)]
+struct MatroskaTrack {
+    track_type: u64,
+    pixel_width: u64,
+    pixel_height: u64,
+    display_width: u64,
+    display_height: u64,
+}
+
+fn probe_matroska<R: Read + Seek>(reader: &mut R) -> Result<VideoInfo> {
+    let file_end = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+    let header = read_ebml_element(reader, file_end)?
+        .filter(|element| element.id == EBML_HEADER)
+        .ok_or_else(|| anyhow!("invalid EBML header"))?;
+    let header_end = header
+        .end
+        .ok_or_else(|| anyhow!("EBML header has unknown size"))?;
+    let mut mime_type = WEBM_MIME_TYPE;
+    while let Some(element) = read_ebml_element(reader, header_end)? {
+        let end = element
+            .end
+            .ok_or_else(|| anyhow!("EBML header has unknown size"))?;
+        if element.id == EBML_DOC_TYPE {
+            match read_ebml_string(reader, end - element.start)?.as_str() {
+                "webm" => mime_type = WEBM_MIME_TYPE,
+                "matroska" => mime_type = MATROSKA_MIME_TYPE,
+                doc_type => bail!("unsupported EBML document type: {doc_type}"),
+            }
+        }
+        reader.seek(SeekFrom::Start(end))?;
+    }
+
+    reader.seek(SeekFrom::Start(header_end))?;
+    let segment = read_ebml_element(reader, file_end)?
+        .filter(|element| element.id == MATROSKA_SEGMENT)
+        .ok_or_else(|| anyhow!("Matroska has no segment"))?;
+    let segment_end = segment.end.unwrap_or(file_end);
+    let mut timecode_scale = 1_000_000_u64;
+    let mut duration = None;
+    let mut track = None;
+    while track.is_none() || duration.is_none() {
+        let Some(element) = read_ebml_element(reader, segment_end)? else {
+            break;
+        };
+        // Clusters from live recorders have no size; nothing after them can
+        // be reached without walking every block.
+        let Some(end) = element.end else {
+            break;
+        };
+        match element.id {
+            MATROSKA_INFO => {
+                while let Some(child) = read_ebml_element(reader, end)? {
+                    let child_end = child.end.ok_or_else(|| anyhow!("invalid Matroska info"))?;
+                    let size = child_end - child.start;
+                    match child.id {
+                        MATROSKA_TIMECODE_SCALE => timecode_scale = read_ebml_uint(reader, size)?,
+                        MATROSKA_DURATION => duration = Some(read_ebml_float(reader, size)?),
+                        _ => {}
+                    }
+                    reader.seek(SeekFrom::Start(child_end))?;
+                }
+            }
+            MATROSKA_TRACKS => {
+                while let Some(child) = read_ebml_element(reader, end)? {
+                    let child_end = child.end.ok_or_else(|| anyhow!("invalid Matroska track"))?;
+                    if child.id == MATROSKA_TRACK_ENTRY && track.is_none() {
+                        let entry = read_matroska_track(reader, child_end)?;
+                        if entry.track_type == MATROSKA_VIDEO_TRACK {
+                            track = Some(entry);
+                        }
+                    }
+                    reader.seek(SeekFrom::Start(child_end))?;
+                }
+            }
+            _ => {}
+        }
+        reader.seek(SeekFrom::Start(end))?;
+    }
+
+    let track = track.ok_or_else(|| anyhow!("Matroska has no video track"))?;
+    let (width, height) = if track.display_width > 0 && track.display_height > 0 {
+        (track.display_width, track.display_height)
+    } else {
+        (track.pixel_width, track.pixel_height)
+    };
+    let duration_millis = duration
+        .map(|duration| duration * timecode_scale as f64 / 1_000_000.0)
+        .filter(|duration| duration.is_finite() && *duration < i64::MAX as f64)
+        .map(|duration| duration.round() as i64);
+    video_info(mime_type, width, height, duration_millis)
+}
+
+fn read_matroska_track<R: Read + Seek>(reader: &mut R, end: u64) -> Result<MatroskaTrack> {
+    let mut track = MatroskaTrack::default();
+    while let Some(element) = read_ebml_element(reader, end)? {
+        let element_end = element
+            .end
+            .ok_or_else(|| anyhow!("invalid Matroska track"))?;
+        match element.id {
+            MATROSKA_TRACK_TYPE => {
+                track.track_type = read_ebml_uint(reader, element_end - element.start)?
+            }
+            MATROSKA_VIDEO => {
+                while let Some(child) = read_ebml_element(reader, element_end)? {
+                    let child_end = child
+                        .end
+                        .ok_or_else(|| anyhow!("invalid Matroska video settings"))?;
+                    let size = child_end - child.start;
+                    match child.id {
+                        MATROSKA_PIXEL_WIDTH => track.pixel_width = read_ebml_uint(reader, size)?,
+                        MATROSKA_PIXEL_HEIGHT => track.pixel_height = read_ebml_uint(reader, size)?,
+                        MATROSKA_DISPLAY_WIDTH => {
+                            track.display_width = read_ebml_uint(reader, size)?
+                        }
+                        MATROSKA_DISPLAY_HEIGHT => {
+                            track.display_height = read_ebml_uint(reader, size)?
+                        }
+                        _ => {}
+                    }
+                    reader.seek(SeekFrom::Start(child_end))?;
+                }
+            }
+            _ => {}
+        }
+        reader.seek(SeekFrom::Start(element_end))?;
+    }
+    Ok(track)
+}
+
+fn read_ebml_element<R: Read + Seek>(
+    reader: &mut R,
+    parent_end: u64,
+) -> Result<Option<EbmlElement>> {
+    if reader.stream_position()? >= parent_end {
+        return Ok(None);
+    }
+    let (id, id_length) = read_ebml_vint(reader, true)?;
+    if id_length > 4 {
+        bail!("invalid EBML element id");
+    }
+    let (size, size_length) = read_ebml_vint(reader, false)?;
+    let start = reader.stream_position()?;
+    let end = if size == (1_u64 << (7 * size_length)) - 1 {
+        None
+    } else {
+        let end = start
+            .checked_add(size)
+            .filter(|end| *end <= parent_end)
+            .ok_or_else(|| anyhow!("invalid EBML element size"))?;
+        Some(end)
+    };
+    Ok(Some(EbmlElement {
+        id: id as u32,
+        start,
+        end,
+    }))
+}
+
+/// Reads an EBML variable-length integer, keeping the length marker for
+/// element ids and stripping it for sizes.
+fn read_ebml_vint<R: Read>(reader: &mut R, keep_marker: bool) -> Result<(u64, u32)> {
+    let [first] = read_array::<_, 1>(reader)?;
+    let length = first.leading_zeros() + 1;
+    if length > 8 {
+        bail!("invalid EBML variable-length integer");
+    }
+    let mut value = if keep_marker {
+        u64::from(first)
+    } else {
+        u64::from(first) & (0xFF >> length)
+    };
+    for _ in 1..length {
+        let [byte] = read_array::<_, 1>(reader)?;
+        value = (value << 8) | u64::from(byte);
+    }
+    Ok((value, length))
+}
+
+fn read_ebml_uint<R: Read>(reader: &mut R, size: u64) -> Result<u64> {
+    if size > 8 {
+        bail!("invalid EBML unsigned integer");
+    }
+    let mut value = 0_u64;
+    for _ in 0..size {
+        let [byte] = read_array::<_, 1>(reader)?;
+        value = (value << 8) | u64::from(byte);
+    }
+    Ok(value)
+}
+
+fn read_ebml_float<R: Read>(reader: &mut R, size: u64) -> Result<f64> {
+    match size {
+        4 => Ok(f64::from(f32::from_be_bytes(read_array(reader)?))),
+        8 => Ok(f64::from_be_bytes(read_array(reader)?)),
+        _ => bail!("invalid EBML float"),
+    }
+}
+
+fn read_ebml_string<R: Read>(reader: &mut R, size: u64) -> Result<String> {
+    if size > 64 {
+        bail!("EBML string is too long");
+    }
+    let mut value = vec![0_u8; size as usize];
+    reader.read_exact(&mut value)?;
+    let value = String::from_utf8(value).context("invalid EBML string")?;
+    Ok(value.trim_end_matches('\0').to_string())
+}
+
+fn read_array<R: Read, const N: usize>(reader: &mut R) -> Result<[u8; N]> {
+    let mut value = [0_u8; N];
+    reader
+        .read_exact(&mut value)
+        .context("video is truncated")?;
+    Ok(value)
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32> {
+    Ok(u32::from_be_bytes(read_array(reader)?))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    Ok(u64::from_be_bytes(read_array(reader)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn mp4_box(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut data = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(payload);
+        data
+    }
+
+    fn mp4_track(handler: &[u8; 4], matrix: [i32; 9], width: u32, height: u32) -> Vec<u8> {
+        let mut tkhd = vec![0_u8; 4 + 20 + 16];
+        for value in matrix {
+            tkhd.extend_from_slice(&value.to_be_bytes());
+        }
+        tkhd.extend_from_slice(&(width << 16).to_be_bytes());
+        tkhd.extend_from_slice(&(height << 16).to_be_bytes());
+        let mut hdlr = vec![0_u8; 8];
+        hdlr.extend_from_slice(handler);
+        hdlr.extend_from_slice(&[0; 12]);
+        let mdia = mp4_box(b"mdia", &mp4_box(b"hdlr", &hdlr));
+        mp4_box(b"trak", &[mp4_box(b"tkhd", &tkhd), mdia].concat())
+    }
+
+    fn ebml(id: &[u8], payload: &[u8]) -> Vec<u8> {
+        let mut data = id.to_vec();
+        data.push(0x01);
+        data.extend_from_slice(&(payload.len() as u64).to_be_bytes()[1..]);
+        data.extend_from_slice(payload);
+        data
+    }
+
+    #[test]
+    fn probes_rotated_mp4_video_track() {
+        let mut mvhd = vec![0_u8; 12];
+        mvhd.extend_from_slice(&600_u32.to_be_bytes());
+        mvhd.extend_from_slice(&7_407_u32.to_be_bytes());
+        let identity = [0x10000, 0, 0, 0, 0x10000, 0, 0, 0, 0x4000_0000];
+        let rotated = [0, 0x10000, 0, -0x10000, 0, 0, 0, 0, 0x4000_0000];
+        let moov = [
+            mp4_box(b"mvhd", &mvhd),
+            mp4_track(b"soun", identity, 0, 0),
+            mp4_track(b"vide", rotated, 1920, 1080),
+        ]
+        .concat();
+        let mut ftyp = b"isom".to_vec();
+        ftyp.extend_from_slice(&[0; 4]);
+        let file = [mp4_box(b"ftyp", &ftyp), mp4_box(b"moov", &moov)].concat();
+
+        assert_eq!(
+            probe(&mut Cursor::new(file)).unwrap(),
+            VideoInfo {
+                mime_type: MP4_MIME_TYPE,
+                width: 1080,
+                height: 1920,
+                duration_millis: Some(12_345),
+            }
+        );
+    }
+
+    #[test]
+    fn probes_webm_with_unknown_sized_segment() {
+        let header = ebml(&[0x1A, 0x45, 0xDF, 0xA3], &ebml(&[0x42, 0x82], b"webm"));
+        let info = [
+            ebml(&[0x2A, 0xD7, 0xB1], &[0x0F, 0x42, 0x40]),
+            ebml(&[0x44, 0x89], &5_000.5_f64.to_be_bytes()),
+        ]
+        .concat();
+        let video = [ebml(&[0xB0], &[0x02, 0x80]), ebml(&[0xBA], &[0x01, 0x68])].concat();
+        let entry = [ebml(&[0x83], &[0x01]), ebml(&[0xE0], &video)].concat();
+        let mut file = header;
+        file.extend_from_slice(&[0x18, 0x53, 0x80, 0x67, 0xFF]);
+        file.extend_from_slice(&ebml(&[0x15, 0x49, 0xA9, 0x66], &info));
+        file.extend_from_slice(&ebml(&[0x16, 0x54, 0xAE, 0x6B], &ebml(&[0xAE], &entry)));
+        file.extend_from_slice(&[0x1F, 0x43, 0xB6, 0x75, 0xFF]);
+
+        assert_eq!(
+            probe(&mut Cursor::new(file)).unwrap(),
+            VideoInfo {
+                mime_type: WEBM_MIME_TYPE,
+                width: 640,
+                height: 360,
+                duration_millis: Some(5_001),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_containers() {
+        assert!(probe(&mut Cursor::new(b"GIF89a\0\0\0\0".to_vec())).is_err());
+        assert!(probe(&mut Cursor::new(b"\0\0".to_vec())).is_err());
+    }
+}
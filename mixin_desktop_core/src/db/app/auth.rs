@@ -244,6 +244,27 @@ impl AuthDao {
         self.write_auths(&auths, Some(&auth.user_id)).await
     }
 
+    pub async fn update_auth(&self, auth: &Auth) -> anyhow::Result<()> {
+        let mut auths = self.find_all_auth().await?;
+        match auths.iter_mut().find(|item| item.user_id == auth.user_id) {
+            Some(item) => *item = auth.clone(),
+            None => auths.push(auth.clone()),
+        }
+        let active_user_id = auths.first().map(|auth| auth.user_id.as_str());
+        self.write_auths(&auths, active_user_id).await
+    }
+
+    pub async fn set_active_auth(&self, user_id: &str) -> anyhow::Result<()> {
+        let auths = self.find_all_auth().await?;
+        if !auths.iter().any(|auth| auth.user_id == user_id) {
+            bail!("no saved authorization for {user_id}");
+        }
+        self.0
+            .set(PropertyGroup::Auth, ACTIVE_USER_ID_KEY, user_id)
+            .await?;
+        Ok(())
+    }
+
     async fn write_auths(
         &self,
         auths: &[Auth],
@@ -260,3 +281,79 @@ impl AuthDao {
         Ok(())
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use serde_json::json;
+
+    use super::{Auth, StoredAuth, AUTH_MIGRATION_KEY};
+    use crate::db::app::{AppDatabase, PropertyGroup};
+
+    pub(crate) fn auth(user_id: &str, identity_number: &str) -> Auth {
+        Auth::try_from(StoredAuth {
+            account: json!({
+                "user_id": user_id,
+                "avatar_url": null,
+                "biography": "",
+                "code_id": "",
+                "code_url": "",
+                "created_at": "2024-01-01T00:00:00Z",
+                "device_status": "",
+                "fiat_currency": "USD",
+                "full_name": identity_number,
+                "has_emergency_contact": false,
+                "accept_search_source": "EVERYBODY",
+                "accept_conversation_source": "EVERYBODY",
+                "receive_message_source": "EVERYBODY",
+                "has_pin": true,
+                "identity_number": identity_number,
+                "is_scam": false,
+                "is_verified": false,
+                "mute_until": "",
+                "phone": "",
+                "pin_token": "",
+                "pin_token_base64": "",
+                "session_id": format!("{user_id}-session"),
+                "transfer_confirmation_threshold": 0,
+                "transfer_notification_threshold": 0,
+            }),
+            private_key: super::encode_private_key(&[7; 32]),
+            primary_session_id: None,
+        })
+        .unwrap()
+    }
+
+    fn user_ids(auths: &[Auth]) -> Vec<&str> {
+        auths.iter().map(|auth| auth.user_id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn switching_the_active_auth_keeps_every_saved_account() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = AppDatabase::connect_at(directory.path().join("app.db"))
+            .await
+            .unwrap();
+        database
+            .property_dao
+            .set(PropertyGroup::Auth, AUTH_MIGRATION_KEY, "true")
+            .await
+            .unwrap();
+        let dao = &database.auth_dao;
+        dao.save_auth(&auth("personal", "1000")).await.unwrap();
+        dao.save_auth(&auth("team", "2000")).await.unwrap();
+        assert_eq!(
+            user_ids(&dao.find_all_auth().await.unwrap()),
+            ["team", "personal"]
+        );
+
+        dao.set_active_auth("personal").await.unwrap();
+        let mut renamed = auth("team", "2000");
+        renamed.account.full_name = Some("Team bot".to_string());
+        dao.update_auth(&renamed).await.unwrap();
+
+        let auths = dao.find_all_auth().await.unwrap();
+        assert_eq!(user_ids(&auths), ["personal", "team"]);
+        assert_eq!(auths[1].account.full_name.as_deref(), Some("Team bot"));
+        assert!(dao.set_active_auth("unknown").await.is_err());
+    }
+}
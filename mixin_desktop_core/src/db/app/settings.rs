@@ -3,6 +3,7 @@ use std::future::Future;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context, Result};
+use chrono::{Datelike, NaiveDateTime, Timelike};
 use futures::{Stream, StreamExt};
 use sdk::message_category::MessageCategory as _;
 use serde::de::DeserializeOwned;
@@ -167,6 +168,10 @@ impl SettingDao {
             => file_auto_download, set_file_auto_download, subscribe_file_auto_download;
         "proxy_settings": ProxySettings = ProxySettings::default
             => proxy_settings, set_proxy_settings, subscribe_proxy_settings;
+        "link_preview_settings": LinkPreviewSettings = LinkPreviewSettings::default
+            => link_preview_settings, set_link_preview_settings, subscribe_link_preview_settings;
+        "notification_rules": NotificationRules = NotificationRules::default
+            => notification_rules, set_notification_rules, subscribe_notification_rules;
     }
 
     pub async fn should_auto_download(&self, category: &str) -> Result<bool> {
@@ -306,11 +311,133 @@ impl ProxySettings {
     }
 }
 
+/// Which hosts link previews may be fetched from. Domains also match their
+/// subdomains; a denied domain wins over an allowed one, and a non-empty
+/// allow list restricts previews to the listed domains.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LinkPreviewSettings {
+    pub enabled: bool,
+    /// Fetching a link someone sent tells its host that the message was
+    /// read, so previews of received messages are opt-in.
+    pub received_messages: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+}
+
+impl Default for LinkPreviewSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            received_messages: false,
+            allowed_domains: Vec::new(),
+            denied_domains: Vec::new(),
+        }
+    }
+}
+
+impl LinkPreviewSettings {
+    pub fn allows(&self, host: &str) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        let matches = |domain: &String| {
+            let domain = domain
+                .trim()
+                .trim_start_matches("*.")
+                .trim_end_matches('.')
+                .to_ascii_lowercase();
+            !domain.is_empty()
+                && (host == domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.')))
+        };
+        self.enabled
+            && !self.denied_domains.iter().any(matches)
+            && (self.allowed_domains.is_empty() || self.allowed_domains.iter().any(matches))
+    }
+
+    /// Whether a preview of `url` may be fetched or shown on a message.
+    pub fn allows_url(&self, url: &str, received: bool) -> bool {
+        (!received || self.received_messages)
+            && Url::parse(url)
+                .ok()
+                .and_then(|url| url.host_str().map(|host| self.allows(host)))
+                .unwrap_or(false)
+    }
+}
+
+/// A daily do-not-disturb window in local time. The window ends on the next
+/// day when `end_minute` is not after `start_minute`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QuietHours {
+    /// Minutes after local midnight.
+    pub start_minute: u16,
+    pub end_minute: u16,
+    /// Days the window starts on, 0 for Monday; every day when empty.
+    #[serde(default)]
+    pub weekdays: Vec<u8>,
+}
+
+impl QuietHours {
+    pub fn contains(&self, local_time: NaiveDateTime) -> bool {
+        let minute = (local_time.hour() * 60 + local_time.minute()) as u16;
+        let weekday = local_time.weekday().num_days_from_monday() as u8;
+        let starts_on = |weekday: u8| self.weekdays.is_empty() || self.weekdays.contains(&weekday);
+        if self.start_minute < self.end_minute {
+            starts_on(weekday) && (self.start_minute..self.end_minute).contains(&minute)
+        } else {
+            (minute >= self.start_minute && starts_on(weekday))
+                || (minute < self.end_minute && starts_on((weekday + 6) % 7))
+        }
+    }
+}
+
+/// How new messages are filtered before they become notifications.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct NotificationRules {
+    pub quiet_hours: Vec<QuietHours>,
+    /// Circles, synced or smart, whose conversations are muted.
+    pub muted_circle_ids: Vec<String>,
+    /// Words that notify even in muted conversations, matched case-insensitively.
+    pub keywords: Vec<String>,
+    /// Groups that only notify for mentions, quotes and keywords.
+    pub mentions_only_conversation_ids: Vec<String>,
+    /// Senders that always notify, even during quiet hours.
+    pub allowed_sender_ids: Vec<String>,
+}
+
+impl NotificationRules {
+    pub fn validate(&self) -> Result<()> {
+        for quiet_hours in &self.quiet_hours {
+            if quiet_hours.start_minute >= 24 * 60 || quiet_hours.end_minute >= 24 * 60 {
+                return Err(anyhow!("quiet hours must be within a day"));
+            }
+            if quiet_hours.start_minute == quiet_hours.end_minute {
+                return Err(anyhow!("quiet hours must not be empty"));
+            }
+            if quiet_hours.weekdays.iter().any(|weekday| *weekday > 6) {
+                return Err(anyhow!("quiet hours weekday is invalid"));
+            }
+        }
+        if self
+            .keywords
+            .iter()
+            .any(|keyword| keyword.trim().is_empty())
+        {
+            return Err(anyhow!("notification keyword must not be empty"));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use futures::StreamExt as _;
 
-    use crate::db::app::{AppDatabase, PropertyGroup};
+    use chrono::NaiveDate;
+
+    use crate::db::app::{AppDatabase, LinkPreviewSettings, PropertyGroup, QuietHours};
 
     #[tokio::test]
     async fn attachment_settings_are_independent_and_default_to_true() {
@@ -474,4 +601,73 @@ mod tests {
             .to_string()
             .contains("decode setting photoAutoDownload"));
     }
+
+    #[test]
+    fn link_preview_denied_domains_win_over_allowed_domains() {
+        let settings = LinkPreviewSettings {
+            enabled: true,
+            received_messages: false,
+            allowed_domains: vec!["mixin.one".to_owned()],
+            denied_domains: vec!["*.private.mixin.one".to_owned()],
+        };
+
+        assert!(settings.allows("mixin.one"));
+        assert!(settings.allows("News.Mixin.One."));
+        assert!(!settings.allows("private.mixin.one"));
+        assert!(!settings.allows("a.private.mixin.one"));
+        assert!(!settings.allows("notmixin.one"));
+        assert!(LinkPreviewSettings::default().allows("example.com"));
+        assert!(!LinkPreviewSettings {
+            enabled: false,
+            ..Default::default()
+        }
+        .allows("mixin.one"));
+    }
+
+    #[test]
+    fn link_previews_of_received_messages_are_opt_in() {
+        let settings = LinkPreviewSettings {
+            denied_domains: vec!["private.example".to_owned()],
+            ..Default::default()
+        };
+
+        assert!(settings.allows_url("https://mixin.one/pages", false));
+        assert!(!settings.allows_url("https://mixin.one/pages", true));
+        assert!(!settings.allows_url("https://a.private.example/", false));
+        assert!(!settings.allows_url("not a url", false));
+        let settings = LinkPreviewSettings {
+            received_messages: true,
+            ..settings
+        };
+        assert!(settings.allows_url("https://mixin.one/pages", true));
+        assert!(!settings.allows_url("https://a.private.example/", true));
+    }
+
+    #[test]
+    fn quiet_hours_wrap_past_midnight_from_their_start_day() {
+        let quiet_hours = QuietHours {
+            start_minute: 22 * 60,
+            end_minute: 7 * 60,
+            weekdays: vec![4],
+        };
+        // 2026-10-16 is a Friday.
+        let at = |day: u32, hour: u32| {
+            NaiveDate::from_ymd_opt(2026, 10, day)
+                .unwrap()
+                .and_hms_opt(hour, 30, 0)
+                .unwrap()
+        };
+
+        assert!(quiet_hours.contains(at(16, 23)));
+        assert!(quiet_hours.contains(at(17, 6)));
+        assert!(!quiet_hours.contains(at(17, 7)));
+        assert!(!quiet_hours.contains(at(17, 23)));
+        assert!(!quiet_hours.contains(at(16, 6)));
+        assert!(QuietHours {
+            start_minute: 9 * 60,
+            end_minute: 17 * 60,
+            weekdays: Vec::new(),
+        }
+        .contains(at(18, 12)));
+    }
 }
@@ -68,3 +68,9 @@ impl From<OptionalDatabaseDateTime> for Option<DateTime<Utc>> {
         value.0
     }
 }
+
+impl From<OptionalDatabaseDateTime> for Option<NaiveDateTime> {
+    fn from(value: OptionalDatabaseDateTime) -> Self {
+        value.0.map(|value| value.naive_utc())
+    }
+}
@@ -1,5 +1,15 @@
-use crate::db::migration::Migrator;
+use crate::db::migration::{Migration, Migrator};
 
-const SCHEMA_VERSION: i64 = 1;
-pub(super) const MIGRATOR: Migrator =
-    Migrator::new("fts", SCHEMA_VERSION, include_str!("schema.sql"), &[]);
+const MIGRATIONS: &[Migration] = &[Migration::sql(
+    2,
+    "drop content indexed before CJK bigram tokenization",
+    "DELETE FROM messages_fts; DELETE FROM messages_metas;",
+)];
+
+const SCHEMA_VERSION: i64 = 2;
+pub(super) const MIGRATOR: Migrator = Migrator::new(
+    "fts",
+    SCHEMA_VERSION,
+    include_str!("schema.sql"),
+    MIGRATIONS,
+);
@@ -1,4 +1,5 @@
--- Current fts.db schema (v1).
+-- Current fts.db schema (v2). CJK text is stored as overlapping bigrams
+-- (see `normalize_content` in db/mixin/message_fts.rs).
 
 CREATE VIRTUAL TABLE messages_fts USING FTS5(
     content,
@@ -12,18 +12,23 @@ pub mod expired_message;
 pub mod favorite_app;
 pub mod fiat;
 pub mod flood_message;
+pub mod hyperlink;
 pub mod inscription;
 pub mod job;
 pub(crate) mod mention_cache;
 pub mod message;
+pub mod message_edit;
 pub mod message_fts;
 pub mod message_history;
 pub mod message_mention;
+pub mod message_reaction;
 pub mod offset;
 pub mod participant;
+pub mod participant_event;
 pub mod participant_session;
 pub mod pin_message;
 pub mod safe_snapshot;
+pub mod smart_circle;
 pub mod snapshot;
 pub mod sticker;
 pub mod transcript_message;
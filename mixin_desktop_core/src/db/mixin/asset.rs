@@ -2,6 +2,23 @@ use sdk::{Asset, Chain, Token};
 
 use crate::db::Error;
 
+/// A Safe token with its chain and the rate of one fiat currency.
+#[derive(Clone, sqlx::FromRow)]
+pub struct TokenDetail {
+    pub asset_id: String,
+    pub symbol: String,
+    pub name: String,
+    pub icon_url: String,
+    pub price_usd: String,
+    pub chain_id: String,
+    pub precision: i64,
+    pub collection_hash: Option<String>,
+    pub chain_name: Option<String>,
+    pub chain_icon_url: Option<String>,
+    pub chain_threshold: Option<i64>,
+    pub fiat_rate: Option<f64>,
+}
+
 #[derive(Clone)]
 pub struct AssetDao(pub(crate) sqlx::Pool<sqlx::Sqlite>);
 
@@ -72,4 +89,27 @@ impl AssetDao {
         .await?;
         Ok(())
     }
+
+    pub async fn find_token_detail(
+        &self,
+        asset_id: &str,
+        fiat_currency: &str,
+    ) -> Result<Option<TokenDetail>, Error> {
+        Ok(sqlx::query_as::<_, TokenDetail>(
+            r#"
+SELECT t.asset_id, t.symbol, t.name, t.icon_url, t.price_usd, t.chain_id, t.precision,
+       t.collection_hash, c.name AS chain_name, c.icon_url AS chain_icon_url,
+       c.threshold AS chain_threshold,
+       (SELECT rate FROM fiats WHERE code = ?) AS fiat_rate
+  FROM tokens t
+  LEFT JOIN chains c ON c.chain_id = t.chain_id
+ WHERE t.asset_id = ?
+ LIMIT 1
+            "#,
+        )
+        .bind(fiat_currency)
+        .bind(asset_id)
+        .fetch_optional(&self.0)
+        .await?)
+    }
 }
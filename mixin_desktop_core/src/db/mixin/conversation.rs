@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use sdk::ConversationCategory;
 
 use crate::db::mixin::message::Message;
+use crate::db::mixin::smart_circle::SMART_CIRCLE_MATCH;
 use crate::db::Error;
 
 #[derive(Clone)]
@@ -91,6 +92,26 @@ impl ConversationListItem {
     }
 }
 
+/// Restricts a conversation query to the members of the circle bound as `?2`,
+/// either a circle synced with the server or a smart circle, unless `?2` is
+/// empty.
+fn circle_filter() -> String {
+    format!(
+        r#"(
+      ?2 = ''
+      OR EXISTS (
+          SELECT 1 FROM circle_conversations circle_conversation
+          WHERE circle_conversation.conversation_id = conversation.conversation_id
+            AND circle_conversation.circle_id = ?2
+      )
+      OR EXISTS (
+          SELECT 1 FROM smart_circles smart_circle
+          WHERE smart_circle.circle_id = ?2 AND {SMART_CIRCLE_MATCH}
+      )
+  )"#
+    )
+}
+
 impl ConversationDao {
     pub async fn count_items(
         &self,
@@ -99,7 +120,7 @@ impl ConversationDao {
         keyword: &str,
         unseen_only: bool,
     ) -> Result<i64, Error> {
-        let count = sqlx::query_scalar::<_, i64>(
+        let sql = format!(
             r#"
 SELECT COUNT(*)
 FROM conversations conversation
@@ -114,12 +135,9 @@ WHERE conversation.category IN ('CONTACT', 'GROUP')
       OR (?1 = 'bots' AND conversation.category = 'CONTACT' AND owner.app_id IS NOT NULL)
       OR (?1 = 'strangers' AND conversation.category = 'CONTACT'
           AND owner.relationship = 'STRANGER' AND owner.app_id IS NULL)
-      OR (?1 = 'circle' AND EXISTS (
-          SELECT 1 FROM circle_conversations circle_conversation
-          WHERE circle_conversation.conversation_id = conversation.conversation_id
-            AND circle_conversation.circle_id = ?2
-      ))
+      OR (?1 = 'circle' AND ?2 != '')
   )
+  AND {circle_filter}
   AND (?3 = FALSE OR conversation.unseen_message_count > 0)
   AND (
       ?4 = ''
@@ -129,13 +147,15 @@ WHERE conversation.category IN ('CONTACT', 'GROUP')
       OR COALESCE(last_message.content, '') LIKE '%' || ?4 || '%' COLLATE NOCASE
   )
             "#,
-        )
-        .bind(category)
-        .bind(circle_id.unwrap_or_default())
-        .bind(unseen_only)
-        .bind(keyword.trim())
-        .fetch_one(&self.0)
-        .await?;
+            circle_filter = circle_filter(),
+        );
+        let count = sqlx::query_scalar::<_, i64>(sqlx::AssertSqlSafe(sql))
+            .bind(category)
+            .bind(circle_id.unwrap_or_default())
+            .bind(unseen_only)
+            .bind(keyword.trim())
+            .fetch_one(&self.0)
+            .await?;
         Ok(count)
     }
 
@@ -196,7 +216,7 @@ WHERE conversation.category IN ('CONTACT', 'GROUP')
         offset: i64,
         conversation_ids_json: &str,
     ) -> Result<Vec<ConversationListItem>, Error> {
-        let result = sqlx::query_as::<_, ConversationListItem>(
+        let sql = format!(
             r#"
 SELECT conversation.conversation_id,
        COALESCE(conversation.owner_id, '') AS owner_id,
@@ -283,12 +303,9 @@ WHERE conversation.category IN ('CONTACT', 'GROUP')
       OR (?1 = 'bots' AND conversation.category = 'CONTACT' AND owner.app_id IS NOT NULL)
       OR (?1 = 'strangers' AND conversation.category = 'CONTACT'
           AND owner.relationship = 'STRANGER' AND owner.app_id IS NULL)
-      OR (?1 = 'circle' AND EXISTS (
-          SELECT 1 FROM circle_conversations circle_conversation
-          WHERE circle_conversation.conversation_id = conversation.conversation_id
-            AND circle_conversation.circle_id = ?2
-      ))
+      OR (?1 = 'circle' AND ?2 != '')
   )
+  AND {circle_filter}
   AND (?3 = FALSE OR conversation.unseen_message_count > 0)
   AND (
       ?4 = ''
@@ -304,21 +321,23 @@ ORDER BY conversation.pin_time DESC,
          conversation.created_at DESC
 LIMIT ?5 OFFSET ?6
             "#,
-        )
-        .bind(category)
-        .bind(circle_id.unwrap_or_default())
-        .bind(unseen_only)
-        .bind(keyword.trim())
-        .bind(limit.max(1))
-        .bind(offset.max(0))
-        .bind(conversation_ids_json)
-        .fetch_all(&self.0)
-        .await?;
+            circle_filter = circle_filter(),
+        );
+        let result = sqlx::query_as::<_, ConversationListItem>(sqlx::AssertSqlSafe(sql))
+            .bind(category)
+            .bind(circle_id.unwrap_or_default())
+            .bind(unseen_only)
+            .bind(keyword.trim())
+            .bind(limit.max(1))
+            .bind(offset.max(0))
+            .bind(conversation_ids_json)
+            .fetch_all(&self.0)
+            .await?;
         Ok(result)
     }
 
     pub async fn unseen_counts(&self) -> Result<Vec<ConversationUnseenCountItem>, Error> {
-        Ok(sqlx::query_as::<_, ConversationUnseenCountItem>(
+        let sql = format!(
             r#"
 WITH base AS (
     SELECT conversation.conversation_id,
@@ -357,11 +376,55 @@ FROM base
 INNER JOIN circle_conversations circle_conversation
         ON circle_conversation.conversation_id = base.conversation_id
 GROUP BY circle_conversation.circle_id
+UNION ALL
+SELECT 'circle' AS category,
+       smart_circle.circle_id,
+       COUNT(1) AS count,
+       COALESCE(SUM(CASE WHEN base.is_muted THEN 1 ELSE 0 END), 0) AS muted_count
+FROM base
+INNER JOIN conversations conversation ON conversation.conversation_id = base.conversation_id
+INNER JOIN users owner ON owner.user_id = conversation.owner_id
+INNER JOIN smart_circles smart_circle ON {SMART_CIRCLE_MATCH}
+GROUP BY smart_circle.circle_id
 ORDER BY category, circle_id
-            "#,
+            "#
+        );
+        Ok(
+            sqlx::query_as::<_, ConversationUnseenCountItem>(sqlx::AssertSqlSafe(sql))
+                .fetch_all(&self.0)
+                .await?,
+        )
+    }
+
+    /// Pairs each of `conversation_ids` with the circles, synced or smart, it
+    /// belongs to.
+    pub async fn circle_memberships(
+        &self,
+        conversation_ids: &[String],
+    ) -> Result<Vec<(String, String)>, Error> {
+        if conversation_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids = serde_json::to_string(conversation_ids).map_err(anyhow::Error::from)?;
+        let sql = format!(
+            r#"
+SELECT circle_conversation.conversation_id, circle_conversation.circle_id
+FROM circle_conversations circle_conversation
+WHERE circle_conversation.conversation_id IN (SELECT value FROM json_each(?1))
+UNION
+SELECT conversation.conversation_id, smart_circle.circle_id
+FROM conversations conversation
+INNER JOIN users owner ON owner.user_id = conversation.owner_id
+INNER JOIN smart_circles smart_circle ON {SMART_CIRCLE_MATCH}
+WHERE conversation.conversation_id IN (SELECT value FROM json_each(?1))
+            "#
+        );
+        Ok(
+            sqlx::query_as::<_, (String, String)>(sqlx::AssertSqlSafe(sql))
+                .bind(ids)
+                .fetch_all(&self.0)
+                .await?,
         )
-        .fetch_all(&self.0)
-        .await?)
     }
 
     pub async fn unseen_unmuted_message_count(&self) -> Result<i64, Error> {
@@ -457,6 +520,7 @@ SELECT EXISTS (
         for query in [
             "DELETE FROM message_mentions WHERE conversation_id = ?",
             "DELETE FROM pin_messages WHERE conversation_id = ?",
+            "DELETE FROM participant_events WHERE conversation_id = ?",
             "DELETE FROM messages WHERE conversation_id = ?",
             "DELETE FROM conversations WHERE conversation_id = ?",
         ] {
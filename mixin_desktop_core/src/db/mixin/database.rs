@@ -12,17 +12,22 @@ use crate::db::mixin::expired_message::ExpiredMessageDao;
 use crate::db::mixin::favorite_app::FavoriteAppDao;
 use crate::db::mixin::fiat::FiatDao;
 use crate::db::mixin::flood_message::FloodMessageDao;
+use crate::db::mixin::hyperlink::HyperlinkDao;
 use crate::db::mixin::inscription::InscriptionDao;
 use crate::db::mixin::job::JobDao;
 use crate::db::mixin::message::MessageDao;
+use crate::db::mixin::message_edit::MessageEditDao;
 use crate::db::mixin::message_fts::MessageFtsDao;
 use crate::db::mixin::message_history::MessageHistoryDao;
 use crate::db::mixin::message_mention::MessageMentionDao;
+use crate::db::mixin::message_reaction::MessageReactionDao;
 use crate::db::mixin::offset::OffsetDao;
 use crate::db::mixin::participant::ParticipantDao;
+use crate::db::mixin::participant_event::ParticipantEventDao;
 use crate::db::mixin::participant_session::ParticipantSessionDao;
 use crate::db::mixin::pin_message::PinMessageDao;
 use crate::db::mixin::safe_snapshot::SafeSnapshotDao;
+use crate::db::mixin::smart_circle::SmartCircleDao;
 use crate::db::mixin::snapshot::SnapshotDao;
 use crate::db::mixin::sticker::StickerDao;
 use crate::db::mixin::transcript_message::TranscriptMessageDao;
@@ -34,8 +39,10 @@ pub(crate) const MARK_LIMIT: usize = 999;
 pub struct MixinDatabase {
     pub user_dao: UserDao,
     pub message_dao: MessageDao,
+    pub message_edit_dao: MessageEditDao,
     pub message_fts_dao: MessageFtsDao,
     pub message_mention_dao: MessageMentionDao,
+    pub message_reaction_dao: MessageReactionDao,
     pub offset_dao: OffsetDao,
     pub asset_dao: AssetDao,
     pub inscription_dao: InscriptionDao,
@@ -45,17 +52,20 @@ pub struct MixinDatabase {
     pub message_history_dao: MessageHistoryDao,
     pub conversation_dao: ConversationDao,
     pub participant_dao: ParticipantDao,
+    pub participant_event_dao: ParticipantEventDao,
     pub participant_session_dao: ParticipantSessionDao,
     pub circle_dao: CircleDao,
     pub circle_conversation_dao: CircleConversationDao,
     pub snapshot_dao: SnapshotDao,
     pub safe_snapshot_dao: SafeSnapshotDao,
+    pub smart_circle_dao: SmartCircleDao,
     pub app_dao: AppDao,
     pub pin_message_dao: PinMessageDao,
     pub flood_message_dao: FloodMessageDao,
     pub expired_message_dao: ExpiredMessageDao,
     pub favorite_app_dao: FavoriteAppDao,
     pub fiat_dao: FiatDao,
+    pub hyperlink_dao: HyperlinkDao,
 }
 
 impl MixinDatabase {
@@ -94,8 +104,10 @@ impl MixinDatabase {
         Ok(MixinDatabase {
             user_dao: UserDao::new(pool.clone()),
             message_dao: MessageDao(pool.clone()),
-            message_fts_dao: MessageFtsDao(pool.clone()),
+            message_edit_dao: MessageEditDao(pool.clone()),
+            message_fts_dao: MessageFtsDao(pool.clone(), Default::default()),
             message_mention_dao: MessageMentionDao(pool.clone()),
+            message_reaction_dao: MessageReactionDao(pool.clone()),
             offset_dao: OffsetDao::new(pool.clone()),
             asset_dao: AssetDao(pool.clone()),
             inscription_dao: InscriptionDao(pool.clone()),
@@ -105,17 +117,20 @@ impl MixinDatabase {
             message_history_dao: MessageHistoryDao(pool.clone()),
             conversation_dao: ConversationDao(pool.clone()),
             participant_dao: ParticipantDao(pool.clone()),
+            participant_event_dao: ParticipantEventDao(pool.clone()),
             participant_session_dao: ParticipantSessionDao(pool.clone()),
             circle_dao: CircleDao(pool.clone()),
             circle_conversation_dao: CircleConversationDao(pool.clone()),
             snapshot_dao: SnapshotDao(pool.clone()),
             safe_snapshot_dao: SafeSnapshotDao(pool.clone()),
+            smart_circle_dao: SmartCircleDao(pool.clone()),
             app_dao: AppDao(pool.clone()),
             pin_message_dao: PinMessageDao(pool.clone()),
             flood_message_dao: FloodMessageDao::new(pool.clone()),
             expired_message_dao: ExpiredMessageDao(pool.clone()),
             favorite_app_dao: FavoriteAppDao(pool.clone()),
             fiat_dao: FiatDao(pool.clone()),
+            hyperlink_dao: HyperlinkDao(pool.clone()),
         })
     }
 
@@ -0,0 +1,66 @@
+use sqlx::QueryBuilder;
+
+use crate::db::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct Hyperlink {
+    pub hyperlink: String,
+    pub site_name: String,
+    pub site_title: String,
+    pub site_description: Option<String>,
+    pub site_image: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct HyperlinkDao(pub(crate) sqlx::Pool<sqlx::Sqlite>);
+
+impl HyperlinkDao {
+    pub async fn insert(&self, hyperlink: &Hyperlink) -> Result<(), Error> {
+        sqlx::query(
+            r#"INSERT OR REPLACE INTO hyperlinks
+               (hyperlink, site_name, site_title, site_description, site_image)
+               VALUES (?, ?, ?, ?, ?)"#,
+        )
+        .bind(&hyperlink.hyperlink)
+        .bind(&hyperlink.site_name)
+        .bind(&hyperlink.site_title)
+        .bind(&hyperlink.site_description)
+        .bind(&hyperlink.site_image)
+        .execute(&self.0)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn find(&self, hyperlink: &str) -> Result<Option<Hyperlink>, Error> {
+        Ok(
+            sqlx::query_as::<_, Hyperlink>("SELECT * FROM hyperlinks WHERE hyperlink = ?")
+                .bind(hyperlink)
+                .fetch_optional(&self.0)
+                .await?,
+        )
+    }
+
+    pub async fn find_all(&self, hyperlinks: &[String]) -> Result<Vec<Hyperlink>, Error> {
+        if hyperlinks.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut builder = QueryBuilder::new("SELECT * FROM hyperlinks WHERE hyperlink IN (");
+        let mut separated = builder.separated(", ");
+        for hyperlink in hyperlinks {
+            separated.push_bind(hyperlink);
+        }
+        separated.push_unseparated(")");
+        Ok(builder
+            .build_query_as::<Hyperlink>()
+            .fetch_all(&self.0)
+            .await?)
+    }
+
+    pub async fn delete(&self, hyperlink: &str) -> Result<u64, Error> {
+        Ok(sqlx::query("DELETE FROM hyperlinks WHERE hyperlink = ?")
+            .bind(hyperlink)
+            .execute(&self.0)
+            .await?
+            .rows_affected())
+    }
+}
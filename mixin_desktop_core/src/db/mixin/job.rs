@@ -2,8 +2,10 @@ use chrono::{NaiveDateTime, Utc};
 use sqlx::{QueryBuilder, Sqlite};
 use uuid::Uuid;
 
-use sdk::blaze_message::{CREATE_MESSAGE, PIN_MESSAGE, RECALL_MESSAGE};
-use sdk::message::{BlazeAckMessage, RecallMessage};
+use sdk::blaze_message::{
+    CREATE_MESSAGE, EDIT_MESSAGE, PIN_MESSAGE, REACTION_MESSAGE, RECALL_MESSAGE,
+};
+use sdk::message::{BlazeAckMessage, EditMessage, ReactionMessage, RecallMessage};
 use sdk::{ACKNOWLEDGE_MESSAGE_RECEIPTS, SENDING_MESSAGE};
 
 use crate::db::mixin::database::MARK_LIMIT;
@@ -27,6 +29,10 @@ pub struct Job {
     pub conversation_id: Option<String>,
     pub resend_message_id: Option<String>,
     pub run_count: i32,
+    pub last_error: Option<String>,
+    /// When a failed job may run again; `None` runs it right away.
+    #[sqlx(try_from = "crate::db::datetime::OptionalDatabaseDateTime")]
+    pub next_run_at: Option<NaiveDateTime>,
 }
 
 pub const UPDATE_STICKER: &str = "LOCAL_UPDATE_STICKER";
@@ -55,6 +61,24 @@ impl Job {
             conversation_id: None,
             resend_message_id: None,
             run_count: 0,
+            last_error: None,
+            next_run_at: None,
+        }
+    }
+
+    /// Returns the message a `SENDING_MESSAGE` job delivers. Flutter stored the
+    /// bare message id as the payload before it switched to JSON.
+    pub fn sending_message_id(&self) -> Option<String> {
+        if self.action != SENDING_MESSAGE {
+            return None;
+        }
+        let payload = self.blaze_message.as_deref()?;
+        match serde_json::from_str::<serde_json::Value>(payload) {
+            Ok(value) => value
+                .get("message_id")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+            Err(_) => Some(payload.to_string()),
         }
     }
 
@@ -117,6 +141,38 @@ impl Job {
         }
     }
 
+    pub fn create_send_edit_job(conversation_id: &str, message_id: &str, content: &str) -> Job {
+        Job {
+            conversation_id: Some(conversation_id.to_string()),
+            action: EDIT_MESSAGE.to_string(),
+            blaze_message: serde_json::to_string(&EditMessage {
+                message_id: message_id.to_string(),
+                content: content.to_string(),
+            })
+            .ok(),
+            ..Self::new()
+        }
+    }
+
+    pub fn create_send_reaction_job(
+        conversation_id: &str,
+        message_id: &str,
+        emoji: &str,
+        removed: bool,
+    ) -> Job {
+        Job {
+            conversation_id: Some(conversation_id.to_string()),
+            action: REACTION_MESSAGE.to_string(),
+            blaze_message: serde_json::to_string(&ReactionMessage {
+                message_id: message_id.to_string(),
+                emoji: emoji.to_string(),
+                removed,
+            })
+            .ok(),
+            ..Self::new()
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn create_sending_job(
         message_id: &str,
@@ -329,12 +385,96 @@ impl JobDao {
 
     pub async fn sending_jobs(&self) -> Result<Vec<Job>, Error> {
         let result = sqlx::query_as::<_, Job>(
-            "SELECT * FROM jobs WHERE action IN (?, ?, ?) AND blaze_message IS NOT NULL \
-             ORDER BY created_at ASC LIMIT 100",
+            "SELECT * FROM jobs WHERE action IN (?, ?, ?, ?, ?) AND blaze_message IS NOT NULL \
+             AND (next_run_at IS NULL OR next_run_at <= ?) ORDER BY created_at ASC LIMIT 100",
         )
         .bind(SENDING_MESSAGE)
         .bind(PIN_MESSAGE)
         .bind(RECALL_MESSAGE)
+        .bind(EDIT_MESSAGE)
+        .bind(REACTION_MESSAGE)
+        .bind(Utc::now().timestamp_millis())
+        .fetch_all(&self.0)
+        .await?;
+        Ok(result)
+    }
+
+    /// Whether any sending job is still waiting out its backoff.
+    pub async fn has_postponed_sending_jobs(&self) -> Result<bool, Error> {
+        let result = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM jobs WHERE action IN (?, ?, ?, ?, ?) \
+             AND blaze_message IS NOT NULL AND next_run_at > ?)",
+        )
+        .bind(SENDING_MESSAGE)
+        .bind(PIN_MESSAGE)
+        .bind(RECALL_MESSAGE)
+        .bind(EDIT_MESSAGE)
+        .bind(REACTION_MESSAGE)
+        .bind(Utc::now().timestamp_millis())
+        .fetch_one(&self.0)
+        .await?;
+        Ok(result)
+    }
+
+    /// Records a failed attempt and postpones the job until `next_run_at`.
+    pub async fn record_job_failure(
+        &self,
+        job_id: &str,
+        error: &str,
+        next_run_at: NaiveDateTime,
+    ) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "UPDATE jobs SET run_count = run_count + 1, last_error = ?, next_run_at = ? \
+             WHERE job_id = ?",
+        )
+        .bind(error)
+        .bind(next_run_at.and_utc().timestamp_millis())
+        .bind(job_id)
+        .execute(&self.0)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Lets a postponed job run on the next pass, keeping its failure count.
+    pub async fn clear_job_backoff(&self, job_id: &str) -> Result<u64, Error> {
+        let result = sqlx::query("UPDATE jobs SET next_run_at = NULL WHERE job_id = ?")
+            .bind(job_id)
+            .execute(&self.0)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn find_sending_job(&self, message_id: &str) -> Result<Option<Job>, Error> {
+        let result = sqlx::query_as::<_, Job>(
+            "SELECT * FROM jobs WHERE action = ? AND resend_message_id IS NULL \
+             AND CASE WHEN json_valid(blaze_message) \
+             THEN json_extract(blaze_message, '$.message_id') ELSE blaze_message END = ? \
+             LIMIT 1",
+        )
+        .bind(SENDING_MESSAGE)
+        .bind(message_id)
+        .fetch_optional(&self.0)
+        .await?;
+        Ok(result)
+    }
+
+    /// Sending jobs that have failed at least once and are waiting for a retry,
+    /// optionally only those of one conversation.
+    pub async fn retrying_sending_jobs(
+        &self,
+        conversation_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<Job>, Error> {
+        let result = sqlx::query_as::<_, Job>(
+            "SELECT * FROM jobs WHERE action = ? AND resend_message_id IS NULL \
+             AND blaze_message IS NOT NULL AND run_count > 0 \
+             AND (? IS NULL OR conversation_id = ?) \
+             ORDER BY created_at DESC LIMIT ?",
+        )
+        .bind(SENDING_MESSAGE)
+        .bind(conversation_id)
+        .bind(conversation_id)
+        .bind(limit)
         .fetch_all(&self.0)
         .await?;
         Ok(result)
@@ -468,6 +608,139 @@ mod tests {
         assert_eq!(run_count, 3);
     }
 
+    #[tokio::test]
+    async fn failed_sending_jobs_wait_for_their_backoff() {
+        let (_directory, database) = test_database().await;
+        let job = Job::create_sending_job("message", "conversation", None, None, false, false, 0);
+        database.job_dao.insert_job(&job).await.unwrap();
+        database
+            .job_dao
+            .record_job_failure(
+                &job.job_id,
+                "timeout",
+                Utc::now().naive_utc() + chrono::Duration::minutes(1),
+            )
+            .await
+            .unwrap();
+
+        assert!(database.job_dao.sending_jobs().await.unwrap().is_empty());
+        assert!(database.job_dao.has_postponed_sending_jobs().await.unwrap());
+        let retrying = database
+            .job_dao
+            .retrying_sending_jobs(None, 10)
+            .await
+            .unwrap();
+        assert_eq!(retrying.len(), 1);
+        assert_eq!(retrying[0].run_count, 1);
+        assert_eq!(retrying[0].last_error.as_deref(), Some("timeout"));
+        assert_eq!(
+            retrying[0].created_at.and_utc().timestamp_millis(),
+            job.created_at.and_utc().timestamp_millis()
+        );
+        assert!(retrying[0]
+            .next_run_at
+            .is_some_and(|at| at > job.created_at));
+        assert_eq!(
+            database
+                .job_dao
+                .find_sending_job("message")
+                .await
+                .unwrap()
+                .map(|job| job.job_id),
+            Some(job.job_id.clone())
+        );
+
+        database
+            .job_dao
+            .clear_job_backoff(&job.job_id)
+            .await
+            .unwrap();
+        let sending = database.job_dao.sending_jobs().await.unwrap();
+        assert_eq!(sending.len(), 1);
+        assert_eq!(sending[0].run_count, 1);
+        assert!(!database.job_dao.has_postponed_sending_jobs().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn failed_control_jobs_wait_for_their_backoff() {
+        let (_directory, database) = test_database().await;
+        let job = Job::create_send_recall_job("conversation", "message");
+        database.job_dao.insert_job(&job).await.unwrap();
+        database
+            .job_dao
+            .record_job_failure(
+                &job.job_id,
+                "message delivery was not acknowledged",
+                Utc::now().naive_utc() + chrono::Duration::minutes(1),
+            )
+            .await
+            .unwrap();
+
+        assert!(database.job_dao.sending_jobs().await.unwrap().is_empty());
+        assert!(database.job_dao.has_postponed_sending_jobs().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn retrying_sending_jobs_filter_by_conversation_before_the_limit() {
+        let (_directory, database) = test_database().await;
+        let mut jobs = Vec::new();
+        for (index, conversation_id) in ["target", "other", "other"].into_iter().enumerate() {
+            let mut job = Job::create_sending_job(
+                &format!("message-{index}"),
+                conversation_id,
+                None,
+                None,
+                false,
+                false,
+                0,
+            );
+            job.created_at += chrono::Duration::seconds(index as i64);
+            database.job_dao.insert_job(&job).await.unwrap();
+            database
+                .job_dao
+                .record_job_failure(&job.job_id, "timeout", job.created_at)
+                .await
+                .unwrap();
+            jobs.push(job);
+        }
+
+        let retrying = database
+            .job_dao
+            .retrying_sending_jobs(Some("target"), 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            retrying.iter().map(|job| &job.job_id).collect::<Vec<_>>(),
+            [&jobs[0].job_id]
+        );
+        let retrying = database
+            .job_dao
+            .retrying_sending_jobs(None, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            retrying.iter().map(|job| &job.job_id).collect::<Vec<_>>(),
+            [&jobs[2].job_id, &jobs[1].job_id]
+        );
+    }
+
+    #[test]
+    fn reads_message_id_from_json_and_legacy_sending_payloads() {
+        let job = Job::create_sending_job("message", "conversation", None, None, false, false, 0);
+        let legacy = Job {
+            action: SENDING_MESSAGE.to_string(),
+            blaze_message: Some("legacy".to_string()),
+            ..Job::new()
+        };
+
+        assert_eq!(job.sending_message_id().as_deref(), Some("message"));
+        assert_eq!(legacy.sending_message_id().as_deref(), Some("legacy"));
+        assert_eq!(
+            Job::create_update_asset_job("asset").sending_message_id(),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn sticker_jobs_only_return_when_due() {
         let (_directory, database) = test_database().await;
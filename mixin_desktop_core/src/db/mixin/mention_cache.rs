@@ -13,6 +13,8 @@ pub struct MentionCache(Arc<Mutex<MentionCacheState>>);
 struct MentionCacheState {
     contents: HashMap<String, Vec<String>>,
     users: HashMap<String, String>,
+    /// Local aliases by identity number, loaded on first use.
+    aliases: Option<HashMap<String, String>>,
 }
 
 impl MentionCache {
@@ -55,6 +57,21 @@ impl MentionCache {
         }
     }
 
+    pub fn needs_aliases(&self) -> bool {
+        let state = self.0.lock().unwrap_or_else(|error| error.into_inner());
+        state.aliases.is_none()
+    }
+
+    pub fn cache_aliases(&self, aliases: HashMap<String, String>) {
+        let mut state = self.0.lock().unwrap_or_else(|error| error.into_inner());
+        state.aliases = Some(aliases);
+    }
+
+    pub fn clear_aliases(&self) {
+        let mut state = self.0.lock().unwrap_or_else(|error| error.into_inner());
+        state.aliases = None;
+    }
+
     pub fn replace_mentions(&self, content: &str) -> String {
         let mut state = self.0.lock().unwrap_or_else(|error| error.into_inner());
         let identity_numbers = match state.contents.get(content) {
@@ -70,7 +87,12 @@ impl MentionCache {
                 identity_numbers
             }
         };
-        replace_mentions(content, &identity_numbers, &state.users)
+        replace_mentions(
+            content,
+            &identity_numbers,
+            &state.users,
+            state.aliases.as_ref(),
+        )
     }
 
     pub fn mention_names(&self, contents: &[String]) -> HashMap<String, String> {
@@ -79,10 +101,8 @@ impl MentionCache {
             .iter()
             .flat_map(|content| state.contents.get(content).into_iter().flatten())
             .filter_map(|identity_number| {
-                state
-                    .users
-                    .get(identity_number)
-                    .map(|full_name| (identity_number.clone(), full_name.clone()))
+                display_name(identity_number, &state.users, state.aliases.as_ref())
+                    .map(|name| (identity_number.clone(), name.clone()))
             })
             .collect()
     }
@@ -103,15 +123,27 @@ fn mention_identity_numbers(content: &str) -> Vec<String> {
     identity_numbers
 }
 
+/// The alias of a user when one is set, otherwise their full name.
+fn display_name<'a>(
+    identity_number: &str,
+    users: &'a HashMap<String, String>,
+    aliases: Option<&'a HashMap<String, String>>,
+) -> Option<&'a String> {
+    aliases
+        .and_then(|aliases| aliases.get(identity_number))
+        .or_else(|| users.get(identity_number))
+}
+
 fn replace_mentions(
     content: &str,
     identity_numbers: &[String],
     users: &HashMap<String, String>,
+    aliases: Option<&HashMap<String, String>>,
 ) -> String {
     let mut result = content.to_string();
     for identity_number in identity_numbers {
-        if let Some(full_name) = users.get(identity_number) {
-            result = result.replace(&format!("@{identity_number}"), &format!("@{full_name}"));
+        if let Some(name) = display_name(identity_number, users, aliases) {
+            result = result.replace(&format!("@{identity_number}"), &format!("@{name}"));
         }
     }
     result
@@ -137,6 +169,7 @@ mod tests {
                     ("1039549".to_string(), "Alice".to_string()),
                     ("26832".to_string(), "Bob".to_string()),
                 ]),
+                None,
             ),
             "hello @Alice and @Bob"
         );
@@ -145,8 +178,21 @@ mod tests {
                 "@1039549 paid 1039549",
                 &["1039549".to_string()],
                 &HashMap::from([("1039549".to_string(), "Alice".to_string())]),
+                None,
             ),
             "@Alice paid 1039549"
         );
+        assert_eq!(
+            replace_mentions(
+                "hi @1039549 and @26832",
+                &["1039549".to_string(), "26832".to_string()],
+                &HashMap::from([
+                    ("1039549".to_string(), "Alice".to_string()),
+                    ("26832".to_string(), "Bob".to_string()),
+                ]),
+                Some(&HashMap::from([("26832".to_string(), "Bobby".to_string())])),
+            ),
+            "hi @Alice and @Bobby"
+        );
     }
 }
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::{Executor, QueryBuilder, Sqlite};
 
 use sdk::blaze_message::{MessageStatus, CREATE_MESSAGE};
-use sdk::message_category::{MESSAGE_PIN, MESSAGE_RECALL};
+use sdk::message_category::{MessageCategory, MESSAGE_PIN, MESSAGE_RECALL};
 use sdk::{ACKNOWLEDGE_MESSAGE_RECEIPTS, RECALL_MESSAGE as RECALL_MESSAGE_ACTION};
 
 use crate::db::datetime::DatabaseDateTime;
@@ -129,6 +129,10 @@ pub struct MessageListItem {
     pub inscription_name: Option<String>,
     pub inscription_icon_url: Option<String>,
     pub hyperlink: Option<String>,
+    pub hyperlink_site_name: Option<String>,
+    pub hyperlink_site_title: Option<String>,
+    pub hyperlink_site_description: Option<String>,
+    pub hyperlink_site_image: Option<String>,
     pub media_name: Option<String>,
     pub album_id: Option<String>,
     pub sticker_id: Option<String>,
@@ -151,6 +155,11 @@ pub struct MessageListItem {
     pub mention_read: Option<bool>,
     pub pinned: bool,
     pub expire_in: Option<i64>,
+    #[sqlx(try_from = "crate::db::datetime::OptionalDatabaseDateTime")]
+    pub edited_at: Option<DateTime<Utc>>,
+    /// JSON array of `[emoji, user_id]` pairs in reaction order, `NULL` when
+    /// nobody reacted. Use [`MessageListItem::reaction_groups`] to aggregate.
+    pub reactions: Option<String>,
 }
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -165,7 +174,7 @@ const MESSAGE_LIST_QUERY_PREFIX: &str = r#"
 SELECT message.message_id,
        message.conversation_id,
        message.user_id,
-       COALESCE(sender.full_name, '') AS sender_name,
+       COALESCE(NULLIF(sender_annotation.alias, ''), sender.full_name, '') AS sender_name,
        COALESCE(sender.identity_number, '') AS sender_identity_number,
        COALESCE(sender.avatar_url, '') AS sender_avatar_url,
        COALESCE(sender.is_verified, FALSE) AS sender_is_verified,
@@ -214,6 +223,10 @@ SELECT message.message_id,
        inscription_collection.name AS inscription_name,
        inscription_collection.icon_url AS inscription_icon_url,
        message.hyperlink,
+       link.site_name AS hyperlink_site_name,
+       link.site_title AS hyperlink_site_title,
+       link.site_description AS hyperlink_site_description,
+       link.site_image AS hyperlink_site_image,
        message.name AS media_name,
        message.album_id,
        message.sticker_id,
@@ -239,9 +252,16 @@ SELECT message.message_id,
            WHEN message.category = 'SYSTEM_CONVERSATION' AND message.action = 'EXPIRE'
                THEN CAST(message.content AS INTEGER)
            ELSE expired.expire_in
-       END AS expire_in
+       END AS expire_in,
+       (SELECT MAX(edit.edited_at) FROM message_edits edit
+        WHERE edit.message_id = message.message_id) AS edited_at,
+       (SELECT NULLIF(json_group_array(json_array(reaction.emoji, reaction.user_id)), '[]')
+        FROM (SELECT emoji, user_id FROM message_reactions
+              WHERE message_id = message.message_id ORDER BY created_at, rowid) reaction
+       ) AS reactions
 FROM messages message
 LEFT JOIN users sender ON sender.user_id = message.user_id
+LEFT JOIN user_annotations sender_annotation ON sender_annotation.user_id = message.user_id
 LEFT JOIN participants sender_participant
        ON sender_participant.conversation_id = message.conversation_id
       AND sender_participant.user_id = message.user_id
@@ -262,6 +282,7 @@ LEFT JOIN inscription_collections inscription_collection
 LEFT JOIN message_mentions mention ON mention.message_id = message.message_id
 LEFT JOIN pin_messages pin ON pin.message_id = message.message_id
 LEFT JOIN expired_messages expired ON expired.message_id = message.message_id
+LEFT JOIN hyperlinks link ON link.hyperlink = message.hyperlink
 "#;
 
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -284,6 +305,32 @@ impl MessageListItem {
     pub fn created_at_micros(&self) -> i64 {
         self.created_at.and_utc().timestamp_micros()
     }
+
+    /// Reactions grouped by emoji, in the order each emoji was first used.
+    pub fn reaction_groups(&self) -> Vec<ReactionGroup> {
+        let pairs = self
+            .reactions
+            .as_deref()
+            .and_then(|json| serde_json::from_str::<Vec<(String, String)>>(json).ok())
+            .unwrap_or_default();
+        let mut groups: Vec<ReactionGroup> = Vec::new();
+        for (emoji, user_id) in pairs {
+            match groups.iter_mut().find(|group| group.emoji == emoji) {
+                Some(group) => group.user_ids.push(user_id),
+                None => groups.push(ReactionGroup {
+                    emoji,
+                    user_ids: vec![user_id],
+                }),
+            }
+        }
+        groups
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReactionGroup {
+    pub emoji: String,
+    pub user_ids: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
@@ -461,15 +508,9 @@ pub struct NotificationMessageItem {
     pub is_muted: bool,
 }
 
-impl MessageDao {
-    pub async fn notification_items_after(
-        &self,
-        current_user_id: &str,
-        after_row_id: i64,
-        limit: i64,
-    ) -> Result<Vec<NotificationMessageItem>, Error> {
-        Ok(sqlx::query_as::<_, NotificationMessageItem>(
-            r#"
+/// Selects `NotificationMessageItem`s, binding the current user as `?1` and
+/// the current time in millis as `?2`.
+const NOTIFICATION_ITEM_SELECT: &str = r#"
 SELECT message.rowid AS row_id,
        message.message_id,
        message.conversation_id,
@@ -483,25 +524,50 @@ SELECT message.rowid AS row_id,
             THEN conversation.name ELSE COALESCE(owner.full_name, '') END AS conversation_name,
        COALESCE(conversation.category, '') AS conversation_category,
        CASE WHEN conversation.category = 'GROUP'
-            THEN conversation.mute_until >= ?3
-            ELSE owner.mute_until >= ?3 END AS is_muted
+            THEN conversation.mute_until >= ?2
+            ELSE owner.mute_until >= ?2 END AS is_muted
 FROM messages message
 INNER JOIN conversations conversation
         ON conversation.conversation_id = message.conversation_id
 INNER JOIN users owner ON owner.user_id = conversation.owner_id
 LEFT JOIN users sender ON sender.user_id = message.user_id
-WHERE message.user_id != ?1
-  AND message.rowid > ?2
-ORDER BY message.rowid ASC
-LIMIT ?4
-            "#,
+WHERE message.user_id != ?1"#;
+
+impl MessageDao {
+    pub async fn notification_items_after(
+        &self,
+        current_user_id: &str,
+        after_row_id: i64,
+        limit: i64,
+    ) -> Result<Vec<NotificationMessageItem>, Error> {
+        let sql = format!(
+            "{NOTIFICATION_ITEM_SELECT}\n  AND message.rowid > ?3\nORDER BY message.rowid ASC\nLIMIT ?4"
+        );
+        Ok(
+            sqlx::query_as::<_, NotificationMessageItem>(sqlx::AssertSqlSafe(sql))
+                .bind(current_user_id)
+                .bind(Utc::now().timestamp_millis())
+                .bind(after_row_id)
+                .bind(limit.clamp(1, 200))
+                .fetch_all(&self.0)
+                .await?,
+        )
+    }
+
+    pub async fn notification_item(
+        &self,
+        current_user_id: &str,
+        message_id: &str,
+    ) -> Result<Option<NotificationMessageItem>, Error> {
+        let sql = format!("{NOTIFICATION_ITEM_SELECT}\n  AND message.message_id = ?3");
+        Ok(
+            sqlx::query_as::<_, NotificationMessageItem>(sqlx::AssertSqlSafe(sql))
+                .bind(current_user_id)
+                .bind(Utc::now().timestamp_millis())
+                .bind(message_id)
+                .fetch_optional(&self.0)
+                .await?,
         )
-        .bind(current_user_id)
-        .bind(after_row_id)
-        .bind(Utc::now().timestamp_millis())
-        .bind(limit.clamp(1, 200))
-        .fetch_all(&self.0)
-        .await?)
     }
 
     pub async fn latest_row_id(&self) -> Result<i64, Error> {
@@ -709,7 +775,7 @@ LIMIT ?4
 SELECT message.message_id,
        message.conversation_id,
        message.user_id,
-       COALESCE(sender.full_name, '') AS sender_name,
+       COALESCE(NULLIF(sender_annotation.alias, ''), sender.full_name, '') AS sender_name,
        COALESCE(sender.identity_number, '') AS sender_identity_number,
        COALESCE(sender.avatar_url, '') AS sender_avatar_url,
        COALESCE(sender.is_verified, FALSE) AS sender_is_verified,
@@ -758,6 +824,10 @@ SELECT message.message_id,
        inscription_collection.name AS inscription_name,
        inscription_collection.icon_url AS inscription_icon_url,
        message.hyperlink,
+       link.site_name AS hyperlink_site_name,
+       link.site_title AS hyperlink_site_title,
+       link.site_description AS hyperlink_site_description,
+       link.site_image AS hyperlink_site_image,
        message.name AS media_name,
        message.album_id,
        message.sticker_id,
@@ -783,9 +853,16 @@ SELECT message.message_id,
            WHEN message.category = 'SYSTEM_CONVERSATION' AND message.action = 'EXPIRE'
                THEN CAST(message.content AS INTEGER)
            ELSE expired.expire_in
-       END AS expire_in
+       END AS expire_in,
+       (SELECT MAX(edit.edited_at) FROM message_edits edit
+        WHERE edit.message_id = message.message_id) AS edited_at,
+       (SELECT NULLIF(json_group_array(json_array(reaction.emoji, reaction.user_id)), '[]')
+        FROM (SELECT emoji, user_id FROM message_reactions
+              WHERE message_id = message.message_id ORDER BY created_at, rowid) reaction
+       ) AS reactions
 FROM messages message
 LEFT JOIN users sender ON sender.user_id = message.user_id
+LEFT JOIN user_annotations sender_annotation ON sender_annotation.user_id = message.user_id
 LEFT JOIN participants sender_participant
        ON sender_participant.conversation_id = message.conversation_id
       AND sender_participant.user_id = message.user_id
@@ -806,6 +883,7 @@ LEFT JOIN inscription_collections inscription_collection
 LEFT JOIN message_mentions mention ON mention.message_id = message.message_id
 LEFT JOIN pin_messages pin ON pin.message_id = message.message_id
 LEFT JOIN expired_messages expired ON expired.message_id = message.message_id
+LEFT JOIN hyperlinks link ON link.hyperlink = message.hyperlink
 WHERE message.conversation_id = ?1
   AND (
       ?2 IS NULL
@@ -926,7 +1004,7 @@ LIMIT ?
 SELECT message.message_id,
        message.conversation_id,
        message.user_id,
-       COALESCE(sender.full_name, '') AS sender_name,
+       COALESCE(NULLIF(sender_annotation.alias, ''), sender.full_name, '') AS sender_name,
        COALESCE(sender.identity_number, '') AS sender_identity_number,
        COALESCE(sender.avatar_url, '') AS sender_avatar_url,
        COALESCE(sender.is_verified, FALSE) AS sender_is_verified,
@@ -975,6 +1053,10 @@ SELECT message.message_id,
        inscription_collection.name AS inscription_name,
        inscription_collection.icon_url AS inscription_icon_url,
        message.hyperlink,
+       link.site_name AS hyperlink_site_name,
+       link.site_title AS hyperlink_site_title,
+       link.site_description AS hyperlink_site_description,
+       link.site_image AS hyperlink_site_image,
        message.name AS media_name,
        message.album_id,
        message.sticker_id,
@@ -1000,10 +1082,17 @@ SELECT message.message_id,
            WHEN message.category = 'SYSTEM_CONVERSATION' AND message.action = 'EXPIRE'
                THEN CAST(message.content AS INTEGER)
            ELSE expired.expire_in
-       END AS expire_in
+       END AS expire_in,
+       (SELECT MAX(edit.edited_at) FROM message_edits edit
+        WHERE edit.message_id = message.message_id) AS edited_at,
+       (SELECT NULLIF(json_group_array(json_array(reaction.emoji, reaction.user_id)), '[]')
+        FROM (SELECT emoji, user_id FROM message_reactions
+              WHERE message_id = message.message_id ORDER BY created_at, rowid) reaction
+       ) AS reactions
 FROM pin_messages selected_pin
 INNER JOIN messages message ON message.message_id = selected_pin.message_id
 LEFT JOIN users sender ON sender.user_id = message.user_id
+LEFT JOIN user_annotations sender_annotation ON sender_annotation.user_id = message.user_id
 LEFT JOIN participants sender_participant
        ON sender_participant.conversation_id = message.conversation_id
       AND sender_participant.user_id = message.user_id
@@ -1023,6 +1112,7 @@ LEFT JOIN inscription_collections inscription_collection
        ON inscription_collection.collection_hash = inscription_item.collection_hash
 LEFT JOIN message_mentions mention ON mention.message_id = message.message_id
 LEFT JOIN expired_messages expired ON expired.message_id = message.message_id
+LEFT JOIN hyperlinks link ON link.hyperlink = message.hyperlink
 WHERE selected_pin.conversation_id = ?1
 ORDER BY message.created_at DESC, message.message_id DESC
             "#,
@@ -1082,7 +1172,7 @@ WITH message_window AS (
 SELECT message.message_id,
        message.conversation_id,
        message.user_id,
-       COALESCE(sender.full_name, '') AS sender_name,
+       COALESCE(NULLIF(sender_annotation.alias, ''), sender.full_name, '') AS sender_name,
        COALESCE(sender.identity_number, '') AS sender_identity_number,
        COALESCE(sender.avatar_url, '') AS sender_avatar_url,
        COALESCE(sender.is_verified, FALSE) AS sender_is_verified,
@@ -1131,6 +1221,10 @@ SELECT message.message_id,
        inscription_collection.name AS inscription_name,
        inscription_collection.icon_url AS inscription_icon_url,
        message.hyperlink,
+       link.site_name AS hyperlink_site_name,
+       link.site_title AS hyperlink_site_title,
+       link.site_description AS hyperlink_site_description,
+       link.site_image AS hyperlink_site_image,
        message.name AS media_name,
        message.album_id,
        message.sticker_id,
@@ -1156,10 +1250,17 @@ SELECT message.message_id,
            WHEN message.category = 'SYSTEM_CONVERSATION' AND message.action = 'EXPIRE'
                THEN CAST(message.content AS INTEGER)
            ELSE expired.expire_in
-       END AS expire_in
+       END AS expire_in,
+       (SELECT MAX(edit.edited_at) FROM message_edits edit
+        WHERE edit.message_id = message.message_id) AS edited_at,
+       (SELECT NULLIF(json_group_array(json_array(reaction.emoji, reaction.user_id)), '[]')
+        FROM (SELECT emoji, user_id FROM message_reactions
+              WHERE message_id = message.message_id ORDER BY created_at, rowid) reaction
+       ) AS reactions
 FROM message_window
 INNER JOIN messages message ON message.message_id = message_window.message_id
 LEFT JOIN users sender ON sender.user_id = message.user_id
+LEFT JOIN user_annotations sender_annotation ON sender_annotation.user_id = message.user_id
 LEFT JOIN participants sender_participant
        ON sender_participant.conversation_id = message.conversation_id
       AND sender_participant.user_id = message.user_id
@@ -1180,6 +1281,7 @@ LEFT JOIN inscription_collections inscription_collection
 LEFT JOIN message_mentions mention ON mention.message_id = message.message_id
 LEFT JOIN pin_messages pin ON pin.message_id = message.message_id
 LEFT JOIN expired_messages expired ON expired.message_id = message.message_id
+LEFT JOIN hyperlinks link ON link.hyperlink = message.hyperlink
 ORDER BY message.created_at ASC, message.rowid ASC
             "#,
         )
@@ -1407,7 +1509,7 @@ ORDER BY message.created_at ASC, message.rowid ASC
         Ok(())
     }
 
-    async fn insert_job_with(
+    pub(crate) async fn insert_job_with(
         transaction: &mut sqlx::Transaction<'_, Sqlite>,
         job: &Job,
     ) -> Result<(), Error> {
@@ -1842,6 +1944,19 @@ ORDER BY message.created_at ASC, message.rowid ASC
         Ok(result.rows_affected())
     }
 
+    pub async fn update_hyperlink(
+        &self,
+        message_id: &str,
+        hyperlink: Option<&str>,
+    ) -> Result<u64, Error> {
+        let result = sqlx::query("UPDATE messages SET hyperlink = ? WHERE message_id = ?")
+            .bind(hyperlink)
+            .bind(message_id)
+            .execute(&self.0)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
     pub async fn update_message_status(
         &self,
         message_id: &str,
@@ -1923,7 +2038,13 @@ ORDER BY message.created_at ASC, message.rowid ASC
             .execute(&mut *transaction)
             .await?;
             crate::db::mixin::message_fts::delete_message_fts(&mut transaction, message_id).await?;
-            for table in ["pin_messages", "expired_messages", "message_mentions"] {
+            for table in [
+                "pin_messages",
+                "expired_messages",
+                "message_mentions",
+                "message_edits",
+                "message_reactions",
+            ] {
                 let query = format!("DELETE FROM {table} WHERE message_id = ?");
                 sqlx::query(sqlx::AssertSqlSafe(query))
                     .bind(message_id)
@@ -1965,7 +2086,13 @@ ORDER BY message.created_at ASC, message.rowid ASC
         .bind(conversation_id)
         .execute(&mut *transaction)
         .await?;
-        for table in ["pin_messages", "message_mentions", "messages"] {
+        for table in [
+            "pin_messages",
+            "message_mentions",
+            "message_edits",
+            "message_reactions",
+            "messages",
+        ] {
             let query = format!("DELETE FROM {table} WHERE conversation_id = ?");
             sqlx::query(sqlx::AssertSqlSafe(query))
                 .bind(conversation_id)
@@ -2012,6 +2139,50 @@ ORDER BY message.created_at ASC, message.rowid ASC
         Ok(jobs.len() as u64)
     }
 
+    pub async fn failed_outgoing_messages(
+        &self,
+        user_id: &str,
+        conversation_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<Message>, Error> {
+        let messages = sqlx::query_as::<_, Message>(
+            "SELECT * FROM messages WHERE user_id = ? AND status = ? \
+             AND (? IS NULL OR conversation_id = ?) ORDER BY created_at DESC LIMIT ?",
+        )
+        .bind(user_id)
+        .bind(MessageStatus::Failed)
+        .bind(conversation_id)
+        .bind(conversation_id)
+        .bind(limit)
+        .fetch_all(&self.0)
+        .await?;
+        Ok(messages
+            .into_iter()
+            .map(decode_message_attachment_material)
+            .collect())
+    }
+
+    /// Moves a failed outgoing message back to `SENDING` and queues `job` for it.
+    pub async fn requeue_failed_message(&self, message_id: &str, job: &Job) -> Result<bool, Error> {
+        let mut transaction = self.0.begin_with("BEGIN IMMEDIATE").await?;
+        let updated = sqlx::query(
+            "UPDATE messages SET status = ? WHERE message_id = ? AND status = ? \
+             AND content IS NOT NULL",
+        )
+        .bind(MessageStatus::Sending)
+        .bind(message_id)
+        .bind(MessageStatus::Failed)
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Ok(false);
+        }
+        Self::insert_job_with(&mut transaction, job).await?;
+        transaction.commit().await?;
+        Ok(true)
+    }
+
     pub async fn set_message_pinned_with_job(
         &self,
         conversation_id: &str,
@@ -2134,6 +2305,14 @@ ORDER BY message.created_at ASC, message.rowid ASC
             .bind(message_id)
             .execute(&mut *transaction)
             .await?;
+        sqlx::query("DELETE FROM message_edits WHERE message_id = ?")
+            .bind(message_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("DELETE FROM message_reactions WHERE message_id = ?")
+            .bind(message_id)
+            .execute(&mut *transaction)
+            .await?;
         sqlx::query("DELETE FROM transcript_messages WHERE transcript_id = ?")
             .bind(message_id)
             .execute(&mut *transaction)
@@ -2145,6 +2324,81 @@ ORDER BY message.created_at ASC, message.rowid ASC
         Ok(result.rows_affected())
     }
 
+    /// Replaces the content of an editable message and keeps the replaced text
+    /// in `message_edits`. Returns `false` without changes when the message is
+    /// missing or not editable, the edit was already applied, or a newer edit
+    /// has been applied before this one arrived.
+    pub async fn edit_message(
+        &self,
+        edit_id: &str,
+        conversation_id: &str,
+        message_id: &str,
+        content: &str,
+        edited_at: DateTime<Utc>,
+        job: Option<&Job>,
+    ) -> Result<bool, Error> {
+        let mut transaction = self.0.begin_with("BEGIN IMMEDIATE").await?;
+        let current = sqlx::query_as::<_, (String, Option<String>)>(
+            "SELECT category, content FROM messages WHERE conversation_id = ? AND message_id = ?",
+        )
+        .bind(conversation_id)
+        .bind(message_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+        let Some((category, Some(previous))) = current else {
+            return Ok(false);
+        };
+        if !category.can_edit() {
+            return Ok(false);
+        }
+        let (applied, last_edited_at) = sqlx::query_as::<_, (bool, Option<i64>)>(
+            "SELECT EXISTS(SELECT 1 FROM message_edits WHERE edit_id = ?), \
+             (SELECT MAX(edited_at) FROM message_edits WHERE message_id = ?)",
+        )
+        .bind(edit_id)
+        .bind(message_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+        let edited_at = edited_at.timestamp_millis();
+        if applied || last_edited_at.is_some_and(|last| last > edited_at) {
+            return Ok(false);
+        }
+        sqlx::query(
+            "INSERT INTO message_edits \
+             (edit_id, message_id, conversation_id, content, edited_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(edit_id)
+        .bind(message_id)
+        .bind(conversation_id)
+        .bind(&previous)
+        .bind(edited_at)
+        .execute(&mut *transaction)
+        .await?;
+        sqlx::query("UPDATE messages SET content = ? WHERE message_id = ?")
+            .bind(content)
+            .bind(message_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query(
+            "UPDATE messages SET content = json_set(content, '$.content', ?) \
+             WHERE conversation_id = ? AND category = ? AND quote_message_id = ? \
+             AND json_valid(content) AND json_extract(content, '$.content') IS NOT NULL",
+        )
+        .bind(content)
+        .bind(conversation_id)
+        .bind(MESSAGE_PIN)
+        .bind(message_id)
+        .execute(&mut *transaction)
+        .await?;
+        if let Some(job) = job {
+            Self::insert_job_with(&mut transaction, job).await?;
+        }
+        transaction.commit().await?;
+        self.update_message_quote_if_need(conversation_id, message_id)
+            .await?;
+        Ok(true)
+    }
+
     pub async fn delete_message(
         &self,
         conversation_id: &str,
@@ -2173,6 +2427,14 @@ ORDER BY message.created_at ASC, message.rowid ASC
             .bind(message_id)
             .execute(&mut *transaction)
             .await?;
+        sqlx::query("DELETE FROM message_edits WHERE message_id = ?")
+            .bind(message_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("DELETE FROM message_reactions WHERE message_id = ?")
+            .bind(message_id)
+            .execute(&mut *transaction)
+            .await?;
         crate::db::mixin::message_fts::delete_message_fts(&mut transaction, message_id).await?;
         sqlx::query("DELETE FROM transcript_messages WHERE transcript_id = ?")
             .bind(message_id)
@@ -2296,6 +2558,7 @@ mod tests {
 
     use super::*;
     use crate::db::mixin::conversation::{Conversation, ConversationStatus};
+    use crate::db::mixin::message_reaction::MessageReaction;
     use crate::db::mixin::MixinDatabase;
 
     async fn test_database() -> (tempfile::TempDir, MixinDatabase) {
@@ -2645,6 +2908,226 @@ mod tests {
         assert_eq!((recalled, jobs), (0, 2));
     }
 
+    #[tokio::test]
+    async fn requeues_only_failed_outgoing_messages() {
+        let (_directory, database) = test_database().await;
+        let mut failed = message("failed");
+        failed.status = MessageStatus::Failed;
+        database.message_dao.insert_message(&failed).await.unwrap();
+        database
+            .message_dao
+            .insert_message(&message("sending"))
+            .await
+            .unwrap();
+
+        let listed = database
+            .message_dao
+            .failed_outgoing_messages("sender", Some("conversation"), 10)
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].message_id, "failed");
+
+        for (message_id, expected) in [("failed", true), ("sending", false)] {
+            let job =
+                Job::create_sending_job(message_id, "conversation", None, None, false, false, 0);
+            assert_eq!(
+                database
+                    .message_dao
+                    .requeue_failed_message(message_id, &job)
+                    .await
+                    .unwrap(),
+                expected
+            );
+        }
+        let status: String =
+            sqlx::query_scalar("SELECT status FROM messages WHERE message_id = 'failed'")
+                .fetch_one(&database.message_dao.0)
+                .await
+                .unwrap();
+        let jobs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs")
+            .fetch_one(&database.message_dao.0)
+            .await
+            .unwrap();
+        assert_eq!((status.as_str(), jobs), ("SENDING", 1));
+    }
+
+    #[tokio::test]
+    async fn keeps_previous_versions_of_edited_messages() {
+        let (_directory, database) = test_database().await;
+        database
+            .message_dao
+            .insert_message(&message("text"))
+            .await
+            .unwrap();
+        database
+            .message_dao
+            .insert_message(&pin_event("pin", "text"))
+            .await
+            .unwrap();
+        let now = Utc::now();
+        let job = Job::create_send_edit_job("conversation", "text", "hello, world");
+
+        for (edit_id, content, edited_at, job, expected) in [
+            ("first", "hello, world", now, Some(&job), true),
+            ("first", "hello, world", now, None, false),
+            (
+                "stale",
+                "stale",
+                now - chrono::Duration::seconds(1),
+                None,
+                false,
+            ),
+            (
+                "second",
+                "hi",
+                now + chrono::Duration::seconds(1),
+                None,
+                true,
+            ),
+        ] {
+            assert_eq!(
+                database
+                    .message_dao
+                    .edit_message(edit_id, "conversation", "text", content, edited_at, job)
+                    .await
+                    .unwrap(),
+                expected
+            );
+        }
+
+        let encrypted = Message {
+            category: "SIGNAL_TEXT".to_string(),
+            ..message("encrypted")
+        };
+        database
+            .message_dao
+            .insert_message(&encrypted)
+            .await
+            .unwrap();
+        assert!(!database
+            .message_dao
+            .edit_message("third", "conversation", "encrypted", "hi", now, None)
+            .await
+            .unwrap());
+
+        let history = database.message_edit_dao.history("text").await.unwrap();
+        assert_eq!(
+            history
+                .iter()
+                .map(|edit| (edit.edit_id.as_str(), edit.content.as_str()))
+                .collect::<Vec<_>>(),
+            [("second", "hello, world"), ("first", "hello")]
+        );
+        let contents = database
+            .message_dao
+            .find_messages_by_ids(&["text".to_string(), "pin".to_string()])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|message| message.content.unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert!(contents.contains(&"hi".to_string()));
+        assert!(contents
+            .iter()
+            .any(|content| content.contains(r#""content":"hi""#)));
+        let jobs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs WHERE action = ?")
+            .bind(sdk::EDIT_MESSAGE)
+            .fetch_one(&database.message_dao.0)
+            .await
+            .unwrap();
+        assert_eq!(jobs, 1);
+
+        database
+            .message_dao
+            .delete_message("conversation", "text")
+            .await
+            .unwrap();
+        assert!(database
+            .message_edit_dao
+            .history("text")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn aggregates_reactions_by_emoji() {
+        let (_directory, database) = test_database().await;
+        database
+            .message_dao
+            .insert_message(&message("text"))
+            .await
+            .unwrap();
+        let reaction = |user_id: &str, emoji: &str| MessageReaction {
+            message_id: "text".to_string(),
+            conversation_id: "conversation".to_string(),
+            user_id: user_id.to_string(),
+            emoji: emoji.to_string(),
+            created_at: Utc::now(),
+        };
+        let job = Job::create_send_reaction_job("conversation", "text", "👍", false);
+        let dao = &database.message_reaction_dao;
+        assert!(dao
+            .apply(&reaction("me", "👍"), false, Some(&job))
+            .await
+            .unwrap());
+        assert!(!dao.apply(&reaction("me", "👍"), false, None).await.unwrap());
+        assert!(dao
+            .apply(&reaction("other", "👍"), false, None)
+            .await
+            .unwrap());
+        assert!(dao
+            .apply(&reaction("other", "🎉"), false, None)
+            .await
+            .unwrap());
+        assert!(dao
+            .apply(&reaction("other", "🎉"), true, None)
+            .await
+            .unwrap());
+        assert!(!dao
+            .apply(&reaction("other", "🎉"), true, None)
+            .await
+            .unwrap());
+        assert!(!dao
+            .apply(
+                &MessageReaction {
+                    message_id: "missing".to_string(),
+                    ..reaction("me", "👍")
+                },
+                false,
+                None,
+            )
+            .await
+            .unwrap());
+
+        let items = database
+            .message_dao
+            .list_items_by_ids(&["text".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(
+            items[0].reaction_groups(),
+            [ReactionGroup {
+                emoji: "👍".to_string(),
+                user_ids: vec!["me".to_string(), "other".to_string()],
+            }]
+        );
+        let jobs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs WHERE action = ?")
+            .bind(sdk::REACTION_MESSAGE)
+            .fetch_one(&database.message_dao.0)
+            .await
+            .unwrap();
+        assert_eq!(jobs, 1);
+
+        database
+            .message_dao
+            .delete_message("conversation", "text")
+            .await
+            .unwrap();
+        assert!(dao.reactions("text").await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn rolls_back_recall_jobs_when_any_message_is_missing() {
         let (_directory, database) = test_database().await;
@@ -3819,7 +4302,7 @@ mod tests {
         assert_eq!(pin_count, 0);
         assert!(database
             .message_fts_dao
-            .search("secret", None, None, &[], None, 10)
+            .search("secret", &Default::default(), Default::default(), None, 10)
             .await
             .unwrap()
             .is_empty());
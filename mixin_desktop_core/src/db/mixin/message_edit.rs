@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sqlx::Sqlite;
+
+use crate::db::Error;
+
+#[derive(Clone)]
+pub struct MessageEditDao(pub(crate) sqlx::Pool<Sqlite>);
+
+/// One replaced version of an edited message. `content` is the text the
+/// message carried before the edit identified by `edit_id` was applied.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct MessageEdit {
+    pub edit_id: String,
+    pub message_id: String,
+    pub conversation_id: String,
+    pub content: String,
+    #[sqlx(try_from = "crate::db::datetime::DatabaseDateTime")]
+    pub edited_at: DateTime<Utc>,
+}
+
+impl MessageEditDao {
+    pub async fn history(&self, message_id: &str) -> Result<Vec<MessageEdit>, Error> {
+        Ok(sqlx::query_as::<_, MessageEdit>(
+            "SELECT * FROM message_edits INDEXED BY index_message_edits_message_id_edited_at \
+             WHERE message_id = ? ORDER BY edited_at DESC, rowid DESC",
+        )
+        .bind(message_id)
+        .fetch_all(&self.0)
+        .await?)
+    }
+}
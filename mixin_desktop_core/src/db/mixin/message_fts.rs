@@ -1,4 +1,8 @@
-use sqlx::{FromRow, QueryBuilder, Row, Sqlite};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, Row, Sqlite};
 
 use sdk::blaze_message::MessageStatus;
 use sdk::message_category::MessageCategory;
@@ -6,13 +10,117 @@ use sdk::message_category::MessageCategory;
 use crate::db::mixin::message::Message;
 use crate::db::Error;
 
-#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+/// Characters of context kept before the first match of a snippet.
+const SNIPPET_CONTEXT: usize = 24;
+/// Characters of message text in a snippet, excluding ellipses.
+const SNIPPET_LENGTH: usize = 96;
+/// Relevance searches kept for paging, see [`RelevanceSnapshots`].
+const MAX_RELEVANCE_SNAPSHOTS: usize = 8;
+/// Results of a relevance search that can be paged through.
+const MAX_RELEVANCE_RESULTS: i64 = 1000;
+
+/// Indexed text of a message in `messages AS m`, with the shared contact in
+/// `users AS shared_user`.
+const FTS_CONTENT_SQL: &str = r#"CASE
+  WHEN m.category LIKE '%_TEXT' OR m.category LIKE '%_POST' THEN m.content
+  WHEN m.category LIKE '%_DATA' THEN m.name
+  WHEN m.category LIKE '%_CONTACT' THEN shared_user.full_name
+  WHEN m.category LIKE '%_LOCATION' AND json_valid(m.content) THEN
+    trim(coalesce(json_extract(m.content, '$.name'), '') || ' ' ||
+         coalesce(json_extract(m.content, '$.address'), ''))
+  WHEN m.category = 'APP_CARD' AND json_valid(m.content) THEN
+    trim(coalesce(json_extract(m.content, '$.title'), '') || ' ' ||
+         coalesce(json_extract(m.content, '$.description'), ''))
+  WHEN m.category LIKE '%_TRANSCRIPT' THEN (
+    SELECT group_concat(
+      CASE
+        WHEN tm.category LIKE '%_TEXT' OR tm.category LIKE '%_POST' THEN tm.content
+        WHEN tm.category LIKE '%_DATA' THEN tm.media_name
+        WHEN tm.category LIKE '%_CONTACT' THEN transcript_user.full_name
+        ELSE NULL
+      END, ' ')
+    FROM transcript_messages AS tm
+    LEFT JOIN users AS transcript_user ON transcript_user.user_id = tm.shared_user_id
+    WHERE tm.transcript_id = m.message_id
+  )
+  ELSE NULL
+END"#;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MessageFtsItem {
     pub message_id: String,
+    /// Excerpt of the message text around the first match. `None` when the
+    /// message no longer has searchable text.
+    pub snippet: Option<MessageSnippet>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageSnippet {
+    pub text: String,
+    /// Matched `[start, end)` ranges in `text`, as UTF-16 code unit offsets.
+    pub highlights: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MessageSearchFilter<'a> {
+    pub conversation_id: Option<&'a str>,
+    pub sender_id: Option<&'a str>,
+    pub categories: &'a [String],
+    /// Inclusive lower bound of the message creation time.
+    pub after: Option<DateTime<Utc>>,
+    /// Exclusive upper bound of the message creation time.
+    pub before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MessageSearchOrder {
+    #[default]
+    Newest,
+    /// Best bm25 score first, newest first among equal scores. Later pages
+    /// continue the ranking of the first one, up to 1000 results.
+    Relevance,
+}
+
+/// Conversation with messages matching a search, see
+/// [`MessageFtsDao::search_conversations`].
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct ConversationFtsHit {
+    pub conversation_id: String,
+    pub hit_count: i64,
+    /// Creation time of the newest matching message, in milliseconds.
+    pub latest_created_at: i64,
 }
 
 #[derive(Clone)]
-pub struct MessageFtsDao(pub(crate) sqlx::Pool<Sqlite>);
+pub struct MessageFtsDao(
+    pub(crate) sqlx::Pool<Sqlite>,
+    pub(crate) Arc<RelevanceSnapshots>,
+);
+
+/// Ranked message ids of recent relevance searches. bm25 scores change as
+/// messages are indexed, so later pages read the rank of their anchor from
+/// the snapshot taken for the first page instead of scoring it again.
+#[derive(Default)]
+pub(crate) struct RelevanceSnapshots(Mutex<VecDeque<(String, Arc<Vec<String>>)>>);
+
+impl RelevanceSnapshots {
+    fn get(&self, key: &str) -> Option<Arc<Vec<String>>> {
+        let snapshots = self.0.lock().unwrap_or_else(|error| error.into_inner());
+        snapshots
+            .iter()
+            .find(|(snapshot_key, _)| snapshot_key == key)
+            .map(|(_, message_ids)| message_ids.clone())
+    }
+
+    fn insert(&self, key: String, message_ids: Arc<Vec<String>>) {
+        let mut snapshots = self.0.lock().unwrap_or_else(|error| error.into_inner());
+        snapshots.retain(|(snapshot_key, _)| *snapshot_key != key);
+        if snapshots.len() >= MAX_RELEVANCE_SNAPSHOTS {
+            snapshots.pop_front();
+        }
+        snapshots.push_back((key, message_ids));
+    }
+}
 
 impl MessageFtsDao {
     pub async fn upsert(
@@ -80,39 +188,20 @@ impl MessageFtsDao {
 
     pub(crate) async fn migrate_batch(&self, anchor: Option<i64>) -> Result<Option<i64>, Error> {
         let mut transaction = self.0.begin_with("BEGIN IMMEDIATE").await?;
-        let rows = sqlx::query(
+        let sql = format!(
             r#"SELECT m.rowid AS source_rowid, m.message_id, m.conversation_id, m.category, m.user_id,
                CASE WHEN typeof(m.created_at) = 'integer' THEN m.created_at
                     ELSE CAST(strftime('%s', m.created_at) AS INTEGER) * 1000 END AS created_at,
-               CASE
-                 WHEN m.category LIKE '%_TEXT' OR m.category LIKE '%_POST' THEN m.content
-                 WHEN m.category LIKE '%_DATA' THEN m.name
-                 WHEN m.category LIKE '%_CONTACT' THEN shared_user.full_name
-                 WHEN m.category = 'APP_CARD' AND json_valid(m.content) THEN
-                   trim(coalesce(json_extract(m.content, '$.title'), '') || ' ' ||
-                        coalesce(json_extract(m.content, '$.description'), ''))
-                 WHEN m.category LIKE '%_TRANSCRIPT' THEN (
-                   SELECT group_concat(
-                     CASE
-                       WHEN tm.category LIKE '%_TEXT' OR tm.category LIKE '%_POST' THEN tm.content
-                       WHEN tm.category LIKE '%_DATA' THEN tm.media_name
-                       WHEN tm.category LIKE '%_CONTACT' THEN transcript_user.full_name
-                       ELSE NULL
-                     END, ' ')
-                   FROM transcript_messages AS tm
-                   LEFT JOIN users AS transcript_user ON transcript_user.user_id = tm.shared_user_id
-                   WHERE tm.transcript_id = m.message_id
-                 )
-                 ELSE NULL
-               END AS fts_content
+               {FTS_CONTENT_SQL} AS fts_content
                FROM messages AS m
                LEFT JOIN users AS shared_user ON shared_user.user_id = m.shared_user_id
                WHERE m.rowid > ? AND m.status NOT IN ('UNKNOWN', 'FAILED')
-               ORDER BY m.rowid LIMIT 1000"#,
-        )
-        .bind(anchor.unwrap_or(0))
-        .fetch_all(&mut *transaction)
-        .await?;
+               ORDER BY m.rowid LIMIT 1000"#
+        );
+        let rows = sqlx::query(&sql)
+            .bind(anchor.unwrap_or(0))
+            .fetch_all(&mut *transaction)
+            .await?;
         let next_anchor = rows.last().map(|row| row.get::<i64, _>("source_rowid"));
         for row in rows {
             let message_id = row.get::<String, _>("message_id");
@@ -158,23 +247,58 @@ impl MessageFtsDao {
     pub async fn search(
         &self,
         query: &str,
-        conversation_id: Option<&str>,
-        sender_id: Option<&str>,
-        categories: &[String],
+        filter: &MessageSearchFilter<'_>,
+        order: MessageSearchOrder,
         anchor_message_id: Option<&str>,
         limit: u32,
     ) -> Result<Vec<MessageFtsItem>, Error> {
-        let Some(query) = match_query(query) else {
+        let Some(match_query) = match_query(query) else {
             return Ok(Vec::new());
         };
         if limit == 0 {
             return Ok(Vec::new());
         }
 
+        let message_ids = match order {
+            MessageSearchOrder::Newest => {
+                self.newest_message_ids(match_query, filter, anchor_message_id, limit)
+                    .await?
+            }
+            MessageSearchOrder::Relevance => {
+                self.ranked_message_ids(match_query, filter, anchor_message_id, limit)
+                    .await?
+            }
+        };
+
+        let mut contents = self.fts_contents(&message_ids).await?;
+        Ok(message_ids
+            .into_iter()
+            .map(|message_id| {
+                let snippet = contents
+                    .remove(&message_id)
+                    .and_then(|content| snippet(&content, query));
+                MessageFtsItem {
+                    message_id,
+                    snippet,
+                }
+            })
+            .collect())
+    }
+
+    async fn newest_message_ids(
+        &self,
+        match_query: String,
+        filter: &MessageSearchFilter<'_>,
+        anchor_message_id: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<String>, Error> {
         let anchor = if let Some(message_id) = anchor_message_id {
             let Some(anchor) = sqlx::query_as::<_, (i64, i64)>(
-                "SELECT created_at, rowid FROM fts.messages_metas WHERE message_id = ? LIMIT 1",
+                "SELECT meta.created_at, meta.rowid FROM fts.messages_fts \
+                 JOIN fts.messages_metas AS meta ON meta.doc_id = messages_fts.rowid \
+                 WHERE messages_fts MATCH ? AND meta.message_id = ? LIMIT 1",
             )
+            .bind(&match_query)
             .bind(message_id)
             .fetch_optional(&self.0)
             .await?
@@ -185,29 +309,7 @@ impl MessageFtsDao {
         } else {
             None
         };
-
-        let mut builder = QueryBuilder::<Sqlite>::new(
-            "SELECT meta.message_id FROM fts.messages_metas meta \
-             WHERE meta.doc_id IN (SELECT rowid FROM fts.messages_fts \
-             WHERE messages_fts MATCH ",
-        );
-        builder.push_bind(query).push(")");
-        if let Some(conversation_id) = conversation_id {
-            builder
-                .push(" AND meta.conversation_id = ")
-                .push_bind(conversation_id);
-        }
-        if let Some(sender_id) = sender_id {
-            builder.push(" AND meta.user_id = ").push_bind(sender_id);
-        }
-        if !categories.is_empty() {
-            builder.push(" AND meta.category IN (");
-            let mut separated = builder.separated(", ");
-            for category in categories {
-                separated.push_bind(category);
-            }
-            separated.push_unseparated(")");
-        }
+        let mut builder = search_query(match_query, filter);
         if let Some((created_at, row_id)) = anchor {
             builder
                 .push(" AND (meta.created_at < ")
@@ -218,14 +320,169 @@ impl MessageFtsDao {
                 .push_bind(row_id)
                 .push("))");
         }
-        builder
-            .push(" ORDER BY meta.created_at DESC, meta.rowid DESC LIMIT ")
-            .push_bind(limit.clamp(1, 200));
+        builder.push(" ORDER BY meta.created_at DESC, meta.rowid DESC LIMIT ");
+        builder.push_bind(limit.clamp(1, 200));
         Ok(builder
-            .build_query_as::<MessageFtsItem>()
+            .build_query_scalar::<String>()
             .fetch_all(&self.0)
             .await?)
     }
+
+    /// Pages through the snapshot of the search that returned the anchor,
+    /// ranking the search again when the snapshot is gone.
+    async fn ranked_message_ids(
+        &self,
+        match_query: String,
+        filter: &MessageSearchFilter<'_>,
+        anchor_message_id: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<String>, Error> {
+        let key = format!(
+            "{match_query}\u{1f}{:?}\u{1f}{:?}\u{1f}{:?}\u{1f}{:?}\u{1f}{:?}",
+            filter.conversation_id,
+            filter.sender_id,
+            filter.categories,
+            filter.after,
+            filter.before
+        );
+        let position = |message_ids: &[String], anchor: &str| {
+            message_ids
+                .iter()
+                .position(|message_id| message_id == anchor)
+        };
+        let snapshot = anchor_message_id.and_then(|anchor| {
+            self.1
+                .get(&key)
+                .filter(|message_ids| position(message_ids, anchor).is_some())
+        });
+        let message_ids = match snapshot {
+            Some(message_ids) => message_ids,
+            None => {
+                let mut builder = search_query(match_query, filter);
+                builder.push(" ORDER BY bm25(messages_fts), meta.rowid DESC LIMIT ");
+                builder.push_bind(MAX_RELEVANCE_RESULTS);
+                let message_ids = Arc::new(
+                    builder
+                        .build_query_scalar::<String>()
+                        .fetch_all(&self.0)
+                        .await?,
+                );
+                self.1.insert(key, message_ids.clone());
+                message_ids
+            }
+        };
+        let start = match anchor_message_id {
+            Some(anchor) => match position(&message_ids, anchor) {
+                Some(index) => index + 1,
+                None => return Ok(Vec::new()),
+            },
+            None => 0,
+        };
+        Ok(message_ids
+            .iter()
+            .skip(start)
+            .take(limit.clamp(1, 200) as usize)
+            .cloned()
+            .collect())
+    }
+
+    /// Groups matching messages by conversation, most hits first.
+    pub async fn search_conversations(
+        &self,
+        query: &str,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<ConversationFtsHit>, Error> {
+        let Some(match_query) = match_query(query) else {
+            return Ok(Vec::new());
+        };
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(sqlx::query_as::<_, ConversationFtsHit>(
+            "SELECT meta.conversation_id, COUNT(*) AS hit_count, \
+                    MAX(meta.created_at) AS latest_created_at \
+             FROM fts.messages_fts \
+             JOIN fts.messages_metas AS meta ON meta.doc_id = messages_fts.rowid \
+             WHERE messages_fts MATCH ? \
+             GROUP BY meta.conversation_id \
+             ORDER BY hit_count DESC, latest_created_at DESC, meta.conversation_id \
+             LIMIT ? OFFSET ?",
+        )
+        .bind(match_query)
+        .bind(limit.clamp(1, 200))
+        .bind(offset)
+        .fetch_all(&self.0)
+        .await?)
+    }
+
+    async fn fts_contents(&self, message_ids: &[String]) -> Result<HashMap<String, String>, Error> {
+        if message_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let mut builder = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT m.message_id, {FTS_CONTENT_SQL} AS fts_content FROM messages AS m \
+             LEFT JOIN users AS shared_user ON shared_user.user_id = m.shared_user_id \
+             WHERE m.message_id IN ("
+        ));
+        let mut separated = builder.separated(", ");
+        for message_id in message_ids {
+            separated.push_bind(message_id);
+        }
+        separated.push_unseparated(")");
+        Ok(builder
+            .build_query_as::<(String, Option<String>)>()
+            .fetch_all(&self.0)
+            .await?
+            .into_iter()
+            .filter_map(|(message_id, content)| Some((message_id, content?)))
+            .collect())
+    }
+}
+
+/// Selects the ids of messages matching `match_query` and `filter`, ready
+/// for more conditions and an `ORDER BY`.
+fn search_query(match_query: String, filter: &MessageSearchFilter<'_>) -> QueryBuilder<Sqlite> {
+    let mut builder = QueryBuilder::<Sqlite>::new(
+        "SELECT meta.message_id FROM fts.messages_fts \
+         JOIN fts.messages_metas AS meta ON meta.doc_id = messages_fts.rowid \
+         WHERE messages_fts MATCH ",
+    );
+    builder.push_bind(match_query);
+    if let Some(conversation_id) = filter.conversation_id {
+        builder
+            .push(" AND meta.conversation_id = ")
+            .push_bind(conversation_id);
+    }
+    if let Some(sender_id) = filter.sender_id {
+        builder.push(" AND meta.user_id = ").push_bind(sender_id);
+    }
+    if !filter.categories.is_empty() {
+        builder.push(" AND meta.category IN (");
+        let mut separated = builder.separated(", ");
+        for category in filter.categories {
+            separated.push_bind(category);
+        }
+        separated.push_unseparated(")");
+    }
+    if let Some(after) = filter.after {
+        builder
+            .push(" AND meta.created_at >= ")
+            .push_bind(ceil_millis(after));
+    }
+    if let Some(before) = filter.before {
+        builder
+            .push(" AND meta.created_at < ")
+            .push_bind(ceil_millis(before));
+    }
+    builder
+}
+
+/// Message timestamps are indexed in milliseconds, so bounds round up to keep
+/// sub-millisecond precision from widening the range.
+fn ceil_millis(time: DateTime<Utc>) -> i64 {
+    let micros = time.timestamp_micros();
+    micros.div_euclid(1000) + i64::from(micros.rem_euclid(1000) != 0)
 }
 
 pub(crate) fn message_fts_content(message: &Message) -> Option<String> {
@@ -241,6 +498,17 @@ pub(crate) fn message_fts_content(message: &Message) -> Option<String> {
     if message.category.is_data() || message.category.is_contact() {
         return message.name.clone();
     }
+    if message.category.is_location() {
+        let location =
+            serde_json::from_str::<sdk::LocationMessage>(message.content.as_deref()?).ok()?;
+        let content = [location.name, location.address]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        return (!content.is_empty()).then_some(content);
+    }
     if message.category.is_app_card() {
         let card = serde_json::from_str::<sdk::AppCard>(message.content.as_deref()?).ok()?;
         return Some(format!("{} {}", card.title, card.description));
@@ -288,36 +556,178 @@ pub(crate) async fn delete_conversation_fts(
     )
 }
 
-fn normalize_content(content: &str) -> String {
-    let mut normalized = String::with_capacity(content.len());
-    let mut previous_was_ascii_alphanumeric = false;
-    for character in content.trim().chars() {
-        let is_ascii_alphanumeric = character.is_ascii_alphanumeric();
-        if previous_was_ascii_alphanumeric && !is_ascii_alphanumeric {
-            normalized.push(' ');
+enum Segment {
+    Word(String),
+    Cjk(Vec<char>),
+}
+
+/// Splits content into ASCII alphanumeric words, runs of CJK characters and
+/// single other characters.
+fn segments(content: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut characters = content.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character.is_ascii_alphanumeric() {
+            let mut word = character.to_string();
+            while let Some(next) = characters.next_if(char::is_ascii_alphanumeric) {
+                word.push(next);
+            }
+            segments.push(Segment::Word(word));
+        } else if is_cjk(character) {
+            let mut run = vec![character];
+            while let Some(next) = characters.next_if(|next| is_cjk(*next)) {
+                run.push(next);
+            }
+            segments.push(Segment::Cjk(run));
+        } else if !character.is_whitespace() {
+            segments.push(Segment::Word(character.to_string()));
         }
-        normalized.push(character);
-        if !is_ascii_alphanumeric {
-            normalized.push(' ');
+    }
+    segments
+}
+
+fn is_cjk(character: char) -> bool {
+    matches!(
+        character,
+        '\u{1100}'..='\u{11FF}'
+            | '\u{3040}'..='\u{30FF}'
+            | '\u{3130}'..='\u{318F}'
+            | '\u{31F0}'..='\u{31FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FF66}'..='\u{FF9F}'
+            | '\u{20000}'..='\u{2FA1F}'
+    )
+}
+
+fn bigrams(run: &[char]) -> impl Iterator<Item = String> + '_ {
+    run.windows(2).map(|pair| pair.iter().collect())
+}
+
+/// `unicode61` keeps a run of CJK characters as a single token, so such runs
+/// are stored as overlapping bigrams followed by their last character. Any
+/// substring of two or more characters is then a phrase of adjacent bigrams,
+/// and a single character is the prefix of some token.
+fn normalize_content(content: &str) -> String {
+    let mut tokens = Vec::new();
+    for segment in segments(content) {
+        match segment {
+            Segment::Word(word) => tokens.push(word),
+            Segment::Cjk(run) => {
+                tokens.extend(bigrams(&run));
+                tokens.push(run[run.len() - 1].to_string());
+            }
         }
-        previous_was_ascii_alphanumeric = is_ascii_alphanumeric;
     }
-    normalized
+    tokens.join(" ")
 }
 
 fn match_query(query: &str) -> Option<String> {
-    let normalized = normalize_content(&query.replace('"', ""));
-    let tokens = normalized
-        .split_whitespace()
-        .map(|token| format!("\"{token}\"*"))
+    let tokens = segments(&query.replace('"', ""))
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Cjk(run) if run.len() > 1 => {
+                format!("\"{}\"", bigrams(&run).collect::<Vec<_>>().join(" "))
+            }
+            Segment::Cjk(run) => format!("\"{}\"*", run[0]),
+            Segment::Word(word) => format!("\"{word}\"*"),
+        })
         .collect::<Vec<_>>();
     (!tokens.is_empty()).then(|| tokens.join(" "))
 }
 
+/// Builds a single-line excerpt of `content` around the first place `query`
+/// matches. The indexed column holds normalized tokens rather than the
+/// message text, so FTS5's own `snippet()` cannot be used here.
+fn snippet(content: &str, query: &str) -> Option<MessageSnippet> {
+    let characters = content
+        .trim()
+        .chars()
+        .map(|character| {
+            if character.is_whitespace() {
+                ' '
+            } else {
+                character
+            }
+        })
+        .collect::<Vec<_>>();
+    if characters.is_empty() {
+        return None;
+    }
+
+    let mut matches = Vec::new();
+    for segment in segments(&query.replace('"', "")) {
+        let (term, whole_word_start) = match segment {
+            Segment::Word(word) => {
+                let starts_word = word.starts_with(|c: char| c.is_ascii_alphanumeric());
+                (word.chars().collect::<Vec<_>>(), starts_word)
+            }
+            Segment::Cjk(run) => (run, false),
+        };
+        for start in 0..characters.len().saturating_sub(term.len() - 1) {
+            let candidate = &characters[start..start + term.len()];
+            let at_word_start =
+                !whole_word_start || start == 0 || !characters[start - 1].is_ascii_alphanumeric();
+            if at_word_start
+                && candidate
+                    .iter()
+                    .zip(&term)
+                    .all(|(left, right)| left.eq_ignore_ascii_case(right))
+            {
+                matches.push((start, start + term.len()));
+            }
+        }
+    }
+    matches.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in matches {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let first = merged.first().map_or(0, |range| range.0);
+    let mut window_start = first.saturating_sub(SNIPPET_CONTEXT);
+    let window_end = (window_start + SNIPPET_LENGTH).min(characters.len());
+    window_start = window_start.min(window_end.saturating_sub(SNIPPET_LENGTH));
+
+    let mut text = String::new();
+    if window_start > 0 {
+        text.push('…');
+    }
+    let prefix = text.encode_utf16().count();
+    let mut utf16_offsets = Vec::with_capacity(window_end - window_start + 1);
+    let mut offset = prefix;
+    for character in &characters[window_start..window_end] {
+        utf16_offsets.push(offset);
+        offset += character.len_utf16();
+        text.push(*character);
+    }
+    utf16_offsets.push(offset);
+    if window_end < characters.len() {
+        text.push('…');
+    }
+    let highlights = merged
+        .into_iter()
+        .filter(|(start, end)| *end > window_start && *start < window_end)
+        .map(|(start, end)| {
+            (
+                utf16_offsets[start.max(window_start) - window_start],
+                utf16_offsets[end.min(window_end) - window_start],
+            )
+        })
+        .collect();
+    Some(MessageSnippet { text, highlights })
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
 
+    use super::{ConversationFtsHit, MessageSearchFilter, MessageSearchOrder, MessageSnippet};
     use crate::db::mixin::message::Message;
     use crate::db::mixin::MixinDatabase;
 
@@ -363,7 +773,13 @@ mod tests {
         assert_eq!(
             database
                 .message_fts_dao
-                .search("hello", None, None, &[], None, 2)
+                .search(
+                    "hello",
+                    &MessageSearchFilter::default(),
+                    MessageSearchOrder::Newest,
+                    None,
+                    2
+                )
                 .await
                 .unwrap()
                 .into_iter()
@@ -376,9 +792,13 @@ mod tests {
                 .message_fts_dao
                 .search(
                     "hello",
-                    Some("conversation"),
-                    Some("alice"),
-                    &["PLAIN_TEXT".into()],
+                    &MessageSearchFilter {
+                        conversation_id: Some("conversation"),
+                        sender_id: Some("alice"),
+                        categories: &["PLAIN_TEXT".into()],
+                        ..Default::default()
+                    },
+                    MessageSearchOrder::Newest,
                     Some("new"),
                     10,
                 )
@@ -393,6 +813,293 @@ mod tests {
             .fetch_one(&database.message_fts_dao.0)
             .await
             .unwrap();
-        assert_eq!(version, 1);
+        assert_eq!(version, 2);
+    }
+
+    #[test]
+    fn splits_cjk_runs_into_bigrams() {
+        assert_eq!(
+            super::normalize_content("hello世界和平, ok"),
+            "hello 世界 界和 和平 平 , ok"
+        );
+        assert_eq!(
+            super::match_query("和平 h").as_deref(),
+            Some("\"和平\" \"h\"*")
+        );
+        assert_eq!(super::match_query("世").as_deref(), Some("\"世\"*"));
+    }
+
+    #[test]
+    fn highlights_matches_inside_snippets() {
+        assert_eq!(
+            super::snippet("Say hello to\nHELLOworld, not shello", "hello"),
+            Some(MessageSnippet {
+                text: "Say hello to HELLOworld, not shello".into(),
+                highlights: vec![(4, 9), (13, 18)],
+            })
+        );
+        let long = format!("{}😀北京{}", "a ".repeat(40), " b".repeat(80));
+        let snippet = super::snippet(&long, "北京").unwrap();
+        let start = snippet.text.find('北').unwrap();
+        let utf16_start = snippet.text[..start].encode_utf16().count();
+        assert!(snippet.text.starts_with('…') && snippet.text.ends_with('…'));
+        assert_eq!(snippet.highlights, [(utf16_start, utf16_start + 2)]);
+    }
+
+    #[tokio::test]
+    async fn bounds_and_ranks_search_results() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO conversations (conversation_id, created_at, status) \
+             VALUES ('conversation', 0, 0)",
+        )
+        .execute(&database.message_dao.0)
+        .await
+        .unwrap();
+        let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        for (message_id, content, day) in [
+            ("once", "deploy the mixin release", 0),
+            ("twice", "mixin mixin", 1),
+            ("latest", "a long note that mentions mixin once", 2),
+        ] {
+            database
+                .message_dao
+                .insert_message(&Message {
+                    message_id: message_id.into(),
+                    conversation_id: "conversation".into(),
+                    user_id: "user".into(),
+                    category: "PLAIN_TEXT".into(),
+                    content: Some(content.into()),
+                    status: sdk::blaze_message::MessageStatus::Sent,
+                    created_at: (base + chrono::Duration::days(day)).naive_utc(),
+                    ..Message::default()
+                })
+                .await
+                .unwrap();
+            database
+                .message_fts_dao
+                .upsert(message_id, "conversation", content)
+                .await
+                .unwrap();
+        }
+        let search = |filter: MessageSearchFilter<'static>, order, anchor| {
+            let database = &database;
+            async move {
+                database
+                    .message_fts_dao
+                    .search("mixin", &filter, order, anchor, 10)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|item| item.message_id)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let bounded = MessageSearchFilter {
+            after: Some(base + chrono::Duration::days(1)),
+            before: Some(base + chrono::Duration::days(2)),
+            ..Default::default()
+        };
+        assert_eq!(
+            search(bounded, MessageSearchOrder::Newest, None).await,
+            ["twice"]
+        );
+        let ranked = search(
+            MessageSearchFilter::default(),
+            MessageSearchOrder::Relevance,
+            None,
+        )
+        .await;
+        assert_eq!(ranked[0], "twice");
+
+        // Scores shift once more messages are indexed; later pages keep the
+        // ranking of the first one.
+        database
+            .message_dao
+            .insert_message(&Message {
+                message_id: "newer".into(),
+                conversation_id: "conversation".into(),
+                user_id: "user".into(),
+                category: "PLAIN_TEXT".into(),
+                content: Some("mixin mixin mixin".into()),
+                status: sdk::blaze_message::MessageStatus::Sent,
+                created_at: (base + chrono::Duration::days(3)).naive_utc(),
+                ..Message::default()
+            })
+            .await
+            .unwrap();
+        database
+            .message_fts_dao
+            .upsert("newer", "conversation", "mixin mixin mixin")
+            .await
+            .unwrap();
+        assert_eq!(
+            search(
+                MessageSearchFilter::default(),
+                MessageSearchOrder::Relevance,
+                Some("twice")
+            )
+            .await,
+            ranked[1..]
+        );
+        assert_eq!(
+            search(
+                MessageSearchFilter::default(),
+                MessageSearchOrder::Relevance,
+                None
+            )
+            .await[0],
+            "newer"
+        );
+
+        let item = database
+            .message_fts_dao
+            .search(
+                "release",
+                &MessageSearchFilter::default(),
+                MessageSearchOrder::Newest,
+                None,
+                10,
+            )
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(
+            item.snippet.unwrap(),
+            MessageSnippet {
+                text: "deploy the mixin release".into(),
+                highlights: vec![(17, 24)],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn groups_search_hits_by_conversation() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO conversations (conversation_id, created_at, status) \
+             VALUES ('quiet', 0, 0), ('busy', 0, 0)",
+        )
+        .execute(&database.message_dao.0)
+        .await
+        .unwrap();
+        let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        for (message_id, conversation_id, content, day) in [
+            ("first", "busy", "mixin release", 0),
+            ("second", "busy", "another mixin build", 1),
+            ("third", "quiet", "mixin", 2),
+            ("other", "quiet", "unrelated", 3),
+        ] {
+            database
+                .message_dao
+                .insert_message(&Message {
+                    message_id: message_id.into(),
+                    conversation_id: conversation_id.into(),
+                    user_id: "user".into(),
+                    category: "PLAIN_TEXT".into(),
+                    content: Some(content.into()),
+                    status: sdk::blaze_message::MessageStatus::Sent,
+                    created_at: (base + chrono::Duration::days(day)).naive_utc(),
+                    ..Message::default()
+                })
+                .await
+                .unwrap();
+            database
+                .message_fts_dao
+                .upsert(message_id, conversation_id, content)
+                .await
+                .unwrap();
+        }
+
+        let hits = database
+            .message_fts_dao
+            .search_conversations("mixin", 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(
+            hits,
+            [
+                ConversationFtsHit {
+                    conversation_id: "busy".into(),
+                    hit_count: 2,
+                    latest_created_at: (base + chrono::Duration::days(1)).timestamp_millis(),
+                },
+                ConversationFtsHit {
+                    conversation_id: "quiet".into(),
+                    hit_count: 1,
+                    latest_created_at: (base + chrono::Duration::days(2)).timestamp_millis(),
+                },
+            ]
+        );
+        let next = database
+            .message_fts_dao
+            .search_conversations("mixin", 10, 1)
+            .await
+            .unwrap();
+        assert_eq!(next, hits[1..]);
+    }
+
+    #[tokio::test]
+    async fn finds_words_inside_cjk_sentences() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO conversations (conversation_id, created_at, status) \
+             VALUES ('conversation', 0, 0)",
+        )
+        .execute(&database.message_dao.0)
+        .await
+        .unwrap();
+        let content = "我们明天去北京吃饭";
+        database
+            .message_dao
+            .insert_message(&Message {
+                message_id: "message".into(),
+                conversation_id: "conversation".into(),
+                user_id: "user".into(),
+                category: "PLAIN_TEXT".into(),
+                content: Some(content.into()),
+                status: sdk::blaze_message::MessageStatus::Sent,
+                created_at: Utc::now().naive_utc(),
+                ..Message::default()
+            })
+            .await
+            .unwrap();
+        database
+            .message_fts_dao
+            .upsert("message", "conversation", content)
+            .await
+            .unwrap();
+
+        for (query, found) in [
+            ("北京", true),
+            ("明天去北京", true),
+            ("饭", true),
+            ("京", true),
+            ("上海", false),
+            ("北吃", false),
+        ] {
+            let items = database
+                .message_fts_dao
+                .search(
+                    query,
+                    &MessageSearchFilter::default(),
+                    MessageSearchOrder::Newest,
+                    None,
+                    10,
+                )
+                .await
+                .unwrap();
+            assert_eq!(!items.is_empty(), found, "{query}");
+        }
     }
 }
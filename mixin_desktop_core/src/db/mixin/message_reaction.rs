@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use sqlx::Sqlite;
+
+use crate::db::mixin::job::Job;
+use crate::db::mixin::message::MessageDao;
+use crate::db::Error;
+
+#[derive(Clone)]
+pub struct MessageReactionDao(pub(crate) sqlx::Pool<Sqlite>);
+
+/// One user's reaction to a message. A user may react with several emoji, but
+/// only once with each.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct MessageReaction {
+    pub message_id: String,
+    pub conversation_id: String,
+    pub user_id: String,
+    pub emoji: String,
+    #[sqlx(try_from = "crate::db::datetime::DatabaseDateTime")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl MessageReactionDao {
+    /// Adds or, when `removed` is set, withdraws a reaction and queues `job`
+    /// in the same transaction. Returns `false` without changes when the
+    /// message is not in the conversation or the reaction is already in the
+    /// requested state.
+    pub async fn apply(
+        &self,
+        reaction: &MessageReaction,
+        removed: bool,
+        job: Option<&Job>,
+    ) -> Result<bool, Error> {
+        let mut transaction = self.0.begin_with("BEGIN IMMEDIATE").await?;
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM messages WHERE conversation_id = ? AND message_id = ?)",
+        )
+        .bind(&reaction.conversation_id)
+        .bind(&reaction.message_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+        if !exists {
+            return Ok(false);
+        }
+        let changed = if removed {
+            sqlx::query(
+                "DELETE FROM message_reactions WHERE message_id = ? AND user_id = ? AND emoji = ?",
+            )
+            .bind(&reaction.message_id)
+            .bind(&reaction.user_id)
+            .bind(&reaction.emoji)
+            .execute(&mut *transaction)
+            .await?
+        } else {
+            sqlx::query(
+                "INSERT OR IGNORE INTO message_reactions \
+                 (message_id, conversation_id, user_id, emoji, created_at) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&reaction.message_id)
+            .bind(&reaction.conversation_id)
+            .bind(&reaction.user_id)
+            .bind(&reaction.emoji)
+            .bind(reaction.created_at.timestamp_millis())
+            .execute(&mut *transaction)
+            .await?
+        }
+        .rows_affected()
+            > 0;
+        if !changed {
+            return Ok(false);
+        }
+        if let Some(job) = job {
+            MessageDao::insert_job_with(&mut transaction, job).await?;
+        }
+        transaction.commit().await?;
+        Ok(true)
+    }
+
+    pub async fn reactions(&self, message_id: &str) -> Result<Vec<MessageReaction>, Error> {
+        Ok(sqlx::query_as::<_, MessageReaction>(
+            "SELECT * FROM message_reactions WHERE message_id = ? ORDER BY created_at, rowid",
+        )
+        .bind(message_id)
+        .fetch_all(&self.0)
+        .await?)
+    }
+}
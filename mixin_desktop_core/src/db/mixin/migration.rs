@@ -82,9 +82,29 @@ const MIGRATIONS: &[Migration] = &[
     Migration::action(26, "add inscriptions", migrate_to_v26),
     Migration::action(27, "add memberships", migrate_to_v27),
     Migration::action(28, "add token precision", migrate_to_v28),
+    Migration::action(29, "add job failure details", migrate_to_v29),
+    Migration::action(30, "add message edit history", migrate_to_v30),
+    Migration::action(31, "add message reactions", migrate_to_v31),
+    Migration::action(32, "re-index FTS content with CJK bigrams", migrate_to_v32),
+    Migration::action(33, "add participant events", migrate_to_v33),
+    Migration::sql(
+        34,
+        "add smart circles",
+        "CREATE TABLE smart_circles (circle_id TEXT NOT NULL, name TEXT NOT NULL, \
+         rules TEXT NOT NULL, match_all BOOLEAN NOT NULL, created_at INTEGER NOT NULL, \
+         PRIMARY KEY(circle_id))",
+    ),
+    Migration::sql(
+        35,
+        "add user annotations",
+        "CREATE TABLE user_annotations (user_id TEXT NOT NULL, alias TEXT, note TEXT, \
+         updated_at INTEGER NOT NULL, PRIMARY KEY(user_id))",
+    ),
+    Migration::action(36, "add job next run time", migrate_to_v36),
+    Migration::action(37, "add join reviews", migrate_to_v37),
 ];
 
-pub(crate) const SCHEMA_VERSION: i64 = 28;
+pub(crate) const SCHEMA_VERSION: i64 = 37;
 pub(crate) const MIGRATOR: Migrator = Migrator::new(
     "mixin",
     SCHEMA_VERSION,
@@ -339,6 +359,96 @@ fn migrate_to_v28(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
     )
 }
 
+fn migrate_to_v29(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    add_column_boxed(connection, "jobs", "last_error", "TEXT")
+}
+
+fn migrate_to_v30(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    async move {
+        sqlx::raw_sql(
+            r#"CREATE TABLE IF NOT EXISTS message_edits (
+    edit_id TEXT NOT NULL, message_id TEXT NOT NULL, conversation_id TEXT NOT NULL,
+    content TEXT NOT NULL, edited_at INTEGER NOT NULL, PRIMARY KEY(edit_id)
+);
+CREATE INDEX IF NOT EXISTS index_message_edits_message_id_edited_at
+    ON message_edits(message_id, edited_at);"#,
+        )
+        .execute(connection)
+        .await?;
+        Ok(())
+    }
+    .boxed()
+}
+
+fn migrate_to_v31(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    async move {
+        sqlx::raw_sql(
+            r#"CREATE TABLE IF NOT EXISTS message_reactions (
+    message_id TEXT NOT NULL, conversation_id TEXT NOT NULL, user_id TEXT NOT NULL,
+    emoji TEXT NOT NULL, created_at INTEGER NOT NULL, PRIMARY KEY(message_id, user_id, emoji)
+);
+CREATE INDEX IF NOT EXISTS index_message_reactions_conversation_id
+    ON message_reactions(conversation_id);"#,
+        )
+        .execute(connection)
+        .await?;
+        Ok(())
+    }
+    .boxed()
+}
+
+fn migrate_to_v32(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    async move {
+        // fts.db v2 drops the old index, so every message has to be indexed
+        // again from the start.
+        sqlx::query("DELETE FROM jobs WHERE action = 'LOCAL_MIGRATE_FTS'")
+            .execute(&mut *connection)
+            .await?;
+        sqlx::query(
+            "INSERT INTO jobs (job_id, action, created_at, priority, run_count) \
+             VALUES (?, 'LOCAL_MIGRATE_FTS', ?, 5, 0)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(chrono::Utc::now().timestamp_millis())
+        .execute(connection)
+        .await?;
+        Ok(())
+    }
+    .boxed()
+}
+
+fn migrate_to_v33(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    async move {
+        sqlx::raw_sql(
+            r#"CREATE TABLE IF NOT EXISTS participant_events (
+    message_id TEXT NOT NULL, conversation_id TEXT NOT NULL, "action" TEXT NOT NULL,
+    actor_id TEXT NOT NULL, participant_id TEXT NOT NULL, role TEXT,
+    created_at INTEGER NOT NULL, PRIMARY KEY(message_id)
+);
+CREATE INDEX IF NOT EXISTS index_participant_events_conversation_id_created_at
+    ON participant_events(conversation_id, created_at DESC);
+INSERT OR IGNORE INTO participant_events
+    (message_id, conversation_id, "action", actor_id, participant_id, role, created_at)
+SELECT message_id, conversation_id, "action", user_id, COALESCE(participant_id, ''), NULL,
+       created_at
+FROM messages
+WHERE category = 'SYSTEM_CONVERSATION' AND "action" IS NOT NULL;"#,
+        )
+        .execute(connection)
+        .await?;
+        Ok(())
+    }
+    .boxed()
+}
+
+fn migrate_to_v36(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    add_column_boxed(connection, "jobs", "next_run_at", "TIMESTAMP")
+}
+
+fn migrate_to_v37(connection: &mut SqliteConnection) -> MigrationFuture<'_> {
+    add_column_boxed(connection, "participant_events", "reviewed_at", "TIMESTAMP")
+}
+
 fn add_column_boxed<'a>(
     connection: &'a mut SqliteConnection,
     table: &'static str,
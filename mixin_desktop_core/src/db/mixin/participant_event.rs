@@ -0,0 +1,283 @@
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, Sqlite};
+
+use crate::db::Error;
+
+#[derive(Clone)]
+pub struct ParticipantEventDao(pub(crate) sqlx::Pool<Sqlite>);
+
+/// A membership change announced by a `SYSTEM_CONVERSATION` message, kept even
+/// when the message itself is not shown in the conversation.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct ParticipantEvent {
+    pub message_id: String,
+    pub conversation_id: String,
+    pub action: String,
+    /// The user who made the change, the participant itself for `JOIN` and
+    /// `EXIT`.
+    pub actor_id: String,
+    /// Empty for conversation-wide changes such as `UPDATE` and `EXPIRE`.
+    pub participant_id: String,
+    pub role: Option<String>,
+    #[sqlx(try_from = "crate::db::datetime::DatabaseDateTime")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ParticipantEventItem {
+    pub message_id: String,
+    pub action: String,
+    pub actor_id: String,
+    pub actor_name: Option<String>,
+    pub participant_id: String,
+    pub participant_name: Option<String>,
+    pub role: Option<String>,
+    #[sqlx(try_from = "crate::db::datetime::DatabaseDateTime")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl ParticipantEventDao {
+    pub async fn insert(&self, event: &ParticipantEvent) -> Result<(), Error> {
+        sqlx::query(
+            r#"INSERT OR IGNORE INTO participant_events
+               (message_id, conversation_id, "action", actor_id, participant_id, role, created_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&event.message_id)
+        .bind(&event.conversation_id)
+        .bind(&event.action)
+        .bind(&event.actor_id)
+        .bind(&event.participant_id)
+        .bind(&event.role)
+        .bind(event.created_at.timestamp_millis())
+        .execute(&self.0)
+        .await?;
+        Ok(())
+    }
+
+    /// Lists the events of a conversation, newest first, optionally only
+    /// those about `participant_id` and only those before
+    /// `before_created_at_millis`.
+    pub async fn list_items(
+        &self,
+        conversation_id: &str,
+        participant_id: Option<&str>,
+        before_created_at_millis: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<ParticipantEventItem>, Error> {
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"SELECT event.message_id, event."action", event.actor_id,
+                      actor.full_name AS actor_name, event.participant_id,
+                      participant.full_name AS participant_name, event.role, event.created_at
+               FROM participant_events event
+               LEFT JOIN users actor ON actor.user_id = event.actor_id
+               LEFT JOIN users participant ON participant.user_id = event.participant_id
+               WHERE event.conversation_id = "#,
+        );
+        builder.push_bind(conversation_id);
+        if let Some(participant_id) = participant_id {
+            builder
+                .push(" AND event.participant_id = ")
+                .push_bind(participant_id);
+        }
+        if let Some(before) = before_created_at_millis {
+            builder.push(" AND event.created_at < ").push_bind(before);
+        }
+        builder
+            .push(" ORDER BY event.created_at DESC, event.rowid DESC LIMIT ")
+            .push_bind(limit);
+        Ok(builder
+            .build_query_as::<ParticipantEventItem>()
+            .fetch_all(&self.0)
+            .await?)
+    }
+
+    /// Lists the members who joined through an invite link and whose join has
+    /// not been reviewed, oldest first. Members who left since are skipped.
+    pub async fn list_pending_joins(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Vec<ParticipantEventItem>, Error> {
+        Ok(sqlx::query_as::<_, ParticipantEventItem>(
+            r#"SELECT event.message_id, event."action", event.actor_id,
+                      actor.full_name AS actor_name, event.participant_id,
+                      participant.full_name AS participant_name, event.role, event.created_at
+               FROM participant_events event
+               LEFT JOIN users actor ON actor.user_id = event.actor_id
+               LEFT JOIN users participant ON participant.user_id = event.participant_id
+               WHERE event.conversation_id = ? AND event."action" = 'JOIN'
+                 AND event.reviewed_at IS NULL
+                 AND EXISTS (SELECT 1 FROM participants member
+                             WHERE member.conversation_id = event.conversation_id
+                               AND member.user_id = event.participant_id)
+               ORDER BY event.created_at, event.rowid"#,
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.0)
+        .await?)
+    }
+
+    /// Returns who joined with the unreviewed `JOIN` event `message_id`.
+    pub async fn find_pending_join(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<Option<String>, Error> {
+        Ok(sqlx::query_scalar(
+            r#"SELECT participant_id FROM participant_events
+               WHERE conversation_id = ? AND message_id = ? AND "action" = 'JOIN'
+                 AND reviewed_at IS NULL"#,
+        )
+        .bind(conversation_id)
+        .bind(message_id)
+        .fetch_optional(&self.0)
+        .await?)
+    }
+
+    pub async fn mark_reviewed(
+        &self,
+        message_id: &str,
+        reviewed_at: DateTime<Utc>,
+    ) -> Result<(), Error> {
+        sqlx::query("UPDATE participant_events SET reviewed_at = ? WHERE message_id = ?")
+            .bind(reviewed_at.timestamp_millis())
+            .bind(message_id)
+            .execute(&self.0)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::ParticipantEvent;
+    use crate::db::mixin::participant::Participant;
+    use crate::db::MixinDatabase;
+
+    fn event(
+        message_id: &str,
+        action: &str,
+        participant_id: &str,
+        millis: i64,
+    ) -> ParticipantEvent {
+        ParticipantEvent {
+            message_id: message_id.to_string(),
+            conversation_id: "conversation".to_string(),
+            action: action.to_string(),
+            actor_id: "owner".to_string(),
+            participant_id: participant_id.to_string(),
+            role: (action == "ROLE").then(|| "ADMIN".to_string()),
+            created_at: Utc.timestamp_millis_opt(millis).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_participant_history_newest_first() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        let dao = &database.participant_event_dao;
+        for event in [
+            event("add", "ADD", "member", 1_000),
+            event("role", "ROLE", "member", 2_000),
+            event("other", "ADD", "other", 3_000),
+            event("exit", "EXIT", "member", 4_000),
+        ] {
+            dao.insert(&event).await.unwrap();
+        }
+        dao.insert(&event("add", "REMOVE", "member", 5_000))
+            .await
+            .unwrap();
+
+        let history = dao
+            .list_items("conversation", Some("member"), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            history
+                .iter()
+                .map(|item| item.message_id.as_str())
+                .collect::<Vec<_>>(),
+            ["exit", "role", "add"]
+        );
+        assert_eq!(history[1].role.as_deref(), Some("ADMIN"));
+        assert_eq!(history[2].action, "ADD");
+
+        let older = dao
+            .list_items("conversation", None, Some(3_000), 1)
+            .await
+            .unwrap();
+        assert_eq!(older.len(), 1);
+        assert_eq!(older[0].message_id, "role");
+    }
+
+    #[tokio::test]
+    async fn lists_unreviewed_joins_of_current_members() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO conversations (conversation_id, created_at, status) VALUES (?, ?, ?)",
+        )
+        .bind("conversation")
+        .bind(Utc::now().timestamp_millis())
+        .bind(2)
+        .execute(&database.participant_event_dao.0)
+        .await
+        .unwrap();
+        for user_id in ["joined", "reviewed", "added"] {
+            database
+                .participant_dao
+                .insert_participant(&Participant {
+                    conversation_id: "conversation".to_string(),
+                    user_id: user_id.to_string(),
+                    role: None,
+                    created_at: Utc::now(),
+                })
+                .await
+                .unwrap();
+        }
+        let dao = &database.participant_event_dao;
+        for event in [
+            event("joined", "JOIN", "joined", 1_000),
+            event("reviewed", "JOIN", "reviewed", 2_000),
+            event("left", "JOIN", "left", 3_000),
+            event("added", "ADD", "added", 4_000),
+        ] {
+            dao.insert(&event).await.unwrap();
+        }
+        dao.mark_reviewed("reviewed", Utc::now()).await.unwrap();
+
+        let pending = dao.list_pending_joins("conversation").await.unwrap();
+        assert_eq!(
+            pending
+                .iter()
+                .map(|item| item.message_id.as_str())
+                .collect::<Vec<_>>(),
+            ["joined"]
+        );
+        assert_eq!(
+            dao.find_pending_join("conversation", "joined")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("joined")
+        );
+        assert_eq!(
+            dao.find_pending_join("conversation", "reviewed")
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            dao.find_pending_join("conversation", "added")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -127,6 +127,36 @@ impl ParticipantSessionDao {
         Ok(())
     }
 
+    /// Adds a session of `user_id` to every conversation the user is in,
+    /// so sender keys are sent to it before the next group message.
+    pub async fn add_user_session(
+        &self,
+        user_id: &str,
+        session_id: &str,
+        public_key: Option<&str>,
+    ) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO participant_session \
+             (conversation_id, user_id, session_id, public_key) \
+             SELECT DISTINCT conversation_id, user_id, ?, ? FROM participants WHERE user_id = ?",
+        )
+        .bind(session_id)
+        .bind(public_key)
+        .bind(user_id)
+        .execute(&self.0)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn remove_user_session(&self, user_id: &str, session_id: &str) -> Result<(), Error> {
+        sqlx::query("DELETE FROM participant_session WHERE user_id = ? AND session_id = ?")
+            .bind(user_id)
+            .bind(session_id)
+            .execute(&self.0)
+            .await?;
+        Ok(())
+    }
+
     pub async fn get_participant_sessions(
         &self,
         cid: &str,
@@ -219,6 +249,7 @@ impl ParticipantSessionDao {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::db::mixin::participant::Participant;
     use crate::db::MixinDatabase;
 
     fn session(
@@ -260,6 +291,68 @@ mod tests {
         assert_eq!(sessions[0].sent_to_server, None);
     }
 
+    #[tokio::test]
+    async fn user_sessions_follow_the_conversations_of_the_user() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        for (conversation_id, user_id) in [("group", "me"), ("contact", "me"), ("other", "peer")] {
+            sqlx::query(
+                "INSERT OR IGNORE INTO conversations (conversation_id, created_at, status) \
+                 VALUES (?, ?, ?)",
+            )
+            .bind(conversation_id)
+            .bind(Utc::now().timestamp_millis())
+            .bind(2)
+            .execute(&database.participant_dao.0)
+            .await
+            .unwrap();
+            database
+                .participant_dao
+                .insert_participant(&Participant {
+                    conversation_id: conversation_id.to_string(),
+                    user_id: user_id.to_string(),
+                    role: None,
+                    created_at: Utc::now(),
+                })
+                .await
+                .unwrap();
+        }
+        let dao = database.participant_session_dao;
+        dao.insert_session("group", "me", "linked", 1)
+            .await
+            .unwrap();
+
+        dao.add_user_session("me", "linked", Some("key"))
+            .await
+            .unwrap();
+        for conversation_id in ["group", "contact"] {
+            let sessions = dao.get_participant_sessions(conversation_id).await.unwrap();
+            assert_eq!(sessions.len(), 1);
+            assert_eq!(sessions[0].session_id, "linked");
+            assert_eq!(sessions[0].sent_to_server, None);
+            assert_eq!(sessions[0].public_key.as_deref(), Some("key"));
+        }
+        assert!(dao
+            .get_participant_sessions("other")
+            .await
+            .unwrap()
+            .is_empty());
+
+        dao.remove_user_session("me", "linked").await.unwrap();
+        assert!(dao
+            .get_participant_sessions("group")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(dao
+            .get_participant_sessions("contact")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn selects_only_sessions_requiring_sender_keys() -> Result<(), Box<dyn std::error::Error>>
     {
@@ -94,4 +94,64 @@ SELECT s.snapshot_id, s.trace_id, s.type AS type_field, s.asset_id, s.amount,
         .fetch_optional(&self.0)
         .await?)
     }
+
+    /// The snapshot of an outgoing transfer, preferring the confirmed one
+    /// over the pending row written when it was submitted.
+    pub async fn find_by_trace_id(
+        &self,
+        trace_id: &str,
+        fiat_currency: &str,
+    ) -> Result<Option<SafeSnapshotDetail>, Error> {
+        Ok(sqlx::query_as::<_, SafeSnapshotDetail>(
+            r#"
+SELECT s.snapshot_id, s.trace_id, s.type AS type_field, s.asset_id, s.amount,
+       s.created_at, s.opponent_id, s.transaction_hash, s.memo, s.confirmations,
+       s.opening_balance, s.closing_balance, s.withdrawal, s.deposit,
+       t.symbol, t.name AS asset_name, t.icon_url AS asset_icon_url,
+       c.icon_url AS chain_icon_url, t.confirmations AS asset_confirmations,
+       u.full_name AS opponent_name, t.price_usd,
+       (SELECT rate FROM fiats WHERE code = ?) AS fiat_rate
+  FROM safe_snapshots s
+  LEFT JOIN tokens t ON t.asset_id = s.asset_id
+  LEFT JOIN chains c ON c.chain_id = t.chain_id
+  LEFT JOIN users u ON u.user_id = s.opponent_id
+ WHERE s.trace_id = ?
+ ORDER BY s.type = 'pending'
+ LIMIT 1
+            "#,
+        )
+        .bind(fiat_currency)
+        .bind(trace_id)
+        .fetch_optional(&self.0)
+        .await?)
+    }
+
+    /// Every transfer of an inscription, newest first.
+    pub async fn find_by_inscription_hash(
+        &self,
+        inscription_hash: &str,
+        fiat_currency: &str,
+    ) -> Result<Vec<SafeSnapshotDetail>, Error> {
+        Ok(sqlx::query_as::<_, SafeSnapshotDetail>(
+            r#"
+SELECT s.snapshot_id, s.trace_id, s.type AS type_field, s.asset_id, s.amount,
+       s.created_at, s.opponent_id, s.transaction_hash, s.memo, s.confirmations,
+       s.opening_balance, s.closing_balance, s.withdrawal, s.deposit,
+       t.symbol, t.name AS asset_name, t.icon_url AS asset_icon_url,
+       c.icon_url AS chain_icon_url, t.confirmations AS asset_confirmations,
+       u.full_name AS opponent_name, t.price_usd,
+       (SELECT rate FROM fiats WHERE code = ?) AS fiat_rate
+  FROM safe_snapshots s
+  LEFT JOIN tokens t ON t.asset_id = s.asset_id
+  LEFT JOIN chains c ON c.chain_id = t.chain_id
+  LEFT JOIN users u ON u.user_id = s.opponent_id
+ WHERE s.inscription_hash = ?
+ ORDER BY s.created_at DESC
+            "#,
+        )
+        .bind(fiat_currency)
+        .bind(inscription_hash)
+        .fetch_all(&self.0)
+        .await?)
+    }
 }
@@ -72,6 +72,25 @@ CREATE TABLE IF NOT EXISTS circles
     PRIMARY KEY (circle_id)
 );
 
+CREATE TABLE IF NOT EXISTS smart_circles
+(
+    circle_id  TEXT      NOT NULL,
+    name       TEXT      NOT NULL,
+    rules      TEXT      NOT NULL,
+    match_all  BOOLEAN   NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (circle_id)
+);
+
+CREATE TABLE IF NOT EXISTS user_annotations
+(
+    user_id    TEXT      NOT NULL,
+    alias      TEXT,
+    note       TEXT,
+    updated_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (user_id)
+);
+
 CREATE TABLE IF NOT EXISTS conversations
 (
     conversation_id         TEXT      NOT NULL,
@@ -125,6 +144,8 @@ CREATE TABLE IF NOT EXISTS jobs
     conversation_id   TEXT,
     resend_message_id TEXT,
     run_count         INTEGER   NOT NULL,
+    last_error        TEXT,
+    next_run_at       TIMESTAMP,
     PRIMARY KEY (job_id)
 );
 
@@ -173,6 +194,39 @@ CREATE TABLE IF NOT EXISTS messages
     FOREIGN KEY (conversation_id) REFERENCES conversations (conversation_id) ON UPDATE NO ACTION ON DELETE CASCADE
 );
 
+CREATE TABLE IF NOT EXISTS message_edits
+(
+    edit_id         TEXT      NOT NULL,
+    message_id      TEXT      NOT NULL,
+    conversation_id TEXT      NOT NULL,
+    content         TEXT      NOT NULL,
+    edited_at       TIMESTAMP NOT NULL,
+    PRIMARY KEY (edit_id)
+);
+
+CREATE TABLE IF NOT EXISTS message_reactions
+(
+    message_id      TEXT      NOT NULL,
+    conversation_id TEXT      NOT NULL,
+    user_id         TEXT      NOT NULL,
+    emoji           TEXT      NOT NULL,
+    created_at      TIMESTAMP NOT NULL,
+    PRIMARY KEY (message_id, user_id, emoji)
+);
+
+CREATE TABLE IF NOT EXISTS participant_events
+(
+    message_id      TEXT      NOT NULL,
+    conversation_id TEXT      NOT NULL,
+    "action"        TEXT      NOT NULL,
+    actor_id        TEXT      NOT NULL,
+    participant_id  TEXT      NOT NULL,
+    role            TEXT,
+    created_at      TIMESTAMP NOT NULL,
+    reviewed_at     TIMESTAMP,
+    PRIMARY KEY (message_id)
+);
+
 CREATE TABLE IF NOT EXISTS messages_history
 (
     message_id TEXT NOT NULL,
@@ -462,6 +516,9 @@ CREATE INDEX IF NOT EXISTS index_conversations_category_status ON conversations
 CREATE INDEX IF NOT EXISTS index_conversations_mute_until ON conversations (mute_until);
 CREATE INDEX IF NOT EXISTS index_flood_messages_created_at ON flood_messages (created_at);
 CREATE INDEX IF NOT EXISTS index_jobs_action ON jobs ("action");
+CREATE INDEX IF NOT EXISTS index_message_edits_message_id_edited_at ON message_edits (message_id, edited_at);
+CREATE INDEX IF NOT EXISTS index_message_reactions_conversation_id ON message_reactions (conversation_id);
+CREATE INDEX IF NOT EXISTS index_participant_events_conversation_id_created_at ON participant_events (conversation_id, created_at DESC);
 CREATE INDEX IF NOT EXISTS index_message_mentions_conversation_id_has_read ON message_mentions (conversation_id, has_read);
 CREATE INDEX IF NOT EXISTS index_participants_conversation_id_created_at ON participants (conversation_id, created_at);
 CREATE INDEX IF NOT EXISTS index_sticker_albums_category_created_at ON sticker_albums (category, created_at DESC);
@@ -0,0 +1,319 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::Sqlite;
+
+use crate::db::Error;
+
+/// Matches a conversation, aliased `conversation` and joined with its
+/// `owner`, against the rules of the smart circle aliased `smart_circle`.
+/// Unknown rule kinds never match.
+pub(crate) const SMART_CIRCLE_MATCH: &str = r#"(
+    SELECT COUNT(1)
+    FROM json_each(smart_circle.rules) rule
+    WHERE COALESCE(json_extract(rule.value, '$.negate'), FALSE) != COALESCE(
+        CASE json_extract(rule.value, '$.kind')
+            WHEN 'bots' THEN conversation.category = 'CONTACT' AND owner.app_id IS NOT NULL
+            WHEN 'contacts' THEN conversation.category = 'CONTACT' AND owner.app_id IS NULL
+            WHEN 'groups' THEN conversation.category = 'GROUP'
+            WHEN 'unread' THEN COALESCE(conversation.unseen_message_count, 0) > 0
+            WHEN 'unread_mentions' THEN EXISTS (
+                SELECT 1 FROM message_mentions mention
+                WHERE mention.conversation_id = conversation.conversation_id
+                  AND COALESCE(mention.has_read, FALSE) = FALSE
+            )
+            WHEN 'muted' THEN CASE
+                WHEN conversation.category = 'GROUP'
+                    THEN conversation.mute_until > CAST(unixepoch('subsec') * 1000 AS INTEGER)
+                ELSE owner.mute_until > CAST(unixepoch('subsec') * 1000 AS INTEGER)
+            END
+            WHEN 'pinned' THEN conversation.pin_time IS NOT NULL
+            WHEN 'member' THEN conversation.owner_id = json_extract(rule.value, '$.user_id')
+                OR EXISTS (
+                    SELECT 1 FROM participants member
+                    WHERE member.conversation_id = conversation.conversation_id
+                      AND member.user_id = json_extract(rule.value, '$.user_id')
+                )
+        END,
+        FALSE
+    )
+) >= CASE WHEN smart_circle.match_all THEN json_array_length(smart_circle.rules) ELSE 1 END"#;
+
+#[derive(Clone)]
+pub struct SmartCircleDao(pub(crate) sqlx::Pool<Sqlite>);
+
+/// What a smart circle rule tests a conversation for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SmartCircleCondition {
+    /// Contact conversations with a bot.
+    Bots,
+    /// Contact conversations with a person.
+    Contacts,
+    Groups,
+    Unread,
+    UnreadMentions,
+    Muted,
+    Pinned,
+    /// Groups with `user_id` as a participant and the contact conversation
+    /// with `user_id`.
+    Member {
+        user_id: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmartCircleRule {
+    #[serde(flatten)]
+    pub condition: SmartCircleCondition,
+    /// Matches the conversations the condition does not.
+    #[serde(default)]
+    pub negate: bool,
+}
+
+/// A local circle whose conversations are the ones matching all of its rules,
+/// or any of them when `match_all` is not set.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SmartCircle {
+    pub circle_id: String,
+    pub name: String,
+    pub rules: Json<Vec<SmartCircleRule>>,
+    pub match_all: bool,
+    #[sqlx(try_from = "crate::db::datetime::DatabaseDateTime")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct SmartCircleSummary {
+    pub circle_id: String,
+    pub name: String,
+    pub rules: Json<Vec<SmartCircleRule>>,
+    pub match_all: bool,
+    pub conversation_count: i64,
+}
+
+impl SmartCircleDao {
+    pub async fn summaries(&self) -> Result<Vec<SmartCircleSummary>, Error> {
+        let sql = format!(
+            r#"
+SELECT smart_circle.circle_id, smart_circle.name, smart_circle.rules, smart_circle.match_all,
+       (
+           SELECT COUNT(1)
+           FROM conversations conversation
+           INNER JOIN users owner ON owner.user_id = conversation.owner_id
+           WHERE conversation.category IN ('CONTACT', 'GROUP')
+             AND {SMART_CIRCLE_MATCH}
+       ) AS conversation_count
+FROM smart_circles smart_circle
+ORDER BY smart_circle.created_at, smart_circle.rowid"#
+        );
+        Ok(
+            sqlx::query_as::<_, SmartCircleSummary>(sqlx::AssertSqlSafe(sql))
+                .fetch_all(&self.0)
+                .await?,
+        )
+    }
+
+    pub async fn find(&self, circle_id: &str) -> Result<Option<SmartCircle>, Error> {
+        Ok(
+            sqlx::query_as::<_, SmartCircle>("SELECT * FROM smart_circles WHERE circle_id = ?")
+                .bind(circle_id)
+                .fetch_optional(&self.0)
+                .await?,
+        )
+    }
+
+    pub async fn insert(&self, circle: &SmartCircle) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO smart_circles (circle_id, name, rules, match_all, created_at) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&circle.circle_id)
+        .bind(&circle.name)
+        .bind(&circle.rules)
+        .bind(circle.match_all)
+        .bind(circle.created_at.timestamp_millis())
+        .execute(&self.0)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, circle_id: &str) -> Result<bool, Error> {
+        Ok(sqlx::query("DELETE FROM smart_circles WHERE circle_id = ?")
+            .bind(circle_id)
+            .execute(&self.0)
+            .await?
+            .rows_affected()
+            > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use sqlx::types::Json;
+
+    use super::{SmartCircle, SmartCircleCondition, SmartCircleRule};
+    use crate::db::MixinDatabase;
+
+    #[test]
+    fn serializes_rules_with_kind_tags() {
+        let rules = vec![
+            SmartCircleRule {
+                condition: SmartCircleCondition::Member {
+                    user_id: "user".to_string(),
+                },
+                negate: false,
+            },
+            SmartCircleRule {
+                condition: SmartCircleCondition::UnreadMentions,
+                negate: true,
+            },
+        ];
+        let json = serde_json::to_string(&rules).unwrap();
+
+        assert_eq!(
+            json,
+            r#"[{"kind":"member","user_id":"user","negate":false},{"kind":"unread_mentions","negate":true}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<SmartCircleRule>>(r#"[{"kind":"bots"}]"#).unwrap(),
+            [SmartCircleRule {
+                condition: SmartCircleCondition::Bots,
+                negate: false,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn evaluates_rules_in_conversation_queries() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        let pool = &database.smart_circle_dao.0;
+        let now = Utc::now().timestamp_millis();
+        sqlx::raw_sql(sqlx::AssertSqlSafe(format!(
+            r#"
+INSERT INTO users (user_id, identity_number, full_name, avatar_url, app_id, mute_until) VALUES
+    ('bot', '7000000001', 'Bot', '', 'app', 0),
+    ('friend', '1001', 'Friend', '', NULL, 0),
+    ('owner', '1002', 'Owner', '', NULL, 0);
+INSERT INTO conversations
+    (conversation_id, owner_id, category, name, icon_url, created_at, status, mute_until)
+VALUES
+    ('bot-chat', 'bot', 'CONTACT', '', '', {now}, 2, 0),
+    ('friend-chat', 'friend', 'CONTACT', '', '', {now}, 2, 0),
+    ('muted-group', 'owner', 'GROUP', 'Muted', '', {now}, 2, {muted}),
+    ('mention-group', 'owner', 'GROUP', 'Mentions', '', {now}, 2, 0);
+INSERT INTO participants (conversation_id, user_id, role, created_at) VALUES
+    ('muted-group', 'owner', 'OWNER', {now}),
+    ('mention-group', 'owner', 'OWNER', {now}),
+    ('mention-group', 'friend', NULL, {now});
+INSERT INTO message_mentions (message_id, conversation_id, has_read) VALUES
+    ('mention', 'mention-group', FALSE);
+"#,
+            muted = now + 3_600_000,
+        )))
+        .execute(pool)
+        .await
+        .unwrap();
+
+        let circle = |circle_id: &str, match_all: bool, rules: Vec<SmartCircleRule>| SmartCircle {
+            circle_id: circle_id.to_string(),
+            name: circle_id.to_string(),
+            rules: Json(rules),
+            match_all,
+            created_at: Utc::now(),
+        };
+        let rule = |condition: SmartCircleCondition| SmartCircleRule {
+            condition,
+            negate: false,
+        };
+        for smart_circle in [
+            circle(
+                "muted-groups",
+                true,
+                vec![
+                    rule(SmartCircleCondition::Groups),
+                    rule(SmartCircleCondition::Muted),
+                ],
+            ),
+            circle(
+                "with-friend",
+                true,
+                vec![rule(SmartCircleCondition::Member {
+                    user_id: "friend".to_string(),
+                })],
+            ),
+            circle(
+                "bots-or-mentions",
+                false,
+                vec![
+                    rule(SmartCircleCondition::Bots),
+                    rule(SmartCircleCondition::UnreadMentions),
+                ],
+            ),
+            circle(
+                "not-groups",
+                true,
+                vec![SmartCircleRule {
+                    condition: SmartCircleCondition::Groups,
+                    negate: true,
+                }],
+            ),
+        ] {
+            database
+                .smart_circle_dao
+                .insert(&smart_circle)
+                .await
+                .unwrap();
+        }
+
+        let members = |circle_id: &'static str| {
+            let database = &database;
+            async move {
+                let mut ids = database
+                    .conversation_dao
+                    .list_items("chats", Some(circle_id), "", false, 10, 0)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|item| item.conversation_id)
+                    .collect::<Vec<_>>();
+                ids.sort();
+                let count = database
+                    .conversation_dao
+                    .count_items("chats", Some(circle_id), "", false)
+                    .await
+                    .unwrap();
+                assert_eq!(count as usize, ids.len());
+                ids
+            }
+        };
+        assert_eq!(members("muted-groups").await, ["muted-group"]);
+        assert_eq!(
+            members("with-friend").await,
+            ["friend-chat", "mention-group"]
+        );
+        assert_eq!(
+            members("bots-or-mentions").await,
+            ["bot-chat", "mention-group"]
+        );
+        assert_eq!(members("not-groups").await, ["bot-chat", "friend-chat"]);
+
+        let summaries = database.smart_circle_dao.summaries().await.unwrap();
+        assert_eq!(
+            summaries
+                .iter()
+                .map(|summary| (summary.circle_id.as_str(), summary.conversation_count))
+                .collect::<Vec<_>>(),
+            [
+                ("muted-groups", 1),
+                ("with-friend", 2),
+                ("bots-or-mentions", 2),
+                ("not-groups", 2),
+            ]
+        );
+    }
+}
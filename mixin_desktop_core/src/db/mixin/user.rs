@@ -35,6 +35,22 @@ pub struct User {
     pub membership: Option<String>,
 }
 
+/// A contact with the local alias it is shown under, if any.
+#[derive(sqlx::FromRow, Debug)]
+pub struct Contact {
+    #[sqlx(flatten)]
+    pub user: User,
+    pub alias: Option<String>,
+}
+
+/// Local-only details about a user that are never sent to the server.
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq, Eq)]
+pub struct UserAnnotation {
+    pub user_id: String,
+    pub alias: Option<String>,
+    pub note: Option<String>,
+}
+
 impl From<sdk::User> for User {
     fn from(value: sdk::User) -> Self {
         User {
@@ -83,6 +99,61 @@ impl UserDao {
         .await?)
     }
 
+    /// Friends other than the current user, in no particular order.
+    pub async fn contacts(&self, current_user_id: &str) -> Result<Vec<Contact>, Error> {
+        Ok(sqlx::query_as::<_, Contact>(
+            r#"SELECT users.*, NULLIF(annotation.alias, '') AS alias FROM users
+               LEFT JOIN user_annotations annotation ON annotation.user_id = users.user_id
+               WHERE users.user_id != ?
+                 AND users.identity_number != '0'
+                 AND users.is_deactivated = FALSE
+                 AND users.relationship = 'FRIEND'"#,
+        )
+        .bind(current_user_id)
+        .fetch_all(&self.0)
+        .await?)
+    }
+
+    pub async fn find_annotation(&self, user_id: &str) -> Result<Option<UserAnnotation>, Error> {
+        Ok(sqlx::query_as::<_, UserAnnotation>(
+            "SELECT user_id, alias, note FROM user_annotations WHERE user_id = ?",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.0)
+        .await?)
+    }
+
+    /// Stores the alias and note of a user, removing the row when both are
+    /// empty.
+    pub async fn set_annotation(
+        &self,
+        user_id: &str,
+        alias: Option<&str>,
+        note: Option<&str>,
+    ) -> Result<(), Error> {
+        let alias = alias.map(str::trim).filter(|alias| !alias.is_empty());
+        let note = note.map(str::trim).filter(|note| !note.is_empty());
+        if alias.is_none() && note.is_none() {
+            sqlx::query("DELETE FROM user_annotations WHERE user_id = ?")
+                .bind(user_id)
+                .execute(&self.0)
+                .await?;
+        } else {
+            sqlx::query(
+                "INSERT OR REPLACE INTO user_annotations (user_id, alias, note, updated_at) \
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(user_id)
+            .bind(alias)
+            .bind(note)
+            .bind(Utc::now().timestamp_millis())
+            .execute(&self.0)
+            .await?;
+        }
+        self.1.clear_aliases();
+        Ok(())
+    }
+
     pub async fn fuzzy_search_users(
         &self,
         current_user_id: &str,
@@ -318,6 +389,16 @@ impl UserDao {
     }
 
     async fn load_mention_users(&self, contents: &[String]) -> Result<(), Error> {
+        if self.1.needs_aliases() {
+            let aliases = sqlx::query_as::<_, (String, String)>(
+                r#"SELECT users.identity_number, annotation.alias FROM user_annotations annotation
+                   INNER JOIN users ON users.user_id = annotation.user_id
+                   WHERE annotation.alias IS NOT NULL AND annotation.alias != ''"#,
+            )
+            .fetch_all(&self.0)
+            .await?;
+            self.1.cache_aliases(aliases.into_iter().collect());
+        }
         let missing = self.1.missing_identity_numbers(contents);
         if !missing.is_empty() {
             let users = self.find_users_by_identity_numbers(&missing).await?;
@@ -458,6 +539,70 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn aliases_rename_contacts_and_mentions() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        for (user_id, identity_number, relationship) in
+            [("user-1", "7001", "FRIEND"), ("user-2", "7002", "STRANGER")]
+        {
+            sqlx::query(
+                r#"INSERT INTO users (
+                    user_id, identity_number, relationship, full_name, avatar_url,
+                    phone, is_verified, created_at, mute_until, has_pin, app_id,
+                    biography, is_scam, code_url, code_id, is_deactivated
+                ) VALUES (?, ?, ?, 'Alice', '', '', FALSE, CURRENT_TIMESTAMP,
+                    CURRENT_TIMESTAMP, FALSE, NULL, '', FALSE, '', '', FALSE)"#,
+            )
+            .bind(user_id)
+            .bind(identity_number)
+            .bind(relationship)
+            .execute(&database.user_dao.0)
+            .await
+            .unwrap();
+        }
+        let dao = &database.user_dao;
+        assert_eq!(
+            dao.replace_mentions(&["hi @7001".to_string()])
+                .await
+                .unwrap(),
+            ["hi @Alice"]
+        );
+
+        dao.set_annotation("user-1", Some(" Mom "), Some("birthday in May"))
+            .await
+            .unwrap();
+        assert_eq!(
+            dao.find_annotation("user-1").await.unwrap(),
+            Some(super::UserAnnotation {
+                user_id: "user-1".to_string(),
+                alias: Some("Mom".to_string()),
+                note: Some("birthday in May".to_string()),
+            })
+        );
+        assert_eq!(
+            dao.replace_mentions(&["hi @7001".to_string()])
+                .await
+                .unwrap(),
+            ["hi @Mom"]
+        );
+        let contacts = dao.contacts("me").await.unwrap();
+        assert_eq!(contacts.len(), 1);
+        assert_eq!(contacts[0].user.user_id, "user-1");
+        assert_eq!(contacts[0].alias.as_deref(), Some("Mom"));
+
+        dao.set_annotation("user-1", Some(""), None).await.unwrap();
+        assert_eq!(dao.find_annotation("user-1").await.unwrap(), None);
+        assert_eq!(
+            dao.replace_mentions(&["hi @7001".to_string()])
+                .await
+                .unwrap(),
+            ["hi @Alice"]
+        );
+    }
+
     #[tokio::test]
     async fn searches_bot_group_friends_with_flutter_semantics() {
         let directory = tempfile::tempdir().unwrap();
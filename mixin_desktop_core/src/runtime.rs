@@ -5,18 +5,18 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 use std::thread::JoinHandle;
 use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use log::{error, warn};
-use tokio::sync::{mpsc, oneshot, watch, RwLock};
+use tokio::sync::{broadcast, mpsc, oneshot, watch, RwLock};
 use tokio_util::sync::CancellationToken;
 
 use sdk::api::account_api::AccountUpdateRequest;
 use sdk::message_category::MessageCategory as _;
-use sdk::{Account, Client, Credential, KeyStore, MessageStatus};
+use sdk::{Account, Client, Credential, KeyStore, MessageStatus, SpendKey};
 
 use crate::core::attachment::AttachmentService;
 use crate::core::constants::SCP;
@@ -24,37 +24,46 @@ use crate::core::conversation_change::ConversationChangeNotifier;
 use crate::core::crypto::signal_protocol::SignalProtocol;
 use crate::core::device_transfer::{DeviceTransferControlEvent, DeviceTransferService};
 use crate::core::message::blaze::Blaze;
-use crate::core::message::decrypt::{AttachmentTransferRequest, ServiceDecryptMessage};
+use crate::core::message::decrypt::{
+    AttachmentTransferRequest, ServiceDecryptMessage, SessionLinkedEvent,
+};
 use crate::core::message::sender::MessageSender;
 use crate::core::model::auth::AuthService;
 use crate::core::model::signal::SignalService;
 use crate::core::model::{AppService, AttachmentExtra, ConversationService};
 use crate::core::user_agent::generate_user_agent;
-use crate::db::app::{Auth, SettingDao};
+use crate::db::app::{Auth, NotificationRules, SettingDao};
 use crate::db::mixin::message::{MediaStatus, Message};
 use crate::db::mixin::sticker::{Sticker, StickerAlbum};
 use crate::db::path::account_data_directory;
 use crate::db::{MixinDatabase, SignalDatabase};
+use crate::network::SharedNetworkService;
 
 const MIXIN_DATABASE_OPEN_ERROR_PREFIX: &str = "mixin_database_open_error";
 
 mod attachment;
 mod conversation;
 mod conversion;
+#[cfg(all(target_os = "linux", feature = "dbus-notifications"))]
+pub mod dbus_notification;
 pub mod desktop;
 pub mod logging;
 pub mod login;
 pub mod mcp;
 mod message;
 pub mod model;
+mod search;
 mod sticker;
 mod user;
+mod wallet;
 
 pub use attachment::AttachmentAccess;
 pub use conversation::ConversationAccess;
 pub use message::MessageAccess;
+pub use search::SearchAccess;
 pub use sticker::StickerAccess;
 pub use user::UserAccess;
+pub use wallet::WalletAccess;
 
 #[derive(Debug, thiserror::Error)]
 #[error("session unauthorized")]
@@ -92,12 +101,15 @@ pub struct AccountState {
     profile: watch::Sender<Account>,
     auth_service: Arc<AuthService>,
     client: Arc<Client>,
+    settings: SettingDao,
+    network_service: SharedNetworkService,
     database: Arc<MixinDatabase>,
     signal_database: Arc<SignalDatabase>,
     app_service: Arc<AppService>,
     conversation_changes: ConversationChangeNotifier,
     shutdown: watch::Receiver<bool>,
     notification_changes: watch::Sender<u64>,
+    session_linked_events: broadcast::Sender<SessionLinkedEvent>,
     blaze: Arc<Blaze>,
     device_transfer: Arc<DeviceTransferService>,
     account_health: watch::Sender<String>,
@@ -105,6 +117,7 @@ pub struct AccountState {
     mutation_gate: RwLock<()>,
     attachment_downloads: Mutex<HashMap<String, CancellationToken>>,
     attachment_progresses: Mutex<HashMap<String, f64>>,
+    spend_key: Mutex<Option<SpendKey>>,
 }
 
 impl Deref for AccountRuntime {
@@ -136,6 +149,23 @@ impl AccountState {
         }
     }
 
+    async fn refresh_fiats(&self) {
+        match self.client.account_api.get_fiats().await {
+            Ok(fiats) => {
+                if let Err(error) = self.database.fiat_dao.insert_all(&fiats).await {
+                    warn!("failed to persist fiat rates: {error}");
+                }
+            }
+            Err(error) => warn!("failed to refresh fiat rates: {error}"),
+        }
+    }
+
+    fn unlocked_spend_key(&self) -> MutexGuard<'_, Option<SpendKey>> {
+        self.spend_key
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     fn cancel_attachment_downloads(&self) {
         let downloads = self
             .attachment_downloads
@@ -198,6 +228,7 @@ impl AccountRuntime {
         auth: Auth,
         auth_service: Arc<AuthService>,
         setting_dao: SettingDao,
+        network_service: SharedNetworkService,
     ) -> Result<Self> {
         let account_id = auth.account.user_id.clone();
         let account = auth.account.clone();
@@ -210,10 +241,13 @@ impl AccountRuntime {
         let (shutdown, shutdown_receiver) = watch::channel(false);
         let conversation_changes = ConversationChangeNotifier::new();
         let (notification_changes, _) = watch::channel(0);
+        let (session_linked_events, _) = broadcast::channel(16);
         let (account_health_updates, _) = watch::channel("ready".to_string());
         let (attachment_transfer_sender, attachment_transfer_requests) = mpsc::unbounded_channel();
         let account_conversation_changes = conversation_changes.clone();
         let account_notification_changes = notification_changes.clone();
+        let account_session_linked_events = session_linked_events.clone();
+        let account_auth_service = auth_service.clone();
         let (ready_sender, ready_receiver) = oneshot::channel();
         let thread = std::thread::Builder::new()
             .name(format!("mixin-account-{account_id}"))
@@ -231,6 +265,8 @@ impl AccountRuntime {
                             shutdown_receiver,
                             conversation_changes: account_conversation_changes,
                             notification_changes: account_notification_changes,
+                            session_linked_events: account_session_linked_events,
+                            auth_service: account_auth_service,
                             account_health_updates,
                             initial_account_health,
                             attachment_transfer_requests: attachment_transfer_sender,
@@ -260,12 +296,15 @@ impl AccountRuntime {
             profile,
             auth_service,
             client,
+            settings: setting_dao.clone(),
+            network_service,
             database,
             signal_database,
             app_service,
             conversation_changes,
             shutdown: shutdown.subscribe(),
             notification_changes,
+            session_linked_events,
             blaze,
             device_transfer,
             account_health: account_health_updates,
@@ -273,6 +312,7 @@ impl AccountRuntime {
             mutation_gate: RwLock::new(()),
             attachment_downloads: Mutex::new(HashMap::new()),
             attachment_progresses: Mutex::new(HashMap::new()),
+            spend_key: Mutex::new(None),
         });
         tokio::spawn(run_attachment_transfer_requests(
             state.clone(),
@@ -298,6 +338,10 @@ impl AccountRuntime {
         AttachmentAccess::new(self.state.clone())
     }
 
+    pub fn search_access(&self) -> SearchAccess {
+        SearchAccess::new(self.state.clone())
+    }
+
     pub fn sticker_access(&self) -> StickerAccess {
         StickerAccess::new(self.state.clone())
     }
@@ -306,6 +350,10 @@ impl AccountRuntime {
         UserAccess::new(self.state.clone())
     }
 
+    pub fn wallet_access(&self) -> WalletAccess {
+        WalletAccess::new(self.state.clone())
+    }
+
     pub fn device_transfer(&self) -> Arc<DeviceTransferService> {
         self.device_transfer.clone()
     }
@@ -343,6 +391,11 @@ impl AccountRuntime {
         self.notification_changes.subscribe()
     }
 
+    /// Emits when another device is linked to this account.
+    pub fn subscribe_session_linked_events(&self) -> broadcast::Receiver<SessionLinkedEvent> {
+        self.session_linked_events.subscribe()
+    }
+
     pub async fn notification_event_batch(
         &self,
         after_created_at_micros: i64,
@@ -366,11 +419,26 @@ impl AccountRuntime {
             .unwrap_or((after_created_at_micros, after_row_id));
         let has_more = messages.len() == limit as usize;
         let identity_number = self.account().identity_number;
+        let rules = self.settings.notification_rules().await?;
+        let circle_ids = self
+            .notification_circle_ids(
+                &rules,
+                messages
+                    .iter()
+                    .map(|message| message.conversation_id.clone())
+                    .collect(),
+            )
+            .await?;
+        let context = model::NotificationContext {
+            rules: &rules,
+            current_user_id: &self.account_id,
+            current_identity_number: &identity_number,
+            circle_ids: &circle_ids,
+            local_offset: *chrono::Local::now().offset(),
+        };
         let mut events = messages
             .into_iter()
-            .filter_map(|message| {
-                model::NotificationEvent::from_message(message, &self.account_id, &identity_number)
-            })
+            .filter_map(|message| model::NotificationEvent::from_message(message, &context))
             .collect::<Vec<_>>();
         let text_event_indices = events
             .iter()
@@ -397,6 +465,113 @@ impl AccountRuntime {
         })
     }
 
+    /// Explains whether the notification rules let `message_id` notify.
+    pub async fn explain_notification(
+        &self,
+        message_id: &str,
+    ) -> Result<model::NotificationDecision> {
+        let message = self
+            .database
+            .message_dao
+            .notification_item(&self.account_id, message_id)
+            .await?
+            .ok_or_else(|| anyhow!("no notification for message {message_id}"))?;
+        let rules = self.settings.notification_rules().await?;
+        let circle_ids = self
+            .notification_circle_ids(&rules, vec![message.conversation_id.clone()])
+            .await?;
+        let identity_number = self.account().identity_number;
+        Ok(model::NotificationEvent::decide(
+            &message,
+            &model::NotificationContext {
+                rules: &rules,
+                current_user_id: &self.account_id,
+                current_identity_number: &identity_number,
+                circle_ids: &circle_ids,
+                local_offset: *chrono::Local::now().offset(),
+            },
+        ))
+    }
+
+    pub async fn perform_notification_action(
+        &self,
+        handle: &model::NotificationActionHandle,
+        action: model::NotificationAction,
+    ) -> Result<()> {
+        if handle.account_id != self.account_id {
+            return Err(anyhow!("notification belongs to another account"));
+        }
+        let conversation_id = handle.conversation_id.clone();
+        match action {
+            model::NotificationAction::Reply { text } => {
+                if text.trim().is_empty() {
+                    return Err(anyhow!("reply must not be empty"));
+                }
+                let messages = self.message_access();
+                messages
+                    .send_text(
+                        conversation_id.clone(),
+                        text,
+                        Some(handle.message_id.clone()),
+                        false,
+                    )
+                    .await?;
+                messages.mark_conversation_read(conversation_id).await?;
+            }
+            model::NotificationAction::MarkRead => {
+                self.message_access()
+                    .mark_conversation_read(conversation_id)
+                    .await?;
+            }
+            model::NotificationAction::MuteForOneHour => {
+                let conversation = self
+                    .database
+                    .conversation_dao
+                    .find_conversation_by_id(&conversation_id)
+                    .await?
+                    .ok_or_else(|| anyhow!("conversation {conversation_id} not found"))?;
+                let category = match conversation.category {
+                    Some(sdk::ConversationCategory::Group) => "GROUP",
+                    _ => "CONTACT",
+                };
+                self.conversation_access()
+                    .set_muted(
+                        conversation_id,
+                        conversation.owner_id.unwrap_or_default(),
+                        category.to_owned(),
+                        60 * 60,
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn notification_circle_ids(
+        &self,
+        rules: &NotificationRules,
+        mut conversation_ids: Vec<String>,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        if rules.muted_circle_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        conversation_ids.sort();
+        conversation_ids.dedup();
+        let mut circle_ids = HashMap::<String, Vec<String>>::new();
+        for (conversation_id, circle_id) in self
+            .database
+            .conversation_dao
+            .circle_memberships(&conversation_ids)
+            .await?
+        {
+            circle_ids
+                .entry(conversation_id)
+                .or_default()
+                .push(circle_id);
+        }
+        Ok(circle_ids)
+    }
+
     pub async fn latest_notification_row_id(&self) -> Result<i64> {
         Ok(self.database.message_dao.latest_row_id().await?)
     }
@@ -575,17 +750,6 @@ impl AccountRuntime {
         ))
     }
 
-    async fn refresh_fiats(&self) {
-        match self.client.account_api.get_fiats().await {
-            Ok(fiats) => {
-                if let Err(error) = self.database.fiat_dao.insert_all(&fiats).await {
-                    warn!("failed to persist fiat rates: {error}");
-                }
-            }
-            Err(error) => warn!("failed to refresh fiat rates: {error}"),
-        }
-    }
-
     async fn ticker_price_usd(
         &self,
         asset_id: &str,
@@ -662,15 +826,10 @@ impl AccountRuntime {
         }
         let mut auth = self
             .auth_service
-            .get_auth()
+            .get_auth_for(&self.account_id)
             .ok_or_else(|| anyhow!("account authorization not found"))?;
-        if auth.user_id != self.account_id {
-            return Err(anyhow!(
-                "saved authorization does not match the active account"
-            ));
-        }
         auth.account = account.clone();
-        self.auth_service.save_auth(&auth).await?;
+        self.auth_service.update_auth(&auth).await?;
         self.profile.send_replace(account.clone());
         Ok(account)
     }
@@ -779,6 +938,7 @@ impl AccountRuntime {
     async fn shutdown_inner(&self) {
         self.active.store(false, Ordering::Release);
         self.cancel_attachment_downloads();
+        self.unlocked_spend_key().take();
         let _ = self.shutdown.send(true);
         let thread = self.thread.lock().unwrap().take();
         if let Some(thread) = thread {
@@ -786,6 +946,54 @@ impl AccountRuntime {
         }
     }
 
+    /// Lists every active session of the account, including this desktop.
+    pub async fn linked_sessions(&self) -> Result<Vec<model::AccountSession>> {
+        self.ensure_active()?;
+        let current_session_id = self.account().session_id;
+        let primary_session_id = self
+            .auth_service
+            .get_auth_for(&self.account_id)
+            .and_then(|auth| auth.primary_session_id);
+        let mut sessions = self
+            .client
+            .user_api
+            .get_sessions(std::slice::from_ref(&self.account_id))
+            .await?
+            .into_iter()
+            .filter(|session| session.user_id == self.account_id)
+            .map(|session| model::AccountSession {
+                is_current: session.session_id == current_session_id,
+                is_primary: primary_session_id.as_deref() == Some(session.session_id.as_str()),
+                session_id: session.session_id,
+                platform: session.platform,
+                app_version: session.app_version,
+                last_seen_at: session.active_at,
+            })
+            .collect::<Vec<_>>();
+        sessions.sort_by(|left, right| {
+            right
+                .is_current
+                .cmp(&left.is_current)
+                .then_with(|| right.last_seen_at.cmp(&left.last_seen_at))
+        });
+        Ok(sessions)
+    }
+
+    /// Revokes another linked session. This desktop is signed out with
+    /// `sign_out` and the primary phone session cannot be revoked from here.
+    pub async fn revoke_linked_session(&self, session_id: &str) -> Result<()> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        ensure_revocable_session(
+            &self.auth_service,
+            &self.account_id,
+            &self.account().session_id,
+            session_id,
+        )?;
+        self.client.account_api.logout(session_id).await?;
+        Ok(())
+    }
+
     pub async fn revoke_session(&self) {
         let session_id = self.account().session_id;
         if let Err(error) = self.client.account_api.logout(&session_id).await {
@@ -972,6 +1180,8 @@ struct AccountRunContext {
     shutdown_receiver: watch::Receiver<bool>,
     conversation_changes: ConversationChangeNotifier,
     notification_changes: watch::Sender<u64>,
+    session_linked_events: broadcast::Sender<SessionLinkedEvent>,
+    auth_service: Arc<AuthService>,
     account_health_updates: watch::Sender<String>,
     initial_account_health: String,
     attachment_transfer_requests: mpsc::UnboundedSender<AttachmentTransferRequest>,
@@ -985,6 +1195,8 @@ async fn run_account(context: AccountRunContext) {
         mut shutdown_receiver,
         conversation_changes,
         notification_changes,
+        session_linked_events,
+        auth_service,
         account_health_updates,
         initial_account_health,
         attachment_transfer_requests,
@@ -995,6 +1207,8 @@ async fn run_account(context: AccountRunContext) {
         client.clone(),
         conversation_changes,
         notification_changes,
+        session_linked_events,
+        auth_service,
         initial_account_health,
         attachment_transfer_requests,
     )
@@ -1088,11 +1302,14 @@ type AccountServices = (
     String,
 );
 
+#[allow(clippy::too_many_arguments)]
 async fn prepare_account(
     auth: &Auth,
     client: Arc<Client>,
     conversation_changes: ConversationChangeNotifier,
     notification_changes: watch::Sender<u64>,
+    session_linked_events: broadcast::Sender<SessionLinkedEvent>,
+    auth_service: Arc<AuthService>,
     account_health: String,
     attachment_transfer_requests: mpsc::UnboundedSender<AttachmentTransferRequest>,
 ) -> Result<AccountServices> {
@@ -1177,6 +1394,8 @@ async fn prepare_account(
         .with_conversation_changes(conversation_changes)
         .with_notification_changes(notification_changes)
         .with_device_transfer_controls(device_transfer_control_sender)
+        .with_session_linked_events(session_linked_events)
+        .with_auth_service(auth_service)
         .with_attachment_transfer_requests(attachment_transfer_requests),
     );
     Ok((
@@ -1257,6 +1476,24 @@ pub fn credential(auth: &Auth) -> Credential {
     })
 }
 
+fn ensure_revocable_session(
+    auth_service: &AuthService,
+    account_id: &str,
+    current_session_id: &str,
+    session_id: &str,
+) -> Result<()> {
+    if session_id == current_session_id {
+        return Err(anyhow!("use sign out to revoke the current session"));
+    }
+    let primary_session_id = auth_service
+        .get_auth_for(account_id)
+        .and_then(|auth| auth.primary_session_id);
+    if primary_session_id.as_deref() == Some(session_id) {
+        return Err(anyhow!("the primary session cannot be revoked"));
+    }
+    Ok(())
+}
+
 fn transcript_download_key(transcript_id: &str, message_id: &str) -> String {
     format!("transcript:{transcript_id}:{message_id}")
 }
@@ -1445,10 +1682,22 @@ fn forward_transcript_category(source: &str, target_prefix: &str) -> Result<Stri
 mod tests {
     use std::io::Cursor;
     use std::path::Path;
+    use std::sync::Arc;
 
+    use chrono::Utc;
     use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+    use sdk::blaze_message::{message_action, SystemSessionMessage};
 
-    use super::{startup_account_health, validate_sticker_image, SessionUnauthorized};
+    use super::{
+        ensure_revocable_session, startup_account_health, validate_sticker_image,
+        SessionUnauthorized,
+    };
+    use crate::core::message::decrypt::apply_system_session_message;
+    use crate::core::model::auth::AuthService;
+    use crate::db::app::auth::tests::auth;
+    use crate::db::app::AppDatabase;
+    use crate::db::mixin::participant::Participant;
+    use crate::db::MixinDatabase;
 
     fn server_error(code: i64) -> sdk::ApiError {
         sdk::ApiError::Server(sdk::Error {
@@ -1487,6 +1736,92 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn newly_provisioned_sessions_stay_revocable() {
+        let directory = tempfile::tempdir().unwrap();
+        let app_database = Arc::new(
+            AppDatabase::connect_at(directory.path().join("app.db"))
+                .await
+                .unwrap(),
+        );
+        let auth_service = AuthService::new(app_database);
+        let mut account = auth("me", "7000");
+        account.primary_session_id = Some("phone".to_string());
+        auth_service.save_auth(&account).await.unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO conversations (conversation_id, created_at, status) VALUES (?, ?, ?)",
+        )
+        .bind("conversation")
+        .bind(Utc::now().timestamp_millis())
+        .bind(2)
+        .execute(&database.participant_dao.0)
+        .await
+        .unwrap();
+        database
+            .participant_dao
+            .insert_participant(&Participant {
+                conversation_id: "conversation".to_string(),
+                user_id: "me".to_string(),
+                role: None,
+                created_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+        let message = |action: &str, session_id: &str| SystemSessionMessage {
+            action: action.to_string(),
+            user_id: "me".to_string(),
+            session_id: session_id.to_string(),
+            public_key: Some("key".to_string()),
+        };
+        let apply = |message: SystemSessionMessage| {
+            let database = &database;
+            let auth_service = &auth_service;
+            async move {
+                apply_system_session_message(
+                    database,
+                    Some(auth_service),
+                    "me",
+                    "me-session",
+                    &message,
+                    Utc::now(),
+                )
+                .await
+                .unwrap()
+            }
+        };
+        let primary_session_id = || auth_service.get_auth_for("me").unwrap().primary_session_id;
+
+        let event = apply(message(message_action::PROVISION, "tablet")).await;
+        assert_eq!(event.unwrap().session_id, "tablet");
+        assert_eq!(primary_session_id().as_deref(), Some("phone"));
+        let sessions = database
+            .participant_session_dao
+            .get_participant_sessions("conversation")
+            .await
+            .unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].session_id, "tablet");
+        ensure_revocable_session(&auth_service, "me", "me-session", "tablet").unwrap();
+        assert!(ensure_revocable_session(&auth_service, "me", "me-session", "phone").is_err());
+        assert!(ensure_revocable_session(&auth_service, "me", "me-session", "me-session").is_err());
+
+        assert!(apply(message(message_action::PROVISION, "phone"))
+            .await
+            .is_none());
+        assert!(apply(message(message_action::DESTROY, "tablet"))
+            .await
+            .is_none());
+        assert_eq!(primary_session_id().as_deref(), Some("phone"));
+        assert!(apply(message(message_action::DESTROY, "phone"))
+            .await
+            .is_none());
+        assert_eq!(primary_session_id(), None);
+        ensure_revocable_session(&auth_service, "me", "me-session", "phone").unwrap();
+    }
+
     fn png(width: u32, height: u32) -> Vec<u8> {
         let image = RgbaImage::from_fn(width, height, |x, y| {
             Rgba([
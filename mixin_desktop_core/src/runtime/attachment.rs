@@ -15,7 +15,7 @@ use crate::db::mixin::message::AttachmentMessageUpdate;
 use crate::db::mixin::message::MediaStatus;
 use crate::db::path::account_data_directory;
 
-use super::{transcript_download_key, AccountState, MessageAccess};
+use super::{model, transcript_download_key, AccountState, MessageAccess};
 
 pub struct AttachmentAccess {
     state: Arc<AccountState>,
@@ -659,6 +659,39 @@ impl AttachmentAccess {
         Ok(())
     }
 
+    /// Reads plaintext bytes of a video for playback, including a video that
+    /// is still downloading.
+    pub async fn read_video_range(
+        &self,
+        message_id: String,
+        offset: u64,
+        length: u64,
+    ) -> Result<model::VideoRangeItem> {
+        self.ensure_active()?;
+        let message = self
+            .database
+            .message_dao
+            .find_message_by_id(&message_id)
+            .await?
+            .ok_or_else(|| anyhow!("message not found: {message_id}"))?;
+        if !message.category.is_video() {
+            return Err(anyhow!("message is not a video: {message_id}"));
+        }
+        let range = self
+            .app_service
+            .attachment
+            .read_video_range(&message, offset, length)
+            .await?;
+        Ok(model::VideoRangeItem {
+            data: range.bytes,
+            offset,
+            total_size: range.total_size,
+            mime_type: message
+                .media_mime_type
+                .unwrap_or_else(|| "video/mp4".to_string()),
+        })
+    }
+
     pub async fn download_attachment(&self, message_id: String) -> Result<()> {
         let message_id = message_id.as_str();
         let _mutation = self.mutation_gate.read().await;
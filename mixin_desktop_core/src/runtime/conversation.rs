@@ -11,11 +11,15 @@ use futures::stream;
 use futures::{Stream, StreamExt};
 use log::warn;
 use sdk::{
-    generate_conversation_id, group_conversation_id, CircleConversationRequest,
-    ConversationCategory, ConversationRequest, ParticipantRequest,
+    generate_conversation_id, group_conversation_id, message_action, participant_role,
+    CircleConversationRequest, ConversationCategory, ConversationRequest, ParticipantRequest,
 };
 use uuid::Uuid;
 
+use crate::core::mixin_uri::MixinUri;
+use crate::db::mixin::conversation::ConversationStatus;
+use crate::db::mixin::smart_circle::{SmartCircle, SmartCircleCondition, SmartCircleRule};
+
 use super::{model, AccountState};
 
 const DEFAULT_UPDATE_SUBSCRIPTION_THROTTLE: Duration = Duration::from_millis(333);
@@ -67,6 +71,33 @@ impl ConversationAccess {
         )
     }
 
+    /// Re-evaluates the smart circles whenever a conversation changes.
+    pub fn subscribe_smart_circle_changes(
+        &self,
+    ) -> impl Stream<Item = Vec<model::SmartCircleItem>> + Send + 'static {
+        let database = self.database.clone();
+        subscribe_on_updates(
+            move || {
+                let database = database.clone();
+                async move {
+                    Ok(database
+                        .smart_circle_dao
+                        .summaries()
+                        .await?
+                        .into_iter()
+                        .map(Into::into)
+                        .collect())
+                }
+            },
+            vec![self.conversation_updates()],
+            self.shutdown.clone(),
+            UpdateSubscriptionOptions {
+                name: Some("smart_circles"),
+                ..Default::default()
+            },
+        )
+    }
+
     pub fn subscribe_unseen_count_changes(
         &self,
     ) -> impl Stream<Item = Vec<model::ConversationUnseenCount>> + Send + 'static {
@@ -136,13 +167,16 @@ impl ConversationAccess {
         &self,
         code: String,
     ) -> Result<model::CodeResult, crate::error::CoreError> {
-        let code = code.trim();
+        let code = match MixinUri::parse(&code) {
+            Ok(MixinUri::Code { code }) => code,
+            _ => code.trim().to_owned(),
+        };
         if code.is_empty() {
             return Err(anyhow!("code is empty").into());
         }
         let _mutation = self.mutation_gate.read().await;
         self.ensure_active()?;
-        let value = self.client.account_api.code(code).await?;
+        let value = self.client.account_api.code(&code).await?;
         match value.get("type").and_then(serde_json::Value::as_str) {
             Some("user") => {
                 let user: sdk::User = serde_json::from_value(value)?;
@@ -316,6 +350,259 @@ impl ConversationAccess {
         }
     }
 
+    /// Resolves a `mixin://` or `https://mixin.one/` URI, or a raw code, into
+    /// the user, conversation, asset, snapshot, sticker or code it points at.
+    /// Users, assets, snapshots and stickers are fetched and stored when they
+    /// are not known yet. Device auth URIs are only for the phone that links
+    /// a device and are rejected.
+    pub async fn resolve_uri(
+        &self,
+        uri: String,
+    ) -> Result<model::ResolvedUri, crate::error::CoreError> {
+        let uri = uri.trim();
+        if uri.is_empty() {
+            return Err(anyhow!("uri is empty").into());
+        }
+        let parsed = if uri.contains("://") {
+            MixinUri::parse(uri)?
+        } else {
+            MixinUri::Code {
+                code: uri.to_owned(),
+            }
+        };
+        self.ensure_active()?;
+        let result = match &parsed {
+            MixinUri::Code { code } => self.resolve_code(code.clone()).await?,
+            MixinUri::User { user_id } | MixinUri::Transfer { user_id } => {
+                let participant_avatars = self.resolve_user_avatars(&[user_id.as_str()]).await?;
+                model::CodeResult {
+                    kind: if matches!(parsed, MixinUri::User { .. }) {
+                        "user"
+                    } else {
+                        "transfer"
+                    }
+                    .to_string(),
+                    user_id: Some(user_id.clone()),
+                    participant_avatars,
+                    ..Default::default()
+                }
+            }
+            MixinUri::App { app_id, action } => model::CodeResult {
+                kind: "app".to_string(),
+                user_id: Some(app_id.clone()),
+                participant_avatars: self.resolve_user_avatars(&[app_id.as_str()]).await?,
+                action: action.clone(),
+                ..Default::default()
+            },
+            MixinUri::Conversation {
+                conversation_id,
+                user_id,
+            } => {
+                let conversation_id = match (conversation_id, user_id) {
+                    (Some(conversation_id), _) => conversation_id.clone(),
+                    (None, Some(user_id)) => generate_conversation_id(&self.account_id, user_id),
+                    (None, None) => return Err(anyhow!("conversation URI is empty").into()),
+                };
+                let participant_avatars = match user_id {
+                    Some(user_id) => self.resolve_user_avatars(&[user_id.as_str()]).await?,
+                    None => Vec::new(),
+                };
+                let conversation = self
+                    .database
+                    .conversation_dao
+                    .find_conversation_by_id(&conversation_id)
+                    .await?;
+                model::CodeResult {
+                    kind: "conversation".to_string(),
+                    user_id: user_id.clone(),
+                    conversation_name: conversation
+                        .as_ref()
+                        .map(|conversation| conversation.name.clone())
+                        .filter(|name| !name.is_empty()),
+                    participant_avatars,
+                    already_member: conversation.is_some_and(|conversation| {
+                        conversation.status == ConversationStatus::SUCCESS
+                    }),
+                    conversation_id: Some(conversation_id),
+                    ..Default::default()
+                }
+            }
+            MixinUri::Pay(pay) => {
+                let recipients = [pay.recipient.as_str()]
+                    .into_iter()
+                    .filter(|recipient| Uuid::parse_str(recipient).is_ok())
+                    .collect::<Vec<_>>();
+                let participant_avatars = self.resolve_user_avatars(&recipients).await?;
+                let (asset_symbol, asset_icon_url, chain_icon_url) = match &pay.asset_id {
+                    Some(asset_id) => {
+                        let _mutation = self.mutation_gate.read().await;
+                        self.ensure_active()?;
+                        let asset = self.client.asset_api.get_asset_by_id(asset_id).await?;
+                        let chain = self.client.asset_api.get_chain(&asset.chain_id).await?;
+                        self.database.asset_dao.insert_asset(&asset).await?;
+                        self.database.asset_dao.insert_chain(&chain).await?;
+                        (
+                            Some(asset.symbol),
+                            Some(asset.icon_url),
+                            Some(chain.icon_url),
+                        )
+                    }
+                    None => (None, None, None),
+                };
+                model::CodeResult {
+                    kind: "pay".to_string(),
+                    participant_avatars,
+                    asset_id: pay.asset_id.clone(),
+                    asset_symbol,
+                    asset_icon_url,
+                    chain_icon_url,
+                    amount: pay.amount.clone(),
+                    senders: vec![self.account_id.clone()],
+                    receivers: vec![pay.recipient.clone()],
+                    threshold: 1,
+                    ..Default::default()
+                }
+            }
+            MixinUri::Snapshot {
+                snapshot_id,
+                trace_id,
+            } => {
+                let snapshot = self
+                    .resolve_snapshot(snapshot_id.as_deref(), trace_id.as_deref())
+                    .await?;
+                let participant_avatars = match Uuid::parse_str(&snapshot.opponent_id) {
+                    Ok(_) => {
+                        self.resolve_user_avatars(&[snapshot.opponent_id.as_str()])
+                            .await?
+                    }
+                    Err(_) => Vec::new(),
+                };
+                model::CodeResult {
+                    kind: "snapshot".to_string(),
+                    user_id: Some(snapshot.opponent_id).filter(|id| !id.is_empty()),
+                    participant_avatars,
+                    asset_id: Some(snapshot.asset_id),
+                    asset_symbol: snapshot.symbol,
+                    asset_icon_url: snapshot.asset_icon_url,
+                    chain_icon_url: snapshot.chain_icon_url,
+                    amount: Some(snapshot.amount),
+                    state: Some(snapshot.type_field),
+                    ..Default::default()
+                }
+            }
+            MixinUri::Sticker { sticker_id } => {
+                if self
+                    .database
+                    .sticker_dao
+                    .find_sticker_by_id(sticker_id)
+                    .await?
+                    .is_none()
+                {
+                    let _mutation = self.mutation_gate.read().await;
+                    self.ensure_active()?;
+                    let sticker = self
+                        .client
+                        .account_api
+                        .get_sticker_by_id(sticker_id)
+                        .await?;
+                    self.database.sticker_dao.insert(&sticker).await?;
+                }
+                model::CodeResult {
+                    kind: "sticker".to_string(),
+                    ..Default::default()
+                }
+            }
+            MixinUri::DeviceAuth { .. } => {
+                return Err(anyhow!("device auth URIs are only scanned by the phone").into())
+            }
+        };
+        Ok(model::ResolvedUri {
+            uri: parsed,
+            result,
+        })
+    }
+
+    /// Loads a safe snapshot by id, or by the trace id of its transaction,
+    /// fetching it and its token when they are not stored yet.
+    async fn resolve_snapshot(
+        &self,
+        snapshot_id: Option<&str>,
+        trace_id: Option<&str>,
+    ) -> Result<crate::db::mixin::safe_snapshot::SafeSnapshotDetail, crate::error::CoreError> {
+        let fiat_currency = self.profile.borrow().fiat_currency.clone();
+        let dao = &self.database.safe_snapshot_dao;
+        let local = match (snapshot_id, trace_id) {
+            (Some(snapshot_id), _) => dao.find_by_id(snapshot_id, &fiat_currency).await?,
+            (None, Some(trace_id)) => dao.find_by_trace_id(trace_id, &fiat_currency).await?,
+            (None, None) => None,
+        };
+        if let Some(snapshot) = local.filter(|snapshot| snapshot.symbol.is_some()) {
+            return Ok(snapshot);
+        }
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let snapshot_id = match (snapshot_id, trace_id) {
+            (Some(snapshot_id), _) => snapshot_id.to_owned(),
+            (None, Some(trace_id)) => self
+                .client
+                .safe_api
+                .get_transaction(trace_id)
+                .await?
+                .snapshot_id
+                .ok_or(crate::error::CoreError::NotFound)?,
+            (None, None) => return Err(anyhow!("snapshot URI is empty").into()),
+        };
+        let snapshot = self
+            .client
+            .token_api
+            .get_snapshot_by_id(&snapshot_id)
+            .await?;
+        let token = self
+            .client
+            .token_api
+            .get_asset_by_id(&snapshot.asset_id)
+            .await?;
+        let chain = self.client.asset_api.get_chain(&token.chain_id).await?;
+        dao.insert(&snapshot).await?;
+        self.database.asset_dao.insert_chain(&chain).await?;
+        self.database.asset_dao.insert_token(&token).await?;
+        dao.find_by_id(&snapshot_id, &fiat_currency)
+            .await?
+            .ok_or(crate::error::CoreError::NotFound)
+    }
+
+    /// Loads users from the database, fetching and storing the missing ones.
+    async fn resolve_user_avatars(
+        &self,
+        user_ids: &[&str],
+    ) -> anyhow::Result<Vec<model::GroupAvatar>> {
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let user_ids = user_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        let mut users = self.database.user_dao.find_users(&user_ids).await?;
+        let missing = user_ids
+            .iter()
+            .filter(|id| !users.iter().any(|user| user.user_id == **id))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let _mutation = self.mutation_gate.read().await;
+            self.ensure_active()?;
+            let fetched = self.client.user_api.get_users(&missing).await?;
+            users.extend(self.database.user_dao.insert_sdk_users(fetched).await?);
+            self.notify_all_conversations_changed();
+        }
+        Ok(users
+            .into_iter()
+            .map(|user| model::GroupAvatar {
+                user_id: user.user_id,
+                name: user.full_name,
+                avatar_url: user.avatar_url,
+            })
+            .collect())
+    }
+
     pub async fn join_group(&self, code: String) -> Result<String, crate::error::CoreError> {
         let code = code.trim();
         if code.is_empty() {
@@ -731,6 +1018,228 @@ impl ConversationAccess {
         Ok(())
     }
 
+    /// Makes `user_id` an admin when `role` is `ADMIN`, or a regular member
+    /// when `role` is `None`. Only the group owner may change roles.
+    pub async fn set_participant_role(
+        &self,
+        conversation_id: String,
+        user_id: String,
+        role: Option<String>,
+    ) -> Result<(), crate::error::CoreError> {
+        let role = role.filter(|role| !role.is_empty());
+        if let Some(role) = role
+            .as_deref()
+            .filter(|role| !role.eq_ignore_ascii_case(participant_role::ADMIN))
+        {
+            return Err(anyhow!("unsupported participant role: {role}").into());
+        }
+        self.change_participant_role(
+            &conversation_id,
+            &user_id,
+            role.map(|_| participant_role::ADMIN),
+        )
+        .await
+    }
+
+    /// Hands the group over to `user_id`, who must already be a member.
+    pub async fn transfer_ownership(
+        &self,
+        conversation_id: String,
+        user_id: String,
+    ) -> Result<(), crate::error::CoreError> {
+        self.change_participant_role(&conversation_id, &user_id, Some(participant_role::OWNER))
+            .await
+    }
+
+    async fn change_participant_role(
+        &self,
+        conversation_id: &str,
+        user_id: &str,
+        role: Option<&str>,
+    ) -> Result<(), crate::error::CoreError> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let conversation = self
+            .database
+            .conversation_dao
+            .find_conversation_by_id(conversation_id)
+            .await?
+            .ok_or(crate::error::CoreError::NotFound)?;
+        let current_user_role = self
+            .database
+            .participant_dao
+            .find_participant_by_id(conversation_id, &self.account_id)
+            .await?
+            .and_then(|participant| participant.role);
+        let target = self
+            .database
+            .participant_dao
+            .find_participant_by_id(conversation_id, user_id)
+            .await?;
+        if !can_change_participant_role(
+            &conversation,
+            &self.account_id,
+            current_user_role.as_deref(),
+            target.as_ref(),
+            role,
+        ) {
+            return Err(anyhow!("not allowed to change the role of {user_id}").into());
+        }
+        self.client
+            .conversation_api
+            .update_participants(
+                conversation_id,
+                message_action::ROLE,
+                &vec![sdk::Participant {
+                    user_id: user_id.to_string(),
+                    role: Some(role.unwrap_or_default().to_string()),
+                    created_at: Utc::now(),
+                }],
+            )
+            .await?;
+        self.app_service
+            .conversation
+            .refresh_conversation(conversation_id)
+            .await?;
+        self.notify_conversation_changed(conversation_id);
+        Ok(())
+    }
+
+    /// Lists the group's membership changes, newest first. With
+    /// `participant_id` only that member's joins, exits and role changes are
+    /// listed.
+    pub async fn participant_events(
+        &self,
+        conversation_id: String,
+        participant_id: Option<String>,
+        before_created_at_millis: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<model::ParticipantEventView>, crate::error::CoreError> {
+        self.ensure_active()?;
+        Ok(self
+            .database
+            .participant_event_dao
+            .list_items(
+                &conversation_id,
+                participant_id.as_deref(),
+                before_created_at_millis,
+                limit,
+            )
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Lists members who joined through the group's invite link and still
+    /// wait for an owner or admin to approve them, oldest first. Mixin admits
+    /// link joins right away, so a rejected join removes the member again.
+    pub async fn pending_joins(
+        &self,
+        conversation_id: String,
+    ) -> Result<Vec<model::ParticipantEventView>, crate::error::CoreError> {
+        self.ensure_active()?;
+        self.ensure_group_manager(&conversation_id).await?;
+        Ok(self
+            .database
+            .participant_event_dao
+            .list_pending_joins(&conversation_id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Approves the join announced by `message_id`, taking it off
+    /// [`ConversationAccess::pending_joins`].
+    pub async fn approve_join(
+        &self,
+        conversation_id: String,
+        message_id: String,
+    ) -> Result<(), crate::error::CoreError> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        self.ensure_group_manager(&conversation_id).await?;
+        self.database
+            .participant_event_dao
+            .find_pending_join(&conversation_id, &message_id)
+            .await?
+            .ok_or(crate::error::CoreError::NotFound)?;
+        self.database
+            .participant_event_dao
+            .mark_reviewed(&message_id, Utc::now())
+            .await?;
+        self.notify_conversation_changed(&conversation_id);
+        Ok(())
+    }
+
+    /// Rejects the join announced by `message_id` and removes the member who
+    /// joined from the group.
+    pub async fn reject_join(
+        &self,
+        conversation_id: String,
+        message_id: String,
+    ) -> Result<(), crate::error::CoreError> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        self.ensure_group_manager(&conversation_id).await?;
+        let user_id = self
+            .database
+            .participant_event_dao
+            .find_pending_join(&conversation_id, &message_id)
+            .await?
+            .ok_or(crate::error::CoreError::NotFound)?;
+        self.client
+            .conversation_api
+            .update_participants(
+                &conversation_id,
+                message_action::REMOVE,
+                &vec![sdk::Participant {
+                    user_id,
+                    role: None,
+                    created_at: Utc::now(),
+                }],
+            )
+            .await?;
+        self.database
+            .participant_event_dao
+            .mark_reviewed(&message_id, Utc::now())
+            .await?;
+        self.app_service
+            .conversation
+            .refresh_conversation(&conversation_id)
+            .await?;
+        self.notify_conversation_changed(&conversation_id);
+        Ok(())
+    }
+
+    /// Fails unless the current user owns or administers the group.
+    async fn ensure_group_manager(
+        &self,
+        conversation_id: &str,
+    ) -> Result<(), crate::error::CoreError> {
+        let conversation = self
+            .database
+            .conversation_dao
+            .find_conversation_by_id(conversation_id)
+            .await?
+            .ok_or(crate::error::CoreError::NotFound)?;
+        let current_user_role = self
+            .database
+            .participant_dao
+            .find_participant_by_id(conversation_id, &self.account_id)
+            .await?
+            .and_then(|participant| participant.role);
+        if !can_manage_group(
+            &conversation,
+            &self.account_id,
+            current_user_role.as_deref(),
+        ) {
+            return Err(anyhow!("only the owner and admins can manage the group").into());
+        }
+        Ok(())
+    }
+
     pub async fn set_disappearing_messages(
         &self,
         conversation_id: String,
@@ -850,6 +1359,100 @@ impl ConversationAccess {
         Ok(())
     }
 
+    /// Creates a local circle holding the conversations that match all of
+    /// `rules`, or any of them unless `match_all` is set.
+    pub async fn create_smart_circle(
+        &self,
+        name: String,
+        rules: Vec<SmartCircleRule>,
+        match_all: bool,
+    ) -> Result<model::SmartCircleItem, crate::error::CoreError> {
+        let circle_id = Uuid::new_v4().to_string();
+        self.save_smart_circle(circle_id.clone(), name, rules, match_all, Utc::now())
+            .await?;
+        self.smart_circle(&circle_id).await
+    }
+
+    pub async fn update_smart_circle(
+        &self,
+        circle_id: String,
+        name: String,
+        rules: Vec<SmartCircleRule>,
+        match_all: bool,
+    ) -> Result<(), crate::error::CoreError> {
+        let created_at = self
+            .database
+            .smart_circle_dao
+            .find(&circle_id)
+            .await?
+            .ok_or(crate::error::CoreError::NotFound)?
+            .created_at;
+        self.save_smart_circle(circle_id, name, rules, match_all, created_at)
+            .await
+    }
+
+    pub async fn delete_smart_circle(
+        &self,
+        circle_id: String,
+    ) -> Result<(), crate::error::CoreError> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        if !self.database.smart_circle_dao.delete(&circle_id).await? {
+            return Err(crate::error::CoreError::NotFound);
+        }
+        self.notify_all_conversations_changed();
+        Ok(())
+    }
+
+    async fn save_smart_circle(
+        &self,
+        circle_id: String,
+        name: String,
+        rules: Vec<SmartCircleRule>,
+        match_all: bool,
+        created_at: chrono::DateTime<Utc>,
+    ) -> Result<(), crate::error::CoreError> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > 64 {
+            return Err(anyhow!("circle name must contain 1 to 64 characters").into());
+        }
+        if rules.is_empty() {
+            return Err(anyhow!("smart circle must have at least one rule").into());
+        }
+        let has_blank_member = rules.iter().any(|rule| match &rule.condition {
+            SmartCircleCondition::Member { user_id } => user_id.trim().is_empty(),
+            _ => false,
+        });
+        if has_blank_member {
+            return Err(anyhow!("member rule requires a user id").into());
+        }
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        self.database
+            .smart_circle_dao
+            .insert(&SmartCircle {
+                circle_id,
+                name: name.to_owned(),
+                rules: sqlx::types::Json(rules),
+                match_all,
+                created_at,
+            })
+            .await?;
+        self.notify_all_conversations_changed();
+        Ok(())
+    }
+
+    async fn smart_circle(
+        &self,
+        circle_id: &str,
+    ) -> Result<model::SmartCircleItem, crate::error::CoreError> {
+        self.smart_circles()
+            .await?
+            .into_iter()
+            .find(|circle| circle.circle_id == circle_id)
+            .ok_or(crate::error::CoreError::NotFound)
+    }
+
     pub async fn create_group(
         &self,
         name: String,
@@ -910,6 +1513,7 @@ impl ConversationAccess {
         let conversation_id = conversation_id.as_str();
         let _mutation = self.mutation_gate.read().await;
         self.ensure_active()?;
+        self.ensure_group_manager(conversation_id).await?;
         self.client
             .conversation_api
             .update(&ConversationRequest {
@@ -986,6 +1590,19 @@ impl ConversationAccess {
             .collect())
     }
 
+    pub async fn smart_circles(
+        &self,
+    ) -> Result<Vec<model::SmartCircleItem>, crate::error::CoreError> {
+        Ok(self
+            .database
+            .smart_circle_dao
+            .summaries()
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
     pub async fn set_pinned(
         &self,
         conversation_id: String,
@@ -1115,6 +1732,50 @@ impl ConversationAccess {
     }
 }
 
+fn is_owner(
+    conversation: &crate::db::mixin::conversation::Conversation,
+    user_id: &str,
+    role: Option<&str>,
+) -> bool {
+    conversation.owner_id.as_deref() == Some(user_id)
+        || role.is_some_and(|role| role.eq_ignore_ascii_case(participant_role::OWNER))
+}
+
+/// Owners and admins may edit the name and announcement of a group and
+/// review who joined it.
+fn can_manage_group(
+    conversation: &crate::db::mixin::conversation::Conversation,
+    current_user_id: &str,
+    current_user_role: Option<&str>,
+) -> bool {
+    conversation.category == Some(ConversationCategory::Group)
+        && (is_owner(conversation, current_user_id, current_user_role)
+            || current_user_role
+                .is_some_and(|role| role.eq_ignore_ascii_case(participant_role::ADMIN)))
+}
+
+/// Only the owner changes roles, never their own, and the owner is only
+/// replaced by handing ownership over.
+fn can_change_participant_role(
+    conversation: &crate::db::mixin::conversation::Conversation,
+    current_user_id: &str,
+    current_user_role: Option<&str>,
+    target: Option<&crate::db::mixin::participant::Participant>,
+    role: Option<&str>,
+) -> bool {
+    let Some(target) = target else {
+        return false;
+    };
+    conversation.category == Some(ConversationCategory::Group)
+        && is_owner(conversation, current_user_id, current_user_role)
+        && target.user_id != current_user_id
+        && !is_owner(conversation, &target.user_id, target.role.as_deref())
+        && role.is_none_or(|role| {
+            role.eq_ignore_ascii_case(participant_role::ADMIN)
+                || role.eq_ignore_ascii_case(participant_role::OWNER)
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -1125,7 +1786,15 @@ mod tests {
     use futures::StreamExt;
     use tokio::sync::watch;
 
-    use super::{subscribe_on_updates, UpdateStream, UpdateSubscriptionOptions};
+    use chrono::Utc;
+    use sdk::ConversationCategory;
+
+    use super::{
+        can_change_participant_role, can_manage_group, subscribe_on_updates, UpdateStream,
+        UpdateSubscriptionOptions,
+    };
+    use crate::db::mixin::conversation::{Conversation, ConversationStatus};
+    use crate::db::mixin::participant::Participant;
 
     #[tokio::test(start_paused = true)]
     async fn subscribe_on_updates_emits_initial_value_and_coalesces_updates() {
@@ -1161,4 +1830,114 @@ mod tests {
         assert_eq!(query_count.load(Ordering::SeqCst), 2);
         drop(shutdown_sender);
     }
+
+    #[test]
+    fn role_policy_lets_only_the_owner_change_member_roles() {
+        let conversation = group();
+        let member = participant("member", None);
+        let admin = participant("admin", Some("ADMIN"));
+        let owner = participant("owner", Some("OWNER"));
+
+        assert!(can_change_participant_role(
+            &conversation,
+            "owner",
+            Some("OWNER"),
+            Some(&member),
+            Some("ADMIN"),
+        ));
+        assert!(can_change_participant_role(
+            &conversation,
+            "owner",
+            None,
+            Some(&admin),
+            None,
+        ));
+        assert!(can_change_participant_role(
+            &conversation,
+            "owner",
+            Some("OWNER"),
+            Some(&admin),
+            Some("OWNER"),
+        ));
+
+        assert!(!can_change_participant_role(
+            &conversation,
+            "admin",
+            Some("ADMIN"),
+            Some(&member),
+            Some("ADMIN"),
+        ));
+        assert!(!can_change_participant_role(
+            &conversation,
+            "admin",
+            Some("ADMIN"),
+            Some(&owner),
+            None,
+        ));
+        assert!(!can_change_participant_role(
+            &conversation,
+            "owner",
+            Some("OWNER"),
+            Some(&owner),
+            Some("ADMIN"),
+        ));
+        assert!(!can_change_participant_role(
+            &conversation,
+            "owner",
+            Some("OWNER"),
+            None,
+            Some("ADMIN"),
+        ));
+        assert!(!can_change_participant_role(
+            &conversation,
+            "owner",
+            Some("OWNER"),
+            Some(&member),
+            Some("MODERATOR"),
+        ));
+
+        let mut contact = group();
+        contact.category = Some(ConversationCategory::Contact);
+        assert!(!can_change_participant_role(
+            &contact,
+            "owner",
+            Some("OWNER"),
+            Some(&member),
+            Some("ADMIN"),
+        ));
+    }
+
+    #[test]
+    fn group_management_is_limited_to_owner_and_admins() {
+        let conversation = group();
+
+        assert!(can_manage_group(&conversation, "owner", None));
+        assert!(can_manage_group(&conversation, "admin", Some("ADMIN")));
+        assert!(!can_manage_group(&conversation, "member", None));
+    }
+
+    fn group() -> Conversation {
+        Conversation {
+            conversation_id: "conversation".to_string(),
+            owner_id: Some("owner".to_string()),
+            category: Some(ConversationCategory::Group),
+            name: String::new(),
+            icon_url: String::new(),
+            announcement: String::new(),
+            code_url: String::new(),
+            created_at: Utc::now(),
+            status: ConversationStatus::SUCCESS,
+            mute_until: Utc::now(),
+            expire_in: 0,
+        }
+    }
+
+    fn participant(user_id: &str, role: Option<&str>) -> Participant {
+        Participant {
+            conversation_id: "conversation".to_string(),
+            user_id: user_id.to_string(),
+            role: role.map(str::to_string),
+            created_at: Utc::now(),
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use sdk::message_category::MessageCategory as _;
+
 use super::model::*;
 
 impl From<crate::db::mixin::conversation::ConversationListItem> for ConversationListData {
@@ -110,6 +112,21 @@ impl From<crate::db::mixin::participant::ParticipantListItem> for ConversationPa
     }
 }
 
+impl From<crate::db::mixin::participant_event::ParticipantEventItem> for ParticipantEventView {
+    fn from(event: crate::db::mixin::participant_event::ParticipantEventItem) -> Self {
+        Self {
+            message_id: event.message_id,
+            action: event.action,
+            actor_id: event.actor_id,
+            actor_name: event.actor_name,
+            participant_id: event.participant_id,
+            participant_name: event.participant_name,
+            role: event.role,
+            created_at_millis: event.created_at.timestamp_millis(),
+        }
+    }
+}
+
 impl From<crate::db::mixin::user::User> for ConversationParticipantItem {
     fn from(user: crate::db::mixin::user::User) -> Self {
         Self {
@@ -151,6 +168,16 @@ impl From<crate::db::mixin::user::User> for UserProfileItem {
     }
 }
 
+impl From<crate::db::mixin::user::UserAnnotation> for UserAnnotationItem {
+    fn from(annotation: crate::db::mixin::user::UserAnnotation) -> Self {
+        Self {
+            user_id: annotation.user_id,
+            alias: annotation.alias,
+            note: annotation.note,
+        }
+    }
+}
+
 impl From<sdk::App> for SharedAppItem {
     fn from(app: sdk::App) -> Self {
         Self {
@@ -195,6 +222,18 @@ impl From<sdk::Circle> for CircleItem {
     }
 }
 
+impl From<crate::db::mixin::smart_circle::SmartCircleSummary> for SmartCircleItem {
+    fn from(circle: crate::db::mixin::smart_circle::SmartCircleSummary) -> Self {
+        Self {
+            circle_id: circle.circle_id,
+            name: circle.name,
+            rules: circle.rules.0,
+            match_all: circle.match_all,
+            conversation_count: circle.conversation_count,
+        }
+    }
+}
+
 impl From<super::StickerDetail> for StickerDetailItem {
     fn from(detail: super::StickerDetail) -> Self {
         Self {
@@ -257,6 +296,25 @@ impl From<crate::db::mixin::message::ImageMessageItem> for ImageMessageView {
 impl From<crate::db::mixin::message::MessageListItem> for MessageListView {
     fn from(item: crate::db::mixin::message::MessageListItem) -> Self {
         let created_at_micros = item.created_at_micros();
+        let location = item
+            .content
+            .as_deref()
+            .filter(|_| item.category.is_location())
+            .and_then(location_view);
+        let link_preview = match (
+            &item.hyperlink,
+            item.hyperlink_site_name,
+            item.hyperlink_site_title,
+        ) {
+            (Some(url), Some(site_name), Some(title)) => Some(LinkPreviewView {
+                url: url.clone(),
+                site_name,
+                title,
+                description: item.hyperlink_site_description,
+                image_url: item.hyperlink_site_image,
+            }),
+            _ => None,
+        };
         Self {
             message_id: item.message_id,
             conversation_id: item.conversation_id,
@@ -332,12 +390,22 @@ impl From<crate::db::mixin::message::MessageListItem> for MessageListView {
             mention_read: item.mention_read,
             pinned: item.pinned,
             expire_in: item.expire_in,
+            edited_at_micros: item.edited_at.map(|edited_at| edited_at.timestamp_micros()),
+            reactions: Vec::new(),
+            location,
+            snippet: None,
+            link_preview,
         }
     }
 }
 
 impl From<crate::db::mixin::transcript_message::TranscriptMessageListItem> for MessageListView {
     fn from(item: crate::db::mixin::transcript_message::TranscriptMessageListItem) -> Self {
+        let location = item
+            .content
+            .as_deref()
+            .filter(|_| item.category.is_location())
+            .and_then(location_view);
         Self {
             message_id: item.message_id,
             conversation_id: item.conversation_id,
@@ -413,6 +481,78 @@ impl From<crate::db::mixin::transcript_message::TranscriptMessageListItem> for M
             mention_read: None,
             pinned: false,
             expire_in: None,
+            edited_at_micros: None,
+            reactions: Vec::new(),
+            location,
+            snippet: None,
+            link_preview: None,
         }
     }
 }
+
+impl From<crate::db::mixin::message_fts::MessageSnippet> for MessageSnippetView {
+    fn from(snippet: crate::db::mixin::message_fts::MessageSnippet) -> Self {
+        Self {
+            text: snippet.text,
+            highlights: snippet
+                .highlights
+                .into_iter()
+                .map(|(start, end)| TextRangeView {
+                    start: start as u32,
+                    end: end as u32,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<crate::db::mixin::hyperlink::Hyperlink> for LinkPreviewView {
+    fn from(hyperlink: crate::db::mixin::hyperlink::Hyperlink) -> Self {
+        Self {
+            url: hyperlink.hyperlink,
+            site_name: hyperlink.site_name,
+            title: hyperlink.site_title,
+            description: hyperlink.site_description,
+            image_url: hyperlink.site_image,
+        }
+    }
+}
+
+impl From<sdk::LocationMessage> for LocationView {
+    fn from(location: sdk::LocationMessage) -> Self {
+        Self {
+            latitude: location.latitude,
+            longitude: location.longitude,
+            name: location.name,
+            address: location.address,
+        }
+    }
+}
+
+fn location_view(content: &str) -> Option<LocationView> {
+    serde_json::from_str::<sdk::LocationMessage>(content)
+        .ok()
+        .filter(sdk::LocationMessage::is_valid)
+        .map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::location_view;
+
+    #[test]
+    fn location_view_keeps_labels_and_skips_invalid_content() {
+        let view = location_view(
+            r#"{"latitude":0.0,"longitude":121.4737,"name":"Pier","address":null,"venue_type":"foursquare"}"#,
+        )
+        .unwrap();
+        assert_eq!(view.latitude, 0.0);
+        assert_eq!(view.longitude, 121.4737);
+        assert_eq!(view.name.as_deref(), Some("Pier"));
+        assert_eq!(view.address, None);
+
+        assert!(location_view(r#"{"latitude":0.0,"longitude":0.0}"#).is_none());
+        assert!(location_view(r#"{"latitude":95.0,"longitude":10.0}"#).is_none());
+        assert!(location_view("not json").is_none());
+    }
+}
@@ -0,0 +1,480 @@
+//! Shows notification events through the `org.freedesktop.Notifications`
+//! D-Bus service for frontends that do not display notifications themselves.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use futures::StreamExt;
+use log::warn;
+use tokio::task::JoinHandle;
+use zbus::zvariant::Value;
+
+use super::model::NotificationEvent;
+use super::AccountRuntime;
+
+const APP_NAME: &str = "Mixin";
+const MAX_GROUP_LINES: usize = 5;
+const MARK_READ_ACTION: &str = "mark-read";
+/// The action key servers supporting inline replies answer with a
+/// `NotificationReplied` signal.
+const REPLY_ACTION: &str = "inline-reply";
+
+#[zbus::proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    fn close_notification(&self, id: u32) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn action_invoked(&self, id: u32, action_key: String) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn notification_replied(&self, id: u32, text: String) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    fn notification_closed(&self, id: u32, reason: u32) -> zbus::Result<()>;
+}
+
+/// Posts one notification per conversation for as long as it is alive.
+/// Replies and "mark as read" actions are sent back through
+/// `MessageAccess`.
+pub struct DbusNotificationSink {
+    task: JoinHandle<()>,
+}
+
+impl DbusNotificationSink {
+    pub async fn start(runtime: Arc<AccountRuntime>) -> Result<Self> {
+        Self::start_on(runtime, zbus::Connection::session().await?).await
+    }
+
+    pub async fn start_on(
+        runtime: Arc<AccountRuntime>,
+        connection: zbus::Connection,
+    ) -> Result<Self> {
+        let proxy = NotificationsProxy::new(&connection).await?;
+        let row_id = runtime.latest_notification_row_id().await?;
+        let task = tokio::spawn(async move {
+            if let Err(error) = run(runtime, proxy, row_id).await {
+                warn!("D-Bus notifications stopped: {error:#}");
+            }
+        });
+        Ok(Self { task })
+    }
+}
+
+impl Drop for DbusNotificationSink {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn run(
+    runtime: Arc<AccountRuntime>,
+    proxy: NotificationsProxy<'static>,
+    mut row_id: i64,
+) -> Result<()> {
+    let mut actions = proxy.receive_action_invoked().await?;
+    let mut replies = proxy.receive_notification_replied().await?;
+    let mut closed = proxy.receive_notification_closed().await?;
+    let mut changes = runtime.subscribe_notification_changes();
+    let mut shutdown = runtime.subscribe_shutdown();
+    let mut created_at_micros = Utc::now().timestamp_micros();
+    let mut poster = NotificationPoster::new(proxy);
+    loop {
+        loop {
+            let batch = runtime
+                .notification_event_batch(created_at_micros, row_id, 200)
+                .await?;
+            created_at_micros = batch.next_created_at_micros;
+            row_id = batch.next_row_id;
+            for event in &batch.events {
+                if let Err(error) = poster.show(event).await {
+                    warn!("failed to post notification {}: {error}", event.message_id);
+                }
+            }
+            if !batch.has_more {
+                break;
+            }
+        }
+        tokio::select! {
+            changed = changes.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+            }
+            Some(signal) = actions.next() => {
+                let args = signal.args()?;
+                if args.action_key == MARK_READ_ACTION {
+                    poster.act(&runtime, args.id, None).await;
+                }
+            }
+            Some(signal) = replies.next() => {
+                let args = signal.args()?;
+                poster.act(&runtime, args.id, Some(args.text)).await;
+            }
+            Some(signal) = closed.next() => {
+                poster.groups.remove(signal.args()?.id);
+            }
+            changed = shutdown.changed() => {
+                if changed.is_err() || *shutdown.borrow() {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+struct NotificationPoster {
+    proxy: NotificationsProxy<'static>,
+    groups: NotificationGroups,
+}
+
+impl NotificationPoster {
+    fn new(proxy: NotificationsProxy<'static>) -> Self {
+        Self {
+            proxy,
+            groups: NotificationGroups::default(),
+        }
+    }
+
+    async fn show(&mut self, event: &NotificationEvent) -> zbus::Result<()> {
+        match self.groups.apply(event) {
+            Some(GroupUpdate::Post {
+                conversation_id,
+                replaces_id,
+                summary,
+                body,
+            }) => {
+                let hints = HashMap::from([("category", Value::from("im.received"))]);
+                let id = self
+                    .proxy
+                    .notify(
+                        APP_NAME,
+                        replaces_id,
+                        "",
+                        &summary,
+                        &body,
+                        &[MARK_READ_ACTION, "Mark as Read", REPLY_ACTION, "Reply"],
+                        hints,
+                        -1,
+                    )
+                    .await?;
+                self.groups.posted(&conversation_id, id);
+            }
+            Some(GroupUpdate::Close { notification_id }) => {
+                self.proxy.close_notification(notification_id).await?;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Replies with `text` when given, then marks the conversation read and
+    /// closes its notification.
+    async fn act(&mut self, runtime: &AccountRuntime, notification_id: u32, text: Option<String>) {
+        let Some(conversation_id) = self.groups.remove(notification_id) else {
+            return;
+        };
+        let messages = runtime.message_access();
+        if let Some(text) = text.filter(|text| !text.trim().is_empty()) {
+            if let Err(error) = messages
+                .send_text(conversation_id.clone(), text, None, false)
+                .await
+            {
+                warn!("failed to reply from notification: {error:#}");
+            }
+        }
+        if let Err(error) = messages.mark_conversation_read(conversation_id).await {
+            warn!("failed to mark conversation read from notification: {error:#}");
+        }
+        if let Err(error) = self.proxy.close_notification(notification_id).await {
+            warn!("failed to close notification {notification_id}: {error}");
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum GroupUpdate {
+    /// Shows the conversation's notification, replacing `replaces_id` unless
+    /// it is zero.
+    Post {
+        conversation_id: String,
+        replaces_id: u32,
+        summary: String,
+        body: String,
+    },
+    Close {
+        notification_id: u32,
+    },
+}
+
+#[derive(Default)]
+struct NotificationGroups {
+    groups: HashMap<String, NotificationGroup>,
+}
+
+struct NotificationGroup {
+    notification_id: u32,
+    title: String,
+    count: usize,
+    /// The newest `MAX_GROUP_LINES` messages as `(message_id, line)`.
+    lines: Vec<(String, String)>,
+}
+
+impl NotificationGroups {
+    fn apply(&mut self, event: &NotificationEvent) -> Option<GroupUpdate> {
+        if let Some(message_id) = event.dismiss_message_id.as_deref() {
+            let group = self.groups.get_mut(&event.conversation_id)?;
+            let index = group.lines.iter().position(|(id, _)| id == message_id)?;
+            group.lines.remove(index);
+            group.count -= 1;
+            if group.lines.is_empty() {
+                let group = self.groups.remove(&event.conversation_id)?;
+                return (group.notification_id != 0).then_some(GroupUpdate::Close {
+                    notification_id: group.notification_id,
+                });
+            }
+        } else {
+            let group = self
+                .groups
+                .entry(event.conversation_id.clone())
+                .or_insert_with(|| NotificationGroup {
+                    notification_id: 0,
+                    title: String::new(),
+                    count: 0,
+                    lines: Vec::new(),
+                });
+            group.title = event.conversation_name.clone();
+            group.count += 1;
+            group
+                .lines
+                .push((event.message_id.clone(), notification_line(event)));
+            if group.lines.len() > MAX_GROUP_LINES {
+                group.lines.remove(0);
+            }
+        }
+        let group = &self.groups[&event.conversation_id];
+        let summary = if group.count > 1 {
+            format!("{} ({})", group.title, group.count)
+        } else {
+            group.title.clone()
+        };
+        Some(GroupUpdate::Post {
+            conversation_id: event.conversation_id.clone(),
+            replaces_id: group.notification_id,
+            summary,
+            body: group
+                .lines
+                .iter()
+                .map(|(_, line)| line.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+    }
+
+    fn posted(&mut self, conversation_id: &str, notification_id: u32) {
+        if let Some(group) = self.groups.get_mut(conversation_id) {
+            group.notification_id = notification_id;
+        }
+    }
+
+    /// Forgets the group shown as `notification_id`, returning its
+    /// conversation.
+    fn remove(&mut self, notification_id: u32) -> Option<String> {
+        let conversation_id = self
+            .groups
+            .iter()
+            .find(|(_, group)| group.notification_id == notification_id)
+            .map(|(conversation_id, _)| conversation_id.clone())?;
+        self.groups.remove(&conversation_id);
+        Some(conversation_id)
+    }
+}
+
+/// Formats a message as one line of body markup.
+fn notification_line(event: &NotificationEvent) -> String {
+    let content = if event.category.contains("TEXT") || event.category.contains("POST") {
+        event.content.lines().next().unwrap_or_default().to_owned()
+    } else {
+        let kind = event
+            .category
+            .split_once('_')
+            .map_or(event.category.as_str(), |(_, kind)| kind);
+        format!("[{}]", kind.to_lowercase())
+    };
+    let line = if event.conversation_category == "GROUP" {
+        format!("{}: {content}", event.sender_name)
+    } else {
+        content
+    };
+    line.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use tokio::io::{AsyncBufReadExt, BufReader};
+    use zbus::zvariant::OwnedValue;
+
+    use super::{GroupUpdate, NotificationEvent, NotificationGroups, NotificationPoster};
+
+    fn event(
+        message_id: &str,
+        content: &str,
+        dismiss_message_id: Option<&str>,
+    ) -> NotificationEvent {
+        NotificationEvent {
+            message_id: message_id.to_string(),
+            conversation_id: "conversation".to_string(),
+            sender_name: "Alice".to_string(),
+            category: "SIGNAL_TEXT".to_string(),
+            content: content.to_string(),
+            created_at_micros: 0,
+            conversation_name: "Team".to_string(),
+            conversation_category: "GROUP".to_string(),
+            dismiss_message_id: dismiss_message_id.map(str::to_string),
+        }
+    }
+
+    fn post(replaces_id: u32, summary: &str, body: &str) -> Option<GroupUpdate> {
+        Some(GroupUpdate::Post {
+            conversation_id: "conversation".to_string(),
+            replaces_id,
+            summary: summary.to_string(),
+            body: body.to_string(),
+        })
+    }
+
+    #[test]
+    fn groups_messages_by_conversation_and_drops_recalled_ones() {
+        let mut groups = NotificationGroups::default();
+
+        assert_eq!(
+            groups.apply(&event("first", "a < b", None)),
+            post(0, "Team", "Alice: a &lt; b")
+        );
+        groups.posted("conversation", 7);
+        assert_eq!(
+            groups.apply(&event("second", "hi", None)),
+            post(7, "Team (2)", "Alice: a &lt; b\nAlice: hi")
+        );
+        assert_eq!(
+            groups.apply(&event("recall", "first", Some("first"))),
+            post(7, "Team", "Alice: hi")
+        );
+        assert_eq!(
+            groups.apply(&event("recall", "unknown", Some("unknown"))),
+            None
+        );
+        assert_eq!(
+            groups.apply(&event("recall", "second", Some("second"))),
+            Some(GroupUpdate::Close { notification_id: 7 })
+        );
+        assert_eq!(groups.remove(7), None);
+    }
+
+    #[derive(Default)]
+    struct FakeNotifications {
+        calls: Arc<Mutex<Vec<(u32, String)>>>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.Notifications")]
+    impl FakeNotifications {
+        #[allow(clippy::too_many_arguments)]
+        fn notify(
+            &self,
+            _app_name: String,
+            replaces_id: u32,
+            _app_icon: String,
+            _summary: String,
+            body: String,
+            _actions: Vec<String>,
+            _hints: HashMap<String, OwnedValue>,
+            _expire_timeout: i32,
+        ) -> u32 {
+            self.calls.lock().unwrap().push((replaces_id, body));
+            replaces_id.max(1)
+        }
+
+        fn close_notification(&self, id: u32) {
+            self.calls.lock().unwrap().push((id, "closed".to_string()));
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "starts a session bus with dbus-daemon"]
+    async fn posts_and_closes_notifications_on_a_session_bus() {
+        let mut daemon = tokio::process::Command::new("dbus-daemon")
+            .args(["--session", "--nofork", "--print-address"])
+            .stdout(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .unwrap();
+        let mut address = String::new();
+        BufReader::new(daemon.stdout.take().unwrap())
+            .read_line(&mut address)
+            .await
+            .unwrap();
+        let server = FakeNotifications::default();
+        let calls = server.calls.clone();
+        let _server = zbus::connection::Builder::address(address.trim())
+            .unwrap()
+            .name("org.freedesktop.Notifications")
+            .unwrap()
+            .serve_at("/org/freedesktop/Notifications", server)
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client = zbus::connection::Builder::address(address.trim())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let mut poster =
+            NotificationPoster::new(super::NotificationsProxy::new(&client).await.unwrap());
+
+        poster.show(&event("first", "hello", None)).await.unwrap();
+        poster.show(&event("second", "again", None)).await.unwrap();
+        poster
+            .show(&event("recall", "first", Some("first")))
+            .await
+            .unwrap();
+        poster
+            .show(&event("recall", "second", Some("second")))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                (0, "Alice: hello".to_string()),
+                (1, "Alice: hello\nAlice: again".to_string()),
+                (1, "Alice: again".to_string()),
+                (1, "closed".to_string()),
+            ]
+        );
+    }
+}
@@ -7,27 +7,39 @@ use anyhow::{anyhow, Result};
 use chrono::Local;
 use log::{info, warn};
 use sdk::Client;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 
 use crate::core::model::auth::AuthService;
 use crate::core::user_agent::generate_user_agent;
-use crate::db::app::{AppDatabase, PropertyDao, PropertyGroup, SettingDao};
+use crate::db::app::{AppDatabase, Auth, PropertyDao, PropertyGroup, SettingDao};
 use crate::db::path::{account_data_directory, data_directory};
 use crate::db::SignalDatabase;
 use crate::network::{HttpResponse, NetworkService, SharedNetworkService};
 
 use super::login::LoginRuntime;
-use super::mcp::McpServer;
+use super::mcp::{McpServer, McpServerStatus};
+use super::model::{NotificationAction, NotificationActionHandle};
 use super::{credential, AccountRuntime, SessionUnauthorized};
 
 const DEVICE_PROPERTY_KEY: &str = "device_id";
+const MAX_PENDING_NOTIFICATION_ACTIONS: usize = 32;
+
+type PendingNotificationAction = (NotificationActionHandle, NotificationAction);
+type AccountRuntimes = Vec<Arc<AccountRuntime>>;
 
 pub struct DesktopRuntime {
     auth_service: Arc<AuthService>,
     property_dao: PropertyDao,
     pub settings: SettingDao,
     network_service: SharedNetworkService,
-    account: Mutex<Option<Arc<AccountRuntime>>>,
+    /// Running account runtimes, the active account first.
+    accounts: Mutex<AccountRuntimes>,
+    /// Held while an account runtime starts so an account is never started
+    /// twice, leaving `accounts` free during the network startup.
+    account_starts: Mutex<()>,
+    account_changes: watch::Sender<AccountRuntimes>,
+    /// Notification actions taken for accounts that are not running yet.
+    pending_notification_actions: NotificationActionQueue,
     pub mcp_server: Arc<McpServer>,
 }
 
@@ -44,12 +56,16 @@ impl DesktopRuntime {
         auth_service.initialize().await?;
         let mcp_server = McpServer::new(database.setting_dao.clone()).await?;
         let network_service = Arc::new(NetworkService::new(database.setting_dao.clone()).await?);
+        let (account_changes, _) = watch::channel(Vec::new());
         Ok(Self {
             auth_service,
             property_dao: database.property_dao.clone(),
             settings: database.setting_dao.clone(),
             network_service,
-            account: Mutex::new(None),
+            accounts: Mutex::new(Vec::new()),
+            account_starts: Mutex::new(()),
+            account_changes,
+            pending_notification_actions: NotificationActionQueue::default(),
             mcp_server,
         })
     }
@@ -75,30 +91,91 @@ impl DesktopRuntime {
             .await
     }
 
+    /// Starts the active account, or returns it when it is already running.
     pub async fn restore_account(&self) -> Result<Option<Arc<AccountRuntime>>> {
-        let mut active = self.account.lock().await;
-        if let Some(runtime) = active.as_ref() {
-            if runtime.is_running() {
-                info!(
-                    "reusing active account runtime for {}",
-                    runtime.account_id()
-                );
-                return Ok(Some(runtime.clone()));
-            }
-            let runtime = active.take().unwrap();
-            runtime.shutdown().await;
-        }
         let Some(auth) = self.auth_service.get_auth() else {
             return Ok(None);
         };
-        if !device_matches(
-            &self.property_dao,
-            &self.auth_service,
-            &auth.account.user_id,
-            &auth.account.identity_number,
-        )
-        .await?
-        {
+        let runtime = self.start_saved_account(auth).await?;
+        self.sync_mcp(&self.accounts.lock().await).await?;
+        Ok(runtime)
+    }
+
+    /// Starts every saved account that is not running yet. An account that
+    /// fails to start is logged and skipped so it cannot hold the others back.
+    pub async fn restore_accounts(&self) -> Result<Vec<Arc<AccountRuntime>>> {
+        for auth in self.auth_service.auths() {
+            let user_id = auth.user_id.clone();
+            if let Err(error) = self.start_saved_account(auth).await {
+                warn!("failed to restore account runtime for {user_id}: {error:#}");
+            }
+        }
+        let accounts = self.accounts.lock().await;
+        self.sync_mcp(&accounts).await?;
+        Ok(accounts.clone())
+    }
+
+    /// Makes `user_id` the active account, starting it first when needed.
+    pub async fn switch_account(&self, user_id: &str) -> Result<Arc<AccountRuntime>> {
+        let auth = self
+            .auth_service
+            .get_auth_for(user_id)
+            .ok_or_else(|| anyhow!("no saved account for {user_id}"))?;
+        let runtime = self
+            .start_saved_account(auth)
+            .await?
+            .ok_or_else(|| anyhow!("account {user_id} must sign in again"))?;
+        self.auth_service.set_active_auth(user_id).await?;
+        let mut accounts = self.accounts.lock().await;
+        self.publish_accounts(&mut accounts);
+        self.sync_mcp(&accounts).await?;
+        Ok(runtime)
+    }
+
+    pub async fn active_account(&self) -> Option<Arc<AccountRuntime>> {
+        let active_user_id = self.auth_service.get_auth()?.user_id;
+        self.account(&active_user_id).await
+    }
+
+    pub async fn account(&self, user_id: &str) -> Option<Arc<AccountRuntime>> {
+        self.accounts
+            .lock()
+            .await
+            .iter()
+            .find(|runtime| runtime.account_id() == user_id && runtime.is_running())
+            .cloned()
+    }
+
+    pub async fn accounts(&self) -> Vec<Arc<AccountRuntime>> {
+        self.accounts.lock().await.clone()
+    }
+
+    /// Emits the running account runtimes, the active account first,
+    /// whenever an account starts, stops or becomes active.
+    pub fn subscribe_account_changes(&self) -> watch::Receiver<Vec<Arc<AccountRuntime>>> {
+        self.account_changes.subscribe()
+    }
+
+    /// Serves MCP from `account_id`, or from the active account when `None`.
+    pub async fn attach_mcp(&self, account_id: Option<&str>) -> Result<McpServerStatus> {
+        let accounts = self.accounts.lock().await;
+        if let Some(account_id) = account_id {
+            if self.auth_service.get_auth_for(account_id).is_none() {
+                return Err(anyhow!("no saved account for {account_id}"));
+            }
+        }
+        self.mcp_server.set_account_preference(account_id).await?;
+        self.sync_mcp(&accounts).await?;
+        Ok(self.mcp_server.status().await)
+    }
+
+    async fn start_saved_account(&self, auth: Auth) -> Result<Option<Arc<AccountRuntime>>> {
+        let _start = self.account_starts.lock().await;
+        if let Some(runtime) = self.reusable_account(&auth.user_id).await {
+            info!("reusing account runtime for {}", auth.user_id);
+            return Ok(Some(runtime));
+        }
+        if !device_matches(&self.property_dao, &self.auth_service).await? {
             return Ok(None);
         }
         let signal_database = SignalDatabase::connect(auth.account.identity_number.clone())
@@ -114,17 +191,22 @@ impl DesktopRuntime {
             return Ok(None);
         }
         let user_id = auth.account.user_id.clone();
-        match AccountRuntime::start(auth, self.auth_service.clone(), self.settings.clone()).await {
+        match AccountRuntime::start(
+            auth,
+            self.auth_service.clone(),
+            self.settings.clone(),
+            self.network_service.clone(),
+        )
+        .await
+        {
             Ok(runtime) => {
                 let runtime = Arc::new(runtime);
                 info!("started account runtime for {}", runtime.account_id());
-                *active = Some(runtime.clone());
-                drop(active);
-                self.mcp_server.start(runtime.clone()).await?;
+                self.insert_account(&mut self.accounts.lock().await, &runtime);
                 Ok(Some(runtime))
             }
             Err(error) if error.downcast_ref::<SessionUnauthorized>().is_some() => {
-                warn!("saved session is unauthorized; requiring login");
+                warn!("saved session for {user_id} is unauthorized; requiring login");
                 self.auth_service.clear_auth(&user_id).await?;
                 Ok(None)
             }
@@ -132,12 +214,73 @@ impl DesktopRuntime {
         }
     }
 
+    /// Returns the running runtime of `user_id`. A stopped one is removed and
+    /// shut down so it can be started again. Callers hold `account_starts`.
+    async fn reusable_account(&self, user_id: &str) -> Option<Arc<AccountRuntime>> {
+        let stopped = {
+            let mut accounts = self.accounts.lock().await;
+            let index = accounts
+                .iter()
+                .position(|runtime| runtime.account_id() == user_id)?;
+            if accounts[index].is_running() {
+                return Some(accounts[index].clone());
+            }
+            let runtime = accounts.remove(index);
+            self.publish_accounts(&mut accounts);
+            runtime
+        };
+        stopped.shutdown().await;
+        None
+    }
+
+    /// Adds a started runtime and runs the notification actions queued for
+    /// its account.
+    fn insert_account(&self, accounts: &mut AccountRuntimes, runtime: &Arc<AccountRuntime>) {
+        accounts.push(runtime.clone());
+        self.publish_accounts(accounts);
+        self.run_pending_notification_actions(runtime);
+    }
+
+    /// Drops stopped runtimes, orders the rest like the saved authorizations
+    /// and notifies subscribers.
+    fn publish_accounts(&self, accounts: &mut AccountRuntimes) {
+        accounts.retain(|runtime| runtime.is_running());
+        let auths = self.auth_service.auths();
+        accounts.sort_by_key(|runtime| {
+            auths
+                .iter()
+                .position(|auth| auth.user_id == runtime.account_id())
+                .unwrap_or(usize::MAX)
+        });
+        self.account_changes.send_replace(accounts.clone());
+    }
+
+    /// Attaches MCP to the preferred account, falling back to the active one,
+    /// and stops it when that account is not running.
+    async fn sync_mcp(&self, accounts: &AccountRuntimes) -> Result<()> {
+        let preference = self.mcp_server.account_preference().await?;
+        let target = match preference {
+            Some(account_id) => accounts
+                .iter()
+                .find(|runtime| runtime.account_id() == account_id),
+            None => accounts.first(),
+        };
+        match target {
+            Some(runtime) if self.mcp_server.is_attached(runtime).await => {}
+            Some(runtime) => {
+                self.mcp_server.start(runtime.clone()).await?;
+            }
+            None => self.mcp_server.stop().await,
+        }
+        Ok(())
+    }
+
     pub async fn recreate_account_database(&self) -> Result<()> {
-        self.shutdown_active_account().await;
         let auth = self
             .auth_service
             .get_auth()
             .ok_or_else(|| anyhow!("no saved account"))?;
+        self.shutdown_saved_account(&auth.user_id).await;
         let database = account_data_directory(&auth.account.identity_number)?.join("mixin.db");
         rename_with_timestamp_if_exists(&database).await?;
         remove_if_exists(&database.with_extension("db-shm")).await?;
@@ -168,71 +311,181 @@ impl DesktopRuntime {
         Ok(())
     }
 
+    /// Starts signing in another account; running accounts keep running.
     pub async fn begin_login(&self) -> Result<LoginRuntime> {
-        if self.account.lock().await.is_some() {
-            return Err(anyhow!("account runtime already active"));
-        }
         LoginRuntime::start(self.auth_service.clone(), self.property_dao.clone()).await
     }
 
     pub async fn wait_login(&self, login: &LoginRuntime) -> Result<Arc<AccountRuntime>> {
         let auth = login.wait_authorization().await?;
-        let mut active = self.account.lock().await;
-        if let Some(runtime) = active.as_ref() {
-            if runtime.is_running() && runtime.account_id() == auth.account.user_id {
-                return Ok(runtime.clone());
-            }
-            let runtime = active.take().unwrap();
-            runtime.shutdown().await;
+        let _start = self.account_starts.lock().await;
+        if let Some(runtime) = self.reusable_account(&auth.account.user_id).await {
+            let mut accounts = self.accounts.lock().await;
+            self.publish_accounts(&mut accounts);
+            self.sync_mcp(&accounts).await?;
+            login.finish();
+            return Ok(runtime);
         }
         let runtime = Arc::new(
-            AccountRuntime::start(auth, self.auth_service.clone(), self.settings.clone())
-                .await
-                .map_err(|error| anyhow!("login_provisioning_error:{error}"))?,
+            AccountRuntime::start(
+                auth,
+                self.auth_service.clone(),
+                self.settings.clone(),
+                self.network_service.clone(),
+            )
+            .await
+            .map_err(|error| anyhow!("login_provisioning_error:{error}"))
+            .inspect_err(|error| login.fail(error))?,
         );
-        *active = Some(runtime.clone());
-        drop(active);
-        self.mcp_server.start(runtime.clone()).await?;
+        let mut accounts = self.accounts.lock().await;
+        self.insert_account(&mut accounts, &runtime);
+        self.sync_mcp(&accounts).await?;
+        login.finish();
         Ok(runtime)
     }
 
-    pub async fn shutdown_account(&self, runtime: &Arc<AccountRuntime>) {
-        let mut active = self.account.lock().await;
-        let was_active = active
-            .as_ref()
-            .is_some_and(|current| Arc::ptr_eq(current, runtime));
-        if was_active {
-            active.take();
-            self.mcp_server.stop().await;
+    /// Performs a notification action on the account it belongs to. While
+    /// accounts are starting up this waits for them; when that signed-in
+    /// account is not running the action is queued until it starts.
+    pub async fn perform_notification_action(
+        &self,
+        handle: NotificationActionHandle,
+        action: NotificationAction,
+    ) -> Result<()> {
+        let accounts = self.accounts.lock().await;
+        if let Some(runtime) = accounts
+            .iter()
+            .find(|runtime| runtime.account_id() == handle.account_id && runtime.is_running())
+        {
+            let runtime = runtime.clone();
+            drop(accounts);
+            return runtime.perform_notification_action(&handle, action).await;
         }
-        runtime.shutdown().await;
+        if self.auth_service.get_auth_for(&handle.account_id).is_none() {
+            return Err(anyhow!(
+                "notification belongs to an account that is not signed in"
+            ));
+        }
+        self.pending_notification_actions.push(handle, action)
+    }
+
+    /// Must be called with the account lock held so no action is queued
+    /// after the queue has been drained.
+    fn run_pending_notification_actions(&self, runtime: &Arc<AccountRuntime>) {
+        let pending = self.pending_notification_actions.take(runtime.account_id());
+        if pending.is_empty() {
+            return;
+        }
+        let runtime = runtime.clone();
+        tokio::spawn(async move {
+            for (handle, action) in pending {
+                if let Err(error) = runtime.perform_notification_action(&handle, action).await {
+                    warn!(
+                        "failed to perform queued notification action for {}: {error:#}",
+                        handle.message_id
+                    );
+                }
+            }
+        });
+    }
+
+    pub async fn shutdown_account(&self, runtime: &Arc<AccountRuntime>) {
+        let mut accounts = self.accounts.lock().await;
+        let Some(index) = accounts
+            .iter()
+            .position(|current| Arc::ptr_eq(current, runtime))
+        else {
+            runtime.shutdown().await;
+            return;
+        };
+        accounts.remove(index);
+        self.stop_account(&mut accounts, runtime).await;
     }
 
     pub async fn sign_out_account(&self, runtime: &Arc<AccountRuntime>) -> Result<()> {
-        let mut active = self.account.lock().await;
-        if !active
-            .as_ref()
-            .is_some_and(|current| Arc::ptr_eq(current, runtime))
-        {
+        let mut accounts = self.accounts.lock().await;
+        let Some(index) = accounts
+            .iter()
+            .position(|current| Arc::ptr_eq(current, runtime))
+        else {
             return Err(anyhow!("account runtime is no longer active"));
+        };
+        if self.mcp_server.is_attached(runtime).await {
+            self.mcp_server.stop().await;
         }
-        self.mcp_server.stop().await;
         let result = runtime.sign_out().await;
-        active.take();
+        accounts.remove(index);
         if result.is_err() {
             runtime.shutdown().await;
         }
+        let preference = self.mcp_server.account_preference().await;
+        if preference.is_ok_and(|preference| preference.as_deref() == Some(runtime.account_id())) {
+            if let Err(error) = self.mcp_server.set_account_preference(None).await {
+                warn!("failed to reset MCP account after sign out: {error:#}");
+            }
+        }
+        self.publish_accounts(&mut accounts);
+        if let Err(error) = self.sync_mcp(&accounts).await {
+            warn!("failed to reattach MCP after sign out: {error:#}");
+        }
         result
     }
 
-    async fn shutdown_active_account(&self) {
-        let mut active = self.account.lock().await;
-        let runtime = active.take();
-        self.mcp_server.stop().await;
-        if let Some(runtime) = runtime {
-            runtime.shutdown().await;
+    async fn shutdown_saved_account(&self, user_id: &str) {
+        let mut accounts = self.accounts.lock().await;
+        let Some(index) = accounts
+            .iter()
+            .position(|runtime| runtime.account_id() == user_id)
+        else {
+            return;
+        };
+        let runtime = accounts.remove(index);
+        self.stop_account(&mut accounts, &runtime).await;
+    }
+
+    /// Shuts down a runtime already removed from `accounts` and moves MCP
+    /// elsewhere when it was attached to it.
+    async fn stop_account(&self, accounts: &mut AccountRuntimes, runtime: &Arc<AccountRuntime>) {
+        if self.mcp_server.is_attached(runtime).await {
+            self.mcp_server.stop().await;
         }
-        drop(active);
+        runtime.shutdown().await;
+        self.publish_accounts(accounts);
+        if let Err(error) = self.sync_mcp(accounts).await {
+            warn!("failed to reattach MCP after account shutdown: {error:#}");
+        }
+    }
+}
+
+/// Notification actions waiting for their account to start, oldest first.
+#[derive(Default)]
+struct NotificationActionQueue(std::sync::Mutex<Vec<PendingNotificationAction>>);
+
+impl NotificationActionQueue {
+    /// Queues an action, refusing new ones once the queue is full so no
+    /// reply is lost without the caller knowing.
+    fn push(&self, handle: NotificationActionHandle, action: NotificationAction) -> Result<()> {
+        let mut pending = self.lock();
+        if pending.len() >= MAX_PENDING_NOTIFICATION_ACTIONS {
+            return Err(anyhow!("too many notification actions are waiting"));
+        }
+        pending.push((handle, action));
+        Ok(())
+    }
+
+    /// Removes and returns the actions of `account_id`, keeping the others.
+    fn take(&self, account_id: &str) -> Vec<PendingNotificationAction> {
+        let (taken, rest) = std::mem::take(&mut *self.lock())
+            .into_iter()
+            .partition(|(handle, _)| handle.account_id == account_id);
+        *self.lock() = rest;
+        taken
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<PendingNotificationAction>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
     }
 }
 
@@ -256,12 +509,9 @@ async fn remove_if_exists(path: &Path) -> Result<()> {
     }
 }
 
-async fn device_matches(
-    property_dao: &PropertyDao,
-    auth_service: &AuthService,
-    user_id: &str,
-    identity_number: &str,
-) -> Result<bool> {
+/// Checks the data directory was created on this machine. When it was
+/// copied from another one every saved account is signed out locally.
+async fn device_matches(property_dao: &PropertyDao, auth_service: &AuthService) -> Result<bool> {
     let Some(current) = current_device_id().await else {
         return Ok(true);
     };
@@ -278,12 +528,14 @@ async fn device_matches(
         return Ok(true);
     }
 
-    auth_service.clear_auth(user_id).await?;
-    let directory = account_data_directory(identity_number)?;
-    match tokio::fs::remove_dir_all(&directory).await {
-        Ok(()) => {}
-        Err(error) if error.kind() == ErrorKind::NotFound => {}
-        Err(error) => return Err(error.into()),
+    for auth in auth_service.auths() {
+        auth_service.clear_auth(&auth.user_id).await?;
+        let directory = account_data_directory(&auth.account.identity_number)?;
+        match tokio::fs::remove_dir_all(&directory).await {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
     }
     property_dao
         .set(PropertyGroup::Account, DEVICE_PROPERTY_KEY, &current)
@@ -349,3 +601,116 @@ fn current_device_id_sync() -> Option<String> {
     #[allow(unreachable_code)]
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handle(account_id: &str, message_id: &str) -> NotificationActionHandle {
+        NotificationActionHandle {
+            account_id: account_id.to_string(),
+            conversation_id: "conversation".to_string(),
+            message_id: message_id.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn actions_for_accounts_that_are_not_signed_in_are_rejected() {
+        let directory = tempfile::tempdir().unwrap();
+        let database = Arc::new(
+            AppDatabase::connect_at(directory.path().join("app.db"))
+                .await
+                .unwrap(),
+        );
+        let (account_changes, _) = watch::channel(Vec::new());
+        let runtime = DesktopRuntime {
+            auth_service: Arc::new(AuthService::new(database.clone())),
+            property_dao: database.property_dao.clone(),
+            settings: database.setting_dao.clone(),
+            network_service: Arc::new(
+                NetworkService::new(database.setting_dao.clone())
+                    .await
+                    .unwrap(),
+            ),
+            accounts: Mutex::new(Vec::new()),
+            account_starts: Mutex::new(()),
+            account_changes,
+            pending_notification_actions: NotificationActionQueue::default(),
+            mcp_server: McpServer::new(database.setting_dao.clone()).await.unwrap(),
+        };
+
+        assert!(runtime
+            .perform_notification_action(handle("stranger", "1"), NotificationAction::MarkRead)
+            .await
+            .is_err());
+        assert!(runtime
+            .pending_notification_actions
+            .take("stranger")
+            .is_empty());
+    }
+
+    #[test]
+    fn queued_actions_drain_only_for_their_account_in_order() {
+        let queue = NotificationActionQueue::default();
+        queue
+            .push(
+                handle("alice", "1"),
+                NotificationAction::Reply {
+                    text: "hi".to_string(),
+                },
+            )
+            .unwrap();
+        queue
+            .push(handle("bob", "2"), NotificationAction::MarkRead)
+            .unwrap();
+        queue
+            .push(handle("alice", "3"), NotificationAction::MuteForOneHour)
+            .unwrap();
+
+        assert!(queue.take("carol").is_empty());
+        let alice = queue.take("alice");
+        assert_eq!(
+            alice
+                .iter()
+                .map(|(handle, _)| handle.message_id.as_str())
+                .collect::<Vec<_>>(),
+            ["1", "3"]
+        );
+        assert_eq!(
+            alice[0].1,
+            NotificationAction::Reply {
+                text: "hi".to_string()
+            }
+        );
+        assert!(queue.take("alice").is_empty());
+        assert_eq!(
+            queue.take("bob"),
+            [(handle("bob", "2"), NotificationAction::MarkRead)]
+        );
+    }
+
+    #[test]
+    fn full_queue_rejects_new_actions_and_keeps_queued_replies() {
+        let queue = NotificationActionQueue::default();
+        for index in 0..MAX_PENDING_NOTIFICATION_ACTIONS {
+            queue
+                .push(
+                    handle("alice", &index.to_string()),
+                    NotificationAction::Reply {
+                        text: index.to_string(),
+                    },
+                )
+                .unwrap();
+        }
+        assert!(queue
+            .push(handle("alice", "overflow"), NotificationAction::MarkRead)
+            .is_err());
+
+        let pending = queue.take("alice");
+        assert_eq!(pending.len(), MAX_PENDING_NOTIFICATION_ACTIONS);
+        assert_eq!(pending[0].0.message_id, "0");
+        assert!(queue
+            .push(handle("alice", "overflow"), NotificationAction::MarkRead)
+            .is_ok());
+    }
+}
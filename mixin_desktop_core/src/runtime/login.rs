@@ -2,19 +2,50 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Result;
-use tokio::sync::{watch, Mutex};
+use tokio::sync::{broadcast, watch, Mutex};
+use tokio::time::{sleep, Instant};
 
 use crate::core::model::auth::{AuthService, AuthorizationSession};
+use crate::core::qr_code::{QrMatrix, DEFAULT_QUIET_ZONE};
 use crate::db::app::{Auth, PropertyDao};
 
 use super::desktop::record_current_device;
 
+/// How long a provisioning QR code stays valid before it is replaced.
+const AUTHORIZATION_WINDOW: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Progress of a login, from showing the QR code to the account running.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoginStatus {
+    /// Waiting for the primary device to scan `auth_url`.
+    WaitingForScan {
+        auth_url: String,
+    },
+    /// The primary device scanned the code and approved the login; this
+    /// device is now registering as a new session. Provisioning exposes no
+    /// separate event for a scan that is not yet approved.
+    Verifying,
+    /// Setting up signal keys and starting the account.
+    SyncingKeys,
+    Ready,
+    /// The QR code expired; a fresh one follows as `WaitingForScan`.
+    Expired,
+    Failed {
+        message: String,
+    },
+}
+
 pub struct LoginRuntime {
     auth_service: Arc<AuthService>,
     session: Mutex<Option<AuthorizationSession>>,
-    auth_url: String,
+    auth_url: std::sync::Mutex<String>,
     property_dao: PropertyDao,
     cancelled: watch::Sender<bool>,
+    status: std::sync::Mutex<LoginStatus>,
+    /// Every status change in order, so a short-lived `Expired` is not
+    /// replaced by the refreshed `WaitingForScan` before subscribers see it.
+    status_events: broadcast::Sender<LoginStatus>,
 }
 
 impl LoginRuntime {
@@ -24,18 +55,47 @@ impl LoginRuntime {
     ) -> Result<Self> {
         let session = auth_service.begin_authorization(desktop_platform()).await?;
         let auth_url = session.auth_url().to_string();
+        let status = std::sync::Mutex::new(LoginStatus::WaitingForScan {
+            auth_url: auth_url.clone(),
+        });
+        let (status_events, _) = broadcast::channel(16);
         let (cancelled, _) = watch::channel(false);
         Ok(Self {
             auth_service,
             session: Mutex::new(Some(session)),
-            auth_url,
+            auth_url: std::sync::Mutex::new(auth_url),
             property_dao,
             cancelled,
+            status,
+            status_events,
         })
     }
 
-    pub fn auth_url(&self) -> &str {
-        &self.auth_url
+    /// The link encoded in the QR code currently shown; it changes whenever
+    /// an expired code is refreshed.
+    pub fn auth_url(&self) -> String {
+        self.auth_url.lock().unwrap().clone()
+    }
+
+    /// The current QR code as Unicode half blocks for terminals. `invert`
+    /// suits light text on a dark background.
+    pub fn qr_half_blocks(&self, invert: bool) -> Result<String> {
+        Ok(QrMatrix::encode(&self.auth_url())?.to_half_blocks(DEFAULT_QUIET_ZONE, invert))
+    }
+
+    /// The current QR code as a PNG with `scale` pixels per module.
+    pub fn qr_png(&self, scale: u32) -> Result<Vec<u8>> {
+        QrMatrix::encode(&self.auth_url())?.to_png(scale, DEFAULT_QUIET_ZONE)
+    }
+
+    pub fn status(&self) -> LoginStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    /// The current status and a receiver for every later change.
+    pub fn subscribe_status(&self) -> (LoginStatus, broadcast::Receiver<LoginStatus>) {
+        let status = self.status.lock().unwrap();
+        (status.clone(), self.status_events.subscribe())
     }
 
     pub fn cancel(&self) {
@@ -43,8 +103,33 @@ impl LoginRuntime {
     }
 
     pub(super) async fn wait_authorization(&self) -> Result<Auth> {
+        let result = self.authorize().await;
+        if let Err(error) = &result {
+            self.fail(error);
+        }
+        result
+    }
+
+    /// Marks the login complete once its account is running.
+    pub(super) fn finish(&self) {
+        self.set_status(LoginStatus::Ready);
+    }
+
+    pub(super) fn fail(&self, error: &anyhow::Error) {
+        self.set_status(LoginStatus::Failed {
+            message: error.to_string(),
+        });
+    }
+
+    fn set_status(&self, status: LoginStatus) {
+        let mut current = self.status.lock().unwrap();
+        *current = status.clone();
+        let _ = self.status_events.send(status);
+    }
+
+    async fn authorize(&self) -> Result<Auth> {
         let mut session = self.session.lock().await;
-        let active_session = session
+        let mut active_session = session
             .take()
             .ok_or_else(|| anyhow::anyhow!("login is no longer active"))?;
         drop(session);
@@ -53,13 +138,40 @@ impl LoginRuntime {
         if *cancelled.borrow() {
             anyhow::bail!("login cancelled");
         }
-        let result = tokio::select! {
-            result = self.auth_service.wait_authorization(
-                &active_session,
-                Duration::from_secs(60),
-            ) => result?,
-            _ = cancelled.changed() => anyhow::bail!("login cancelled"),
+        let mut deadline = Instant::now() + AUTHORIZATION_WINDOW;
+        let secret = loop {
+            tokio::select! {
+                secret = self.auth_service.provisioning_secret(&active_session) => {
+                    if let Some(secret) = secret? {
+                        break secret;
+                    }
+                }
+                _ = cancelled.changed() => anyhow::bail!("login cancelled"),
+            }
+            if Instant::now() >= deadline {
+                self.set_status(LoginStatus::Expired);
+                active_session = tokio::select! {
+                    session = self.auth_service.begin_authorization(desktop_platform()) => session?,
+                    _ = cancelled.changed() => anyhow::bail!("login cancelled"),
+                };
+                let auth_url = active_session.auth_url().to_string();
+                *self.auth_url.lock().unwrap() = auth_url.clone();
+                self.set_status(LoginStatus::WaitingForScan { auth_url });
+                deadline = Instant::now() + AUTHORIZATION_WINDOW;
+                continue;
+            }
+            tokio::select! {
+                _ = sleep(POLL_INTERVAL) => {}
+                _ = cancelled.changed() => anyhow::bail!("login cancelled"),
+            }
         };
+
+        self.set_status(LoginStatus::Verifying);
+        let result = self
+            .auth_service
+            .verify_authorization(&active_session, &secret)
+            .await?;
+        self.set_status(LoginStatus::SyncingKeys);
         let auth = self.auth_service.complete_authorization(result).await?;
         record_current_device(&self.property_dao).await?;
         Ok(auth)
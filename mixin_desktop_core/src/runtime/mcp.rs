@@ -21,6 +21,7 @@ use rmcp::transport::streamable_http_server::{
     session::local::LocalSessionManager, StreamableHttpServerConfig, StreamableHttpService,
 };
 use rmcp::{schemars, tool, tool_handler, tool_router, ServerHandler};
+use sdk::generate_conversation_id;
 use serde_json::{json, Map, Value};
 use subtle::ConstantTimeEq;
 use tokio::net::TcpListener;
@@ -29,6 +30,7 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use crate::core::mixin_uri::MixinUri;
 use crate::db::app::SettingDao;
 
 use super::AccountRuntime;
@@ -38,6 +40,7 @@ const MCP_ENABLED_KEY: &str = "enable_mcp_server";
 const MCP_TOKEN_KEY: &str = "mcp_server_token";
 const MCP_DRAFT_TOOLS_ENABLED_KEY: &str = "enable_mcp_draft_tools";
 const MCP_CIRCLE_MANAGEMENT_ENABLED_KEY: &str = "enable_mcp_circle_management";
+const MCP_ACCOUNT_ID_KEY: &str = "mcp_account_id";
 const MCP_SETTING_KEYS: [&str; 4] = [
     MCP_ENABLED_KEY,
     MCP_TOKEN_KEY,
@@ -166,6 +169,34 @@ impl McpServer {
         state.apply(settings, true).await
     }
 
+    /// The account MCP should serve, or `None` to follow the active account.
+    pub async fn account_preference(&self) -> Result<Option<String>> {
+        Self::decode_setting(&self.settings, MCP_ACCOUNT_ID_KEY, None).await
+    }
+
+    pub async fn set_account_preference(&self, account_id: Option<&str>) -> Result<()> {
+        let value = serde_json::to_string(&account_id)?;
+        self.settings.set(MCP_ACCOUNT_ID_KEY, Some(&value)).await
+    }
+
+    pub async fn attached_account_id(&self) -> Option<String> {
+        self.state
+            .lock()
+            .await
+            .runtime
+            .as_ref()
+            .map(|runtime| runtime.account_id().to_owned())
+    }
+
+    pub async fn is_attached(&self, runtime: &Arc<AccountRuntime>) -> bool {
+        self.state
+            .lock()
+            .await
+            .runtime
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, runtime))
+    }
+
     pub async fn stop(&self) {
         let mut state = self.state.lock().await;
         state.runtime = None;
@@ -518,10 +549,17 @@ impl McpService {
                     .into_iter()
                     .next()
             } else if let Some(uri) = input.uri {
-                let id = uri
-                    .strip_prefix("mixin://conversations/")
-                    .ok_or_else(|| anyhow!("unsupported conversation URI"))?;
-                let id = require_non_empty(id.to_owned(), "conversation_id")?;
+                let id = match MixinUri::parse(&uri)? {
+                    MixinUri::Conversation {
+                        conversation_id: Some(id),
+                        ..
+                    } => id,
+                    MixinUri::Conversation {
+                        user_id: Some(user_id),
+                        ..
+                    } => generate_conversation_id(self.state.runtime.account_id(), &user_id),
+                    _ => return Err(anyhow!("unsupported conversation URI")),
+                };
                 self.state
                     .runtime
                     .conversation_access()
@@ -712,17 +750,20 @@ impl McpService {
             .map_err(|error| error.to_string())
     }
 
-    #[tool(description = "List local circles and their conversation counts.")]
+    #[tool(
+        description = "List local circles and smart circles with their conversation counts. Either id works as circle_id in mixin_list_conversations."
+    )]
     async fn mixin_list_circles(&self) -> Result<Json<ToolOutput>, String> {
-        let result = self
-            .state
-            .runtime
-            .conversation_access()
-            .circles()
-            .await
-            .map(|circles| {
-                json!({"circles": circles.into_iter().map(circle_json).collect::<Vec<_>>()})
-            });
+        let access = self.state.runtime.conversation_access();
+        let result = async {
+            let circles = access.circles().await?;
+            let smart_circles = access.smart_circles().await?;
+            Ok::<_, crate::error::CoreError>(json!({
+                "circles": circles.into_iter().map(circle_json).collect::<Vec<_>>(),
+                "smart_circles": smart_circles.into_iter().map(smart_circle_json).collect::<Vec<_>>(),
+            }))
+        }
+        .await;
         result
             .map(object)
             .map(Json)
@@ -1031,6 +1072,10 @@ fn circle_json(item: super::model::CircleItem) -> Value {
     json!({"circle_id":item.circle_id,"name":item.name,"conversation_count":item.conversation_count})
 }
 
+fn smart_circle_json(item: super::model::SmartCircleItem) -> Value {
+    json!({"circle_id":item.circle_id,"name":item.name,"rules":item.rules,"match_all":item.match_all,"conversation_count":item.conversation_count})
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -13,15 +13,22 @@ use uuid::Uuid;
 
 use sdk::message_category::{MessageCategory as _, MESSAGE_PIN};
 use sdk::{
-    AttachmentMessage, ContactMessage, ConversationCategory, LiveMessage, MessageStatus,
-    PinMessagePayload, StickerMessage,
+    AttachmentMessage, ContactMessage, ConversationCategory, LiveMessage, LocationMessage,
+    MessageStatus, PinMessagePayload, StickerMessage,
 };
 
 use crate::core::attachment::{attachment_file_name, attachment_path, transcript_attachment_path};
+use crate::core::link_preview::{fetch_link_preview, find_urls};
 use crate::core::model::job::sanitize_transcript_app_card;
 use crate::core::model::AttachmentExtra;
+use crate::core::video::probe_video;
+use crate::db::app::LinkPreviewSettings;
 use crate::db::mixin::job::Job;
 use crate::db::mixin::message::{AttachmentMessageUpdate, MediaStatus, Message};
+use crate::db::mixin::message_fts::{
+    message_fts_content, MessageFtsItem, MessageSearchFilter, MessageSearchOrder,
+};
+use crate::db::mixin::message_reaction::MessageReaction;
 use crate::db::mixin::pin_message::PinMessageMinimal;
 use crate::db::mixin::transcript_message::TranscriptMessage;
 use crate::db::path::account_data_directory;
@@ -37,11 +44,34 @@ pub struct MessageAccess {
 }
 
 impl MessageAccess {
+    /// Previews cached in `hyperlinks` are dropped once the settings no
+    /// longer allow them.
     fn message_list_view(
         &self,
         item: crate::db::mixin::message::MessageListItem,
+        link_previews: &LinkPreviewSettings,
     ) -> Result<model::MessageListView> {
-        let mut view: model::MessageListView = item.into();
+        let reactions = item
+            .reaction_groups()
+            .into_iter()
+            .map(|group| model::MessageReactionView {
+                count: group.user_ids.len() as i64,
+                reacted: group.user_ids.contains(&self.account_id),
+                emoji: group.emoji,
+            })
+            .collect();
+        let mut view = model::MessageListView {
+            reactions,
+            ..item.into()
+        };
+        let received = view.sender_id != self.account_id;
+        if view
+            .link_preview
+            .as_ref()
+            .is_some_and(|preview| !link_previews.allows_url(&preview.url, received))
+        {
+            view.link_preview = None;
+        }
         self.normalize_local_media_url(&mut view, false)?;
         self.normalize_quote_local_media_url(&mut view, false)?;
         Ok(view)
@@ -217,7 +247,11 @@ impl MessageAccess {
             .media_url
             .as_deref()
             .ok_or_else(|| anyhow!("remote image has no URL"))?;
-        let bytes = self.app_service.attachment.download_public(url).await?;
+        let bytes = self
+            .app_service
+            .attachment
+            .download_public(&self.network_service, url)
+            .await?;
         let image = image::ImageReader::new(Cursor::new(bytes.as_slice()))
             .with_guessed_format()?
             .decode()?;
@@ -360,6 +394,7 @@ impl MessageAccess {
         }
         let before_created_at_millis =
             before_created_at_micros.map(|value| value.div_euclid(1_000));
+        let link_previews = self.settings.link_preview_settings().await?;
         self.database
             .message_dao
             .list_items(
@@ -370,7 +405,7 @@ impl MessageAccess {
             )
             .await?
             .into_iter()
-            .map(|item| self.message_list_view(item))
+            .map(|item| self.message_list_view(item, &link_previews))
             .collect::<Result<Vec<_>>>()
     }
 
@@ -379,12 +414,13 @@ impl MessageAccess {
         message_ids: Vec<String>,
     ) -> Result<Vec<model::MessageListView>> {
         self.ensure_active()?;
+        let link_previews = self.settings.link_preview_settings().await?;
         self.database
             .message_dao
             .list_items_by_ids(&message_ids)
             .await?
             .into_iter()
-            .map(|item| self.message_list_view(item))
+            .map(|item| self.message_list_view(item, &link_previews))
             .collect::<Result<Vec<_>>>()
     }
 
@@ -457,21 +493,33 @@ impl MessageAccess {
             .map_err(Into::into)
     }
 
+    /// Searches one conversation. `after_micros` is inclusive and
+    /// `before_micros` exclusive. Results are newest first unless
+    /// `rank_by_relevance` is set, and each carries a highlighted snippet.
+    #[allow(clippy::too_many_arguments)]
     pub async fn search_messages(
         &self,
         conversation_id: String,
         query: String,
         sender_id: Option<String>,
         categories: Vec<String>,
+        after_micros: Option<i64>,
+        before_micros: Option<i64>,
+        rank_by_relevance: bool,
         anchor_message_id: Option<String>,
         limit: u32,
     ) -> Result<Vec<model::MessageListView>> {
-        let sender_id = sender_id.as_deref();
+        let filter = MessageSearchFilter {
+            conversation_id: Some(conversation_id.as_str()),
+            sender_id: sender_id.as_deref(),
+            categories: categories.as_slice(),
+            after: search_bound(after_micros)?,
+            before: search_bound(before_micros)?,
+        };
         self.search_message_items(
-            Some(conversation_id.as_str()),
             query.as_str(),
-            sender_id,
-            categories.as_slice(),
+            &filter,
+            rank_by_relevance,
             anchor_message_id.as_deref(),
             limit,
         )
@@ -481,42 +529,100 @@ impl MessageAccess {
     pub async fn search_global_messages(
         &self,
         query: String,
+        after_micros: Option<i64>,
+        before_micros: Option<i64>,
+        rank_by_relevance: bool,
         anchor_message_id: Option<String>,
         limit: u32,
     ) -> Result<Vec<model::MessageListView>> {
+        let filter = MessageSearchFilter {
+            after: search_bound(after_micros)?,
+            before: search_bound(before_micros)?,
+            ..Default::default()
+        };
         self.search_message_items(
-            None,
             query.as_str(),
-            None,
-            &[],
+            &filter,
+            rank_by_relevance,
             anchor_message_id.as_deref(),
             limit,
         )
         .await
     }
 
+    /// Returns the preview of the first link in a text or post message that
+    /// the link preview settings allow, fetching and caching it in
+    /// `hyperlinks` when needed.
+    pub async fn load_link_preview(
+        &self,
+        message_id: String,
+    ) -> Result<Option<model::LinkPreviewView>> {
+        self.ensure_active()?;
+        let message = self
+            .database
+            .message_dao
+            .find_message_by_id(&message_id)
+            .await?
+            .ok_or_else(|| anyhow!("message not found"))?;
+        if !message.category.is_text() && !message.category.is_post() {
+            return Ok(None);
+        }
+        let settings = self.settings.link_preview_settings().await?;
+        let received = message.user_id != self.account_id;
+        let Some(url) = find_urls(message.content.as_deref().unwrap_or_default())
+            .into_iter()
+            .find(|url| settings.allows_url(url.as_str(), received))
+        else {
+            return Ok(None);
+        };
+        let hyperlink = match self.database.hyperlink_dao.find(url.as_str()).await? {
+            Some(hyperlink) => hyperlink,
+            None => {
+                let Some(hyperlink) = fetch_link_preview(&self.network_service, &url).await? else {
+                    return Ok(None);
+                };
+                self.database.hyperlink_dao.insert(&hyperlink).await?;
+                hyperlink
+            }
+        };
+        if message.hyperlink.as_deref() != Some(hyperlink.hyperlink.as_str()) {
+            self.database
+                .message_dao
+                .update_hyperlink(&message.message_id, Some(&hyperlink.hyperlink))
+                .await?;
+            self.notify_conversation_changed(&message.conversation_id);
+        }
+        Ok(Some(hyperlink.into()))
+    }
+
     async fn search_message_items(
         &self,
-        conversation_id: Option<&str>,
         query: &str,
-        sender_id: Option<&str>,
-        categories: &[String],
+        filter: &MessageSearchFilter<'_>,
+        rank_by_relevance: bool,
         anchor_message_id: Option<&str>,
         limit: u32,
     ) -> Result<Vec<model::MessageListView>> {
         self.ensure_active()?;
+        let order = if rank_by_relevance {
+            MessageSearchOrder::Relevance
+        } else {
+            MessageSearchOrder::Newest
+        };
         let matches = self
             .database
             .message_fts_dao
-            .search(
-                query,
-                conversation_id,
-                sender_id,
-                categories,
-                anchor_message_id,
-                limit,
-            )
+            .search(query, filter, order, anchor_message_id, limit)
             .await?;
+        self.message_search_views(matches).await
+    }
+
+    /// Loads the messages of full-text `matches`, in order, with their
+    /// snippets. Matches whose message was deleted are skipped.
+    pub(super) async fn message_search_views(
+        &self,
+        matches: Vec<MessageFtsItem>,
+    ) -> Result<Vec<model::MessageListView>> {
         let message_ids = matches
             .iter()
             .map(|item| item.message_id.clone())
@@ -529,10 +635,13 @@ impl MessageAccess {
             .into_iter()
             .map(|item| (item.message_id.clone(), item))
             .collect::<HashMap<_, _>>();
+        let link_previews = self.settings.link_preview_settings().await?;
         let mut result = Vec::with_capacity(matches.len());
         for matched in matches {
             if let Some(item) = items_by_id.remove(&matched.message_id) {
-                result.push(self.message_list_view(item)?);
+                let mut view = self.message_list_view(item, &link_previews)?;
+                view.snippet = matched.snippet.map(Into::into);
+                result.push(view);
             }
         }
         Ok(result)
@@ -600,12 +709,13 @@ impl MessageAccess {
     ) -> Result<Vec<model::MessageListView>> {
         let conversation_id = conversation_id.as_str();
         let target_message_id = target_message_id.as_str();
+        let link_previews = self.settings.link_preview_settings().await?;
         self.database
             .message_dao
             .list_items_around(conversation_id, target_message_id, before, after)
             .await?
             .into_iter()
-            .map(|item| self.message_list_view(item))
+            .map(|item| self.message_list_view(item, &link_previews))
             .collect::<Result<Vec<_>>>()
     }
 
@@ -634,12 +744,13 @@ impl MessageAccess {
     ) -> Result<Vec<model::MessageListView>> {
         let conversation_id = conversation_id.as_str();
         self.ensure_active()?;
+        let link_previews = self.settings.link_preview_settings().await?;
         self.database
             .message_dao
             .list_pinned_items(conversation_id)
             .await?
             .into_iter()
-            .map(|item| self.message_list_view(item))
+            .map(|item| self.message_list_view(item, &link_previews))
             .collect::<Result<Vec<_>>>()
     }
 
@@ -930,6 +1041,80 @@ impl MessageAccess {
         Ok(message_id)
     }
 
+    pub async fn send_location(
+        &self,
+        conversation_id: String,
+        location: model::LocationView,
+        quote_message_id: Option<String>,
+        silent: bool,
+    ) -> Result<String> {
+        let conversation_id = conversation_id.as_str();
+        let quote_message_id = quote_message_id.as_deref();
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let location = location_message(location)?;
+        let conversation = self
+            .database
+            .conversation_dao
+            .find_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| anyhow!("conversation not found: {conversation_id}"))?;
+        let text_category = self.text_category(conversation.owner_id.as_deref()).await?;
+        let category = location_category(&text_category)?;
+        let message_id = Uuid::new_v4().to_string();
+        let quote_content = match quote_message_id {
+            Some(message_id) => self
+                .database
+                .message_dao
+                .find_quote_message_by_id(message_id)
+                .await?
+                .map(|message| serde_json::to_string(&message))
+                .transpose()?,
+            None => None,
+        };
+        if quote_message_id.is_some() && quote_content.is_none() {
+            return Err(anyhow!("quote message not found"));
+        }
+        let message = Message {
+            message_id: message_id.clone(),
+            conversation_id: conversation_id.to_string(),
+            user_id: self.account_id.clone(),
+            category,
+            content: Some(serde_json::to_string(&location)?),
+            quote_message_id: quote_message_id.map(str::to_string),
+            quote_content,
+            status: MessageStatus::Sending,
+            created_at: Utc::now().naive_utc(),
+            ..Message::default()
+        };
+        let job = Job::create_sending_job(
+            &message_id,
+            conversation_id,
+            None,
+            None,
+            false,
+            silent,
+            conversation.expire_in,
+        );
+        self.database
+            .message_dao
+            .insert_outgoing_message(&message, &job)
+            .await?;
+        if let Some(content) = message_fts_content(&message) {
+            if let Err(error) = self
+                .database
+                .message_fts_dao
+                .upsert(&message_id, conversation_id, &content)
+                .await
+            {
+                warn!("failed to index outgoing message {message_id}: {error}");
+            }
+        }
+        self.app_service.job.wake(&job.action)?;
+        self.notify_conversation_changed(conversation_id);
+        Ok(message_id)
+    }
+
     pub async fn send_sticker(
         &self,
         conversation_id: String,
@@ -1225,7 +1410,10 @@ impl MessageAccess {
         let conversation_id = conversation_id.as_str();
         let path = path.as_str();
         let kind = kind.trim().to_ascii_uppercase();
-        let mime_type = mime_type.trim();
+        let mut mime_type = mime_type.trim().to_string();
+        let mut width = width;
+        let mut height = height;
+        let mut duration_millis = duration_millis;
         let name = name.filter(|value| !value.trim().is_empty());
         let caption = caption.filter(|value| !value.trim().is_empty());
         let _mutation = self.mutation_gate.read().await;
@@ -1234,6 +1422,24 @@ impl MessageAccess {
         if !matches!(kind.as_str(), "IMAGE" | "VIDEO" | "DATA") {
             return Err(anyhow!("unsupported attachment kind: {kind}"));
         }
+        if kind == "VIDEO"
+            && (mime_type.is_empty()
+                || width.is_none()
+                || height.is_none()
+                || duration_millis.is_none())
+        {
+            // Fill in whatever the caller could not determine from the file.
+            let probe_path = Path::new(path).to_path_buf();
+            let info = tokio::task::spawn_blocking(move || probe_video(&probe_path))
+                .await
+                .context("video probe task failed")??;
+            if mime_type.is_empty() {
+                mime_type = info.mime_type.to_string();
+            }
+            width = width.or(Some(info.width));
+            height = height.or(Some(info.height));
+            duration_millis = duration_millis.or(info.duration_millis);
+        }
         if mime_type.is_empty() {
             return Err(anyhow!("attachment MIME type is required"));
         }
@@ -2051,6 +2257,145 @@ impl MessageAccess {
         Ok(())
     }
 
+    pub async fn edit_message(
+        &self,
+        conversation_id: String,
+        message_id: String,
+        content: String,
+    ) -> Result<()> {
+        let conversation_id = conversation_id.as_str();
+        let message_id = message_id.as_str();
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        if content.is_empty() {
+            return Err(anyhow!("message content is empty"));
+        }
+        let message = self
+            .database
+            .message_dao
+            .find_message_by_id(&message_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("message not found: {message_id}"))?;
+        if !can_edit_message(&message, conversation_id, &self.account_id) {
+            return Err(anyhow!("message can not be edited: {message_id}"));
+        }
+        if message.content.as_deref() == Some(content.as_str()) {
+            return Ok(());
+        }
+        let job = Job::create_send_edit_job(conversation_id, message_id, &content);
+        let edited = self
+            .database
+            .message_dao
+            .edit_message(
+                &job.job_id,
+                conversation_id,
+                message_id,
+                &content,
+                Utc::now(),
+                Some(&job),
+            )
+            .await?;
+        if !edited {
+            return Err(anyhow!("message can not be edited: {message_id}"));
+        }
+        self.database
+            .message_fts_dao
+            .upsert(message_id, conversation_id, &content)
+            .await?;
+        self.app_service.job.wake(sdk::EDIT_MESSAGE)?;
+        self.notify_conversation_changed(conversation_id);
+        Ok(())
+    }
+
+    pub async fn message_edit_history(
+        &self,
+        message_id: String,
+    ) -> Result<Vec<model::MessageEditItem>> {
+        self.ensure_active()?;
+        Ok(self
+            .database
+            .message_edit_dao
+            .history(&message_id)
+            .await?
+            .into_iter()
+            .map(|edit| model::MessageEditItem {
+                edit_id: edit.edit_id,
+                message_id: edit.message_id,
+                content: edit.content,
+                edited_at_micros: edit.edited_at.timestamp_micros(),
+            })
+            .collect())
+    }
+
+    pub async fn react_to_message(
+        &self,
+        conversation_id: String,
+        message_id: String,
+        emoji: String,
+        removed: bool,
+    ) -> Result<()> {
+        let conversation_id = conversation_id.as_str();
+        let message_id = message_id.as_str();
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        if emoji.trim().is_empty() {
+            return Err(anyhow!("reaction emoji is empty"));
+        }
+        let message = self
+            .database
+            .message_dao
+            .find_message_by_id(&message_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("message not found: {message_id}"))?;
+        let sent = matches!(
+            message.status,
+            MessageStatus::Sent | MessageStatus::Delivered | MessageStatus::Read
+        );
+        if message.conversation_id != conversation_id || !message.category.can_reply() || !sent {
+            return Err(anyhow!("message can not be reacted to: {message_id}"));
+        }
+        let job = Job::create_send_reaction_job(conversation_id, message_id, &emoji, removed);
+        let applied = self
+            .database
+            .message_reaction_dao
+            .apply(
+                &MessageReaction {
+                    message_id: message_id.to_string(),
+                    conversation_id: conversation_id.to_string(),
+                    user_id: self.account_id.clone(),
+                    emoji,
+                    created_at: Utc::now(),
+                },
+                removed,
+                Some(&job),
+            )
+            .await?;
+        if applied {
+            self.app_service.job.wake(sdk::REACTION_MESSAGE)?;
+            self.notify_conversation_changed(conversation_id);
+        }
+        Ok(())
+    }
+
+    pub async fn message_reactions(
+        &self,
+        message_id: String,
+    ) -> Result<Vec<model::MessageReactionItem>> {
+        self.ensure_active()?;
+        Ok(self
+            .database
+            .message_reaction_dao
+            .reactions(&message_id)
+            .await?
+            .into_iter()
+            .map(|reaction| model::MessageReactionItem {
+                user_id: reaction.user_id,
+                emoji: reaction.emoji,
+                created_at_micros: reaction.created_at.timestamp_micros(),
+            })
+            .collect())
+    }
+
     pub async fn set_message_pinned(
         &self,
         conversation_id: String,
@@ -2137,6 +2482,126 @@ impl MessageAccess {
         Ok(())
     }
 
+    pub async fn failed_outgoing_messages(
+        &self,
+        conversation_id: Option<String>,
+        limit: i64,
+    ) -> Result<Vec<model::OutgoingMessageItem>> {
+        let conversation_id = conversation_id.as_deref();
+        self.ensure_active()?;
+        let limit = limit.clamp(1, 200);
+        let mut jobs = self
+            .database
+            .job_dao
+            .retrying_sending_jobs(conversation_id, limit)
+            .await?
+            .into_iter()
+            .filter_map(|job| Some((job.sending_message_id()?, job)))
+            .collect::<HashMap<_, _>>();
+        let retrying_ids = jobs.keys().cloned().collect::<Vec<_>>();
+        let mut messages = self
+            .database
+            .message_dao
+            .find_messages_by_ids(&retrying_ids)
+            .await?
+            .into_iter()
+            .filter(|message| {
+                message.status == MessageStatus::Sending
+                    && conversation_id.is_none_or(|id| message.conversation_id == id)
+            })
+            .collect::<Vec<_>>();
+        messages.extend(
+            self.database
+                .message_dao
+                .failed_outgoing_messages(&self.account_id, conversation_id, limit)
+                .await?,
+        );
+        messages.sort_by(|left, right| right.created_at.cmp(&left.created_at));
+        messages.truncate(limit as usize);
+        Ok(messages
+            .into_iter()
+            .map(|message| {
+                let job = jobs.remove(&message.message_id);
+                model::OutgoingMessageItem {
+                    created_at_micros: message.created_at.and_utc().timestamp_micros(),
+                    attempt_count: job.as_ref().map_or(0, |job| i64::from(job.run_count)),
+                    next_attempt_at_micros: job
+                        .as_ref()
+                        .and_then(|job| job.next_run_at)
+                        .map(|next_run_at| next_run_at.and_utc().timestamp_micros()),
+                    last_error: job.and_then(|job| job.last_error),
+                    status: message.status.into(),
+                    message_id: message.message_id,
+                    conversation_id: message.conversation_id,
+                    category: message.category,
+                }
+            })
+            .collect())
+    }
+
+    pub async fn retry_outgoing_message(&self, message_id: String) -> Result<()> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let message = self
+            .database
+            .message_dao
+            .find_message_by_id(&message_id)
+            .await?
+            .ok_or_else(|| anyhow!("message not found: {message_id}"))?;
+        if message.user_id != self.account_id {
+            return Err(anyhow!("message was not sent by the current user"));
+        }
+        if let Some(job) = self.database.job_dao.find_sending_job(&message_id).await? {
+            self.database.job_dao.clear_job_backoff(&job.job_id).await?;
+        } else {
+            let conversation = self
+                .database
+                .conversation_dao
+                .find_conversation_by_id(&message.conversation_id)
+                .await?
+                .ok_or_else(|| anyhow!("conversation not found: {}", message.conversation_id))?;
+            let job = Job::create_sending_job(
+                &message_id,
+                &message.conversation_id,
+                None,
+                None,
+                false,
+                false,
+                conversation.expire_in,
+            );
+            if !self
+                .database
+                .message_dao
+                .requeue_failed_message(&message_id, &job)
+                .await?
+            {
+                return Err(anyhow!("message can not be retried: {message_id}"));
+            }
+        }
+        self.app_service.job.wake(sdk::SENDING_MESSAGE)?;
+        self.notify_conversation_changed(&message.conversation_id);
+        Ok(())
+    }
+
+    pub async fn cancel_outgoing_message(&self, message_id: String) -> Result<()> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let job = self
+            .database
+            .job_dao
+            .find_sending_job(&message_id)
+            .await?
+            .ok_or_else(|| anyhow!("message is not waiting to be sent: {message_id}"))?;
+        self.database
+            .message_dao
+            .complete_sending_job(&message_id, None, MessageStatus::Failed, 0, &job.job_id)
+            .await?;
+        if let Some(conversation_id) = job.conversation_id.as_deref() {
+            self.notify_conversation_changed(conversation_id);
+        }
+        Ok(())
+    }
+
     pub async fn mark_mention_read(
         &self,
         conversation_id: String,
@@ -2250,6 +2715,15 @@ impl MessageAccess {
     }
 }
 
+fn search_bound(micros: Option<i64>) -> Result<Option<DateTime<Utc>>> {
+    micros
+        .map(|micros| {
+            DateTime::from_timestamp_micros(micros)
+                .ok_or_else(|| anyhow!("invalid search time bound"))
+        })
+        .transpose()
+}
+
 fn can_recall_message(
     message: &Message,
     conversation: &crate::db::mixin::conversation::Conversation,
@@ -2288,6 +2762,20 @@ fn can_recall_message(
     }
 }
 
+/// Edits are sent as plain `MESSAGE_EDIT` data, so like incoming edits they
+/// only apply to plain text and post messages.
+fn can_edit_message(message: &Message, conversation_id: &str, current_user_id: &str) -> bool {
+    let sent = matches!(
+        message.status,
+        MessageStatus::Sent | MessageStatus::Delivered | MessageStatus::Read
+    );
+    message.conversation_id == conversation_id
+        && message.user_id == current_user_id
+        && sent
+        && message.category.can_edit()
+        && message.content.is_some()
+}
+
 fn is_shareable_app_card_action(action: &str) -> bool {
     let Ok(uri) = url::Url::parse(action) else {
         return false;
@@ -2347,9 +2835,43 @@ fn normalized_quote_content(
     Ok(Some(serde_json::to_string(quote)?))
 }
 
+/// The outgoing content of a location, without blank labels.
+fn location_message(location: model::LocationView) -> Result<LocationMessage> {
+    let location = LocationMessage {
+        latitude: location.latitude,
+        longitude: location.longitude,
+        name: location.name.filter(|name| !name.trim().is_empty()),
+        address: location
+            .address
+            .filter(|address| !address.trim().is_empty()),
+        venue_type: None,
+    };
+    if !location.is_valid() {
+        return Err(anyhow!(
+            "invalid location: {}, {}",
+            location.latitude,
+            location.longitude
+        ));
+    }
+    Ok(location)
+}
+
+/// The location category with the same `SIGNAL`, `PLAIN` or `ENCRYPTED`
+/// prefix as the conversation's text category.
+fn location_category(text_category: &str) -> Result<String> {
+    let prefix = text_category
+        .split_once('_')
+        .map(|(prefix, _)| prefix)
+        .ok_or_else(|| anyhow!("invalid message category: {text_category}"))?;
+    Ok(format!("{prefix}_LOCATION"))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{can_recall_message, normalized_quote_content};
+    use super::{
+        can_edit_message, can_recall_message, location_category, location_message,
+        normalized_quote_content,
+    };
     use std::path::PathBuf;
 
     use chrono::{Duration, Utc};
@@ -2358,6 +2880,7 @@ mod tests {
     use crate::db::mixin::conversation::{Conversation, ConversationStatus};
     use crate::db::mixin::message::Message;
     use crate::db::mixin::participant::Participant;
+    use crate::runtime::model::LocationView;
 
     #[test]
     fn normalizes_quote_media_path_without_changing_stored_content() {
@@ -2502,6 +3025,69 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn edit_policy_only_allows_own_sent_plain_text_and_post_messages() {
+        let mut message = recallable_message(Utc::now().naive_utc());
+        message.user_id = "current".to_string();
+        message.content = Some("hello".to_string());
+
+        assert!(can_edit_message(&message, "conversation", "current"));
+        assert!(!can_edit_message(&message, "conversation", "sender"));
+        assert!(!can_edit_message(&message, "other", "current"));
+        message.category = "PLAIN_POST".to_string();
+        assert!(can_edit_message(&message, "conversation", "current"));
+        message.category = "SIGNAL_TEXT".to_string();
+        assert!(!can_edit_message(&message, "conversation", "current"));
+        message.category = "ENCRYPTED_POST".to_string();
+        assert!(!can_edit_message(&message, "conversation", "current"));
+        message.category = "PLAIN_POST".to_string();
+        message.status = MessageStatus::Sending;
+        assert!(!can_edit_message(&message, "conversation", "current"));
+        message.status = MessageStatus::Delivered;
+        message.category = "PLAIN_IMAGE".to_string();
+        assert!(!can_edit_message(&message, "conversation", "current"));
+        message.category = "MESSAGE_RECALL".to_string();
+        assert!(!can_edit_message(&message, "conversation", "current"));
+    }
+
+    #[test]
+    fn location_messages_drop_blank_labels_and_keep_the_category_prefix() {
+        let location = location_message(LocationView {
+            latitude: 0.0,
+            longitude: 121.4737,
+            name: Some(" ".to_string()),
+            address: Some("Equator".to_string()),
+        })
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&location).unwrap(),
+            serde_json::json!({
+                "latitude": 0.0,
+                "longitude": 121.4737,
+                "name": null,
+                "address": "Equator",
+                "venue_type": null,
+            })
+        );
+        assert!(location_message(LocationView {
+            latitude: 0.0,
+            longitude: 0.0,
+            name: None,
+            address: None,
+        })
+        .is_err());
+
+        assert_eq!(
+            location_category(sdk::message_category::SIGNAL_TEXT).unwrap(),
+            sdk::message_category::SIGNAL_LOCATION
+        );
+        assert_eq!(
+            location_category(sdk::message_category::ENCRYPTED_TEXT).unwrap(),
+            sdk::message_category::ENCRYPTED_LOCATION
+        );
+        assert!(location_category("TEXT").is_err());
+    }
+
     fn conversation(category: ConversationCategory) -> Conversation {
         Conversation {
             conversation_id: "conversation".to_string(),
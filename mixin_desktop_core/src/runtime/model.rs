@@ -68,6 +68,16 @@ pub struct StorageCategoryUsage {
     pub size_bytes: i64,
 }
 
+/// A slice of a video's plaintext, with what a player needs to serve it as
+/// an HTTP range response.
+#[derive(Clone, Debug)]
+pub struct VideoRangeItem {
+    pub data: Vec<u8>,
+    pub offset: u64,
+    pub total_size: u64,
+    pub mime_type: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct UserProfileItem {
     pub user_id: String,
@@ -82,6 +92,32 @@ pub struct UserProfileItem {
     pub membership: Option<String>,
 }
 
+/// A contact shown under its local alias when one is set.
+#[derive(Clone, Debug)]
+pub struct ContactItem {
+    pub user: UserProfileItem,
+    pub alias: Option<String>,
+    pub display_name: String,
+    /// `display_name` in lowercase with Chinese characters spelled in pinyin.
+    pub sort_key: String,
+}
+
+/// Contacts whose sort key starts with `index`; `#` collects those starting
+/// with anything but a latin letter.
+#[derive(Clone, Debug)]
+pub struct ContactGroup {
+    pub index: String,
+    pub contacts: Vec<ContactItem>,
+}
+
+/// The local-only alias and private note of a user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserAnnotationItem {
+    pub user_id: String,
+    pub alias: Option<String>,
+    pub note: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CircleItem {
     pub circle_id: String,
@@ -89,6 +125,16 @@ pub struct CircleItem {
     pub conversation_count: i64,
 }
 
+/// A local circle whose members are the conversations matching its rules.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmartCircleItem {
+    pub circle_id: String,
+    pub name: String,
+    pub rules: Vec<crate::db::mixin::smart_circle::SmartCircleRule>,
+    pub match_all: bool,
+    pub conversation_count: i64,
+}
+
 #[derive(Clone, Debug)]
 pub struct ConversationDetailItem {
     pub conversation_id: String,
@@ -100,7 +146,7 @@ pub struct ConversationDetailItem {
     pub expire_in: i64,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct CodeResult {
     pub kind: String,
     pub user_id: Option<String>,
@@ -121,6 +167,71 @@ pub struct CodeResult {
     pub action: Option<String>,
 }
 
+/// A Mixin URI together with what it points at. Memos, traces and other
+/// details without a [`CodeResult`] field are read from `uri`.
+#[derive(Clone, Debug)]
+pub struct ResolvedUri {
+    pub uri: crate::core::mixin_uri::MixinUri,
+    pub result: CodeResult,
+}
+
+/// A payment code checked against the local token, chain and fiat tables,
+/// shown for confirmation before [`crate::runtime::WalletAccess::pay_code`].
+#[derive(Clone, Debug)]
+pub struct PaymentPreview {
+    pub trace_id: String,
+    pub asset_id: String,
+    pub asset_symbol: String,
+    pub asset_name: String,
+    pub asset_icon_url: String,
+    pub chain_name: String,
+    pub chain_icon_url: String,
+    pub amount: String,
+    pub receivers: Vec<String>,
+    pub threshold: i64,
+    pub memo: String,
+    pub confirmations: i64,
+    pub fiat_currency: String,
+    /// `amount` in `fiat_currency`, when prices and rates are known.
+    pub fiat_value: Option<String>,
+    /// The code's `status`, e.g. `pending` or `paid`.
+    pub state: Option<String>,
+}
+
+/// Progress of a submitted payment. `state` is `pending` until its
+/// snapshot arrives, then `paid`; `unknown` when nothing was recorded.
+#[derive(Clone, Debug)]
+pub struct PaymentState {
+    pub trace_id: String,
+    pub state: String,
+    pub transaction_hash: Option<String>,
+    pub snapshot_id: Option<String>,
+}
+
+/// An inscription held in one of the account's unspent outputs.
+#[derive(Clone, Debug)]
+pub struct InscriptionView {
+    pub inscription_hash: String,
+    pub collection_hash: String,
+    pub sequence: i64,
+    pub content_type: String,
+    pub content_url: String,
+    pub output_id: String,
+    pub amount: String,
+}
+
+/// The account's inscriptions of one collection, ordered by sequence.
+#[derive(Clone, Debug)]
+pub struct InscriptionCollectionView {
+    pub collection_hash: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon_url: String,
+    pub supply: String,
+    pub unit: String,
+    pub items: Vec<InscriptionView>,
+}
+
 #[derive(Clone, Debug)]
 pub struct SnapshotDetailItem {
     pub snapshot_id: String,
@@ -273,6 +384,20 @@ pub struct SharedAppItem {
     pub home_uri: String,
 }
 
+/// One entry of a group's audit trail: a member joined, left, was added or
+/// removed, or had their role changed.
+#[derive(Clone, Debug)]
+pub struct ParticipantEventView {
+    pub message_id: String,
+    pub action: String,
+    pub actor_id: String,
+    pub actor_name: Option<String>,
+    pub participant_id: String,
+    pub participant_name: Option<String>,
+    pub role: Option<String>,
+    pub created_at_millis: i64,
+}
+
 #[derive(Clone, Debug)]
 pub struct ConversationParticipantItem {
     pub user_id: String,
@@ -397,6 +522,79 @@ pub struct MessageListView {
     pub mention_read: Option<bool>,
     pub pinned: bool,
     pub expire_in: Option<i64>,
+    pub edited_at_micros: Option<i64>,
+    pub reactions: Vec<MessageReactionView>,
+    pub location: Option<LocationView>,
+    /// Set only on search results.
+    pub snippet: Option<MessageSnippetView>,
+    /// Cached preview of `hyperlink`, see `MessageAccess::load_link_preview`.
+    pub link_preview: Option<LinkPreviewView>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MessageSnippetView {
+    pub text: String,
+    pub highlights: Vec<TextRangeView>,
+}
+
+/// A `[start, end)` range of UTF-16 code units.
+#[derive(Clone, Debug)]
+pub struct TextRangeView {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Sections of a global search. Each `*_cursor` is set when the section has
+/// more results, and continues it through `global_search_section`.
+#[derive(Clone, Debug, Default)]
+pub struct GlobalSearchView {
+    pub query: String,
+    pub contacts: Vec<UserProfileItem>,
+    pub contacts_cursor: Option<String>,
+    pub groups: Vec<ConversationListData>,
+    pub groups_cursor: Option<String>,
+    pub message_conversations: Vec<ConversationSearchHitView>,
+    pub message_conversations_cursor: Option<String>,
+    pub files: Vec<MessageListView>,
+    pub files_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConversationSearchHitView {
+    pub conversation: ConversationListData,
+    pub hit_count: i64,
+    pub latest_created_at_millis: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinkPreviewView {
+    pub url: String,
+    pub site_name: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LocationView {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub name: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MessageReactionView {
+    pub emoji: String,
+    pub count: i64,
+    pub reacted: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct MessageReactionItem {
+    pub user_id: String,
+    pub emoji: String,
+    pub created_at_micros: i64,
 }
 
 #[derive(Clone, Debug)]
@@ -406,6 +604,26 @@ pub struct MessageOrderInfoView {
     pub created_at_micros: i64,
 }
 
+#[derive(Clone, Debug)]
+pub struct MessageEditItem {
+    pub edit_id: String,
+    pub message_id: String,
+    pub content: String,
+    pub edited_at_micros: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct OutgoingMessageItem {
+    pub message_id: String,
+    pub conversation_id: String,
+    pub category: String,
+    pub status: String,
+    pub created_at_micros: i64,
+    pub attempt_count: i64,
+    pub last_error: Option<String>,
+    pub next_attempt_at_micros: Option<i64>,
+}
+
 #[derive(Clone, Debug)]
 pub struct NotificationEvent {
     pub message_id: String,
@@ -419,6 +637,35 @@ pub struct NotificationEvent {
     pub dismiss_message_id: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountSession {
+    pub session_id: String,
+    pub platform: Option<String>,
+    pub app_version: Option<String>,
+    pub last_seen_at: Option<String>,
+    pub is_current: bool,
+    pub is_primary: bool,
+}
+
+/// Points at the message a notification was shown for, which is all an
+/// action needs without loading the conversation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationActionHandle {
+    pub account_id: String,
+    pub conversation_id: String,
+    pub message_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotificationAction {
+    /// Replies quoting the message and marks the conversation read.
+    Reply {
+        text: String,
+    },
+    MarkRead,
+    MuteForOneHour,
+}
+
 #[derive(Clone, Debug)]
 pub struct NotificationEventBatch {
     pub events: Vec<NotificationEvent>,
@@ -427,29 +674,135 @@ pub struct NotificationEventBatch {
     pub has_more: bool,
 }
 
+/// Why a message did or did not become a notification, in order of
+/// precedence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotificationReason {
+    /// Recalls always notify so the recalled notification can be dismissed.
+    Recall,
+    AllowedSender,
+    QuietHours,
+    Keyword {
+        keyword: String,
+    },
+    Mentioned,
+    Quoted,
+    ConversationMuted,
+    CircleMuted {
+        circle_id: String,
+    },
+    MentionsOnly,
+    Default,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationDecision {
+    pub notify: bool,
+    pub reason: NotificationReason,
+}
+
+/// What the notification rules need to know besides the message itself.
+pub(crate) struct NotificationContext<'a> {
+    pub rules: &'a crate::db::app::NotificationRules,
+    pub current_user_id: &'a str,
+    pub current_identity_number: &'a str,
+    /// Circles of each conversation, needed only for muted circles.
+    pub circle_ids: &'a std::collections::HashMap<String, Vec<String>>,
+    pub local_offset: chrono::FixedOffset,
+}
+
 impl NotificationEvent {
-    pub(crate) fn from_message(
-        value: crate::db::mixin::message::NotificationMessageItem,
-        current_user_id: &str,
-        current_identity_number: &str,
-    ) -> Option<Self> {
-        let dismiss_message_id = (value.category == sdk::message_category::MESSAGE_RECALL)
-            .then(|| value.content.clone().unwrap_or_default());
-        let mentioned = value.category.contains("TEXT")
+    pub(crate) fn decide(
+        value: &crate::db::mixin::message::NotificationMessageItem,
+        context: &NotificationContext<'_>,
+    ) -> NotificationDecision {
+        let rules = context.rules;
+        let decision = |notify, reason| NotificationDecision { notify, reason };
+        if value.category == sdk::message_category::MESSAGE_RECALL {
+            return decision(true, NotificationReason::Recall);
+        }
+        if rules.allowed_sender_ids.contains(&value.user_id) {
+            return decision(true, NotificationReason::AllowedSender);
+        }
+        let local_time = value.created_at + context.local_offset;
+        if rules
+            .quiet_hours
+            .iter()
+            .any(|quiet_hours| quiet_hours.contains(local_time))
+        {
+            return decision(false, NotificationReason::QuietHours);
+        }
+        let text = value
+            .category
+            .contains("TEXT")
+            .then(|| value.content.as_deref().unwrap_or_default().to_lowercase());
+        if let Some(keyword) = text.as_deref().and_then(|text| {
+            rules
+                .keywords
+                .iter()
+                .find(|keyword| text.contains(&keyword.trim().to_lowercase()))
+        }) {
+            return decision(
+                true,
+                NotificationReason::Keyword {
+                    keyword: keyword.clone(),
+                },
+            );
+        }
+        if value.category.contains("TEXT")
             && mentions_identity(
                 value.content.as_deref().unwrap_or_default(),
-                current_identity_number,
-            );
+                context.current_identity_number,
+            )
+        {
+            return decision(true, NotificationReason::Mentioned);
+        }
         let quoted = value
             .quote_content
             .as_deref()
             .and_then(|content| serde_json::from_str::<serde_json::Value>(content).ok())
             .is_some_and(|quote| {
-                quote.get("user_id").and_then(serde_json::Value::as_str) == Some(current_user_id)
+                quote.get("user_id").and_then(serde_json::Value::as_str)
+                    == Some(context.current_user_id)
             });
-        if dismiss_message_id.is_none() && value.is_muted && !mentioned && !quoted {
+        if quoted {
+            return decision(true, NotificationReason::Quoted);
+        }
+        if value.is_muted {
+            return decision(false, NotificationReason::ConversationMuted);
+        }
+        if let Some(circle_id) = context
+            .circle_ids
+            .get(&value.conversation_id)
+            .into_iter()
+            .flatten()
+            .find(|circle_id| rules.muted_circle_ids.contains(circle_id))
+        {
+            return decision(
+                false,
+                NotificationReason::CircleMuted {
+                    circle_id: circle_id.clone(),
+                },
+            );
+        }
+        if rules
+            .mentions_only_conversation_ids
+            .contains(&value.conversation_id)
+        {
+            return decision(false, NotificationReason::MentionsOnly);
+        }
+        decision(true, NotificationReason::Default)
+    }
+
+    pub(crate) fn from_message(
+        value: crate::db::mixin::message::NotificationMessageItem,
+        context: &NotificationContext<'_>,
+    ) -> Option<Self> {
+        if !Self::decide(&value, context).notify {
             return None;
         }
+        let dismiss_message_id = (value.category == sdk::message_category::MESSAGE_RECALL)
+            .then(|| value.content.clone().unwrap_or_default());
         Some(Self {
             message_id: value.message_id,
             conversation_id: value.conversation_id,
@@ -493,11 +846,35 @@ pub struct ImageMessageView {
 
 #[cfg(test)]
 mod notification_tests {
-    use chrono::DateTime;
+    use std::collections::HashMap;
 
-    use super::NotificationEvent;
+    use chrono::{DateTime, FixedOffset};
+
+    use super::{NotificationContext, NotificationEvent, NotificationReason};
+    use crate::db::app::{NotificationRules, QuietHours};
     use crate::db::mixin::message::NotificationMessageItem;
 
+    fn event(
+        message: NotificationMessageItem,
+        rules: &NotificationRules,
+        circle_ids: &HashMap<String, Vec<String>>,
+    ) -> Option<NotificationEvent> {
+        NotificationEvent::from_message(message, &context(rules, circle_ids))
+    }
+
+    fn context<'a>(
+        rules: &'a NotificationRules,
+        circle_ids: &'a HashMap<String, Vec<String>>,
+    ) -> NotificationContext<'a> {
+        NotificationContext {
+            rules,
+            current_user_id: "current-user",
+            current_identity_number: "7000",
+            circle_ids,
+            local_offset: FixedOffset::east_opt(0).unwrap(),
+        }
+    }
+
     fn message(
         category: &str,
         content: &str,
@@ -522,10 +899,10 @@ mod notification_tests {
 
     #[test]
     fn filters_muted_messages_without_a_mention_or_quote() {
-        let event = NotificationEvent::from_message(
+        let event = event(
             message("SIGNAL_TEXT", "hello @70001", None, true),
-            "current-user",
-            "7000",
+            &NotificationRules::default(),
+            &HashMap::new(),
         );
 
         assert!(event.is_none());
@@ -533,20 +910,20 @@ mod notification_tests {
 
     #[test]
     fn keeps_muted_messages_that_mention_or_quote_the_current_user() {
-        let mention = NotificationEvent::from_message(
+        let mention = event(
             message("SIGNAL_TEXT", "hello @7000", None, true),
-            "current-user",
-            "7000",
+            &NotificationRules::default(),
+            &HashMap::new(),
         );
-        let quote = NotificationEvent::from_message(
+        let quote = event(
             message(
                 "SIGNAL_TEXT",
                 "reply",
                 Some(r#"{"user_id":"current-user"}"#),
                 true,
             ),
-            "current-user",
-            "7000",
+            &NotificationRules::default(),
+            &HashMap::new(),
         );
 
         assert!(mention.is_some());
@@ -555,10 +932,10 @@ mod notification_tests {
 
     #[test]
     fn converts_recall_messages_to_dismiss_events_even_when_muted() {
-        let event = NotificationEvent::from_message(
+        let event = event(
             message("MESSAGE_RECALL", "recalled-message", None, true),
-            "current-user",
-            "7000",
+            &NotificationRules::default(),
+            &HashMap::new(),
         )
         .unwrap();
 
@@ -567,4 +944,76 @@ mod notification_tests {
             Some("recalled-message")
         );
     }
+
+    #[test]
+    fn explains_rule_decisions_in_order_of_precedence() {
+        let rules = NotificationRules {
+            quiet_hours: vec![QuietHours {
+                start_minute: 0,
+                end_minute: 60,
+                weekdays: Vec::new(),
+            }],
+            muted_circle_ids: vec!["work".to_string()],
+            keywords: vec!["Deploy".to_string()],
+            mentions_only_conversation_ids: vec!["conversation".to_string()],
+            allowed_sender_ids: vec!["boss".to_string()],
+        };
+        let circle_ids = HashMap::from([("conversation".to_string(), vec!["work".to_string()])]);
+        let context = context(&rules, &circle_ids);
+        let at_noon = |mut message: NotificationMessageItem| {
+            message.created_at += chrono::Duration::hours(12);
+            message
+        };
+        let reason = |message: NotificationMessageItem| {
+            let decision = NotificationEvent::decide(&message, &context);
+            (decision.notify, decision.reason)
+        };
+
+        assert_eq!(
+            reason(message("SIGNAL_TEXT", "deploy now", None, false)),
+            (false, NotificationReason::QuietHours)
+        );
+        let mut boss = message("SIGNAL_TEXT", "hi", None, false);
+        boss.user_id = "boss".to_string();
+        assert_eq!(reason(boss), (true, NotificationReason::AllowedSender));
+        assert_eq!(
+            reason(at_noon(message("SIGNAL_TEXT", "please DEPLOY", None, true))),
+            (
+                true,
+                NotificationReason::Keyword {
+                    keyword: "Deploy".to_string()
+                }
+            )
+        );
+        assert_eq!(
+            reason(at_noon(message("SIGNAL_TEXT", "hello @7000", None, false))),
+            (true, NotificationReason::Mentioned)
+        );
+        assert_eq!(
+            reason(at_noon(message("SIGNAL_TEXT", "hello", None, true))),
+            (false, NotificationReason::ConversationMuted)
+        );
+        assert_eq!(
+            reason(at_noon(message("SIGNAL_TEXT", "hello", None, false))),
+            (
+                false,
+                NotificationReason::CircleMuted {
+                    circle_id: "work".to_string()
+                }
+            )
+        );
+        let other_circles = HashMap::new();
+        let unmuted_circle = super::NotificationContext {
+            circle_ids: &other_circles,
+            ..context
+        };
+        assert_eq!(
+            NotificationEvent::decide(
+                &at_noon(message("SIGNAL_TEXT", "hello", None, false)),
+                &unmuted_circle
+            )
+            .reason,
+            NotificationReason::MentionsOnly
+        );
+    }
 }
@@ -0,0 +1,575 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use futures::{Stream, StreamExt};
+use sdk::message_category::{ENCRYPTED_DATA, PLAIN_DATA, SIGNAL_DATA};
+
+use crate::db::mixin::message_fts::{MessageFtsItem, MessageSearchFilter, MessageSearchOrder};
+use crate::db::MixinDatabase;
+
+use super::{model, AccountState, MessageAccess};
+
+const MAX_SECTION_LIMIT: u32 = 100;
+
+pub struct SearchAccess {
+    state: Arc<AccountState>,
+}
+
+impl SearchAccess {
+    pub(crate) fn new(state: Arc<AccountState>) -> Self {
+        Self { state }
+    }
+}
+
+impl Deref for SearchAccess {
+    type Target = AccountState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+struct Section<T> {
+    items: Vec<T>,
+    cursor: Option<String>,
+}
+
+impl<T> Section<T> {
+    fn empty() -> Self {
+        Self {
+            items: Vec::new(),
+            cursor: None,
+        }
+    }
+
+    /// Trims a page fetched with one extra row, keeping `cursor` for the
+    /// next page when that row exists.
+    fn page(mut items: Vec<T>, limit: u32, cursor: impl FnOnce(&[T]) -> String) -> Self {
+        if items.len() > limit as usize {
+            items.truncate(limit as usize);
+            let cursor = Some(cursor(&items));
+            Self { items, cursor }
+        } else {
+            Self {
+                items,
+                cursor: None,
+            }
+        }
+    }
+}
+
+impl SearchAccess {
+    /// Searches contacts, groups, messages and files at once, returning the
+    /// first `limit` results of every section.
+    pub async fn global_search(
+        &self,
+        query: String,
+        limit: u32,
+    ) -> Result<model::GlobalSearchView> {
+        self.ensure_active()?;
+        let trimmed = query.trim();
+        if trimmed.is_empty() || limit == 0 {
+            return Ok(model::GlobalSearchView {
+                query,
+                ..Default::default()
+            });
+        }
+        let (contacts, groups, message_conversations, files) = tokio::try_join!(
+            self.search_contacts(trimmed, None, limit),
+            self.search_groups(trimmed, None, limit),
+            self.search_message_conversations(trimmed, None, limit),
+            self.search_files(trimmed, None, limit),
+        )?;
+        Ok(model::GlobalSearchView {
+            query,
+            contacts: contacts.items,
+            contacts_cursor: contacts.cursor,
+            groups: groups.items,
+            groups_cursor: groups.cursor,
+            message_conversations: message_conversations.items,
+            message_conversations_cursor: message_conversations.cursor,
+            files: files.items,
+            files_cursor: files.cursor,
+        })
+    }
+
+    /// Loads the page after `cursor` of one section, which is `contacts`,
+    /// `groups`, `messages` or `files`. Other sections are left empty.
+    pub async fn global_search_section(
+        &self,
+        query: String,
+        section: String,
+        cursor: Option<String>,
+        limit: u32,
+    ) -> Result<model::GlobalSearchView> {
+        self.ensure_active()?;
+        let trimmed = query.trim().to_string();
+        let cursor = cursor.as_deref();
+        let mut view = model::GlobalSearchView {
+            query,
+            ..Default::default()
+        };
+        if trimmed.is_empty() || limit == 0 {
+            return Ok(view);
+        }
+        match section.as_str() {
+            "contacts" => {
+                let page = self.search_contacts(&trimmed, cursor, limit).await?;
+                view.contacts = page.items;
+                view.contacts_cursor = page.cursor;
+            }
+            "groups" => {
+                let page = self.search_groups(&trimmed, cursor, limit).await?;
+                view.groups = page.items;
+                view.groups_cursor = page.cursor;
+            }
+            "messages" => {
+                let page = self
+                    .search_message_conversations(&trimmed, cursor, limit)
+                    .await?;
+                view.message_conversations = page.items;
+                view.message_conversations_cursor = page.cursor;
+            }
+            "files" => {
+                let page = self.search_files(&trimmed, cursor, limit).await?;
+                view.files = page.items;
+                view.files_cursor = page.cursor;
+            }
+            _ => return Err(anyhow!("unknown search section: {section}")),
+        }
+        Ok(view)
+    }
+
+    /// Runs [`Self::global_search`] for every query of `queries`. A new
+    /// query cancels the search still running for the previous one, so only
+    /// results for the latest query are yielded. The stream ends with
+    /// `queries` or when the account shuts down.
+    pub fn subscribe_global_search(
+        &self,
+        queries: impl Stream<Item = String> + Send + 'static,
+        limit: u32,
+    ) -> impl Stream<Item = Result<model::GlobalSearchView>> + Send + 'static {
+        let access = SearchAccess::new(self.state.clone());
+        let mut shutdown = self.shutdown.clone();
+        async_stream::stream! {
+            let mut queries = Box::pin(queries);
+            let mut pending = None;
+            'queries: loop {
+                let query = match pending.take() {
+                    Some(query) => query,
+                    None => tokio::select! {
+                        query = queries.next() => match query {
+                            Some(query) => query,
+                            None => break 'queries,
+                        },
+                        changed = shutdown.changed() => {
+                            if changed.is_err() || *shutdown.borrow() {
+                                break 'queries;
+                            }
+                            continue 'queries;
+                        }
+                    },
+                };
+                let search = access.global_search(query.clone(), limit);
+                tokio::pin!(search);
+                let result = tokio::select! {
+                    result = &mut search => Some(result),
+                    next = queries.next() => match next {
+                        Some(next) => {
+                            pending = Some(next);
+                            None
+                        }
+                        None => break 'queries,
+                    },
+                    changed = shutdown.changed() => {
+                        if changed.is_err() || *shutdown.borrow() {
+                            break 'queries;
+                        }
+                        pending = Some(query);
+                        None
+                    }
+                };
+                if let Some(result) = result {
+                    yield result;
+                }
+            }
+        }
+    }
+
+    async fn search_contacts(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<Section<model::UserProfileItem>> {
+        contacts_page(&self.database, &self.account_id, query, cursor, limit).await
+    }
+
+    async fn search_groups(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<Section<model::ConversationListData>> {
+        groups_page(&self.database, query, cursor, limit).await
+    }
+
+    async fn search_message_conversations(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<Section<model::ConversationSearchHitView>> {
+        message_conversations_page(&self.database, query, cursor, limit).await
+    }
+
+    async fn search_files(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<Section<model::MessageListView>> {
+        let page = file_matches_page(&self.database, query, cursor, limit).await?;
+        let items = MessageAccess::new(self.state.clone())
+            .message_search_views(page.items)
+            .await?;
+        Ok(Section {
+            items,
+            cursor: page.cursor,
+        })
+    }
+}
+
+async fn contacts_page(
+    database: &MixinDatabase,
+    account_id: &str,
+    query: &str,
+    cursor: Option<&str>,
+    limit: u32,
+) -> Result<Section<model::UserProfileItem>> {
+    let offset = offset_cursor(cursor)?;
+    let limit = limit.min(MAX_SECTION_LIMIT);
+    let users = database
+        .user_dao
+        .fuzzy_search_users(account_id, query, (offset + limit + 1) as i64)
+        .await?
+        .into_iter()
+        .skip(offset as usize)
+        .map(Into::into)
+        .collect();
+    Ok(Section::page(users, limit, |_| {
+        (offset + limit).to_string()
+    }))
+}
+
+async fn groups_page(
+    database: &MixinDatabase,
+    query: &str,
+    cursor: Option<&str>,
+    limit: u32,
+) -> Result<Section<model::ConversationListData>> {
+    let offset = offset_cursor(cursor)?;
+    let limit = limit.min(MAX_SECTION_LIMIT);
+    let groups = database
+        .conversation_dao
+        .list_items(
+            "groups",
+            None,
+            query,
+            false,
+            (limit + 1) as i64,
+            offset as i64,
+        )
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    Ok(Section::page(groups, limit, |_| {
+        (offset + limit).to_string()
+    }))
+}
+
+async fn message_conversations_page(
+    database: &MixinDatabase,
+    query: &str,
+    cursor: Option<&str>,
+    limit: u32,
+) -> Result<Section<model::ConversationSearchHitView>> {
+    let offset = offset_cursor(cursor)?;
+    let limit = limit.min(MAX_SECTION_LIMIT);
+    let hits = database
+        .message_fts_dao
+        .search_conversations(query, limit + 1, offset)
+        .await?;
+    if hits.is_empty() {
+        return Ok(Section::empty());
+    }
+    let conversation_ids = hits
+        .iter()
+        .map(|hit| hit.conversation_id.clone())
+        .collect::<Vec<_>>();
+    let mut conversations = database
+        .conversation_dao
+        .list_items_by_ids(&conversation_ids)
+        .await?
+        .into_iter()
+        .map(|item| (item.conversation_id.clone(), item))
+        .collect::<HashMap<_, _>>();
+    let has_more = hits.len() > limit as usize;
+    let items = hits
+        .into_iter()
+        .take(limit as usize)
+        .filter_map(|hit| {
+            let conversation = conversations.remove(&hit.conversation_id)?;
+            Some(model::ConversationSearchHitView {
+                conversation: conversation.into(),
+                hit_count: hit.hit_count,
+                latest_created_at_millis: hit.latest_created_at,
+            })
+        })
+        .collect();
+    Ok(Section {
+        items,
+        cursor: has_more.then(|| (offset + limit).to_string()),
+    })
+}
+
+/// Newest file messages first; the cursor is the last message id of the
+/// page.
+async fn file_matches_page(
+    database: &MixinDatabase,
+    query: &str,
+    cursor: Option<&str>,
+    limit: u32,
+) -> Result<Section<MessageFtsItem>> {
+    let limit = limit.min(MAX_SECTION_LIMIT);
+    let categories = [PLAIN_DATA, SIGNAL_DATA, ENCRYPTED_DATA].map(String::from);
+    let filter = MessageSearchFilter {
+        categories: &categories,
+        ..Default::default()
+    };
+    let matches = database
+        .message_fts_dao
+        .search(
+            query,
+            &filter,
+            MessageSearchOrder::Newest,
+            cursor,
+            limit + 1,
+        )
+        .await?;
+    Ok(Section::page(matches, limit, |matches| {
+        matches
+            .last()
+            .map(|matched| matched.message_id.clone())
+            .unwrap_or_default()
+    }))
+}
+
+fn offset_cursor(cursor: Option<&str>) -> Result<u32> {
+    cursor
+        .map(|cursor| {
+            cursor
+                .parse()
+                .map_err(|_| anyhow!("invalid search cursor: {cursor}"))
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::db::mixin::message::Message;
+
+    async fn database() -> (tempfile::TempDir, MixinDatabase) {
+        let directory = tempfile::tempdir().unwrap();
+        let database = MixinDatabase::connect_at(directory.path().join("mixin.db"))
+            .await
+            .unwrap();
+        let pool = &database.message_dao.0;
+        for (user_id, identity_number, full_name) in [
+            ("me", "1000", "Mixin Me"),
+            ("owner", "1001", "Owner"),
+            ("alice", "1002", "Mixin Alice"),
+            ("bob", "1003", "Mixin Bob"),
+            ("carol", "1004", "Mixin Carol"),
+        ] {
+            sqlx::query("INSERT INTO users (user_id, identity_number, full_name) VALUES (?, ?, ?)")
+                .bind(user_id)
+                .bind(identity_number)
+                .bind(full_name)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        for (index, conversation_id) in ["first", "second", "third"].into_iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO conversations \
+                 (conversation_id, owner_id, category, name, created_at, status) \
+                 VALUES (?, 'owner', 'GROUP', ?, ?, 2)",
+            )
+            .bind(conversation_id)
+            .bind(format!("Mixin {conversation_id}"))
+            .bind((base + chrono::Duration::days(index as i64)).timestamp_millis())
+            .execute(pool)
+            .await
+            .unwrap();
+            // Earlier groups get more hits so they rank first.
+            for hit in 0..(3 - index) {
+                let message_id = format!("{conversation_id}-text-{hit}");
+                let created_at = base + chrono::Duration::hours((index * 3 + hit) as i64);
+                insert_message(
+                    &database,
+                    &message_id,
+                    conversation_id,
+                    "PLAIN_TEXT",
+                    created_at,
+                )
+                .await;
+            }
+            let message_id = format!("{conversation_id}-file");
+            let created_at = base + chrono::Duration::days(1 + index as i64);
+            insert_message(
+                &database,
+                &message_id,
+                conversation_id,
+                "PLAIN_DATA",
+                created_at,
+            )
+            .await;
+        }
+        (directory, database)
+    }
+
+    async fn insert_message(
+        database: &MixinDatabase,
+        message_id: &str,
+        conversation_id: &str,
+        category: &str,
+        created_at: chrono::DateTime<Utc>,
+    ) {
+        database
+            .message_dao
+            .insert_message(&Message {
+                message_id: message_id.into(),
+                conversation_id: conversation_id.into(),
+                user_id: "alice".into(),
+                category: category.into(),
+                content: Some("mixin notes".into()),
+                name: Some("mixin-notes.txt".into()),
+                status: sdk::blaze_message::MessageStatus::Sent,
+                created_at: created_at.naive_utc(),
+                ..Message::default()
+            })
+            .await
+            .unwrap();
+        database
+            .message_fts_dao
+            .upsert(message_id, conversation_id, "mixin notes")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn every_section_pages_with_its_cursor_until_exhausted() {
+        let (_directory, database) = database().await;
+
+        let first = contacts_page(&database, "me", "mixin", None, 2)
+            .await
+            .unwrap();
+        let next = contacts_page(&database, "me", "mixin", first.cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert_eq!(first.cursor.as_deref(), Some("2"));
+        assert_eq!(next.cursor, None);
+        assert_eq!(
+            first
+                .items
+                .iter()
+                .chain(&next.items)
+                .map(|user| user.user_id.as_str())
+                .collect::<Vec<_>>(),
+            ["alice", "bob", "carol"]
+        );
+
+        let first = groups_page(&database, "mixin", None, 2).await.unwrap();
+        let next = groups_page(&database, "mixin", first.cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert_eq!(first.cursor.as_deref(), Some("2"));
+        assert_eq!(next.cursor, None);
+        assert_eq!(
+            first
+                .items
+                .iter()
+                .chain(&next.items)
+                .map(|group| group.conversation_id.as_str())
+                .collect::<Vec<_>>(),
+            ["third", "second", "first"]
+        );
+
+        let first = message_conversations_page(&database, "mixin", None, 2)
+            .await
+            .unwrap();
+        let next = message_conversations_page(&database, "mixin", first.cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert_eq!(first.cursor.as_deref(), Some("2"));
+        assert_eq!(next.cursor, None);
+        assert_eq!(
+            first
+                .items
+                .iter()
+                .chain(&next.items)
+                .map(|hit| (hit.conversation.conversation_id.as_str(), hit.hit_count))
+                .collect::<Vec<_>>(),
+            [("first", 4), ("second", 3), ("third", 2)]
+        );
+
+        let first = file_matches_page(&database, "mixin", None, 2)
+            .await
+            .unwrap();
+        assert_eq!(first.cursor.as_deref(), Some("second-file"));
+        let next = file_matches_page(&database, "mixin", first.cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert_eq!(next.cursor, None);
+        assert_eq!(
+            first
+                .items
+                .iter()
+                .chain(&next.items)
+                .map(|file| file.message_id.as_str())
+                .collect::<Vec<_>>(),
+            ["third-file", "second-file", "first-file"]
+        );
+    }
+
+    #[tokio::test]
+    async fn pages_end_without_a_cursor_and_reject_foreign_cursors() {
+        let (_directory, database) = database().await;
+
+        let page = contacts_page(&database, "me", "mixin", None, 3)
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 3);
+        assert_eq!(page.cursor, None);
+        let page = groups_page(&database, "mixin", Some("3"), 2).await.unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!(page.cursor, None);
+        assert!(
+            contacts_page(&database, "me", "mixin", Some("first-file"), 2)
+                .await
+                .is_err()
+        );
+        assert!(groups_page(&database, "mixin", Some("-1"), 2)
+            .await
+            .is_err());
+    }
+}
@@ -1,9 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Deref;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
+use pinyin::ToPinyin;
 use sdk::RelationshipAction;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
 use super::{model, AccountState};
 
@@ -53,6 +56,81 @@ impl UserAccess {
             .collect())
     }
 
+    /// Friends grouped by the first letter of their sort key, `#` last.
+    pub async fn contacts(&self) -> Result<Vec<model::ContactGroup>> {
+        self.ensure_active()?;
+        let contacts = self
+            .database
+            .user_dao
+            .contacts(&self.account_id)
+            .await?
+            .into_iter()
+            .map(|contact| {
+                let display_name = contact
+                    .alias
+                    .clone()
+                    .unwrap_or_else(|| contact.user.full_name.clone());
+                model::ContactItem {
+                    sort_key: contact_sort_key(&display_name),
+                    user: contact.user.into(),
+                    alias: contact.alias,
+                    display_name,
+                }
+            })
+            .collect();
+        Ok(group_contacts(contacts))
+    }
+
+    /// Users the account has blocked, refreshed from the server.
+    pub async fn blocked_users(&self) -> Result<Vec<model::UserProfileItem>> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let users = self.client.user_api.blocking_users().await?;
+        let mut users = self
+            .database
+            .user_dao
+            .insert_sdk_users(users)
+            .await?
+            .into_iter()
+            .map(model::UserProfileItem::from)
+            .collect::<Vec<_>>();
+        users.sort_by_cached_key(|user| (contact_sort_key(&user.full_name), user.user_id.clone()));
+        self.notify_all_conversations_changed();
+        Ok(users)
+    }
+
+    pub async fn user_annotation(
+        &self,
+        user_id: String,
+    ) -> Result<Option<model::UserAnnotationItem>> {
+        self.ensure_active()?;
+        Ok(self
+            .database
+            .user_dao
+            .find_annotation(&user_id)
+            .await?
+            .map(Into::into))
+    }
+
+    /// Sets the local alias and private note of a user. Neither leaves this
+    /// device; clearing both removes them.
+    pub async fn set_user_annotation(
+        &self,
+        user_id: String,
+        alias: Option<String>,
+        note: Option<String>,
+    ) -> Result<()> {
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        self.database
+            .user_dao
+            .set_annotation(&user_id, alias.as_deref(), note.as_deref())
+            .await?;
+        self.notify_all_conversations_changed();
+        self.notify_messages_changed();
+        Ok(())
+    }
+
     pub async fn search_local_users(
         &self,
         query: String,
@@ -340,3 +418,103 @@ impl UserAccess {
             .filter(|uri| !uri.trim().is_empty()))
     }
 }
+
+/// Lowercases `name`, strips diacritics and spells Chinese characters in
+/// pinyin so that mixed Chinese and latin names sort together.
+fn contact_sort_key(name: &str) -> String {
+    let mut spelled = String::with_capacity(name.len());
+    for (character, pinyin) in name.trim().chars().zip(name.trim().to_pinyin()) {
+        match pinyin {
+            Some(pinyin) => spelled.push_str(pinyin.plain()),
+            None => spelled.push(character),
+        }
+    }
+    spelled
+        .nfkd()
+        .filter(|character| !is_combining_mark(*character))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn group_contacts(mut contacts: Vec<model::ContactItem>) -> Vec<model::ContactGroup> {
+    contacts.sort_by(|a, b| {
+        a.sort_key
+            .cmp(&b.sort_key)
+            .then_with(|| a.user.user_id.cmp(&b.user.user_id))
+    });
+    let mut groups = BTreeMap::<String, Vec<model::ContactItem>>::new();
+    for contact in contacts {
+        let index = match contact.sort_key.chars().next() {
+            Some(first) if first.is_ascii_lowercase() => first.to_ascii_uppercase().to_string(),
+            _ => "#".to_string(),
+        };
+        groups.entry(index).or_default().push(contact);
+    }
+    let other = groups.remove("#");
+    groups
+        .into_iter()
+        .chain(other.map(|contacts| ("#".to_string(), contacts)))
+        .map(|(index, contacts)| model::ContactGroup { index, contacts })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact(user_id: &str, display_name: &str) -> model::ContactItem {
+        model::ContactItem {
+            user: model::UserProfileItem {
+                user_id: user_id.to_string(),
+                identity_number: String::new(),
+                full_name: display_name.to_string(),
+                avatar_url: String::new(),
+                biography: String::new(),
+                is_verified: false,
+                is_bot: false,
+                relationship: "FRIEND".to_string(),
+                code_url: String::new(),
+                membership: None,
+            },
+            alias: None,
+            display_name: display_name.to_string(),
+            sort_key: contact_sort_key(display_name),
+        }
+    }
+
+    #[test]
+    fn contacts_sort_chinese_names_by_pinyin() {
+        assert_eq!(contact_sort_key("张三"), "zhangsan");
+        assert_eq!(contact_sort_key(" Émile 李"), "emile li");
+        assert_eq!(contact_sort_key("Ørjan Çelik"), "ørjan celik");
+
+        let groups = group_contacts(vec![
+            contact("1", "张三"),
+            contact("2", "Zoe"),
+            contact("3", "阿明"),
+            contact("4", "42"),
+            contact("5", "bob"),
+            contact("6", "Émile"),
+        ]);
+        assert_eq!(
+            groups
+                .iter()
+                .map(|group| (
+                    group.index.as_str(),
+                    group
+                        .contacts
+                        .iter()
+                        .map(|contact| contact.display_name.as_str())
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("A", vec!["阿明"]),
+                ("B", vec!["bob"]),
+                ("E", vec!["Émile"]),
+                ("Z", vec!["张三", "Zoe"]),
+                ("#", vec!["42"]),
+            ]
+        );
+    }
+}
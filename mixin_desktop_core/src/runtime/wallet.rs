@@ -0,0 +1,581 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Result};
+use chrono::Utc;
+use log::warn;
+use sdk::safe::parse_amount;
+use sdk::{
+    InscriptionCollection, InscriptionItem, SafeSnapshotShot, SafeUtxo, SpendKey, TransferRequest,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::core::mixin_uri::MixinUri;
+use crate::db::mixin::asset::TokenDetail;
+
+use super::{model, AccountState};
+
+pub struct WalletAccess {
+    state: Arc<AccountState>,
+}
+
+impl WalletAccess {
+    pub(crate) fn new(state: Arc<AccountState>) -> Self {
+        Self { state }
+    }
+}
+
+impl Deref for WalletAccess {
+    type Target = AccountState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl WalletAccess {
+    /// Keeps the spend key in memory until [`WalletAccess::lock_spend_key`]
+    /// or shutdown. The key must match the account's spend public key.
+    pub fn unlock_spend_key(&self, spend_key: String) -> Result<()> {
+        self.ensure_active()?;
+        let key = SpendKey::from_hex(&spend_key)?;
+        let expected = self.profile.borrow().spend_public_key.clone();
+        if expected.is_empty() {
+            bail!("account has no spend key");
+        }
+        if !key.public_key_hex().eq_ignore_ascii_case(&expected) {
+            bail!("spend key does not belong to this account");
+        }
+        *self.unlocked_spend_key() = Some(key);
+        Ok(())
+    }
+
+    pub fn lock_spend_key(&self) {
+        self.unlocked_spend_key().take();
+    }
+
+    pub fn is_spend_key_unlocked(&self) -> bool {
+        self.unlocked_spend_key().is_some()
+    }
+
+    /// Checks a payment code against the local token and chain tables,
+    /// fetching them when missing, and prices it in the account's fiat
+    /// currency.
+    pub async fn preview_payment(&self, code: String) -> Result<model::PaymentPreview> {
+        let code = payment_code_id(&code)?;
+        self.ensure_active()?;
+        let value = self.client.account_api.code(&code).await?;
+        let request = payment_transfer(&value)?;
+        self.refresh_fiats().await;
+        let token = self.payment_token(&request).await?;
+        let fiat_value = fiat_value(&request.amount, &token.price_usd, token.fiat_rate);
+        Ok(model::PaymentPreview {
+            trace_id: request.trace_id,
+            asset_id: request.asset_id,
+            asset_symbol: token.symbol,
+            asset_name: token.name,
+            asset_icon_url: token.icon_url,
+            chain_name: token.chain_name.unwrap_or_default(),
+            chain_icon_url: token.chain_icon_url.unwrap_or_default(),
+            amount: request.amount,
+            receivers: request.receivers,
+            threshold: i64::from(request.threshold),
+            memo: request.memo,
+            confirmations: token.chain_threshold.unwrap_or_default(),
+            fiat_currency: self.profile.borrow().fiat_currency.clone(),
+            fiat_value,
+            state: value
+                .get("status")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        })
+    }
+
+    /// Pays a `payment` code. The code is fetched again so the amount and
+    /// receivers cannot change after [`WalletAccess::preview_payment`]
+    /// showed them, and its trace id keeps a retried payment from paying
+    /// twice. The payment stays `pending` until its snapshot arrives.
+    pub async fn pay_code(&self, code: String) -> Result<model::PaymentState> {
+        let code = payment_code_id(&code)?;
+        let spend_key = self
+            .unlocked_spend_key()
+            .clone()
+            .ok_or_else(|| anyhow!("spend key is locked"))?;
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let value = self.client.account_api.code(&code).await?;
+        if value.get("status").and_then(Value::as_str) == Some("paid") {
+            bail!("payment is already paid");
+        }
+        let request = payment_transfer(&value)?;
+        self.payment_token(&request).await?;
+        let transaction = self.client.safe_api.transfer(&spend_key, &request).await?;
+        self.record_pending(&request, &transaction.transaction_hash, None)
+            .await?;
+        self.payment_state(request.trace_id).await
+    }
+
+    pub async fn payment_state(&self, trace_id: String) -> Result<model::PaymentState> {
+        self.ensure_active()?;
+        let fiat_currency = self.profile.borrow().fiat_currency.clone();
+        let snapshot = self
+            .database
+            .safe_snapshot_dao
+            .find_by_trace_id(&trace_id, &fiat_currency)
+            .await?;
+        Ok(match snapshot {
+            Some(snapshot) if snapshot.type_field == "pending" => model::PaymentState {
+                trace_id,
+                state: "pending".to_string(),
+                transaction_hash: Some(snapshot.transaction_hash),
+                snapshot_id: None,
+            },
+            Some(snapshot) => model::PaymentState {
+                trace_id,
+                state: "paid".to_string(),
+                transaction_hash: Some(snapshot.transaction_hash),
+                snapshot_id: Some(snapshot.snapshot_id),
+            },
+            None => model::PaymentState {
+                trace_id,
+                state: "unknown".to_string(),
+                transaction_hash: None,
+                snapshot_id: None,
+            },
+        })
+    }
+
+    /// The account's unspent inscriptions grouped by collection. Items and
+    /// collections missing locally are fetched and stored; those the API
+    /// cannot return are left out.
+    pub async fn inscriptions(&self) -> Result<Vec<model::InscriptionCollectionView>> {
+        self.ensure_active()?;
+        let mut collections = BTreeMap::<String, model::InscriptionCollectionView>::new();
+        for output in self.inscription_outputs().await? {
+            let Some(hash) = output.inscription_hash.as_deref() else {
+                continue;
+            };
+            let item = match self.inscription_item(hash).await {
+                Ok(item) => item,
+                Err(error) => {
+                    warn!("failed to get inscription {hash}: {error}");
+                    continue;
+                }
+            };
+            let collection = match collections.entry(item.collection_hash.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let collection = match self.inscription_collection(entry.key()).await {
+                        Ok(collection) => collection,
+                        Err(error) => {
+                            warn!(
+                                "failed to get inscription collection {}: {error}",
+                                entry.key()
+                            );
+                            continue;
+                        }
+                    };
+                    entry.insert(model::InscriptionCollectionView {
+                        collection_hash: collection.collection_hash,
+                        name: collection.name,
+                        symbol: collection.symbol,
+                        icon_url: collection.icon_url,
+                        supply: collection.supply,
+                        unit: collection.unit,
+                        items: Vec::new(),
+                    })
+                }
+            };
+            collection.items.push(model::InscriptionView {
+                inscription_hash: item.inscription_hash,
+                collection_hash: item.collection_hash,
+                sequence: item.sequence,
+                content_type: item.content_type,
+                content_url: item.content_url,
+                output_id: output.output_id,
+                amount: output.amount,
+            });
+        }
+        let mut collections = collections.into_values().collect::<Vec<_>>();
+        for collection in &mut collections {
+            collection.items.sort_by_key(|item| item.sequence);
+        }
+        collections.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        Ok(collections)
+    }
+
+    /// The local path of an inscription's artwork, downloaded into the media
+    /// cache on first use.
+    pub async fn inscription_artwork(&self, inscription_hash: String) -> Result<String> {
+        self.ensure_active()?;
+        let item = self.inscription_item(&inscription_hash).await?;
+        if item.content_url.is_empty() {
+            bail!("inscription {inscription_hash} has no content");
+        }
+        let path = self
+            .app_service
+            .attachment
+            .cache_public(&self.network_service, &item.content_url, "Inscriptions")
+            .await?;
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    /// Transfers of an inscription recorded in the safe snapshots, newest
+    /// first, including one still pending.
+    pub async fn inscription_history(
+        &self,
+        inscription_hash: String,
+    ) -> Result<Vec<model::SnapshotDetailItem>> {
+        self.ensure_active()?;
+        let (fiat_currency, full_name) = {
+            let profile = self.profile.borrow();
+            (
+                profile.fiat_currency.clone(),
+                profile.full_name.clone().unwrap_or_default(),
+            )
+        };
+        let snapshots = self
+            .database
+            .safe_snapshot_dao
+            .find_by_inscription_hash(&inscription_hash, &fiat_currency)
+            .await?;
+        Ok(snapshots
+            .into_iter()
+            .map(|detail| {
+                model::SnapshotDetailItem::from_safe_detail(detail, full_name.clone(), None)
+            })
+            .collect())
+    }
+
+    /// Sends the whole output holding an inscription to `user_id`. It stays
+    /// `pending` in [`WalletAccess::inscription_history`] until its snapshot
+    /// arrives.
+    pub async fn send_inscription(
+        &self,
+        inscription_hash: String,
+        user_id: String,
+    ) -> Result<model::PaymentState> {
+        let user_id = user_id.trim().to_owned();
+        if Uuid::parse_str(&user_id).is_err() {
+            bail!("invalid user id");
+        }
+        let spend_key = self
+            .unlocked_spend_key()
+            .clone()
+            .ok_or_else(|| anyhow!("spend key is locked"))?;
+        let _mutation = self.mutation_gate.read().await;
+        self.ensure_active()?;
+        let output = self
+            .inscription_outputs()
+            .await?
+            .into_iter()
+            .find(|output| output.inscription_hash.as_deref() == Some(inscription_hash.as_str()))
+            .ok_or_else(|| anyhow!("inscription is not owned by this account"))?;
+        let asset_id = output
+            .asset_id
+            .clone()
+            .ok_or_else(|| anyhow!("inscription output has no asset id"))?;
+        let request = TransferRequest {
+            trace_id: Uuid::new_v4().to_string(),
+            asset_id,
+            amount: output.amount.clone(),
+            receivers: vec![user_id],
+            threshold: 1,
+            memo: String::new(),
+        };
+        let transaction = self
+            .client
+            .safe_api
+            .transfer_outputs(&spend_key, &request, std::slice::from_ref(&output))
+            .await?;
+        self.record_pending(
+            &request,
+            &transaction.transaction_hash,
+            Some(inscription_hash),
+        )
+        .await?;
+        self.payment_state(request.trace_id).await
+    }
+
+    /// Records a submitted transfer as `pending` unless its snapshot is
+    /// already known.
+    async fn record_pending(
+        &self,
+        request: &TransferRequest,
+        transaction_hash: &str,
+        inscription_hash: Option<String>,
+    ) -> Result<()> {
+        let dao = &self.database.safe_snapshot_dao;
+        let fiat_currency = self.profile.borrow().fiat_currency.clone();
+        if dao
+            .find_by_trace_id(&request.trace_id, &fiat_currency)
+            .await?
+            .is_some()
+        {
+            return Ok(());
+        }
+        let mut snapshot = pending_snapshot(&self.account_id, request, transaction_hash);
+        snapshot.inscription_hash = inscription_hash;
+        dao.insert(&snapshot).await?;
+        // The snapshot may have been processed in between.
+        let settled = dao
+            .find_by_trace_id(&request.trace_id, &fiat_currency)
+            .await?
+            .is_some_and(|snapshot| snapshot.type_field != "pending");
+        if settled {
+            dao.delete_pending_snapshot_by_hash(transaction_hash)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn inscription_outputs(&self) -> Result<Vec<SafeUtxo>> {
+        let members = [self.account_id.clone()];
+        let outputs = self
+            .client
+            .safe_api
+            .outputs(&members, 1, None, 500)
+            .collect()
+            .await?;
+        Ok(outputs
+            .into_iter()
+            .filter(|output| output.inscription_hash.is_some())
+            .collect())
+    }
+
+    async fn inscription_item(&self, hash: &str) -> Result<InscriptionItem> {
+        let dao = &self.database.inscription_dao;
+        if let Some(item) = dao.find_item(hash).await? {
+            return Ok(item);
+        }
+        let item = self.client.token_api.get_inscription_item(hash).await?;
+        dao.insert_item(&item).await?;
+        Ok(item)
+    }
+
+    async fn inscription_collection(&self, hash: &str) -> Result<InscriptionCollection> {
+        let dao = &self.database.inscription_dao;
+        if let Some(collection) = dao.find_collection(hash).await? {
+            return Ok(collection);
+        }
+        let collection = self
+            .client
+            .token_api
+            .get_inscription_collection(hash)
+            .await?;
+        dao.insert_collection(&collection).await?;
+        Ok(collection)
+    }
+
+    /// The local token of a payment, validating the amount against it.
+    async fn payment_token(&self, request: &TransferRequest) -> Result<TokenDetail> {
+        let fiat_currency = self.profile.borrow().fiat_currency.clone();
+        let dao = &self.database.asset_dao;
+        let token = match dao
+            .find_token_detail(&request.asset_id, &fiat_currency)
+            .await?
+            .filter(|token| token.chain_name.is_some())
+        {
+            Some(token) => token,
+            None => {
+                let token = self
+                    .client
+                    .token_api
+                    .get_asset_by_id(&request.asset_id)
+                    .await?;
+                let chain = self.client.asset_api.get_chain(&token.chain_id).await?;
+                dao.insert_chain(&chain).await?;
+                dao.insert_token(&token).await?;
+                dao.find_token_detail(&request.asset_id, &fiat_currency)
+                    .await?
+                    .ok_or_else(|| anyhow!("token {} not found", request.asset_id))?
+            }
+        };
+        validate_payment_amount(&request.amount, &token)?;
+        Ok(token)
+    }
+}
+
+fn payment_code_id(code: &str) -> Result<String> {
+    let code = match MixinUri::parse(code) {
+        Ok(MixinUri::Code { code }) => code,
+        _ => code.trim().to_owned(),
+    };
+    if code.is_empty() {
+        bail!("code is empty");
+    }
+    Ok(code)
+}
+
+fn validate_payment_amount(amount: &str, token: &TokenDetail) -> Result<()> {
+    let amount = amount.trim();
+    if token.collection_hash.is_some() {
+        bail!(
+            "{} is a collectible and cannot be paid by amount",
+            token.symbol
+        );
+    }
+    if parse_amount(amount)? == 0 {
+        bail!("payment amount must be positive");
+    }
+    let decimals = amount
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.trim_end_matches('0').len());
+    if token.precision >= 0 && decimals as i64 > token.precision {
+        bail!(
+            "{} supports {} decimal places",
+            token.symbol,
+            token.precision
+        );
+    }
+    Ok(())
+}
+
+fn fiat_value(amount: &str, price_usd: &str, fiat_rate: Option<f64>) -> Option<String> {
+    let value = amount.trim().parse::<f64>().ok()? * price_usd.parse::<f64>().ok()? * fiat_rate?;
+    value.is_finite().then(|| format!("{value:.2}"))
+}
+
+/// The row tracking a submitted payment until its snapshot replaces it.
+fn pending_snapshot(
+    user_id: &str,
+    request: &TransferRequest,
+    transaction_hash: &str,
+) -> SafeSnapshotShot {
+    SafeSnapshotShot {
+        snapshot_id: request.trace_id.clone(),
+        type_field: "pending".to_string(),
+        asset_id: request.asset_id.clone(),
+        amount: format!("-{}", request.amount.trim()),
+        user_id: user_id.to_string(),
+        opponent_id: match request.receivers.as_slice() {
+            [receiver] => receiver.clone(),
+            _ => String::new(),
+        },
+        memo: request.memo.clone(),
+        transaction_hash: transaction_hash.to_string(),
+        created_at: Utc::now(),
+        trace_id: Some(request.trace_id.clone()),
+        confirmations: None,
+        opening_balance: None,
+        closing_balance: None,
+        withdrawal: None,
+        deposit: None,
+        deposit_hash: None,
+        inscription_hash: None,
+    }
+}
+
+fn payment_transfer(value: &Value) -> Result<TransferRequest> {
+    let string = |name: &str| {
+        value
+            .get(name)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("payment {name} is missing"))
+    };
+    if value.get("type").and_then(Value::as_str) != Some("payment") {
+        bail!("code is not a payment");
+    }
+    let receivers = value
+        .get("receivers")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if receivers.is_empty() {
+        bail!("payment has no receivers");
+    }
+    let threshold = value
+        .get("threshold")
+        .and_then(Value::as_u64)
+        .and_then(|threshold| u8::try_from(threshold).ok())
+        .ok_or_else(|| anyhow!("payment threshold is invalid"))?;
+    Ok(TransferRequest {
+        trace_id: string("trace_id")?,
+        asset_id: string("asset_id")?,
+        amount: string("amount")?,
+        receivers,
+        threshold,
+        memo: value
+            .get("memo")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn token(precision: i64) -> TokenDetail {
+        TokenDetail {
+            asset_id: "965e5c6e-434c-3fa9-b780-c50f43cd955c".to_string(),
+            symbol: "CNB".to_string(),
+            name: "Chui Niu Bi".to_string(),
+            icon_url: String::new(),
+            price_usd: "2".to_string(),
+            chain_id: "43d61dcd-e413-450d-80b8-101d5e903357".to_string(),
+            precision,
+            collection_hash: None,
+            chain_name: Some("Ethereum".to_string()),
+            chain_icon_url: None,
+            chain_threshold: Some(64),
+            fiat_rate: Some(7.0),
+        }
+    }
+
+    #[test]
+    fn payment_codes_become_transfers() {
+        let mut value = json!({
+            "type": "payment",
+            "trace_id": "7c7e1d52-6e8a-4f55-8c53-6b1bd7f0c3c9",
+            "asset_id": "965e5c6e-434c-3fa9-b780-c50f43cd955c",
+            "amount": "0.5",
+            "receivers": ["a", "b"],
+            "threshold": 2,
+            "memo": "order 42",
+            "status": "pending",
+        });
+        let request = payment_transfer(&value).unwrap();
+        assert_eq!(request.receivers, vec!["a", "b"]);
+        assert_eq!(request.threshold, 2);
+        assert_eq!(request.memo, "order 42");
+
+        let pending = pending_snapshot("me", &request, "hash");
+        assert_eq!(pending.type_field, "pending");
+        assert_eq!(pending.amount, "-0.5");
+        assert_eq!(pending.opponent_id, "");
+
+        value["receivers"] = json!([]);
+        assert!(payment_transfer(&value).is_err());
+        value["receivers"] = json!(["a"]);
+        value["type"] = json!("multisig_request");
+        assert!(payment_transfer(&value).is_err());
+    }
+
+    #[test]
+    fn payments_respect_token_precision_and_price_in_fiat() {
+        assert!(validate_payment_amount("1.50", &token(1)).is_ok());
+        assert!(validate_payment_amount("1.55", &token(1)).is_err());
+        assert!(validate_payment_amount("1.55", &token(-1)).is_ok());
+        assert!(validate_payment_amount("0", &token(-1)).is_err());
+        let mut collectible = token(0);
+        collectible.collection_hash = Some("hash".to_string());
+        assert!(validate_payment_amount("1", &collectible).is_err());
+
+        assert_eq!(fiat_value("1.5", "2", Some(7.0)).as_deref(), Some("21.00"));
+        assert_eq!(fiat_value("1.5", "2", None), None);
+    }
+}
@@ -8,11 +8,13 @@ use std::{
 
 use futures::{Stream, StreamExt as _};
 use mixin_desktop_api::{
-    AccountClient, AccountProfile, CircleItem, CodeResult, ConversationChangeEvent,
-    ConversationDetailItem, ConversationListData, ConversationParticipantItem,
-    ConversationStorageUsage, ConversationUnseenCount, DeviceTransferCommand,
-    GroupConversationItem, ImageMessageView, NotificationEvent, SharedAppItem, SnapshotDetailItem,
-    StickerDetailItem, StorageCategoryUsage, UserProfileItem,
+    AccountClient, AccountNotificationItem, AccountProfile, AccountSession, AccountUnreadCountItem,
+    CircleItem, CodeResult, ContactGroup, ConversationChangeEvent, ConversationDetailItem,
+    ConversationListData, ConversationParticipantItem, ConversationStorageUsage,
+    ConversationUnseenCount, DeviceTransferCommand, GroupConversationItem, ImageMessageView,
+    InscriptionCollectionView, LoginStatus, NotificationEvent, PaymentPreview, PaymentState,
+    SessionLinkedItem, SharedAppItem, SnapshotDetailItem, StickerDetailItem, StorageCategoryUsage,
+    UserAnnotationItem, UserProfileItem, VideoRangeItem,
 };
 use tokio::sync::{Mutex, Notify};
 
@@ -59,6 +61,26 @@ pub struct SwiftNotificationSubscription {
     inner: CancellableStream<Result<NotificationEvent, SwiftClientError>>,
 }
 
+#[derive(uniffi::Object)]
+pub struct SwiftAccountUnreadCountSubscription {
+    inner: CancellableStream<Vec<AccountUnreadCountItem>>,
+}
+
+#[derive(uniffi::Object)]
+pub struct SwiftAccountNotificationSubscription {
+    inner: CancellableStream<AccountNotificationItem>,
+}
+
+#[derive(uniffi::Object)]
+pub struct SwiftSessionLinkedSubscription {
+    inner: CancellableStream<SessionLinkedItem>,
+}
+
+#[derive(uniffi::Object)]
+pub struct SwiftLoginStatusSubscription {
+    inner: CancellableStream<LoginStatus>,
+}
+
 #[derive(uniffi::Object)]
 pub struct SwiftDeviceTransferSubscription {
     inner: CancellableStream<DeviceTransferEventItem>,
@@ -176,6 +198,78 @@ impl SwiftNotificationSubscription {
     }
 }
 
+impl SwiftAccountUnreadCountSubscription {
+    pub(crate) fn new(
+        stream: impl Stream<Item = Vec<AccountUnreadCountItem>> + Send + 'static,
+    ) -> Self {
+        Self {
+            inner: CancellableStream::new(stream),
+        }
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl SwiftAccountUnreadCountSubscription {
+    pub async fn next(&self) -> Option<Vec<AccountUnreadCountItem>> {
+        self.inner.next().await
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+}
+
+impl SwiftAccountNotificationSubscription {
+    pub(crate) fn new(
+        stream: impl Stream<Item = AccountNotificationItem> + Send + 'static,
+    ) -> Self {
+        Self {
+            inner: CancellableStream::new(stream),
+        }
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl SwiftAccountNotificationSubscription {
+    pub async fn next(&self) -> Option<AccountNotificationItem> {
+        self.inner.next().await
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl SwiftSessionLinkedSubscription {
+    pub async fn next(&self) -> Option<SessionLinkedItem> {
+        self.inner.next().await
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+}
+
+impl SwiftLoginStatusSubscription {
+    pub(crate) fn new(stream: impl Stream<Item = LoginStatus> + Send + 'static) -> Self {
+        Self {
+            inner: CancellableStream::new(stream),
+        }
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl SwiftLoginStatusSubscription {
+    pub async fn next(&self) -> Option<LoginStatus> {
+        self.inner.next().await
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+}
+
 #[uniffi::export(async_runtime = "tokio")]
 impl SwiftDeviceTransferSubscription {
     pub async fn next(&self) -> Option<DeviceTransferEventItem> {
@@ -252,6 +346,68 @@ impl SwiftAccountHandle {
         Ok(self.client.conversation().resolve_code(code).await?)
     }
 
+    pub fn unlock_spend_key(&self, spend_key: String) -> Result<(), SwiftClientError> {
+        Ok(self.client.wallet().unlock_spend_key(spend_key)?)
+    }
+
+    pub fn lock_spend_key(&self) {
+        self.client.wallet().lock_spend_key()
+    }
+
+    pub fn is_spend_key_unlocked(&self) -> bool {
+        self.client.wallet().is_spend_key_unlocked()
+    }
+
+    pub async fn preview_payment(&self, code: String) -> Result<PaymentPreview, SwiftClientError> {
+        Ok(self.client.wallet().preview_payment(code).await?)
+    }
+
+    pub async fn pay_code(&self, code: String) -> Result<PaymentState, SwiftClientError> {
+        Ok(self.client.wallet().pay_code(code).await?)
+    }
+
+    pub async fn payment_state(&self, trace_id: String) -> Result<PaymentState, SwiftClientError> {
+        Ok(self.client.wallet().payment_state(trace_id).await?)
+    }
+
+    pub async fn inscriptions(&self) -> Result<Vec<InscriptionCollectionView>, SwiftClientError> {
+        Ok(self.client.wallet().inscriptions().await?)
+    }
+
+    pub async fn inscription_artwork(
+        &self,
+        inscription_hash: String,
+    ) -> Result<String, SwiftClientError> {
+        Ok(self
+            .client
+            .wallet()
+            .inscription_artwork(inscription_hash)
+            .await?)
+    }
+
+    pub async fn inscription_history(
+        &self,
+        inscription_hash: String,
+    ) -> Result<Vec<SnapshotDetailItem>, SwiftClientError> {
+        Ok(self
+            .client
+            .wallet()
+            .inscription_history(inscription_hash)
+            .await?)
+    }
+
+    pub async fn send_inscription(
+        &self,
+        inscription_hash: String,
+        user_id: String,
+    ) -> Result<PaymentState, SwiftClientError> {
+        Ok(self
+            .client
+            .wallet()
+            .send_inscription(inscription_hash, user_id)
+            .await?)
+    }
+
     pub async fn join_group(&self, code: String) -> Result<String, SwiftClientError> {
         Ok(self.client.conversation().join_group(code).await?)
     }
@@ -467,6 +623,9 @@ impl SwiftAccountHandle {
                 query,
                 sender_id,
                 categories,
+                None,
+                None,
+                false,
                 anchor_message_id,
                 limit,
             )
@@ -485,7 +644,7 @@ impl SwiftAccountHandle {
         Ok(self
             .client
             .message()
-            .search_global_messages(query, anchor_message_id, limit)
+            .search_global_messages(query, None, None, false, anchor_message_id, limit)
             .await?
             .into_iter()
             .map(Into::into)
@@ -629,6 +788,19 @@ impl SwiftAccountHandle {
         Ok(self.client.attachment().mark_audio_read(message_id).await?)
     }
 
+    pub async fn read_video_range(
+        &self,
+        message_id: String,
+        offset: u64,
+        length: u64,
+    ) -> Result<VideoRangeItem, SwiftClientError> {
+        Ok(self
+            .client
+            .attachment()
+            .read_video_range(message_id, offset, length)
+            .await?)
+    }
+
     pub async fn retry_transcript_attachment(
         &self,
         transcript_id: String,
@@ -1240,6 +1412,34 @@ impl SwiftAccountHandle {
         Ok(self.client.user().selectable_users().await?)
     }
 
+    pub async fn contacts(&self) -> Result<Vec<ContactGroup>, SwiftClientError> {
+        Ok(self.client.user().contacts().await?)
+    }
+
+    pub async fn blocked_users(&self) -> Result<Vec<UserProfileItem>, SwiftClientError> {
+        Ok(self.client.user().blocked_users().await?)
+    }
+
+    pub async fn user_annotation(
+        &self,
+        user_id: String,
+    ) -> Result<Option<UserAnnotationItem>, SwiftClientError> {
+        Ok(self.client.user().user_annotation(user_id).await?)
+    }
+
+    pub async fn set_user_annotation(
+        &self,
+        user_id: String,
+        alias: Option<String>,
+        note: Option<String>,
+    ) -> Result<(), SwiftClientError> {
+        Ok(self
+            .client
+            .user()
+            .set_user_annotation(user_id, alias, note)
+            .await?)
+    }
+
     pub async fn search_mao_user(
         &self,
         query: String,
@@ -1364,6 +1564,20 @@ impl SwiftAccountHandle {
         Ok(self.client.user().report_user(user_id).await?)
     }
 
+    pub async fn linked_sessions(&self) -> Result<Vec<AccountSession>, SwiftClientError> {
+        Ok(self.client.linked_sessions().await?)
+    }
+
+    pub async fn revoke_linked_session(&self, session_id: String) -> Result<(), SwiftClientError> {
+        Ok(self.client.revoke_linked_session(session_id).await?)
+    }
+
+    pub fn session_linked_events(&self) -> SwiftSessionLinkedSubscription {
+        SwiftSessionLinkedSubscription {
+            inner: CancellableStream::new(self.client.session_linked_events()),
+        }
+    }
+
     pub async fn shutdown(&self) {
         self.client.shutdown().await;
     }
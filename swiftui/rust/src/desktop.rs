@@ -1,11 +1,17 @@
 use std::sync::Arc;
 
 use mixin_desktop_api::{
-    DesktopClient, HttpResponseItem, McpServerStatusItem, McpSettingsItem, ProxySettingsItem,
+    DesktopClient, HttpResponseItem, McpServerStatusItem, McpSettingsItem, NotificationAction,
+    NotificationActionHandle, ProxySettingsItem,
 };
 
 use crate::{
-    account::SwiftAccountHandle, error::SwiftClientError, login::SwiftLoginHandle,
+    account::{
+        SwiftAccountHandle, SwiftAccountNotificationSubscription,
+        SwiftAccountUnreadCountSubscription,
+    },
+    error::SwiftClientError,
+    login::SwiftLoginHandle,
     media::SwiftMediaHandle,
 };
 
@@ -55,10 +61,62 @@ impl SwiftDesktopHandle {
         ))
     }
 
+    pub async fn restore_accounts(&self) -> Result<Vec<Arc<SwiftAccountHandle>>, SwiftClientError> {
+        Ok(self
+            .client
+            .restore_accounts()
+            .await?
+            .into_iter()
+            .map(|client| Arc::new(SwiftAccountHandle::new(client)))
+            .collect())
+    }
+
+    pub async fn accounts(&self) -> Vec<Arc<SwiftAccountHandle>> {
+        self.client
+            .accounts()
+            .await
+            .into_iter()
+            .map(|client| Arc::new(SwiftAccountHandle::new(client)))
+            .collect()
+    }
+
+    pub async fn active_account(&self) -> Option<Arc<SwiftAccountHandle>> {
+        let client = self.client.active_account().await?;
+        Some(Arc::new(SwiftAccountHandle::new(client)))
+    }
+
+    pub async fn switch_account(
+        &self,
+        account_id: String,
+    ) -> Result<SwiftAccountHandle, SwiftClientError> {
+        Ok(SwiftAccountHandle::new(
+            self.client.switch_account(account_id).await?,
+        ))
+    }
+
+    pub fn unread_count_changes(&self) -> SwiftAccountUnreadCountSubscription {
+        SwiftAccountUnreadCountSubscription::new(self.client.unread_count_changes())
+    }
+
+    pub fn notification_events(&self) -> SwiftAccountNotificationSubscription {
+        SwiftAccountNotificationSubscription::new(self.client.notification_events())
+    }
+
     pub async fn begin_login(&self) -> Result<SwiftLoginHandle, SwiftClientError> {
         Ok(SwiftLoginHandle::new(self.client.begin_login().await?))
     }
 
+    pub async fn perform_notification_action(
+        &self,
+        handle: NotificationActionHandle,
+        action: NotificationAction,
+    ) -> Result<(), SwiftClientError> {
+        Ok(self
+            .client
+            .perform_notification_action(handle, action)
+            .await?)
+    }
+
     pub async fn recreate_account_database(&self) -> Result<(), SwiftClientError> {
         Ok(self.client.recreate_account_database().await?)
     }
@@ -137,6 +195,21 @@ impl SwiftDesktopHandle {
         Ok(self.client.settings().mcp_server_status().await?)
     }
 
+    pub async fn mcp_account_id(&self) -> Result<Option<String>, SwiftClientError> {
+        Ok(self.client.settings().mcp_account_id().await?)
+    }
+
+    pub async fn set_mcp_account_id(
+        &self,
+        account_id: Option<String>,
+    ) -> Result<McpServerStatusItem, SwiftClientError> {
+        Ok(self
+            .client
+            .settings()
+            .set_mcp_account_id(account_id)
+            .await?)
+    }
+
     pub fn log_directory(&self) -> Result<String, SwiftClientError> {
         Ok(mixin_desktop_api::log_directory()?)
     }
@@ -1,8 +1,11 @@
 use std::sync::Arc;
 
-use mixin_desktop_api::LoginClient;
+use mixin_desktop_api::{LoginClient, LoginStatus};
 
-use crate::{account::SwiftAccountHandle, error::SwiftClientError};
+use crate::{
+    account::{SwiftAccountHandle, SwiftLoginStatusSubscription},
+    error::SwiftClientError,
+};
 
 #[derive(uniffi::Object)]
 pub struct SwiftLoginHandle {
@@ -23,6 +26,22 @@ impl SwiftLoginHandle {
         self.client.auth_url()
     }
 
+    pub fn qr_half_blocks(&self, invert: bool) -> Result<String, SwiftClientError> {
+        Ok(self.client.qr_half_blocks(invert)?)
+    }
+
+    pub fn qr_png(&self, scale: u32) -> Result<Vec<u8>, SwiftClientError> {
+        Ok(self.client.qr_png(scale)?)
+    }
+
+    pub fn status(&self) -> LoginStatus {
+        self.client.status()
+    }
+
+    pub fn status_changes(&self) -> SwiftLoginStatusSubscription {
+        SwiftLoginStatusSubscription::new(self.client.status_changes())
+    }
+
     pub fn cancel(&self) {
         self.client.cancel();
     }
@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 
 use mixin_desktop_api::{
-    AccountProfile, CircleItem, CodeResult, ConnectionFailedReason, ConversationChangeEvent,
+    AccountNotificationItem, AccountProfile, AccountSession, AccountUnreadCountItem, CircleItem,
+    CodeResult, ConnectionFailedReason, ContactGroup, ContactItem, ConversationChangeEvent,
     ConversationDetailItem, ConversationListData, ConversationParticipantItem,
     ConversationStorageUsage, ConversationUnseenCount, DeviceTransferCommand, DeviceTransferEvent,
-    GroupAvatar, GroupConversationItem, HttpResponseItem, ImageMessageView, McpServerStatusItem,
-    McpSettingsItem, MessageListView, NotificationEvent, ProxyItem, ProxySettingsItem,
-    SharedAppItem, SnapshotDetailItem, StickerAlbumItem, StickerDetailItem, StickerItem,
-    StorageCategoryUsage, UserProfileItem,
+    GroupAvatar, GroupConversationItem, HttpResponseItem, ImageMessageView,
+    InscriptionCollectionView, InscriptionView, LoginStatus, McpServerStatusItem, McpSettingsItem,
+    MessageListView, NotificationAction, NotificationActionHandle, NotificationEvent,
+    PaymentPreview, PaymentState, ProxyItem, ProxySettingsItem, SessionLinkedItem, SharedAppItem,
+    SnapshotDetailItem, StickerAlbumItem, StickerDetailItem, StickerItem, StorageCategoryUsage,
+    UserAnnotationItem, UserProfileItem, VideoRangeItem,
 };
 
 #[uniffi::remote(Record)]
@@ -45,6 +48,34 @@ pub struct AccountProfile {
     pub membership: Option<String>,
 }
 
+#[uniffi::remote(Record)]
+pub struct AccountSession {
+    pub session_id: String,
+    pub platform: Option<String>,
+    pub app_version: Option<String>,
+    pub last_seen_at: Option<String>,
+    pub is_current: bool,
+    pub is_primary: bool,
+}
+
+#[uniffi::remote(Record)]
+pub struct SessionLinkedItem {
+    pub session_id: String,
+    pub linked_at_micros: i64,
+}
+
+#[uniffi::remote(Record)]
+pub struct AccountUnreadCountItem {
+    pub account_id: String,
+    pub unseen_message_count: i64,
+}
+
+#[uniffi::remote(Record)]
+pub struct AccountNotificationItem {
+    pub account_id: String,
+    pub event: NotificationEvent,
+}
+
 #[uniffi::remote(Record)]
 pub struct NotificationEvent {
     pub message_id: String,
@@ -58,6 +89,30 @@ pub struct NotificationEvent {
     pub dismiss_message_id: Option<String>,
 }
 
+#[uniffi::remote(Record)]
+pub struct NotificationActionHandle {
+    pub account_id: String,
+    pub conversation_id: String,
+    pub message_id: String,
+}
+
+#[uniffi::remote(Enum)]
+pub enum NotificationAction {
+    Reply { text: String },
+    MarkRead,
+    MuteForOneHour,
+}
+
+#[uniffi::remote(Enum)]
+pub enum LoginStatus {
+    WaitingForScan { auth_url: String },
+    Verifying,
+    SyncingKeys,
+    Ready,
+    Expired,
+    Failed { message: String },
+}
+
 #[derive(uniffi::Enum)]
 pub enum ConnectionFailedReasonItem {
     VersionNotMatched,
@@ -249,6 +304,55 @@ pub struct CodeResult {
     pub action: Option<String>,
 }
 
+#[uniffi::remote(Record)]
+pub struct PaymentPreview {
+    pub trace_id: String,
+    pub asset_id: String,
+    pub asset_symbol: String,
+    pub asset_name: String,
+    pub asset_icon_url: String,
+    pub chain_name: String,
+    pub chain_icon_url: String,
+    pub amount: String,
+    pub receivers: Vec<String>,
+    pub threshold: i64,
+    pub memo: String,
+    pub confirmations: i64,
+    pub fiat_currency: String,
+    pub fiat_value: Option<String>,
+    pub state: Option<String>,
+}
+
+#[uniffi::remote(Record)]
+pub struct PaymentState {
+    pub trace_id: String,
+    pub state: String,
+    pub transaction_hash: Option<String>,
+    pub snapshot_id: Option<String>,
+}
+
+#[uniffi::remote(Record)]
+pub struct InscriptionView {
+    pub inscription_hash: String,
+    pub collection_hash: String,
+    pub sequence: i64,
+    pub content_type: String,
+    pub content_url: String,
+    pub output_id: String,
+    pub amount: String,
+}
+
+#[uniffi::remote(Record)]
+pub struct InscriptionCollectionView {
+    pub collection_hash: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon_url: String,
+    pub supply: String,
+    pub unit: String,
+    pub items: Vec<InscriptionView>,
+}
+
 #[uniffi::remote(Record)]
 pub struct SnapshotDetailItem {
     pub snapshot_id: String,
@@ -499,6 +603,27 @@ pub struct UserProfileItem {
     pub membership: Option<String>,
 }
 
+#[uniffi::remote(Record)]
+pub struct ContactItem {
+    pub user: UserProfileItem,
+    pub alias: Option<String>,
+    pub display_name: String,
+    pub sort_key: String,
+}
+
+#[uniffi::remote(Record)]
+pub struct ContactGroup {
+    pub index: String,
+    pub contacts: Vec<ContactItem>,
+}
+
+#[uniffi::remote(Record)]
+pub struct UserAnnotationItem {
+    pub user_id: String,
+    pub alias: Option<String>,
+    pub note: Option<String>,
+}
+
 #[uniffi::remote(Record)]
 pub struct ConversationDetailItem {
     pub conversation_id: String,
@@ -577,6 +702,14 @@ pub struct StorageCategoryUsage {
     pub size_bytes: i64,
 }
 
+#[uniffi::remote(Record)]
+pub struct VideoRangeItem {
+    pub data: Vec<u8>,
+    pub offset: u64,
+    pub total_size: u64,
+    pub mime_type: String,
+}
+
 #[uniffi::remote(Record)]
 pub struct ProxyItem {
     pub id: String,